@@ -0,0 +1,62 @@
+use prost::Message;
+use prost_reflect::{DynamicMessage, MessageDescriptor};
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::Status;
+
+/// A [Codec] for messages whose types are only known at runtime.
+///
+/// Requests are encoded from whatever [DynamicMessage] they hold; responses are decoded as the message
+/// type the codec was created with.
+#[derive(Debug, Clone)]
+pub(crate) struct DynamicCodec {
+  response: MessageDescriptor,
+}
+
+impl DynamicCodec {
+  pub(crate) const fn new(response: MessageDescriptor) -> Self {
+    Self { response }
+  }
+}
+
+impl Codec for DynamicCodec {
+  type Encode = DynamicMessage;
+  type Decode = DynamicMessage;
+  type Encoder = DynamicEncoder;
+  type Decoder = DynamicDecoder;
+
+  fn encoder(&mut self) -> Self::Encoder {
+    DynamicEncoder
+  }
+
+  fn decoder(&mut self) -> Self::Decoder {
+    DynamicDecoder(self.response.clone())
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicEncoder;
+
+impl Encoder for DynamicEncoder {
+  type Item = DynamicMessage;
+  type Error = Status;
+
+  fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+    item
+      .encode(dst)
+      .map_err(|e| Status::internal(format!("could not encode request: {}", e)))
+  }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DynamicDecoder(MessageDescriptor);
+
+impl Decoder for DynamicDecoder {
+  type Item = DynamicMessage;
+  type Error = Status;
+
+  fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+    DynamicMessage::decode(self.0.clone(), src)
+      .map(Some)
+      .map_err(|e| Status::internal(format!("could not decode response: {}", e)))
+  }
+}
@@ -0,0 +1,741 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use futures::channel::oneshot;
+use futures::{Stream, StreamExt};
+use http::uri::PathAndQuery;
+use prost_reflect::{DynamicMessage, MessageDescriptor, MethodDescriptor, ReflectMessage, SerializeOptions};
+use tokio::time::Instant;
+use tonic::client::Grpc;
+use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+use tonic::Status;
+use tracing::Span;
+use wick_config::config::components::GrpcClientComponentConfig;
+use wick_config::config::{Metadata, UrlResource};
+use wick_config::Resolver;
+use wick_interface_types::ComponentSignature;
+use wick_packet::{
+  Invocation,
+  InvocationContext,
+  Observer,
+  Packet,
+  PacketExt,
+  PacketSender,
+  PacketStream,
+  RuntimeConfig,
+};
+
+use crate::codec::DynamicCodec;
+use crate::{descriptor, Error};
+
+/// A component whose operations are the RPCs of a gRPC service.
+///
+/// Operations and their types are generated from the service definition when the component is created.
+/// Every operation takes request messages on its `input` port and sends response messages on its `output`
+/// port. Unary and server-streaming RPCs are called once per input packet, client-streaming and
+/// bidirectional RPCs stream the whole input as one call.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct GrpcClientComponent {
+  signature: ComponentSignature,
+  methods: HashMap<String, MethodDescriptor>,
+  channel: Channel,
+  metadata: MetadataMap,
+  timeout: Option<Duration>,
+}
+
+impl GrpcClientComponent {
+  /// Create a new component, generating its operations from the configured service definition.
+  ///
+  /// The connection to the server is made when the first call is.
+  #[allow(clippy::needless_pass_by_value)]
+  pub async fn new(
+    config: GrpcClientComponentConfig,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let addr: UrlResource = resolver(config.resource())
+      .and_then(|r| r.try_resource())
+      .and_then(|r| r.try_url())?;
+    let url = addr
+      .url()
+      .value()
+      .cloned()
+      .ok_or_else(|| Error::InvalidEndpoint(config.resource().to_string(), "unresolved URL".to_owned()))?;
+
+    let proto = config.proto();
+    let path = proto
+      .path()
+      .map_err(|e| Error::Definition(proto.location().to_owned(), e.to_string()))?;
+    let pool = descriptor::load(&path).await?;
+    let service = descriptor::find_service(&pool, config.service().map(String::as_str), proto.location())?;
+    let (operations, types) = descriptor::operations(&service);
+
+    let mut sig = ComponentSignature::new_named("wick/component/grpc");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = operations;
+    sig.types = types;
+    sig.config = config.config().to_vec();
+
+    let mut headers = MetadataMap::new();
+    for (key, value) in config.metadata() {
+      let name =
+        MetadataKey::from_bytes(key.as_bytes()).map_err(|e| Error::InvalidMetadata(key.clone(), e.to_string()))?;
+      let value: MetadataValue<_> = value
+        .parse()
+        .map_err(|_| Error::InvalidMetadata(key.clone(), "invalid value".to_owned()))?;
+      headers.insert(name, value);
+    }
+
+    let invalid = |e: tonic::transport::Error| Error::InvalidEndpoint(url.to_string(), e.to_string());
+    let mut endpoint = Endpoint::from_shared(url.to_string()).map_err(invalid)?;
+    if url.scheme() == "https" {
+      endpoint = endpoint.tls_config(ClientTlsConfig::new()).map_err(invalid)?;
+    }
+
+    Ok(Self {
+      signature: sig,
+      methods: service.methods().map(|m| (m.name().to_owned(), m)).collect(),
+      channel: endpoint.connect_lazy(),
+      metadata: headers,
+      timeout: config.timeout().map(Duration::from_millis),
+    })
+  }
+}
+
+impl Component for GrpcClientComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let op = invocation.target().operation_id().to_owned();
+    let method = self.methods.get(&op).cloned();
+    let channel = self.channel.clone();
+    let mut metadata = self.metadata.clone();
+    let timeout = self.timeout;
+
+    Box::pin(async move {
+      let Some(method) = method else {
+        return Err(Error::OpNotFound(op).into());
+      };
+      let (tx, rx) = invocation.make_response();
+      let span = invocation.span().clone();
+      propagate_context(&mut metadata, &span, invocation.inherent().context());
+      let deadline = invocation
+        .inherent()
+        .context()
+        .remaining()
+        .map(|remaining| Instant::now() + remaining);
+      let (_, stream) = invocation.split();
+
+      let mut call = Call {
+        grpc: Grpc::new(channel),
+        codec: DynamicCodec::new(method.output()),
+        path: rpc_path(&method)?,
+        metadata,
+        timeout,
+        deadline,
+      };
+
+      tokio::spawn(async move {
+        span.in_scope(|| debug!(method = method.full_name(), "grpc:client:call"));
+        let result = if method.is_client_streaming() {
+          call.streaming(&method, stream, &tx).await
+        } else {
+          call.each(&method, stream, &tx).await
+        };
+        if let Err(e) = result {
+          span.in_scope(|| error!(error = %e, "grpc:client"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+        let _ = tx.send(Packet::done("output"));
+      });
+      Ok(rx)
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+/// The RPCs made for one invocation.
+#[derive(Debug)]
+struct Call {
+  grpc: Grpc<Channel>,
+  codec: DynamicCodec,
+  path: PathAndQuery,
+  metadata: MetadataMap,
+  timeout: Option<Duration>,
+  deadline: Option<Instant>,
+}
+
+impl Call {
+  /// Call a unary or server-streaming RPC once for every input packet.
+  async fn each(
+    &mut self,
+    method: &MethodDescriptor,
+    mut stream: PacketStream,
+    tx: &PacketSender,
+  ) -> Result<(), Error> {
+    let message = method.input();
+    while let Some(packet) = stream.next().await {
+      let request = match Input::from_packet(packet, &message) {
+        Input::Request(request) => request,
+        Input::Skip => continue,
+        Input::Done => break,
+        Input::Invalid(e) => {
+          let _ = tx.send(Packet::err("output", e));
+          continue;
+        }
+      };
+      let result = if method.is_server_streaming() {
+        self.server_streaming(request, tx).await
+      } else {
+        self.unary(request, tx).await
+      };
+      if let Err(status) = result {
+        let _ = tx.send(Packet::err("output", Error::from(status).to_string()));
+      }
+    }
+    Ok(())
+  }
+
+  /// Call a client-streaming or bidirectional RPC with the whole input as its request stream.
+  ///
+  /// An invalid input cancels the call rather than ending the request stream early, so the server never
+  /// answers an incomplete request.
+  async fn streaming(
+    &mut self,
+    method: &MethodDescriptor,
+    stream: PacketStream,
+    tx: &PacketSender,
+  ) -> Result<(), Error> {
+    let (abort_tx, abort_rx) = oneshot::channel();
+    let requests = requests(stream, method.input(), abort_tx);
+    let server_streaming = method.is_server_streaming();
+
+    let result = tokio::select! {
+      result = self.stream_requests(requests, server_streaming, tx) => result.map_err(Error::from),
+      Ok(reason) = abort_rx => Err(reason),
+    };
+    if let Err(e) = result {
+      let _ = tx.send(Packet::err("output", e.to_string()));
+    }
+    Ok(())
+  }
+
+  async fn unary(&mut self, request: DynamicMessage, tx: &PacketSender) -> Result<(), Status> {
+    let timeout = self.timeout();
+    let request = self.request(request, timeout);
+    let response = within(timeout, async {
+      self.ready().await?;
+      self.grpc.unary(request, self.path.clone(), self.codec.clone()).await
+    })
+    .await?;
+    let _ = tx.send(output(response.get_ref()));
+    Ok(())
+  }
+
+  async fn server_streaming(&mut self, request: DynamicMessage, tx: &PacketSender) -> Result<(), Status> {
+    let timeout = self.timeout();
+    let request = self.request(request, timeout);
+    within(timeout, async {
+      self.ready().await?;
+      let mut responses = self
+        .grpc
+        .server_streaming(request, self.path.clone(), self.codec.clone())
+        .await?
+        .into_inner();
+      while let Some(response) = responses.message().await? {
+        let _ = tx.send(output(&response));
+      }
+      Ok(())
+    })
+    .await
+  }
+
+  async fn stream_requests<S>(&mut self, requests: S, server_streaming: bool, tx: &PacketSender) -> Result<(), Status>
+  where
+    S: Stream<Item = DynamicMessage> + Send + 'static,
+  {
+    let timeout = self.timeout();
+    let request = self.request(requests, timeout);
+    within(timeout, async {
+      self.ready().await?;
+      if server_streaming {
+        let mut responses = self
+          .grpc
+          .streaming(request, self.path.clone(), self.codec.clone())
+          .await?
+          .into_inner();
+        while let Some(response) = responses.message().await? {
+          let _ = tx.send(output(&response));
+        }
+      } else {
+        let response = self
+          .grpc
+          .client_streaming(request, self.path.clone(), self.codec.clone())
+          .await?;
+        let _ = tx.send(output(response.get_ref()));
+      }
+      Ok(())
+    })
+    .await
+  }
+
+  async fn ready(&mut self) -> Result<(), Status> {
+    self
+      .grpc
+      .ready()
+      .await
+      .map_err(|e| Status::unavailable(format!("could not connect: {}", e)))
+  }
+
+  /// How long the next call may take: the configured timeout, or less if the invocation's deadline comes
+  /// first.
+  fn timeout(&self) -> Option<Duration> {
+    let remaining = self
+      .deadline
+      .map(|deadline| deadline.saturating_duration_since(Instant::now()));
+    match (self.timeout, remaining) {
+      (Some(timeout), Some(remaining)) => Some(timeout.min(remaining)),
+      (timeout, remaining) => timeout.or(remaining),
+    }
+  }
+
+  fn request<T>(&self, message: T, timeout: Option<Duration>) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    *request.metadata_mut() = self.metadata.clone();
+    if let Some(timeout) = timeout {
+      request.set_timeout(timeout);
+    }
+    request
+  }
+}
+
+/// Run a call, failing with `DEADLINE_EXCEEDED` if it takes longer than the timeout.
+///
+/// The server is told the timeout too, this makes sure the call ends even if it doesn't honor it. A call
+/// that fails once the timeout has passed failed because of it, whatever status the server ended it with.
+async fn within<F, T>(timeout: Option<Duration>, call: F) -> Result<T, Status>
+where
+  F: Future<Output = Result<T, Status>> + Send,
+  T: Send,
+{
+  let Some(timeout) = timeout else {
+    return call.await;
+  };
+  let start = Instant::now();
+  let deadline_exceeded = || Status::deadline_exceeded(format!("no response within {:?}", timeout));
+  match tokio::time::timeout(timeout, call).await {
+    Ok(Err(_)) if start.elapsed() >= timeout => Err(deadline_exceeded()),
+    Ok(result) => result,
+    Err(_) => Err(deadline_exceeded()),
+  }
+}
+
+/// What a packet sent to an operation means for its RPC.
+#[derive(Debug)]
+enum Input {
+  /// A request message.
+  Request(DynamicMessage),
+  /// A packet that doesn't carry a request, like a bracket.
+  Skip,
+  /// The `input` port is done.
+  Done,
+  /// An error packet, or a value that isn't a valid request.
+  Invalid(String),
+}
+
+impl Input {
+  fn from_packet(packet: Result<Packet, wick_packet::Error>, message: &MessageDescriptor) -> Self {
+    let packet = match packet {
+      Ok(packet) => packet,
+      Err(e) => return Self::Invalid(e.to_string()),
+    };
+    if packet.port() != "input" {
+      return Self::Skip;
+    }
+    if packet.is_done() {
+      return Self::Done;
+    }
+    if packet.is_error() {
+      return Self::Invalid(packet.unwrap_err().msg().to_owned());
+    }
+    if !packet.has_data() {
+      return Self::Skip;
+    }
+    let request = packet
+      .decode_value()
+      .map_err(|e| e.to_string())
+      .and_then(|value| DynamicMessage::deserialize(message.clone(), value).map_err(|e| e.to_string()));
+    match request {
+      Ok(request) => Self::Request(request),
+      Err(e) => Self::Invalid(Error::InvalidInput(message.full_name().to_owned(), e).to_string()),
+    }
+  }
+}
+
+/// The request messages of a streaming call, one for every input packet.
+///
+/// If an input isn't valid, the reason is sent to `abort` and the stream never ends so the call can be
+/// cancelled instead of completed.
+fn requests(
+  stream: PacketStream,
+  message: MessageDescriptor,
+  abort: oneshot::Sender<Error>,
+) -> impl Stream<Item = DynamicMessage> + Send + 'static {
+  futures::stream::unfold(
+    (stream, message, Some(abort)),
+    |(mut stream, message, mut abort)| async move {
+      while let Some(packet) = stream.next().await {
+        match Input::from_packet(packet, &message) {
+          Input::Request(request) => return Some((request, (stream, message, abort))),
+          Input::Skip => continue,
+          Input::Done => return None,
+          Input::Invalid(reason) => {
+            if let Some(abort) = abort.take() {
+              let _ = abort.send(Error::InvalidInput(message.full_name().to_owned(), reason));
+            }
+            futures::future::pending::<()>().await;
+          }
+        }
+      }
+      None
+    },
+  )
+}
+
+/// Convert a response message to an `output` packet, using the protobuf JSON mapping with the field names
+/// from the service definition.
+fn output(message: &DynamicMessage) -> Packet {
+  let options = SerializeOptions::new()
+    .use_proto_field_name(true)
+    .stringify_64_bit_integers(false)
+    .skip_default_fields(false);
+  match message.serialize_with_options(serde_json::value::Serializer, &options) {
+    Ok(value) => Packet::encode("output", value),
+    Err(e) => Packet::err(
+      "output",
+      Error::InvalidOutput(message.descriptor().full_name().to_owned(), e.to_string()).to_string(),
+    ),
+  }
+}
+
+fn rpc_path(method: &MethodDescriptor) -> Result<PathAndQuery, Error> {
+  let path = format!("/{}/{}", method.parent_service().full_name(), method.name());
+  PathAndQuery::try_from(path.as_str()).map_err(|e| Error::Definition(path, e.to_string()))
+}
+
+/// Forward the W3C trace context of the invocation as request metadata.
+///
+/// The active span's context is preferred. If it isn't being exported, the `traceparent` and
+/// `tracestate` from the invocation context are passed along as-is.
+fn propagate_context(metadata: &mut MetadataMap, span: &Span, context: &InvocationContext) {
+  let trace = wick_logger::propagation::trace_context(span).or_else(|| {
+    context
+      .traceparent()
+      .map(|traceparent| (traceparent.to_owned(), context.tracestate().map(ToOwned::to_owned)))
+  });
+  let Some((traceparent, tracestate)) = trace else {
+    return;
+  };
+  if let Ok(value) = traceparent.parse() {
+    metadata.insert(InvocationContext::TRACEPARENT, value);
+  }
+  if let Some(Ok(value)) = tracestate.map(|v| v.parse()) {
+    metadata.insert(InvocationContext::TRACESTATE, value);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::convert::Infallible;
+  use std::path::Path;
+  use std::pin::Pin;
+  use std::task::{Context, Poll};
+
+  use anyhow::Result;
+  use futures::stream::BoxStream;
+  use pretty_assertions::assert_eq;
+  use serde_json::{json, Value};
+  use tonic::body::BoxBody;
+  use tonic::codec::{ProstCodec, Streaming};
+  use tonic::codegen::Service;
+  use tonic::server::NamedService;
+  use tonic::transport::{Body, Server};
+  use tonic::{Request, Response};
+  use url::Url;
+  use wick_config::config::components::GrpcClientComponentConfigBuilder;
+  use wick_config::config::{AppConfiguration, ResourceDefinition};
+  use wick_config::AssetReference;
+  use wick_packet::{packet_stream, Entity};
+
+  use super::*;
+
+  #[derive(Clone, PartialEq, prost::Message)]
+  struct HelloRequest {
+    #[prost(string, tag = "1")]
+    name: String,
+    #[prost(uint32, tag = "2")]
+    times: u32,
+  }
+
+  #[derive(Clone, PartialEq, prost::Message)]
+  struct HelloReply {
+    #[prost(string, tag = "1")]
+    message: String,
+  }
+
+  type Replies = BoxStream<'static, Result<HelloReply, Status>>;
+
+  async fn say_hello(request: Request<HelloRequest>) -> Result<Response<HelloReply>, Status> {
+    let greeting = request
+      .metadata()
+      .get("x-greeting")
+      .and_then(|v| v.to_str().ok())
+      .unwrap_or("Hello")
+      .to_owned();
+    let request = request.into_inner();
+    match request.name.as_str() {
+      "" => Err(Status::invalid_argument("name is required")),
+      "sleepy" => {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Ok(Response::new(HelloReply::default()))
+      }
+      name => Ok(Response::new(HelloReply {
+        message: format!("{}, {}!", greeting, name),
+      })),
+    }
+  }
+
+  #[allow(clippy::unused_async)]
+  async fn say_hellos(request: Request<HelloRequest>) -> Result<Response<Replies>, Status> {
+    let request = request.into_inner();
+    let replies = (1..=request.times).map(move |i| {
+      Ok(HelloReply {
+        message: format!("Hello #{}, {}!", i, request.name),
+      })
+    });
+    Ok(Response::new(futures::stream::iter(replies).boxed()))
+  }
+
+  async fn count_names(request: Request<Streaming<HelloRequest>>) -> Result<Response<HelloReply>, Status> {
+    let mut requests = request.into_inner();
+    let mut names = Vec::new();
+    while let Some(request) = requests.message().await? {
+      names.push(request.name);
+    }
+    Ok(Response::new(HelloReply {
+      message: format!("{} names: {}", names.len(), names.join(", ")),
+    }))
+  }
+
+  #[allow(clippy::unused_async)]
+  async fn chat(request: Request<Streaming<HelloRequest>>) -> Result<Response<Replies>, Status> {
+    let replies = request.into_inner().map(|request| {
+      request.map(|request| HelloReply {
+        message: format!("Hello, {}!", request.name),
+      })
+    });
+    Ok(Response::new(replies.boxed()))
+  }
+
+  /// Adapts an async fn to the service tonic expects for each RPC.
+  struct Handler<F>(F);
+
+  impl<F, R, Fut, M> Service<Request<R>> for Handler<F>
+  where
+    F: FnMut(Request<R>) -> Fut,
+    Fut: Future<Output = Result<Response<M>, Status>>,
+  {
+    type Response = Response<M>;
+    type Error = Status;
+    type Future = Fut;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+      Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<R>) -> Self::Future {
+      (self.0)(request)
+    }
+  }
+
+  /// The `test.Greeter` service from `tests/greeter.proto`, written by hand against tonic's server API.
+  #[derive(Debug, Clone, Copy)]
+  struct Greeter;
+
+  impl NamedService for Greeter {
+    const NAME: &'static str = "test.Greeter";
+  }
+
+  impl Service<http::Request<Body>> for Greeter {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+      Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+      Box::pin(async move {
+        let mut grpc = tonic::server::Grpc::new(ProstCodec::<HelloReply, HelloRequest>::default());
+        let response = match request.uri().path() {
+          "/test.Greeter/SayHello" => grpc.unary(Handler(say_hello), request).await,
+          "/test.Greeter/SayHellos" => grpc.server_streaming(Handler(say_hellos), request).await,
+          "/test.Greeter/CountNames" => grpc.client_streaming(Handler(count_names), request).await,
+          "/test.Greeter/Chat" => grpc.streaming(Handler(chat), request).await,
+          _ => Status::unimplemented("no such method").to_http(),
+        };
+        Ok(response)
+      })
+    }
+  }
+
+  async fn serve() -> Result<Url> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let url = Url::parse(&format!("http://{}", listener.local_addr()?))?;
+    let incoming = tokio_stream::wrappers::TcpListenerStream::new(listener);
+    tokio::spawn(Server::builder().add_service(Greeter).serve_with_incoming(incoming));
+    Ok(url)
+  }
+
+  async fn component(url: Url) -> Result<GrpcClientComponent> {
+    let proto = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/greeter.proto");
+    let config = GrpcClientComponentConfigBuilder::default()
+      .resource("base")
+      .proto(AssetReference::new(proto.to_string_lossy()))
+      .timeout(Some(500_u64))
+      .metadata(HashMap::from([("x-greeting".to_owned(), "Howdy".to_owned())]))
+      .build()?;
+    let mut app_config = AppConfiguration::default();
+    app_config.add_resource("base", ResourceDefinition::Url(UrlResource::new(url)));
+    GrpcClientComponent::new(config, None, &app_config.resolver()).await
+  }
+
+  /// Invoke an operation, returning the values and errors it sent on its `output` port.
+  async fn invoke(comp: &GrpcClientComponent, op: &str, packets: PacketStream) -> Result<Vec<Result<Value, String>>> {
+    let invocation = Invocation::test("grpc_test", Entity::local(op), packets, Default::default())?;
+    let stream = comp
+      .handle(invocation, Default::default(), Default::default())
+      .await?
+      .collect::<Vec<_>>()
+      .await;
+    let mut outputs = Vec::new();
+    for packet in stream {
+      let packet = packet?;
+      if packet.is_error() {
+        outputs.push(Err(packet.unwrap_err().msg().to_owned()));
+      } else if packet.has_data() {
+        outputs.push(Ok(packet.decode_value()?));
+      }
+    }
+    Ok(outputs)
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_unary() -> Result<()> {
+    let comp = component(serve().await?).await?;
+    let outputs = invoke(
+      &comp,
+      "SayHello",
+      packet_stream!(("input", json!({"name": "Ada"})), ("input", json!({"name": ""}))),
+    )
+    .await?;
+    assert_eq!(
+      outputs,
+      vec![
+        Ok(json!({"message": "Howdy, Ada!"})),
+        Err("InvalidArgument: name is required".to_owned())
+      ]
+    );
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_server_streaming() -> Result<()> {
+    let comp = component(serve().await?).await?;
+    let outputs = invoke(
+      &comp,
+      "SayHellos",
+      packet_stream!(("input", json!({"name": "Ada", "times": 2}))),
+    )
+    .await?;
+    assert_eq!(
+      outputs,
+      vec![
+        Ok(json!({"message": "Hello #1, Ada!"})),
+        Ok(json!({"message": "Hello #2, Ada!"}))
+      ]
+    );
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_client_streaming() -> Result<()> {
+    let comp = component(serve().await?).await?;
+    let outputs = invoke(
+      &comp,
+      "CountNames",
+      packet_stream!(("input", json!({"name": "Ada"})), ("input", json!({"name": "Grace"}))),
+    )
+    .await?;
+    assert_eq!(outputs, vec![Ok(json!({"message": "2 names: Ada, Grace"}))]);
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_bidi_streaming() -> Result<()> {
+    let comp = component(serve().await?).await?;
+    let outputs = invoke(
+      &comp,
+      "Chat",
+      packet_stream!(("input", json!({"name": "Ada"})), ("input", json!({"name": "Grace"}))),
+    )
+    .await?;
+    assert_eq!(
+      outputs,
+      vec![
+        Ok(json!({"message": "Hello, Ada!"})),
+        Ok(json!({"message": "Hello, Grace!"}))
+      ]
+    );
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_invalid_input() -> Result<()> {
+    let comp = component(serve().await?).await?;
+    let outputs = invoke(
+      &comp,
+      "CountNames",
+      packet_stream!(
+        ("input", json!({"name": "Ada"})),
+        ("input", json!({"nickname": "Grace"}))
+      ),
+    )
+    .await?;
+    assert_eq!(outputs.len(), 1);
+    assert!(outputs[0]
+      .as_ref()
+      .is_err_and(|e| e.starts_with("Invalid input for test.HelloRequest")));
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_timeout() -> Result<()> {
+    let comp = component(serve().await?).await?;
+    let outputs = invoke(&comp, "SayHello", packet_stream!(("input", json!({"name": "sleepy"})))).await?;
+    assert_eq!(outputs.len(), 1);
+    assert!(outputs[0].as_ref().is_err_and(|e| e.starts_with("DeadlineExceeded")));
+    Ok(())
+  }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use prost_reflect::{DescriptorPool, EnumDescriptor, FieldDescriptor, Kind, MessageDescriptor, ServiceDescriptor};
+use wick_interface_types::{
+  EnumDefinition,
+  EnumVariant,
+  Field,
+  OperationSignature,
+  StructDefinition,
+  Type,
+  TypeDefinition,
+};
+
+use crate::Error;
+
+/// Load a service definition from a `.proto` file, compiled together with the files it imports, or from
+/// an encoded `FileDescriptorSet`.
+pub(crate) async fn load(path: &Path) -> Result<DescriptorPool, Error> {
+  let error = |e: String| Error::Definition(path.display().to_string(), e);
+
+  if path.extension().map_or(false, |ext| ext == "proto") {
+    let include = path.parent().unwrap_or_else(|| Path::new("."));
+    let mut compiler = protox::Compiler::new([include]).map_err(|e| error(e.to_string()))?;
+    compiler.include_imports(true);
+    compiler.open_file(path).map_err(|e| error(e.to_string()))?;
+    Ok(compiler.descriptor_pool())
+  } else {
+    let bytes = tokio::fs::read(path).await.map_err(|e| error(e.to_string()))?;
+    DescriptorPool::decode(bytes.as_slice()).map_err(|e| error(e.to_string()))
+  }
+}
+
+/// Find the service to call: the one named in the configuration, or the only one defined.
+pub(crate) fn find_service(pool: &DescriptorPool, name: Option<&str>, source: &str) -> Result<ServiceDescriptor, Error> {
+  if let Some(name) = name {
+    return pool
+      .get_service_by_name(name)
+      .ok_or_else(|| Error::ServiceNotFound(name.to_owned(), source.to_owned()));
+  }
+  let services: Vec<_> = pool.services().collect();
+  match services.as_slice() {
+    [service] => Ok(service.clone()),
+    _ => Err(Error::AmbiguousService(source.to_owned(), services.len())),
+  }
+}
+
+/// Generate an operation for every RPC of a service, along with the types of the messages they use.
+///
+/// Each operation takes its request messages on the `input` port and sends its response messages on the
+/// `output` port, whether the RPC streams them or not.
+pub(crate) fn operations(service: &ServiceDescriptor) -> (Vec<OperationSignature>, Vec<TypeDefinition>) {
+  let mut types = TypeGenerator::default();
+  let operations = service
+    .methods()
+    .map(|method| {
+      OperationSignature::new(
+        method.name(),
+        vec![Field::new("input", types.message(&method.input()))],
+        vec![Field::new("output", types.message(&method.output()))],
+        Vec::new(),
+      )
+    })
+    .collect();
+  (operations, types.definitions)
+}
+
+/// Converts protobuf messages and enums to wick types, collecting a definition for each one it names.
+#[derive(Debug, Default)]
+struct TypeGenerator {
+  seen: HashSet<String>,
+  definitions: Vec<TypeDefinition>,
+}
+
+impl TypeGenerator {
+  /// The type of a message, matching its protobuf JSON mapping.
+  fn message(&mut self, message: &MessageDescriptor) -> Type {
+    match message.full_name() {
+      "google.protobuf.Timestamp" => return Type::Datetime,
+      "google.protobuf.Duration" | "google.protobuf.FieldMask" => return Type::String,
+      "google.protobuf.Struct" | "google.protobuf.Value" | "google.protobuf.Any" => return Type::Object,
+      "google.protobuf.ListValue" => return Type::List { ty: Box::new(Type::Object) },
+      "google.protobuf.Empty" => {}
+      // Wrapper types are represented by the value they wrap.
+      name if name.starts_with("google.protobuf.") && name.ends_with("Value") => {
+        if let Some(value) = message.get_field_by_name("value") {
+          return self.scalar(&value);
+        }
+      }
+      _ => {}
+    }
+
+    let name = message.full_name().to_owned();
+    // Recursive messages only need to be defined once.
+    if self.seen.insert(name.clone()) {
+      let fields = message
+        .fields()
+        .map(|field| Field::new(field.name(), self.field(&field)))
+        .collect();
+      self
+        .definitions
+        .push(TypeDefinition::Struct(StructDefinition::new(&name, fields, None)));
+    }
+    Type::Named(name)
+  }
+
+  fn enumeration(&mut self, enumeration: &EnumDescriptor) -> Type {
+    let name = enumeration.full_name().to_owned();
+    if self.seen.insert(name.clone()) {
+      let variants = enumeration
+        .values()
+        .map(|v| EnumVariant::new(v.name(), u32::try_from(v.number()).ok(), Some(v.name().to_owned()), None))
+        .collect();
+      self
+        .definitions
+        .push(TypeDefinition::Enum(EnumDefinition::new(&name, variants, None)));
+    }
+    Type::Named(name)
+  }
+
+  /// The type of a field, including whether it repeats and whether it can be absent.
+  fn field(&mut self, field: &FieldDescriptor) -> Type {
+    if field.is_map() {
+      let Kind::Message(entry) = field.kind() else {
+        unreachable!("map fields are always messages")
+      };
+      return Type::Map {
+        key: Box::new(self.scalar(&entry.map_entry_key_field())),
+        value: Box::new(self.scalar(&entry.map_entry_value_field())),
+      };
+    }
+    let ty = self.scalar(field);
+    if field.is_list() {
+      Type::List { ty: Box::new(ty) }
+    } else if field.supports_presence() {
+      Type::Optional { ty: Box::new(ty) }
+    } else {
+      ty
+    }
+  }
+
+  /// The type of a single value of a field.
+  fn scalar(&mut self, field: &FieldDescriptor) -> Type {
+    match field.kind() {
+      Kind::Double => Type::F64,
+      Kind::Float => Type::F32,
+      Kind::Int32 | Kind::Sint32 | Kind::Sfixed32 => Type::I32,
+      Kind::Int64 | Kind::Sint64 | Kind::Sfixed64 => Type::I64,
+      Kind::Uint32 | Kind::Fixed32 => Type::U32,
+      Kind::Uint64 | Kind::Fixed64 => Type::U64,
+      Kind::Bool => Type::Bool,
+      Kind::String => Type::String,
+      Kind::Bytes => Type::Bytes,
+      Kind::Message(message) => self.message(&message),
+      Kind::Enum(enumeration) => self.enumeration(&enumeration),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+
+  fn greeter_proto() -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/greeter.proto")
+  }
+
+  #[tokio::test]
+  async fn test_operations() -> Result<()> {
+    let pool = load(&greeter_proto()).await?;
+    let service = find_service(&pool, None, "greeter.proto")?;
+    assert_eq!(service.full_name(), "test.Greeter");
+
+    let (operations, types) = operations(&service);
+    let names: Vec<_> = operations.iter().map(|op| op.name()).collect();
+    assert_eq!(names, vec!["SayHello", "SayHellos", "CountNames", "Chat"]);
+    assert_eq!(
+      operations[0].inputs,
+      vec![Field::new("input", Type::Named("test.HelloRequest".to_owned()))]
+    );
+    assert_eq!(
+      operations[0].outputs,
+      vec![Field::new("output", Type::Named("test.HelloReply".to_owned()))]
+    );
+
+    let Some(TypeDefinition::Struct(request)) = types.iter().find(|t| t.name() == "test.HelloRequest") else {
+      panic!("no definition for test.HelloRequest");
+    };
+    let fields: Vec<_> = request.fields.iter().map(|f| (f.name(), f.ty().clone())).collect();
+    assert_eq!(
+      fields,
+      vec![
+        ("name", Type::String),
+        ("times", Type::U32),
+        ("mood", Type::Named("test.Mood".to_owned())),
+        ("tags", Type::List { ty: Box::new(Type::String) }),
+        (
+          "counts",
+          Type::Map {
+            key: Box::new(Type::String),
+            value: Box::new(Type::I64)
+          }
+        ),
+        ("title", Type::Optional { ty: Box::new(Type::String) }),
+        ("sent", Type::Optional { ty: Box::new(Type::Datetime) }),
+      ]
+    );
+    assert!(types.iter().any(|t| t.name() == "test.Mood"));
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_descriptor_set() -> Result<()> {
+    let set = protox::compile([greeter_proto()], [Path::new(env!("CARGO_MANIFEST_DIR")).join("tests")])?;
+    let path = std::env::temp_dir().join(format!("wick-grpc-client-{}.bin", std::process::id()));
+    tokio::fs::write(&path, prost::Message::encode_to_vec(&set)).await?;
+    let pool = load(&path).await;
+    tokio::fs::remove_file(&path).await?;
+
+    let service = find_service(&pool?, Some("test.Greeter"), "greeter.bin")?;
+    assert_eq!(service.methods().len(), 4);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_missing_service() -> Result<()> {
+    let pool = load(&greeter_proto()).await?;
+    let result = find_service(&pool, Some("test.Missing"), "greeter.proto");
+    assert!(matches!(result, Err(Error::ServiceNotFound(..))));
+    Ok(())
+  }
+}
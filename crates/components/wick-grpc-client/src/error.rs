@@ -0,0 +1,46 @@
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+/// Errors that can occur while running a gRPC client component.
+pub enum Error {
+  /// The requested operation isn't one of this component's operations.
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  /// The `.proto` file or descriptor set could not be read or compiled.
+  #[error("Could not load the service definition at {0}: {1}")]
+  Definition(String, String),
+
+  /// The configured service isn't defined in the service definition.
+  #[error("Service {0} is not defined in {1}")]
+  ServiceNotFound(String, String),
+
+  /// No service was configured and the service definition doesn't define exactly one.
+  #[error("{0} defines {1} services, set `service` to the one to call")]
+  AmbiguousService(String, usize),
+
+  /// The component's resource isn't a valid gRPC endpoint.
+  #[error("Invalid gRPC endpoint {0}: {1}")]
+  InvalidEndpoint(String, String),
+
+  /// A configured metadata entry isn't a valid header name or value.
+  #[error("Invalid metadata '{0}': {1}")]
+  InvalidMetadata(String, String),
+
+  /// An input packet could not be converted to the RPC's request message.
+  #[error("Invalid input for {0}: {1}")]
+  InvalidInput(String, String),
+
+  /// A response message could not be converted to JSON.
+  #[error("Could not convert {0} to JSON: {1}")]
+  InvalidOutput(String, String),
+
+  /// The server returned a non-OK status.
+  #[error("{0:?}: {1}")]
+  Status(tonic::Code, String),
+}
+
+impl From<tonic::Status> for Error {
+  fn from(status: tonic::Status) -> Self {
+    Self::Status(status.code(), status.message().to_owned())
+  }
+}
@@ -0,0 +1,418 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use reqwest::header::{
+  HeaderMap,
+  HeaderValue,
+  CACHE_CONTROL,
+  CONTENT_ENCODING,
+  CONTENT_LENGTH,
+  DATE,
+  ETAG,
+  EXPIRES,
+  LAST_MODIFIED,
+  TRANSFER_ENCODING,
+  VARY,
+};
+use reqwest::Request;
+use serde::{Deserialize, Serialize};
+use wick_interface_http::types::HttpResponse;
+use wick_packet::Base64Bytes;
+
+use crate::Error;
+
+/// The result of looking up a request in the cache.
+#[derive(Debug)]
+pub(crate) enum CacheLookup {
+  /// A fresh response can be served without contacting the server.
+  Fresh(CachedResponse),
+  /// A stale response that needs revalidating with the server before being served.
+  Stale(CachedResponse),
+  /// Nothing usable is cached.
+  Miss,
+}
+
+/// A cached HTTP response along with the metadata needed to judge its freshness.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedResponse {
+  pub(crate) response: HttpResponse,
+  pub(crate) body: Base64Bytes,
+  stored_at: u64,
+  max_age: Option<u64>,
+  no_cache: bool,
+  etag: Option<String>,
+  last_modified: Option<String>,
+}
+
+impl CachedResponse {
+  /// Create a new cache entry from a response, if the response allows caching.
+  pub(crate) fn new(response: HttpResponse, headers: &HeaderMap, body: Base64Bytes) -> Option<Self> {
+    let policy = CachePolicy::from_headers(headers);
+    if policy.no_store {
+      return None;
+    }
+    let entry = Self {
+      response,
+      body,
+      stored_at: now(),
+      max_age: policy.max_age,
+      no_cache: policy.no_cache,
+      etag: header_string(headers, ETAG),
+      last_modified: header_string(headers, LAST_MODIFIED),
+    };
+    // Without freshness information or validators there's nothing to gain by keeping the response.
+    (entry.max_age.is_some() || entry.etag.is_some() || entry.last_modified.is_some()).then_some(entry)
+  }
+
+  /// Whether this response can be served without revalidation.
+  pub(crate) fn is_fresh(&self) -> bool {
+    !self.no_cache
+      && self
+        .max_age
+        .map_or(false, |age| now() < self.stored_at.saturating_add(age))
+  }
+
+  /// Whether the server can be asked if this response is still valid.
+  pub(crate) const fn can_revalidate(&self) -> bool {
+    self.etag.is_some() || self.last_modified.is_some()
+  }
+
+  /// Add `If-None-Match`/`If-Modified-Since` headers to revalidate this response.
+  pub(crate) fn add_conditional_headers(&self, request: &mut Request) {
+    let headers = request.headers_mut();
+    if let Some(etag) = self.etag.as_deref().and_then(|v| HeaderValue::from_str(v).ok()) {
+      headers.insert(reqwest::header::IF_NONE_MATCH, etag);
+    }
+    if let Some(modified) = self
+      .last_modified
+      .as_deref()
+      .and_then(|v| HeaderValue::from_str(v).ok())
+    {
+      headers.insert(reqwest::header::IF_MODIFIED_SINCE, modified);
+    }
+  }
+
+  /// Refresh this entry with the headers from a `304 Not Modified` response.
+  ///
+  /// Headers sent with the `304` replace the stored ones, except those that describe the stored body.
+  pub(crate) fn refresh(&mut self, headers: &HeaderMap) {
+    for name in headers.keys() {
+      if name == CONTENT_LENGTH || name == CONTENT_ENCODING || name == TRANSFER_ENCODING {
+        continue;
+      }
+      let values = headers
+        .get_all(name)
+        .iter()
+        .filter_map(|v| v.to_str().ok().map(ToOwned::to_owned))
+        .collect();
+      self.response.headers.insert(name.as_str().to_owned(), values);
+    }
+
+    self.stored_at = now();
+    // Without new freshness information, the stored response keeps its own.
+    if headers.contains_key(CACHE_CONTROL) || headers.contains_key(EXPIRES) {
+      let policy = CachePolicy::from_headers(headers);
+      self.max_age = policy.max_age;
+      self.no_cache = policy.no_cache;
+    }
+    if let Some(etag) = header_string(headers, ETAG) {
+      self.etag = Some(etag);
+    }
+    if let Some(modified) = header_string(headers, LAST_MODIFIED) {
+      self.last_modified = Some(modified);
+    }
+  }
+
+  fn size(&self) -> u64 {
+    self.body.len() as u64
+  }
+}
+
+#[derive(Debug, Default)]
+struct CachePolicy {
+  no_store: bool,
+  no_cache: bool,
+  max_age: Option<u64>,
+}
+
+impl CachePolicy {
+  fn from_headers(headers: &HeaderMap) -> Self {
+    let mut policy = Self::default();
+
+    if headers
+      .get_all(VARY)
+      .iter()
+      .any(|v| v.to_str().map_or(false, |v| v.trim() == "*"))
+    {
+      policy.no_store = true;
+      return policy;
+    }
+
+    for value in headers.get_all(CACHE_CONTROL) {
+      let Ok(value) = value.to_str() else { continue };
+      for directive in value.split(',') {
+        let (name, arg) = directive.trim().split_once('=').unwrap_or((directive.trim(), ""));
+        match name.to_ascii_lowercase().as_str() {
+          "no-store" => policy.no_store = true,
+          "no-cache" => policy.no_cache = true,
+          // `must-revalidate` forbids serving a stale response without revalidating it, which this cache
+          // never does, so it doesn't affect fresh responses.
+          "max-age" => policy.max_age = arg.trim_matches('"').parse().ok(),
+          _ => {}
+        }
+      }
+    }
+
+    if policy.max_age.is_none() {
+      // Fall back to `Expires`, relative to the server's `Date` header when present.
+      let expires = header_date(headers, EXPIRES);
+      let date = header_date(headers, DATE).unwrap_or_else(now);
+      policy.max_age = expires.map(|expires| expires.saturating_sub(date));
+    }
+
+    policy
+  }
+}
+
+#[derive(Debug)]
+enum CacheStore {
+  Memory(Mutex<HashMap<String, CachedResponse>>),
+  Volume(PathBuf),
+}
+
+/// A size-bounded response cache backed by memory or a volume on disk.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+  store: CacheStore,
+  max_size: u64,
+}
+
+impl ResponseCache {
+  /// Create a cache that holds responses in memory.
+  pub(crate) fn new_memory(max_size: u64) -> Self {
+    Self {
+      store: CacheStore::Memory(Mutex::new(HashMap::new())),
+      max_size,
+    }
+  }
+
+  /// Create a cache that persists responses to files in the passed directory.
+  pub(crate) const fn new_volume(path: PathBuf, max_size: u64) -> Self {
+    Self {
+      store: CacheStore::Volume(path),
+      max_size,
+    }
+  }
+
+  /// The maximum total size of the cached response bodies.
+  pub(crate) const fn max_size(&self) -> u64 {
+    self.max_size
+  }
+
+  /// Generate the cache key for a request.
+  ///
+  /// Request headers are part of the key so responses that `Vary` by them are never mixed up.
+  pub(crate) fn key(request: &Request) -> String {
+    let mut headers: Vec<_> = request
+      .headers()
+      .iter()
+      .map(|(k, v)| format!("{}:{}", k, String::from_utf8_lossy(v.as_bytes())))
+      .collect();
+    headers.sort();
+    sha256::digest(format!(
+      "{} {}\n{}",
+      request.method(),
+      request.url(),
+      headers.join("\n")
+    ))
+  }
+
+  /// Look up a cached response for the passed key.
+  pub(crate) async fn lookup(&self, key: &str) -> CacheLookup {
+    let entry = match &self.store {
+      CacheStore::Memory(entries) => entries.lock().get(key).cloned(),
+      CacheStore::Volume(dir) => tokio::fs::read(dir.join(key))
+        .await
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok()),
+    };
+    match entry {
+      Some(entry) if entry.is_fresh() => CacheLookup::Fresh(entry),
+      Some(entry) if entry.can_revalidate() => CacheLookup::Stale(entry),
+      _ => CacheLookup::Miss,
+    }
+  }
+
+  /// Store a response, evicting the oldest entries if the cache would exceed its maximum size.
+  pub(crate) async fn store(&self, key: String, entry: CachedResponse) -> Result<(), Error> {
+    if entry.size() > self.max_size {
+      return Ok(());
+    }
+    match &self.store {
+      CacheStore::Memory(entries) => {
+        let mut entries = entries.lock();
+        entries.remove(&key);
+        let mut total: u64 = entries.values().map(CachedResponse::size).sum();
+        while total + entry.size() > self.max_size {
+          let Some(oldest) = entries.iter().min_by_key(|(_, v)| v.stored_at).map(|(k, _)| k.clone()) else {
+            break;
+          };
+          if let Some(evicted) = entries.remove(&oldest) {
+            total -= evicted.size();
+          }
+        }
+        entries.insert(key, entry);
+      }
+      CacheStore::Volume(dir) => {
+        let bytes = serde_json::to_vec(&entry).map_err(|e| Error::Cache(e.to_string()))?;
+        let _ = tokio::fs::remove_file(dir.join(&key)).await;
+        self.evict_files(dir, entry.size()).await?;
+        tokio::fs::write(dir.join(key), bytes)
+          .await
+          .map_err(|e| Error::Cache(e.to_string()))?;
+      }
+    }
+    Ok(())
+  }
+
+  async fn evict_files(&self, dir: &PathBuf, incoming: u64) -> Result<(), Error> {
+    let mut files = Vec::new();
+    let mut dir_entries = tokio::fs::read_dir(dir)
+      .await
+      .map_err(|e| Error::Cache(e.to_string()))?;
+    while let Ok(Some(file)) = dir_entries.next_entry().await {
+      let Ok(meta) = file.metadata().await else { continue };
+      if meta.is_file() {
+        files.push((meta.modified().unwrap_or(UNIX_EPOCH), meta.len(), file.path()));
+      }
+    }
+    // Files hold the serialized entry, so they overestimate the body size. That errs on the side of evicting.
+    let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+    files.sort_by_key(|(modified, _, _)| *modified);
+    for (_, len, path) in files {
+      if total + incoming <= self.max_size {
+        break;
+      }
+      if tokio::fs::remove_file(path).await.is_ok() {
+        total -= len;
+      }
+    }
+    Ok(())
+  }
+}
+
+fn header_string(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<String> {
+  headers.get(name).and_then(|v| v.to_str().ok()).map(ToOwned::to_owned)
+}
+
+pub(crate) fn header_date(headers: &HeaderMap, name: reqwest::header::HeaderName) -> Option<u64> {
+  let value = headers.get(name)?.to_str().ok()?;
+  let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+  u64::try_from(date.timestamp()).ok()
+}
+
+pub(crate) fn now() -> u64 {
+  SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod test {
+  use std::str::FromStr;
+
+  use anyhow::Result;
+  use reqwest::header::HeaderName;
+  use wick_interface_http::types::{HttpVersion, StatusCode};
+
+  use super::*;
+
+  fn headers(list: &[(&str, &str)]) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (k, v) in list {
+      map.append(HeaderName::from_str(k).unwrap(), HeaderValue::from_str(v).unwrap());
+    }
+    map
+  }
+
+  fn response(body: &str, list: &[(&str, &str)]) -> Option<CachedResponse> {
+    CachedResponse::new(
+      HttpResponse {
+        version: HttpVersion::Http11,
+        status: StatusCode::Ok,
+        headers: Default::default(),
+      },
+      &headers(list),
+      Base64Bytes::new(body.as_bytes().to_vec()),
+    )
+  }
+
+  #[test]
+  fn test_cache_policy() {
+    assert!(response("", &[("cache-control", "no-store, max-age=60")]).is_none());
+    assert!(response("", &[("cache-control", "max-age=60"), ("vary", "*")]).is_none());
+    assert!(response("", &[]).is_none());
+
+    let fresh = response("", &[("cache-control", "public, max-age=60")]).unwrap();
+    assert!(fresh.is_fresh());
+
+    let fresh = response("", &[("cache-control", "max-age=60, must-revalidate")]).unwrap();
+    assert!(fresh.is_fresh());
+
+    let stale = response("", &[("cache-control", "no-cache"), ("etag", "\"abc\"")]).unwrap();
+    assert!(!stale.is_fresh());
+    assert!(stale.can_revalidate());
+
+    let expired = response(
+      "",
+      &[
+        ("date", "Sun, 06 Nov 1994 08:49:37 GMT"),
+        ("expires", "Sun, 06 Nov 1994 08:49:37 GMT"),
+        ("last-modified", "Sun, 06 Nov 1994 08:00:00 GMT"),
+      ],
+    )
+    .unwrap();
+    assert!(!expired.is_fresh());
+    assert!(expired.can_revalidate());
+  }
+
+  #[test]
+  fn test_refresh() {
+    let mut entry = response("", &[("cache-control", "no-cache"), ("etag", "\"abc\"")]).unwrap();
+    entry.refresh(&headers(&[("etag", "\"abc\""), ("x-version", "2"), ("content-length", "0")]));
+    assert!(!entry.is_fresh());
+    assert_eq!(entry.response.headers.get("x-version"), Some(&vec!["2".to_owned()]));
+    assert_eq!(entry.response.headers.get("content-length"), None);
+
+    entry.refresh(&headers(&[("cache-control", "max-age=60")]));
+    assert!(entry.is_fresh());
+  }
+
+  #[tokio::test]
+  async fn test_memory_eviction() -> Result<()> {
+    let cache = ResponseCache::new_memory(10);
+    cache
+      .store(
+        "first".to_owned(),
+        response("123456", &[("cache-control", "max-age=60")]).unwrap(),
+      )
+      .await?;
+    assert!(matches!(cache.lookup("first").await, CacheLookup::Fresh(_)));
+    cache
+      .store(
+        "second".to_owned(),
+        response("123456", &[("cache-control", "max-age=60")]).unwrap(),
+      )
+      .await?;
+    assert!(matches!(cache.lookup("first").await, CacheLookup::Miss));
+    assert!(matches!(cache.lookup("second").await, CacheLookup::Fresh(_)));
+    cache
+      .store(
+        "too_big".to_owned(),
+        response("12345678901", &[("cache-control", "max-age=60")]).unwrap(),
+      )
+      .await?;
+    assert!(matches!(cache.lookup("too_big").await, CacheLookup::Miss));
+    Ok(())
+  }
+}
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use eventsource_stream::Eventsource;
 use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
 use futures::{Stream, StreamExt, TryStreamExt};
-use reqwest::header::CONTENT_TYPE;
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use reqwest::redirect::Policy;
 use reqwest::{ClientBuilder, Method, Request, RequestBuilder};
 use serde_json::{Map, Value};
 use tracing::Span;
@@ -14,9 +16,10 @@ use wick_config::config::components::{
   ComponentConfig,
   HttpClientComponentConfig,
   HttpClientOperationDefinition,
+  HttpRedirectConfig,
   OperationConfig,
 };
-use wick_config::config::{Codec, HttpMethod, LiquidJsonConfig, Metadata, UrlResource};
+use wick_config::config::{Codec, LiquidJsonConfig, Metadata, UrlResource};
 use wick_config::{ConfigValidation, Resolver};
 use wick_interface_http::types::HttpEvent;
 use wick_interface_types::{ComponentSignature, OperationSignatures};
@@ -24,6 +27,7 @@ use wick_packet::{
   Base64Bytes,
   FluxChannel,
   Invocation,
+  InvocationContext,
   Observer,
   Packet,
   PacketExt,
@@ -32,7 +36,9 @@ use wick_packet::{
   RuntimeConfig,
 };
 
+use crate::cache::{CacheLookup, CachedResponse, ResponseCache};
 use crate::error::Error;
+use crate::retry::{self, RetryPolicy};
 static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
 
 #[derive(Debug, Clone)]
@@ -43,6 +49,8 @@ pub struct HttpClientComponent {
   config: HttpClientComponentConfig,
   root_config: Option<RuntimeConfig>,
   path_templates: HashMap<String, Arc<(String, String)>>,
+  caches: HashMap<String, Arc<ResponseCache>>,
+  retries: HashMap<String, Arc<RetryPolicy>>,
   client: reqwest::Client,
 }
 
@@ -100,35 +108,56 @@ impl HttpClientComponent {
       );
     }
 
+    let mut caches = HashMap::new();
+    for op in config.operations() {
+      let Some(cache) = op.cache() else { continue };
+      let cache = match cache.volume() {
+        Some(volume) => {
+          let path = resolver(volume)
+            .and_then(|r| r.try_resource())
+            .and_then(|r| r.try_volume())
+            .and_then(|v| v.path())?;
+          ResponseCache::new_volume(path, cache.max_size())
+        }
+        None => ResponseCache::new_memory(cache.max_size()),
+      };
+      caches.insert(op.name().to_owned(), Arc::new(cache));
+    }
+
+    // An operation's retry policy replaces the component's.
+    let mut retries = HashMap::new();
+    for op in config.operations() {
+      if let Some(retry) = op.retry().or(config.retry()) {
+        retries.insert(op.name().to_owned(), Arc::new(RetryPolicy::from(retry)));
+      }
+    }
+
     if proxy_addr == Some(addr.clone()) {
       return Err(Error::ProxyLoop(addr.url().value().unwrap().clone()).into());
     }
 
-    let client = match proxy_addr {
-      Some(proxy_addr) => {
-        let mut proxy = reqwest::Proxy::all(proxy_addr.url().value().unwrap().clone())?;
+    let mut builder = ClientBuilder::new()
+      .connect_timeout(timeout)
+      .user_agent(APP_USER_AGENT)
+      .redirect(redirect_policy(config.redirects()));
 
-        if let (Some(username), Some(password)) = (&proxy_username, &proxy_password) {
-          proxy = proxy.basic_auth(username.as_str(), password.as_str());
-        }
+    if let Some(proxy_addr) = proxy_addr {
+      let mut proxy = reqwest::Proxy::all(proxy_addr.url().value().unwrap().clone())?;
 
-        ClientBuilder::new()
-          .proxy(proxy)
-          .connect_timeout(timeout)
-          .user_agent(APP_USER_AGENT)
-          .build()?
+      if let (Some(username), Some(password)) = (&proxy_username, &proxy_password) {
+        proxy = proxy.basic_auth(username.as_str(), password.as_str());
       }
-      None => ClientBuilder::new()
-        .connect_timeout(timeout)
-        .user_agent(APP_USER_AGENT)
-        .build()
-        .unwrap(),
-    };
+
+      builder = builder.proxy(proxy);
+    }
+    let client = builder.build()?;
 
     Ok(Self {
       signature: sig,
       base: url,
       path_templates,
+      caches,
+      retries,
       client,
       root_config,
       config,
@@ -146,10 +175,13 @@ impl Component for HttpClientComponent {
     let config = self.config.clone();
     let baseurl = self.base.clone();
     let codec = config.codec().copied();
+    let max_response_size = config.max_response_size();
     let opdef = get_op_by_name(&config, invocation.target().operation_id());
     let path_template = opdef
       .as_ref()
       .and_then(|op| self.path_templates.get(op.name()).cloned());
+    let cache = opdef.as_ref().and_then(|op| self.caches.get(op.name()).cloned());
+    let retry = opdef.as_ref().and_then(|op| self.retries.get(op.name()).cloned());
     let client = self.client.clone();
 
     Box::pin(async move {
@@ -165,6 +197,9 @@ impl Component for HttpClientComponent {
         path_template,
         baseurl,
         client,
+        cache,
+        retry,
+        max_response_size,
       );
       tokio::spawn(async move {
         if let Err(e) = fut.await {
@@ -179,6 +214,40 @@ impl Component for HttpClientComponent {
   fn signature(&self) -> &ComponentSignature {
     &self.signature
   }
+
+  fn health(&self) -> BoxFuture<Result<(), ComponentError>> {
+    let request = self.client.head(self.base.clone());
+    let base = self.base.clone();
+    Box::pin(async move {
+      let response = request.send().await?;
+      if response.status().is_server_error() {
+        return Err(Error::HealthCheck(base, response.status().as_u16()).into());
+      }
+      Ok(())
+    })
+  }
+}
+
+/// Follow at most `max` redirects, or none at all when `max` is zero, in which case the redirect
+/// itself is the response.
+fn redirect_policy(config: Option<&HttpRedirectConfig>) -> Policy {
+  let Some(config) = config.copied() else {
+    return Policy::default();
+  };
+  if config.max() == 0 {
+    return Policy::none();
+  }
+  Policy::custom(move |attempt| {
+    let previous = attempt.previous();
+    if previous.len() > config.max() as usize {
+      attempt.error(Error::TooManyRedirects(config.max()))
+    } else if config.same_origin() && attempt.url().origin() != previous[0].origin() {
+      let url = attempt.url().clone();
+      attempt.error(Error::CrossOriginRedirect(url))
+    } else {
+      attempt.follow()
+    }
+  })
 }
 
 fn get_op_by_name(config: &HttpClientComponentConfig, name: &str) -> Option<HttpClientOperationDefinition> {
@@ -196,6 +265,9 @@ async fn handle(
   path_template: Option<Arc<(String, String)>>,
   baseurl: Url,
   client: reqwest::Client,
+  cache: Option<Arc<ResponseCache>>,
+  retry: Option<Arc<RetryPolicy>>,
+  max_response_size: Option<u64>,
 ) -> anyhow::Result<()> {
   if baseurl.cannot_be_a_base() {
     return Err(Error::InvalidBaseUrl(baseurl).into());
@@ -277,12 +349,7 @@ async fn handle(
 
     invocation.trace(|| trace!(url= %request_url,body=?body, "http:client:request"));
 
-    let request = match opdef.method() {
-      HttpMethod::Get => Request::new(Method::GET, request_url),
-      HttpMethod::Post => Request::new(Method::POST, request_url),
-      HttpMethod::Put => Request::new(Method::PUT, request_url),
-      HttpMethod::Delete => Request::new(Method::DELETE, request_url),
-    };
+    let request = Request::new(retry::to_method(*opdef.method()), request_url);
 
     let request_builder = RequestBuilder::from_parts(client.clone(), request);
     let mut request_builder = if let Some(body) = body {
@@ -314,11 +381,51 @@ async fn handle(
     }
 
     let (client, request) = request_builder.build_split();
-    let request = request.unwrap();
+    let mut request = request.unwrap();
+
+    // Only GET requests are cached.
+    let cache = cache.as_ref().filter(|_| request.method() == Method::GET);
+    let cache_key = cache.map(|_| ResponseCache::key(&request));
+    let mut stale = None;
+    if let (Some(cache), Some(key)) = (cache, &cache_key) {
+      match cache.lookup(key).await {
+        CacheLookup::Fresh(entry) => {
+          invocation.trace(|| debug!(cache = "hit", url = %request.url(), "http:client:cache"));
+          handles.push(send_cached(invocation.span.clone(), entry, tx.clone()));
+          continue 'outer;
+        }
+        CacheLookup::Stale(entry) => {
+          entry.add_conditional_headers(&mut request);
+          stale = Some(entry);
+        }
+        CacheLookup::Miss => {}
+      }
+    }
+
+    // Trace headers are added after computing the cache key so they don't affect it.
+    propagate_context(&mut request, &invocation.span, invocation.inherent.context());
+
+    let remaining = invocation.inherent.context().remaining();
+    if remaining.map_or(false, |remaining| remaining.is_zero()) {
+      let _ = tx.error(wick_packet::Error::component_error(
+        "Request not sent, the invocation deadline has passed",
+      ));
+      break 'outer;
+    }
+    let deadline = remaining.map(|remaining| tokio::time::Instant::now() + remaining);
 
     invocation.trace(|| debug!(request=?request, "http:client:request"));
 
-    let response = match client.execute(request).await {
+    let response = match retry::execute(
+      &client,
+      request,
+      retry.as_deref(),
+      opdef.timeout().map(Duration::from_millis),
+      deadline,
+      &invocation.span,
+    )
+    .await
+    {
       Ok(r) => r,
       Err(e) => {
         let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
@@ -328,29 +435,23 @@ async fn handle(
 
     invocation.trace(|| debug!(status=%response.status(), "http:client:response_status"));
 
-    let content_type = response.headers().get(CONTENT_TYPE);
-    let event_stream = content_type.map_or(false, |t| t == "text/event-stream");
-
-    let codec = content_type.map_or(Codec::Raw, |value| {
-      let value = value.to_str().unwrap();
-      let (value, _other) = value.split_once(';').unwrap_or((value, ""));
-      if value.starts_with("text/") {
-        if event_stream {
-          Codec::Json
-        } else {
-          Codec::Text
-        }
-      } else {
-        match value {
-          "application/json" => Codec::Json,
-          "application/x-www-form-urlencoded" => Codec::FormData,
-          "application/xhtml+xml" => Codec::Text,
-          _ => Codec::Raw,
+    if let (Some(mut entry), Some(cache), Some(key)) = (stale, cache, &cache_key) {
+      if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        invocation.trace(|| debug!(cache = "revalidated", url = %response.url(), "http:client:cache"));
+        entry.refresh(response.headers());
+        if let Err(e) = cache.store(key.clone(), entry.clone()).await {
+          invocation.trace(|| warn!(error = %e, "http:client:cache"));
         }
+        handles.push(send_cached(invocation.span.clone(), entry, tx.clone()));
+        continue 'outer;
       }
-    });
+    }
 
-    let (our_response, body_stream) = match crate::conversions::to_wick_response(response) {
+    let (codec, event_stream) = response_codec(response.headers().get(CONTENT_TYPE).and_then(|v| v.to_str().ok()));
+    let cacheable_headers = (cache.is_some() && !event_stream && response.status() == reqwest::StatusCode::OK)
+      .then(|| response.headers().clone());
+
+    let (our_response, body_stream) = match crate::conversions::to_wick_response(response, max_response_size) {
       Ok(r) => r,
       Err(e) => {
         let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
@@ -359,6 +460,58 @@ async fn handle(
     };
     invocation.trace(|| debug!(response = ?our_response, "http:client:response"));
 
+    if let (Some(cache), Some(key)) = (cache, cache_key) {
+      invocation.trace(|| debug!(cache = "miss", "http:client:cache"));
+      if let Some(headers) = cacheable_headers {
+        let mut body_stream = body_stream;
+        let mut chunks = Vec::new();
+        let mut size = 0_u64;
+        // Buffer the body to cache it, until it turns out to be too big for the cache.
+        let complete = loop {
+          match body_stream.next().await {
+            Some(Ok(chunk)) => {
+              size += chunk.len() as u64;
+              chunks.push(chunk);
+              if size > cache.max_size() {
+                break false;
+              }
+            }
+            Some(Err(e)) => {
+              let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+              break 'outer;
+            }
+            None => break true,
+          }
+        };
+        let _ = tx.send(Packet::encode("response", &our_response));
+        if !complete {
+          invocation.trace(|| debug!(cache = "skip", size, "http:client:cache"));
+          handles.push(tokio::spawn(output_task(
+            invocation.span.clone(),
+            codec,
+            futures::stream::iter(chunks.into_iter().map(Ok)).chain(body_stream),
+            false,
+            tx.clone(),
+          )));
+          continue 'outer;
+        }
+        let body = Base64Bytes::new(chunks.concat());
+        if let Some(entry) = CachedResponse::new(our_response, &headers, body.clone()) {
+          if let Err(e) = cache.store(key, entry).await {
+            invocation.trace(|| warn!(error = %e, "http:client:cache"));
+          }
+        }
+        handles.push(tokio::spawn(output_task(
+          invocation.span.clone(),
+          codec,
+          futures::stream::iter([Ok(body)]),
+          false,
+          tx.clone(),
+        )));
+        continue 'outer;
+      }
+    }
+
     let _ = tx.send(Packet::encode("response", our_response));
     handles.push(tokio::spawn(output_task(
       invocation.span.clone(),
@@ -375,7 +528,70 @@ async fn handle(
   Ok(())
 }
 
-fn output_task<T: Stream<Item = Result<Base64Bytes, reqwest::Error>> + Send + Unpin + 'static>(
+/// Forward the W3C trace context of the invocation to an outbound request.
+///
+/// The active span's context is preferred. If it isn't being exported, the `traceparent` and
+/// `tracestate` from the invocation context are passed along as-is.
+fn propagate_context(request: &mut Request, span: &Span, context: &InvocationContext) {
+  let trace = wick_logger::propagation::trace_context(span).or_else(|| {
+    context
+      .traceparent()
+      .map(|traceparent| (traceparent.to_owned(), context.tracestate().map(ToOwned::to_owned)))
+  });
+  let Some((traceparent, tracestate)) = trace else {
+    return;
+  };
+  let headers = request.headers_mut();
+  if let Ok(value) = HeaderValue::from_str(&traceparent) {
+    headers.insert(InvocationContext::TRACEPARENT, value);
+  }
+  if let Some(Ok(value)) = tracestate.map(|v| HeaderValue::from_str(&v)) {
+    headers.insert(InvocationContext::TRACESTATE, value);
+  }
+}
+
+fn response_codec(content_type: Option<&str>) -> (Codec, bool) {
+  let event_stream = content_type.map_or(false, |t| t == "text/event-stream");
+
+  let codec = content_type.map_or(Codec::Raw, |value| {
+    let (value, _other) = value.split_once(';').unwrap_or((value, ""));
+    if value.starts_with("text/") {
+      if event_stream {
+        Codec::Json
+      } else {
+        Codec::Text
+      }
+    } else {
+      match value {
+        "application/json" => Codec::Json,
+        "application/x-www-form-urlencoded" => Codec::FormData,
+        "application/xhtml+xml" => Codec::Text,
+        _ => Codec::Raw,
+      }
+    }
+  });
+  (codec, event_stream)
+}
+
+fn send_cached(span: Span, entry: CachedResponse, tx: PacketSender) -> tokio::task::JoinHandle<()> {
+  let content_type = entry
+    .response
+    .headers
+    .iter()
+    .find(|(k, _)| k.eq_ignore_ascii_case(CONTENT_TYPE.as_str()))
+    .and_then(|(_, v)| v.first().cloned());
+  let (codec, _) = response_codec(content_type.as_deref());
+  let _ = tx.send(Packet::encode("response", entry.response));
+  tokio::spawn(output_task(
+    span,
+    codec,
+    futures::stream::iter([Ok(entry.body)]),
+    false,
+    tx,
+  ))
+}
+
+fn output_task<T: Stream<Item = Result<Base64Bytes, Error>> + Send + Unpin + 'static>(
   span: Span,
   codec: Codec,
   body_stream: T,
@@ -390,7 +606,7 @@ fn output_task<T: Stream<Item = Result<Base64Bytes, reqwest::Error>> + Send + Un
   Box::pin(task)
 }
 
-async fn output_task_inner<T: Stream<Item = Result<Base64Bytes, reqwest::Error>> + Send + Unpin + 'static>(
+async fn output_task_inner<T: Stream<Item = Result<Base64Bytes, Error>> + Send + Unpin + 'static>(
   span: Span,
   codec: Codec,
   mut body_stream: T,
@@ -482,6 +698,20 @@ mod test {
 
   use super::*;
 
+  #[test]
+  fn test_propagate_context() {
+    let traceparent = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+    let mut request = Request::new(Method::GET, Url::parse("http://localhost/").unwrap());
+    let context = InvocationContext::from([
+      (InvocationContext::TRACEPARENT, traceparent),
+      (InvocationContext::TENANT, "acme"),
+    ]);
+    propagate_context(&mut request, &Span::none(), &context);
+    assert_eq!(request.headers().get("traceparent").unwrap(), traceparent);
+    assert!(request.headers().get("tracestate").is_none());
+    assert!(request.headers().get("tenant").is_none());
+  }
+
   #[test]
   const fn test_component() {
     const fn is_send_sync<T: Send + Sync>() {}
@@ -578,12 +808,239 @@ mod test {
     Ok(())
   }
 
+  mod retry_test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use wick_config::config::components::HttpRetryConfigBuilder;
+
+    use super::*;
+
+    /// Serve requests that fail with a 503 until `failures` of them have failed, counting every request.
+    async fn flaky_server(failures: u32) -> Result<(Url, Arc<AtomicU32>)> {
+      let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+      let url = Url::parse(&format!("http://{}/", listener.local_addr()?))?;
+      let requests = Arc::new(AtomicU32::new(0));
+      let counter = requests.clone();
+      tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+          let mut buf = vec![0; 4096];
+          let _ = socket.read(&mut buf).await;
+          let response = if counter.fetch_add(1, Ordering::SeqCst) < failures {
+            "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 0\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+          } else {
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 11\r\nconnection: close\r\n\r\n{\"ok\":true}"
+          };
+          let _ = socket.write_all(response.as_bytes()).await;
+        }
+      });
+      Ok((url, requests))
+    }
+
+    fn flaky_component(url: Url, max_response_size: Option<u64>) -> HttpClientComponent {
+      let retry = HttpRetryConfigBuilder::default()
+        .max_attempts(3_u32)
+        .backoff(10_u64)
+        .max_backoff(100_u64)
+        .build()
+        .unwrap();
+      let mut config = HttpClientComponentConfigBuilder::default()
+        .resource("base")
+        .codec(Codec::Json)
+        .retry(retry)
+        .max_response_size(max_response_size)
+        .build()
+        .unwrap();
+      let inputs = vec![Field::new("input", Type::String)];
+      config
+        .operations_mut()
+        .push(HttpClientOperationDefinition::new_get(GET_OP, "flaky", inputs.clone(), None).build().unwrap());
+      config.operations_mut().push(
+        HttpClientOperationDefinition::new_post(POST_OP, "flaky", inputs, Some(json!({}).into()), None)
+          .build()
+          .unwrap(),
+      );
+      let mut app_config = AppConfiguration::default();
+      app_config.add_resource("base", ResourceDefinition::Url(UrlResource::new(url)));
+      get_component(&app_config, config)
+    }
+
+    pub(super) async fn invoke(comp: &HttpClientComponent, op: &str) -> Result<Vec<Packet>> {
+      let packets = packet_stream!(("input", "unused"));
+      let invocation = Invocation::test("retry_test", Entity::local(op), packets, Default::default())?;
+      let stream = comp
+        .handle(invocation, Default::default(), Default::default())
+        .await?
+        .collect::<Vec<_>>()
+        .await;
+      Ok(stream.into_iter().collect::<Result<Vec<_>, _>>()?)
+    }
+
+    pub(super) fn status(packets: &[Packet]) -> u16 {
+      let response = packets.iter().find(|p| p.port() == "response").unwrap();
+      let response: wick_interface_http::types::HttpResponse = response.decode().unwrap();
+      response.status.to_string().parse().unwrap()
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn test_retry_until_success() -> Result<()> {
+      let (url, requests) = flaky_server(2).await?;
+      let comp = flaky_component(url, None);
+      let packets = invoke(&comp, GET_OP).await?;
+      assert_eq!(requests.load(Ordering::SeqCst), 3);
+      assert_eq!(status(&packets), 200);
+      let body = packets.iter().find(|p| p.port() == "body" && p.has_data()).unwrap();
+      assert_eq!(body.decode_value()?, json!({"ok": true}));
+      Ok(())
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn test_retry_gives_up() -> Result<()> {
+      let (url, requests) = flaky_server(5).await?;
+      let comp = flaky_component(url, None);
+      let packets = invoke(&comp, GET_OP).await?;
+      assert_eq!(requests.load(Ordering::SeqCst), 3);
+      assert_eq!(status(&packets), 503);
+      Ok(())
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn test_no_retry_for_post() -> Result<()> {
+      let (url, requests) = flaky_server(1).await?;
+      let comp = flaky_component(url, None);
+      let packets = invoke(&comp, POST_OP).await?;
+      assert_eq!(requests.load(Ordering::SeqCst), 1);
+      assert_eq!(status(&packets), 503);
+      Ok(())
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn test_max_response_size() -> Result<()> {
+      let (url, _) = flaky_server(0).await?;
+      let comp = flaky_component(url, Some(4));
+      let result = invoke(&comp, GET_OP).await;
+      assert!(result.is_err());
+      Ok(())
+    }
+  }
+
+  mod cache_test {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use wick_config::config::components::HttpCacheConfigBuilder;
+    use wick_interface_http::types::HttpResponse;
+
+    use super::retry_test::{invoke, status};
+    use super::*;
+
+    /// Serve a response with the passed `Cache-Control`, answering revalidations with a `304`. Every response
+    /// numbers itself in an `x-version` header.
+    async fn cache_server(cache_control: &'static str) -> Result<(Url, Arc<AtomicU32>)> {
+      let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+      let url = Url::parse(&format!("http://{}/", listener.local_addr()?))?;
+      let requests = Arc::new(AtomicU32::new(0));
+      let counter = requests.clone();
+      tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+          let mut buf = vec![0; 4096];
+          let read = socket.read(&mut buf).await.unwrap_or(0);
+          let request = String::from_utf8_lossy(&buf[..read]).to_ascii_lowercase();
+          let version = counter.fetch_add(1, Ordering::SeqCst) + 1;
+          let response = if request.contains("if-none-match: \"v1\"") {
+            format!("HTTP/1.1 304 Not Modified\r\netag: \"v1\"\r\nx-version: {version}\r\nconnection: close\r\n\r\n")
+          } else {
+            format!(
+              "HTTP/1.1 200 OK\r\ncache-control: {cache_control}\r\netag: \"v1\"\r\nx-version: {version}\r\ncontent-type: application/json\r\ncontent-length: 11\r\nconnection: close\r\n\r\n{{\"ok\":true}}"
+            )
+          };
+          let _ = socket.write_all(response.as_bytes()).await;
+        }
+      });
+      Ok((url, requests))
+    }
+
+    fn cache_component(url: Url, max_size: u64) -> HttpClientComponent {
+      let mut config = HttpClientComponentConfigBuilder::default()
+        .resource("base")
+        .codec(Codec::Json)
+        .build()
+        .unwrap();
+      let cache = HttpCacheConfigBuilder::default().max_size(max_size).build().unwrap();
+      config.operations_mut().push(
+        HttpClientOperationDefinition::new_get(GET_OP, "cached", vec![Field::new("input", Type::String)], None)
+          .cache(cache)
+          .build()
+          .unwrap(),
+      );
+      let mut app_config = AppConfiguration::default();
+      app_config.add_resource("base", ResourceDefinition::Url(UrlResource::new(url)));
+      get_component(&app_config, config)
+    }
+
+    fn body(packets: &[Packet]) -> Result<Value> {
+      let body = packets.iter().find(|p| p.port() == "body" && p.has_data()).unwrap();
+      Ok(body.decode_value()?)
+    }
+
+    fn version(packets: &[Packet]) -> Option<String> {
+      let response: HttpResponse = packets.iter().find(|p| p.port() == "response")?.decode().ok()?;
+      response.headers.get("x-version")?.first().cloned()
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn test_cache_hit() -> Result<()> {
+      let (url, requests) = cache_server("max-age=60, must-revalidate").await?;
+      let comp = cache_component(url, 1024);
+      let first = invoke(&comp, GET_OP).await?;
+      let second = invoke(&comp, GET_OP).await?;
+      assert_eq!(requests.load(Ordering::SeqCst), 1);
+      assert_eq!(status(&second), 200);
+      assert_eq!(body(&first)?, json!({"ok": true}));
+      assert_eq!(body(&second)?, json!({"ok": true}));
+      assert_eq!(version(&second).as_deref(), Some("1"));
+      Ok(())
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn test_cache_revalidate() -> Result<()> {
+      let (url, requests) = cache_server("no-cache").await?;
+      let comp = cache_component(url, 1024);
+      invoke(&comp, GET_OP).await?;
+      let second = invoke(&comp, GET_OP).await?;
+      assert_eq!(requests.load(Ordering::SeqCst), 2);
+      assert_eq!(status(&second), 200);
+      assert_eq!(body(&second)?, json!({"ok": true}));
+      assert_eq!(version(&second).as_deref(), Some("2"));
+      Ok(())
+    }
+
+    #[test_logger::test(tokio::test)]
+    async fn test_cache_too_large() -> Result<()> {
+      let (url, requests) = cache_server("max-age=60").await?;
+      let comp = cache_component(url, 4);
+      invoke(&comp, GET_OP).await?;
+      let second = invoke(&comp, GET_OP).await?;
+      assert_eq!(requests.load(Ordering::SeqCst), 2);
+      assert_eq!(body(&second)?, json!({"ok": true}));
+      Ok(())
+    }
+  }
+
   mod integration_test {
     use serde_json::json;
     use wick_interface_http::types::{HttpResponse, HttpVersion};
 
     use super::*;
 
+    #[test_logger::test(tokio::test)]
+    async fn test_health() -> Result<()> {
+      let (app_config, component_config) = get_config();
+      let comp = get_component(&app_config, component_config);
+      comp.health().await?;
+      Ok(())
+    }
+
     #[test_logger::test(tokio::test)]
     async fn test_get_request() -> Result<()> {
       let (app_config, component_config) = get_config();
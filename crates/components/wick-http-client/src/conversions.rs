@@ -9,9 +9,16 @@ use wick_packet::Base64Bytes;
 
 use crate::Error;
 
+/// Convert a response into ours and a stream of its body, which fails once it exceeds `max_size` bytes.
 pub(crate) fn to_wick_response(
   res: reqwest::Response,
-) -> Result<(HttpResponse, impl Stream<Item = Result<Base64Bytes, reqwest::Error>>), Error> {
+  max_size: Option<u64>,
+) -> Result<(HttpResponse, impl Stream<Item = Result<Base64Bytes, Error>>), Error> {
+  if let (Some(max), Some(len)) = (max_size, res.content_length()) {
+    if len > max {
+      return Err(Error::ResponseTooLarge(max));
+    }
+  }
   let ours = HttpResponse {
     version: match res.version() {
       Version::HTTP_09 => unimplemented!("HTTP/0.9 is not supported"),
@@ -23,7 +30,16 @@ pub(crate) fn to_wick_response(
     status: StatusCode::from_str(res.status().as_str()).unwrap_or(StatusCode::Unknown),
     headers: convert_headers(res.headers())?,
   };
-  Ok((ours, res.bytes_stream().map(|b| b.map(Base64Bytes::new))))
+  let mut received = 0_u64;
+  let body = res.bytes_stream().map(move |chunk| {
+    let chunk = chunk.map_err(|e| Error::Body(e.to_string()))?;
+    received += chunk.len() as u64;
+    match max_size {
+      Some(max) if received > max => Err(Error::ResponseTooLarge(max)),
+      _ => Ok(Base64Bytes::new(chunk)),
+    }
+  });
+  Ok((ours, body))
 }
 
 fn convert_headers(from_headers: &HeaderMap) -> Result<HashMap<String, Vec<String>>, Error> {
@@ -20,4 +20,22 @@ pub enum Error {
 
   #[error("Proxy and baseurl must not be the same: {0}")]
   ProxyLoop(Url),
+
+  #[error("Response cache error: {0}")]
+  Cache(String),
+
+  #[error("Failed to read response body: {0}")]
+  Body(String),
+
+  #[error("Response body is larger than the maximum of {0} bytes")]
+  ResponseTooLarge(u64),
+
+  #[error("Too many redirects, the maximum is {0}")]
+  TooManyRedirects(u32),
+
+  #[error("Redirect to {0} leaves the origin of the request")]
+  CrossOriginRedirect(Url),
+
+  #[error("Health check request to {0} failed with status {1}")]
+  HealthCheck(Url, u16),
 }
@@ -111,9 +111,11 @@
 // !!END_LINTS
 // Add exceptions here
 #![allow(missing_docs)]
+mod cache;
 mod component;
 mod conversions;
 mod error;
+mod retry;
 
 #[macro_use]
 extern crate tracing;
@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Client, Method, Request, Response, StatusCode};
+use tokio::time::Instant;
+use tracing::{Instrument, Span};
+use wick_config::config::components::HttpRetryConfig;
+use wick_config::config::HttpMethod;
+
+use crate::cache::{header_date, now};
+
+/// The statuses retried when a policy doesn't list any.
+static DEFAULT_STATUSES: [StatusCode; 4] = [
+  StatusCode::TOO_MANY_REQUESTS,
+  StatusCode::BAD_GATEWAY,
+  StatusCode::SERVICE_UNAVAILABLE,
+  StatusCode::GATEWAY_TIMEOUT,
+];
+
+/// When and how often a failed request is sent again.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RetryPolicy {
+  max_attempts: u32,
+  statuses: Vec<StatusCode>,
+  methods: Vec<Method>,
+  backoff: Duration,
+  max_backoff: Duration,
+}
+
+impl From<&HttpRetryConfig> for RetryPolicy {
+  fn from(config: &HttpRetryConfig) -> Self {
+    let statuses = if config.statuses().is_empty() {
+      DEFAULT_STATUSES.to_vec()
+    } else {
+      config
+        .statuses()
+        .iter()
+        .filter_map(|s| StatusCode::from_u16(*s).ok())
+        .collect()
+    };
+    // Only idempotent methods are safe to send twice unless the configuration says otherwise.
+    let methods = if config.methods().is_empty() {
+      vec![Method::GET, Method::PUT, Method::DELETE]
+    } else {
+      config.methods().iter().copied().map(to_method).collect()
+    };
+    Self {
+      max_attempts: config.max_attempts().max(1),
+      statuses,
+      methods,
+      backoff: Duration::from_millis(config.backoff()),
+      max_backoff: Duration::from_millis(config.max_backoff()),
+    }
+  }
+}
+
+impl RetryPolicy {
+  /// The delay before the passed retry: the backoff doubled for every earlier retry, capped at the
+  /// maximum backoff and reduced by a random amount of up to half to spread out retrying clients.
+  fn backoff(&self, retry: u32) -> Duration {
+    let factor = 2_u32.saturating_pow(retry.saturating_sub(1));
+    let delay = self.backoff.saturating_mul(factor).min(self.max_backoff);
+    delay.mul_f64(1.0 - rand::thread_rng().gen_range(0.0..=0.5))
+  }
+
+  /// The delay before retrying a response, or `None` if the server asked to wait longer than the
+  /// maximum backoff.
+  fn delay(&self, retry: u32, headers: &HeaderMap) -> Option<Duration> {
+    match retry_after(headers) {
+      Some(delay) if delay > self.max_backoff => None,
+      Some(delay) => Some(delay),
+      None => Some(self.backoff(retry)),
+    }
+  }
+}
+
+pub(crate) const fn to_method(method: HttpMethod) -> Method {
+  match method {
+    HttpMethod::Get => Method::GET,
+    HttpMethod::Post => Method::POST,
+    HttpMethod::Put => Method::PUT,
+    HttpMethod::Delete => Method::DELETE,
+  }
+}
+
+/// Parse a `Retry-After` header, given either in seconds or as an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+  let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+  if let Ok(seconds) = value.parse::<u64>() {
+    return Some(Duration::from_secs(seconds));
+  }
+  let date = header_date(headers, RETRY_AFTER)?;
+  Some(Duration::from_secs(date.saturating_sub(now())))
+}
+
+/// Send a request, sending it again as the retry policy allows when it fails.
+///
+/// Every attempt is traced in its own span and waits at most `timeout` for a response, less if the
+/// invocation's `deadline` comes first. No retry is made that would start after the deadline.
+pub(crate) async fn execute(
+  client: &Client,
+  mut request: Request,
+  policy: Option<&RetryPolicy>,
+  timeout: Option<Duration>,
+  deadline: Option<Instant>,
+  parent: &Span,
+) -> Result<Response, reqwest::Error> {
+  let policy = policy.filter(|p| p.methods.contains(request.method()));
+  let max_attempts = policy.map_or(1, |p| p.max_attempts);
+  let mut attempt = 1;
+
+  loop {
+    // Requests with streaming bodies can't be cloned, and so can't be retried.
+    let next = if attempt < max_attempts {
+      request.try_clone()
+    } else {
+      None
+    };
+    let remaining = deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()));
+    if let Some(timeout) = match (timeout, remaining) {
+      (Some(timeout), Some(remaining)) => Some(timeout.min(remaining)),
+      (timeout, remaining) => timeout.or(remaining),
+    } {
+      *request.timeout_mut() = Some(timeout);
+    }
+
+    let span = info_span!(
+      parent: parent,
+      "http:client:attempt",
+      attempt,
+      method = %request.method(),
+      url = %request.url(),
+      status = tracing::field::Empty
+    );
+    let result = client.execute(request).instrument(span.clone()).await;
+
+    let delay = match &result {
+      Ok(response) => {
+        span.record("status", response.status().as_u16());
+        policy
+          .filter(|p| p.statuses.contains(&response.status()))
+          .and_then(|p| p.delay(attempt, response.headers()))
+      }
+      // Only failures to get any response are retried, a broken response body isn't.
+      Err(e) if e.is_connect() || e.is_timeout() || e.is_request() => {
+        span.in_scope(|| debug!(error = %e, "http:client:attempt"));
+        policy.map(|p| p.backoff(attempt))
+      }
+      Err(_) => None,
+    };
+
+    let (Some(delay), Some(next)) = (delay, next) else {
+      return result;
+    };
+    if deadline.map_or(false, |deadline| Instant::now() + delay >= deadline) {
+      return result;
+    }
+    drop(result);
+    span.in_scope(|| debug!(delay_ms = delay.as_millis() as u64, "http:client:retry"));
+    tokio::time::sleep(delay).await;
+    request = next;
+    attempt += 1;
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use std::time::SystemTime;
+
+  use reqwest::header::HeaderValue;
+  use wick_config::config::components::HttpRetryConfigBuilder;
+
+  use super::*;
+
+  fn policy() -> RetryPolicy {
+    let config = HttpRetryConfigBuilder::default()
+      .max_attempts(5_u32)
+      .backoff(100_u64)
+      .max_backoff(1000_u64)
+      .build()
+      .unwrap();
+    (&config).into()
+  }
+
+  #[test]
+  fn test_defaults() {
+    let policy = policy();
+    assert_eq!(policy.statuses, DEFAULT_STATUSES.to_vec());
+    assert_eq!(policy.methods, vec![Method::GET, Method::PUT, Method::DELETE]);
+  }
+
+  #[test]
+  fn test_backoff() {
+    let policy = policy();
+    for _ in 0..20 {
+      let first = policy.backoff(1);
+      assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+      let third = policy.backoff(3);
+      assert!(third >= Duration::from_millis(200) && third <= Duration::from_millis(400));
+      let capped = policy.backoff(30);
+      assert!(capped >= Duration::from_millis(500) && capped <= Duration::from_millis(1000));
+    }
+  }
+
+  #[test]
+  fn test_retry_after() -> Result<()> {
+    let policy = policy();
+    let mut headers = HeaderMap::new();
+    headers.insert(RETRY_AFTER, HeaderValue::from_static("1"));
+    assert_eq!(policy.delay(1, &headers), Some(Duration::from_secs(1)));
+
+    headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+    assert_eq!(policy.delay(1, &headers), None);
+
+    headers.insert(RETRY_AFTER, HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+    assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+    let later = chrono::DateTime::<chrono::Utc>::from(SystemTime::now() + Duration::from_secs(60));
+    headers.insert(RETRY_AFTER, HeaderValue::from_str(&later.to_rfc2822())?);
+    let delay = retry_after(&headers).unwrap();
+    assert!(delay >= Duration::from_secs(59) && delay <= Duration::from_secs(60));
+    Ok(())
+  }
+}
@@ -0,0 +1,247 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use wick_config::config::components::KeyValueComponentConfig;
+use wick_config::config::Metadata;
+use wick_config::Resolver;
+use wick_interface_types::{ComponentSignature, OperationSignatures};
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream, RuntimeConfig, StreamMap};
+
+use crate::store::Store;
+use crate::Error;
+
+/// A component that stores JSON values by key and shares them across invocations.
+///
+/// The store lives in memory, or in a log file on a volume resource when one is configured.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct KeyValueComponent {
+  signature: ComponentSignature,
+  store: Arc<Mutex<Store>>,
+}
+
+impl KeyValueComponent {
+  /// Create a new component, opening its store on the configured volume if there is one.
+  #[allow(clippy::needless_pass_by_value)]
+  pub fn new(
+    config: KeyValueComponentConfig,
+    metadata: Option<Metadata>,
+    resolver: &Resolver,
+  ) -> Result<Self, ComponentError> {
+    let store = match config.volume() {
+      Some(volume) => {
+        let path = resolver(volume)
+          .and_then(|r| r.try_resource())
+          .and_then(|r| r.try_volume())
+          .and_then(|v| v.path())?;
+        Store::open(&path)?
+      }
+      None => Store::new_memory(),
+    };
+
+    let mut sig = ComponentSignature::new_named("wick/component/kv");
+    sig.metadata.version = metadata.map(|v| v.version().to_owned());
+    sig.operations = config.operation_signatures();
+
+    Ok(Self {
+      signature: sig,
+      store: Arc::new(Mutex::new(store)),
+    })
+  }
+}
+
+impl Component for KeyValueComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    op_config: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let op = invocation.target().operation_id().to_owned();
+    let opdef = self.signature.get_operation(&op).cloned();
+    let store = self.store.clone();
+
+    Box::pin(async move {
+      let Some(opdef) = opdef else {
+        return Err(Error::OpNotFound(op).into());
+      };
+      let ttl = get_ttl(op_config.as_ref())?;
+      let (tx, rx) = invocation.make_response();
+      let span = invocation.span().clone();
+      let (_, stream) = invocation.split();
+      let inputs = StreamMap::from_stream(stream, opdef.inputs().iter().map(|i| i.name.clone()));
+
+      tokio::spawn(async move {
+        if let Err(e) = handle(&op, ttl, &store, inputs, &tx).await {
+          span.in_scope(|| error!(error = %e, "kv"));
+          let _ = tx.error(wick_packet::Error::component_error(e.to_string()));
+        }
+        let _ = tx.send(Packet::done("output"));
+      });
+      Ok(rx)
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+fn get_ttl(config: Option<&RuntimeConfig>) -> Result<Option<Duration>, Error> {
+  match config.and_then(|c| c.get("ttl")) {
+    None | Some(Value::Null) => Ok(None),
+    Some(value) => value
+      .as_u64()
+      .map(|ms| Some(Duration::from_millis(ms)))
+      .ok_or_else(|| Error::InvalidTtl(value.to_string())),
+  }
+}
+
+async fn handle(
+  op: &str,
+  ttl: Option<Duration>,
+  store: &Mutex<Store>,
+  mut inputs: StreamMap,
+  tx: &PacketSender,
+) -> Result<(), ComponentError> {
+  while let Some(mut set) = inputs.next_set().await? {
+    if set.values().all(|p| p.is_done()) {
+      break;
+    }
+    if let Some(packet) = set.values().find(|p| p.is_error()) {
+      let _ = tx.send(Packet::err("output", packet.clone().unwrap_err().msg()));
+      continue;
+    }
+    let mut input = |name: &str| set.remove(name).unwrap_or_else(|| Packet::done(name));
+
+    match op {
+      "get" => {
+        let key: String = decode(&input("key"))?;
+        let value = store.lock().get(&key);
+        tx.send(Packet::encode("output", value))?;
+      }
+      "set" => {
+        let key: String = decode(&input("key"))?;
+        let value: Value = decode(&input("value"))?;
+        store.lock().set(key, value, ttl)?;
+        tx.send(Packet::encode("output", true))?;
+      }
+      "delete" => {
+        let key: String = decode(&input("key"))?;
+        let existed = store.lock().delete(&key)?;
+        tx.send(Packet::encode("output", existed))?;
+      }
+      "compare_and_swap" => {
+        let key: String = decode(&input("key"))?;
+        let expected: Option<Value> = decode(&input("expected"))?;
+        let value: Value = decode(&input("value"))?;
+        let swapped = store.lock().compare_and_swap(key, expected.as_ref(), value, ttl)?;
+        tx.send(Packet::encode("output", swapped))?;
+      }
+      "scan" => {
+        let prefix: String = decode(&input("prefix"))?;
+        let entries = store.lock().scan(&prefix);
+        for (key, value) in entries {
+          tx.send(Packet::encode("output", json!({ "key": key, "value": value })))?;
+        }
+      }
+      _ => return Err(Error::OpNotFound(op.to_owned()).into()),
+    }
+  }
+  Ok(())
+}
+
+fn decode<T: DeserializeOwned>(packet: &Packet) -> Result<T, Error> {
+  let port = packet.port().to_owned();
+  packet.decode().map_err(|e| Error::InvalidInput(port, e.to_string()))
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use futures::StreamExt;
+  use pretty_assertions::assert_eq;
+  use wick_config::config::AppConfiguration;
+  use wick_packet::{packet_stream, Entity};
+
+  use super::*;
+
+  fn component() -> KeyValueComponent {
+    let resolver = AppConfiguration::default().resolver();
+    KeyValueComponent::new(Default::default(), None, &resolver).unwrap()
+  }
+
+  async fn invoke(
+    component: &KeyValueComponent,
+    op: &str,
+    packets: PacketStream,
+    config: Option<RuntimeConfig>,
+  ) -> Result<Vec<Value>> {
+    let invocation = Invocation::test(op, Entity::local(op), packets, Default::default())?;
+    let stream = component.handle(invocation, config, Default::default()).await?;
+    let packets: Vec<_> = stream.collect().await;
+    let mut values = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      if packet.has_data() {
+        values.push(packet.decode_value()?);
+      }
+    }
+    Ok(values)
+  }
+
+  #[test]
+  const fn test_component() {
+    const fn is_send_sync<T: Send + Sync>() {}
+    is_send_sync::<KeyValueComponent>();
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_operations() -> Result<()> {
+    let kv = component();
+    let set = invoke(&kv, "set", packet_stream!(("key", "a"), ("value", json!({"n": 1}))), None).await?;
+    assert_eq!(set, vec![json!(true)]);
+    let get = invoke(&kv, "get", packet_stream!(("key", "a")), None).await?;
+    assert_eq!(get, vec![json!({"n": 1})]);
+
+    let packets = packet_stream!(("key", "a"), ("expected", json!({"n": 2})), ("value", 3));
+    let cas = invoke(&kv, "compare_and_swap", packets, None).await?;
+    assert_eq!(cas, vec![json!(false)]);
+    let packets = packet_stream!(("key", "a"), ("expected", json!({"n": 1})), ("value", 3));
+    let cas = invoke(&kv, "compare_and_swap", packets, None).await?;
+    assert_eq!(cas, vec![json!(true)]);
+
+    invoke(&kv, "set", packet_stream!(("key", "b"), ("value", 4)), None).await?;
+    let scan = invoke(&kv, "scan", packet_stream!(("prefix", "")), None).await?;
+    assert_eq!(
+      scan,
+      vec![json!({"key": "a", "value": 3}), json!({"key": "b", "value": 4})]
+    );
+
+    let delete = invoke(&kv, "delete", packet_stream!(("key", "a"), ("key", "a")), None).await?;
+    assert_eq!(delete, vec![json!(true), json!(false)]);
+    let get = invoke(&kv, "get", packet_stream!(("key", "a")), None).await?;
+    assert_eq!(get, vec![Value::Null]);
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_ttl_config() -> Result<()> {
+    let kv = component();
+    let config = RuntimeConfig::from(HashMap::from([("ttl".to_owned(), json!(0))]));
+    invoke(&kv, "set", packet_stream!(("key", "a"), ("value", 1)), Some(config)).await?;
+    let get = invoke(&kv, "get", packet_stream!(("key", "a")), None).await?;
+    assert_eq!(get, vec![Value::Null]);
+
+    let config = RuntimeConfig::from(HashMap::from([("ttl".to_owned(), json!("soon"))]));
+    let result = invoke(&kv, "set", packet_stream!(("key", "a"), ("value", 1)), Some(config)).await;
+    assert!(result.is_err());
+    Ok(())
+  }
+}
@@ -0,0 +1,20 @@
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+/// Errors that can occur while running a key/value component.
+pub enum Error {
+  /// The requested operation isn't one of this component's operations.
+  #[error("Could not find operation {0} on this component")]
+  OpNotFound(String),
+
+  /// The store could not be read from or written to its volume.
+  #[error("Key/value store error at {0}: {1}")]
+  Storage(std::path::PathBuf, std::io::Error),
+
+  /// An input value could not be decoded.
+  #[error("Invalid input for port '{0}': {1}")]
+  InvalidInput(String, String),
+
+  /// The `ttl` configuration could not be decoded.
+  #[error("Invalid ttl configuration: {0}")]
+  InvalidTtl(String),
+}
@@ -0,0 +1,381 @@
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Error;
+
+/// The name of the file the store is persisted to inside its volume.
+pub(crate) const LOG_FILE: &str = "kv.log";
+
+/// Logs shorter than this are never compacted.
+const COMPACT_MIN_RECORDS: usize = 1024;
+
+/// Expired entries are swept from memory after this many writes.
+const SWEEP_INTERVAL: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Entry {
+  value: Value,
+  expires: Option<u64>,
+}
+
+impl Entry {
+  fn is_live(&self, now: u64) -> bool {
+    self.expires.map_or(true, |expires| expires > now)
+  }
+}
+
+/// A change to the store, as written to its log.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum Record {
+  Set {
+    key: String,
+    value: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    expires: Option<u64>,
+  },
+  Delete {
+    key: String,
+  },
+}
+
+#[derive(Debug)]
+struct Log {
+  path: PathBuf,
+  writer: BufWriter<File>,
+  records: usize,
+}
+
+impl Log {
+  fn append(&mut self, record: &Record) -> Result<(), Error> {
+    let mut line = serde_json::to_vec(record).map_err(|e| self.error(e.into()))?;
+    line.push(b'\n');
+    self.writer.write_all(&line).map_err(|e| self.error(e))?;
+    self.writer.flush().map_err(|e| self.error(e))?;
+    self.records += 1;
+    Ok(())
+  }
+
+  fn error(&self, e: std::io::Error) -> Error {
+    Error::Storage(self.path.clone(), e)
+  }
+}
+
+/// An ordered key/value map whose entries can expire.
+///
+/// A store opened on a directory appends every change to a log file there and replays it when reopened. The log is
+/// rewritten with only the live entries once it holds more than twice as many records as there are entries.
+#[derive(Debug, Default)]
+pub(crate) struct Store {
+  entries: BTreeMap<String, Entry>,
+  log: Option<Log>,
+  writes: usize,
+}
+
+impl Store {
+  /// Create a store that only lives in memory.
+  pub(crate) fn new_memory() -> Self {
+    Self::default()
+  }
+
+  /// Open the store persisted in the passed directory, creating it if necessary.
+  pub(crate) fn open(dir: &Path) -> Result<Self, Error> {
+    std::fs::create_dir_all(dir).map_err(|e| Error::Storage(dir.to_path_buf(), e))?;
+    let path = dir.join(LOG_FILE);
+    let mut entries = BTreeMap::new();
+    let mut records = 0;
+    let mut unreadable = false;
+    if path.exists() {
+      let file = File::open(&path).map_err(|e| Error::Storage(path.clone(), e))?;
+      for line in BufReader::new(file).lines() {
+        let line = line.map_err(|e| Error::Storage(path.clone(), e))?;
+        if line.is_empty() {
+          continue;
+        }
+        match serde_json::from_str(&line) {
+          Ok(record) => {
+            apply(&mut entries, record);
+            records += 1;
+          }
+          // A partial line is left behind when the process stops mid-write.
+          Err(e) => {
+            warn!(path = %path.display(), error = %e, "kv:skipping unreadable record");
+            unreadable = true;
+          }
+        }
+      }
+    }
+    let now = now();
+    entries.retain(|_, entry: &mut Entry| entry.is_live(now));
+
+    let mut store = Self {
+      entries,
+      log: Some(open_log(path, records)?),
+      writes: 0,
+    };
+    if unreadable {
+      // Rewrite the log so later records aren't appended to a partial line.
+      store.compact()?;
+    } else {
+      store.compact_if_needed()?;
+    }
+    Ok(store)
+  }
+
+  /// Get the value stored for `key`.
+  pub(crate) fn get(&mut self, key: &str) -> Option<Value> {
+    let now = now();
+    match self.entries.get(key) {
+      Some(entry) if entry.is_live(now) => Some(entry.value.clone()),
+      Some(_) => {
+        self.entries.remove(key);
+        None
+      }
+      None => None,
+    }
+  }
+
+  /// Store `value` for `key`, expiring it after `ttl` if passed.
+  pub(crate) fn set(&mut self, key: String, value: Value, ttl: Option<Duration>) -> Result<(), Error> {
+    let expires = ttl.map(|ttl| now().saturating_add(u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX)));
+    self.write(Record::Set { key, value, expires })
+  }
+
+  /// Delete `key`, returning whether it held a live value.
+  pub(crate) fn delete(&mut self, key: &str) -> Result<bool, Error> {
+    let existed = self.get(key).is_some();
+    if existed {
+      self.write(Record::Delete { key: key.to_owned() })?;
+    }
+    Ok(existed)
+  }
+
+  /// Store `value` for `key` only if its current value equals `expected`, where `None` means the key must be
+  /// unset. Returns whether the value was stored.
+  pub(crate) fn compare_and_swap(
+    &mut self,
+    key: String,
+    expected: Option<&Value>,
+    value: Value,
+    ttl: Option<Duration>,
+  ) -> Result<bool, Error> {
+    if self.get(&key).as_ref() != expected {
+      return Ok(false);
+    }
+    self.set(key, value, ttl)?;
+    Ok(true)
+  }
+
+  /// Get every live entry whose key starts with `prefix`, in key order.
+  pub(crate) fn scan(&self, prefix: &str) -> Vec<(String, Value)> {
+    let now = now();
+    self
+      .entries
+      .range::<str, _>((Bound::Included(prefix), Bound::Unbounded))
+      .take_while(|(key, _)| key.starts_with(prefix))
+      .filter(|(_, entry)| entry.is_live(now))
+      .map(|(key, entry)| (key.clone(), entry.value.clone()))
+      .collect()
+  }
+
+  fn write(&mut self, record: Record) -> Result<(), Error> {
+    if let Some(log) = &mut self.log {
+      log.append(&record)?;
+    }
+    apply(&mut self.entries, record);
+
+    self.writes += 1;
+    if self.writes % SWEEP_INTERVAL == 0 {
+      let now = now();
+      self.entries.retain(|_, entry| entry.is_live(now));
+    }
+    self.compact_if_needed()
+  }
+
+  fn compact_if_needed(&mut self) -> Result<(), Error> {
+    let Some(log) = &self.log else {
+      return Ok(());
+    };
+    if log.records < COMPACT_MIN_RECORDS || log.records <= self.entries.len() * 2 {
+      return Ok(());
+    }
+    self.compact()
+  }
+
+  fn compact(&mut self) -> Result<(), Error> {
+    let Some(log) = &self.log else {
+      return Ok(());
+    };
+    let path = log.path.clone();
+    let tmp = path.with_extension("log.tmp");
+    let now = now();
+    let write_tmp = || -> std::io::Result<usize> {
+      let mut writer = BufWriter::new(File::create(&tmp)?);
+      let mut records = 0;
+      for (key, entry) in self.entries.iter().filter(|(_, entry)| entry.is_live(now)) {
+        let record = Record::Set {
+          key: key.clone(),
+          value: entry.value.clone(),
+          expires: entry.expires,
+        };
+        serde_json::to_writer(&mut writer, &record)?;
+        writer.write_all(b"\n")?;
+        records += 1;
+      }
+      writer.into_inner().map_err(std::io::IntoInnerError::into_error)?.sync_all()?;
+      std::fs::rename(&tmp, &path)?;
+      Ok(records)
+    };
+    let records = write_tmp().map_err(|e| Error::Storage(path.clone(), e))?;
+    debug!(path = %path.display(), records, "kv:compacted log");
+    self.log = Some(open_log(path, records)?);
+    Ok(())
+  }
+}
+
+fn apply(entries: &mut BTreeMap<String, Entry>, record: Record) {
+  match record {
+    Record::Set { key, value, expires } => {
+      entries.insert(key, Entry { value, expires });
+    }
+    Record::Delete { key } => {
+      entries.remove(&key);
+    }
+  }
+}
+
+fn open_log(path: PathBuf, records: usize) -> Result<Log, Error> {
+  let file = OpenOptions::new()
+    .create(true)
+    .append(true)
+    .open(&path)
+    .map_err(|e| Error::Storage(path.clone(), e))?;
+  Ok(Log {
+    path,
+    writer: BufWriter::new(file),
+    records,
+  })
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wick-kv-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  #[test]
+  fn test_basic_operations() -> Result<()> {
+    let mut store = Store::new_memory();
+    assert_eq!(store.get("a"), None);
+    store.set("a".to_owned(), json!(1), None)?;
+    assert_eq!(store.get("a"), Some(json!(1)));
+    assert!(store.delete("a")?);
+    assert!(!store.delete("a")?);
+    assert_eq!(store.get("a"), None);
+    Ok(())
+  }
+
+  #[test]
+  fn test_compare_and_swap() -> Result<()> {
+    let mut store = Store::new_memory();
+    assert!(store.compare_and_swap("a".to_owned(), None, json!(1), None)?);
+    assert!(!store.compare_and_swap("a".to_owned(), None, json!(2), None)?);
+    assert!(!store.compare_and_swap("a".to_owned(), Some(&json!(2)), json!(3), None)?);
+    assert!(store.compare_and_swap("a".to_owned(), Some(&json!(1)), json!(3), None)?);
+    assert_eq!(store.get("a"), Some(json!(3)));
+    Ok(())
+  }
+
+  #[test]
+  fn test_ttl() -> Result<()> {
+    let mut store = Store::new_memory();
+    store.set("a".to_owned(), json!(1), Some(Duration::from_millis(0)))?;
+    store.set("b".to_owned(), json!(2), Some(Duration::from_secs(60)))?;
+    assert_eq!(store.get("a"), None);
+    assert_eq!(store.get("b"), Some(json!(2)));
+    assert!(store.compare_and_swap("a".to_owned(), None, json!(3), None)?);
+    Ok(())
+  }
+
+  #[test]
+  fn test_scan() -> Result<()> {
+    let mut store = Store::new_memory();
+    for key in ["user:2", "user:1", "users", "other", "user:3"] {
+      store.set(key.to_owned(), json!(key), None)?;
+    }
+    store.set("user:4".to_owned(), json!(4), Some(Duration::from_millis(0)))?;
+    let keys: Vec<_> = store.scan("user:").into_iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, vec!["user:1", "user:2", "user:3"]);
+    assert_eq!(store.scan("").len(), 5);
+    Ok(())
+  }
+
+  #[test]
+  fn test_persistence() -> Result<()> {
+    let dir = temp_dir("persistence");
+    let mut store = Store::open(&dir)?;
+    store.set("a".to_owned(), json!({"n": 1}), None)?;
+    store.set("b".to_owned(), json!(2), None)?;
+    store.set("c".to_owned(), json!(3), Some(Duration::from_millis(0)))?;
+    store.delete("b")?;
+    drop(store);
+
+    // Simulate a write that was interrupted.
+    let mut file = OpenOptions::new().append(true).open(dir.join(LOG_FILE))?;
+    file.write_all(b"{\"op\":\"set\",\"key\":\"d\"")?;
+
+    let mut store = Store::open(&dir)?;
+    assert_eq!(store.get("a"), Some(json!({"n": 1})));
+    assert_eq!(store.get("b"), None);
+    assert_eq!(store.get("c"), None);
+    assert_eq!(store.get("d"), None);
+    store.set("e".to_owned(), json!(5), None)?;
+    drop(store);
+
+    let mut store = Store::open(&dir)?;
+    assert_eq!(store.get("a"), Some(json!({"n": 1})));
+    assert_eq!(store.get("e"), Some(json!(5)));
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_compaction() -> Result<()> {
+    let dir = temp_dir("compaction");
+    let mut store = Store::open(&dir)?;
+    for i in 0..COMPACT_MIN_RECORDS * 2 {
+      store.set("counter".to_owned(), json!(i), None)?;
+    }
+    store.set("other".to_owned(), json!(true), None)?;
+    let lines = std::fs::read_to_string(dir.join(LOG_FILE))?.lines().count();
+    assert!(lines < COMPACT_MIN_RECORDS, "log should have been compacted, has {} lines", lines);
+    drop(store);
+
+    let mut store = Store::open(&dir)?;
+    assert_eq!(store.get("counter"), Some(json!(COMPACT_MIN_RECORDS * 2 - 1)));
+    assert_eq!(store.get("other"), Some(json!(true)));
+    std::fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+}
@@ -18,6 +18,9 @@ use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketS
 use crate::common::{Connection, DatabaseProvider};
 use crate::{common, Error};
 
+/// The query run to check that the database is reachable.
+const HEALTH_CHECK_QUERY: &str = "SELECT 1";
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum DbKind {
   Mssql,
@@ -179,6 +182,18 @@ impl Component for SqlComponent {
   fn signature(&self) -> &ComponentSignature {
     &self.signature
   }
+
+  fn health(&self) -> BoxFuture<Result<(), ComponentError>> {
+    let client = self.provider.clone();
+    Box::pin(async move {
+      let mut connection = client.get_connection().await?;
+      let mut rows = connection.query(HEALTH_CHECK_QUERY, Vec::new()).await?;
+      while let Some(row) = rows.next().await {
+        row?;
+      }
+      Ok(())
+    })
+  }
 }
 
 fn validate(config: &SqlComponentConfig, _resolver: &Resolver) -> Result<(), Error> {
@@ -642,4 +657,11 @@ mod integration_test {
     );
     Ok(())
   }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_sqlite_health() -> Result<()> {
+    let db = init_sqlite_component().await?;
+    db.health().await?;
+    Ok(())
+  }
 }
@@ -14,6 +14,7 @@ async fn request_repr() -> Result<()> {
     method: wick_interface_http::types::HttpMethod::Get,
     query_parameters: [("url".to_owned(), vec!["https://google.com".to_owned()])].into(),
     scheme: wick_interface_http::types::HttpScheme::Http,
+    context: Default::default(),
   };
   let expected_json = json!({
     "authority":"localhost:8080",
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use seeded_random::{Random, Seed};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use wick_packet::{date_from_millis, ContextTransport, DateTime, InherentData};
+use wick_packet::{date_from_millis, ContextTransport, DateTime, InherentData, InvocationContext};
 
 #[cfg(target_family = "wasm")]
 /// A conditional trait that implements Send if the target is not wasm.
@@ -52,10 +52,7 @@ where
 {
   fn from(value: ContextTransport<T>) -> Self {
     Self {
-      inherent: InherentContext {
-        rng: Random::from_seed(Seed::unsafe_new(value.inherent.seed)),
-        timestamp: date_from_millis(value.inherent.timestamp).unwrap(),
-      },
+      inherent: value.inherent.into(),
       config: Arc::new(value.config),
       #[cfg(feature = "invocation")]
       callback: Default::default(),
@@ -71,6 +68,8 @@ pub struct InherentContext {
   pub rng: Random,
   /// The timestamp of the invocation.
   pub timestamp: DateTime,
+  /// The request context propagated with the invocation.
+  pub context: InvocationContext,
 }
 
 impl Clone for InherentContext {
@@ -78,13 +77,14 @@ impl Clone for InherentContext {
     Self {
       rng: Random::from_seed(self.rng.seed()),
       timestamp: self.timestamp,
+      context: self.context.clone(),
     }
   }
 }
 
 impl From<InherentContext> for InherentData {
   fn from(value: InherentContext) -> Self {
-    Self::new(value.rng.gen(), value.timestamp.timestamp_millis() as _)
+    Self::new(value.rng.gen(), value.timestamp.timestamp_millis() as _).with_context(value.context)
   }
 }
 
@@ -93,6 +93,7 @@ impl From<InherentData> for InherentContext {
     Self {
       rng: Random::from_seed(Seed::unsafe_new(value.seed)),
       timestamp: date_from_millis(value.timestamp).unwrap(),
+      context: value.context,
     }
   }
 }
@@ -109,6 +110,7 @@ where
       inherent: InherentContext {
         rng: Random::from_seed(Seed::unsafe_new(inherent.seed)),
         timestamp: date_from_millis(inherent.timestamp).unwrap(),
+        context: inherent.context.clone(),
       },
       config: Arc::new(config),
       callback,
@@ -122,6 +124,7 @@ where
       inherent: InherentContext {
         rng: Random::from_seed(Seed::unsafe_new(inherent.seed)),
         timestamp: date_from_millis(inherent.timestamp).unwrap(),
+        context: inherent.context.clone(),
       },
       config: Arc::new(config),
     }
@@ -29,6 +29,12 @@ pub trait Component {
     // Override if you need a more explicit shutdown.
     Box::pin(async move { Ok(()) })
   }
+
+  /// The `health` method reports whether the component is ready to handle invocations, e.g. whether its
+  /// connections to external services are available. The default implementation always reports ready.
+  fn health(&self) -> BoxFuture<Result<(), anyhow::Error>> {
+    Box::pin(async move { Ok(()) })
+  }
 }
 
 /// The [RenderConfiguration] trait allows you to build structs that can decode and render dynamic configuration.
@@ -271,8 +271,7 @@ impl FromStr for FlowExpression {
   type Err = Error;
 
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let (_, v) = crate::parse::v1::flow_expression(s).map_err(|e| Error::FlowExpressionParse(e.to_string()))?;
-    Ok(v)
+    crate::parse::v1::parse_flow_expression(s)
   }
 }
 
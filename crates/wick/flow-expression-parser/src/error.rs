@@ -33,6 +33,18 @@ pub enum ParserError {
   InvalidSenderData(String),
 
   /// Error occurred parsing a flow expression.
-  #[error("Could not parse string into a FlowExpression '{0}'")]
-  FlowExpressionParse(String),
+  #[error("Could not parse flow expression '{expression}' at column {}: {hint}", column(.expression, *.offset))]
+  FlowExpressionParse {
+    /// The expression that failed to parse.
+    expression: String,
+    /// The byte offset into the expression where parsing failed.
+    offset: usize,
+    /// What the parser expected at `offset`.
+    hint: String,
+  },
+}
+
+/// Get the 1-based character column of a byte offset into an expression.
+fn column(expression: &str, offset: usize) -> usize {
+  expression.get(..offset).map_or(offset, |s| s.chars().count()) + 1
 }
@@ -191,6 +191,57 @@ pub(crate) fn flow_expression(input: &str) -> IResult<&str, FlowExpression> {
   Ok((i, expr))
 }
 
+/// Parse a single flow expression, reporting where it stopped parsing and what was expected there.
+pub(crate) fn parse_flow_expression(input: &str) -> Result<FlowExpression, Error> {
+  flow_expression(input).map(|(_, v)| v).map_err(|_| {
+    let (offset, hint) = diagnose(input);
+    Error::FlowExpressionParse {
+      expression: input.to_owned(),
+      offset,
+      hint,
+    }
+  })
+}
+
+/// Walk an invalid flow expression target by target to find where it stops parsing.
+fn diagnose(input: &str) -> (usize, String) {
+  let offset = |rest: &str| input.len() - rest.len();
+  let target = |i| alt((connection_target_expression, portless_target_expression))(i);
+
+  let Ok((mut rest, _)) = target(input) else {
+    return (0, "expected a target like `<input>.port`, `name.port`, or `ns::operation`".to_owned());
+  };
+  let mut hops = 0;
+  loop {
+    let next = rest.trim_start();
+    if let Some(after) = next.strip_prefix(CONNECTION_SEPARATOR) {
+      let after = after.trim_start();
+      match target(after) {
+        Ok((remaining, _)) => {
+          rest = remaining;
+          hops += 1;
+          continue;
+        }
+        Err(_) => return (offset(after), "expected a target after `->`".to_owned()),
+      }
+    }
+
+    let hint = match next.chars().next() {
+      None | Some(';') if hops == 0 => "expected `->` followed by another target".to_owned(),
+      Some(';') => {
+        let after = next[1..].trim_start();
+        return (offset(after), "unexpected input after `;`".to_owned());
+      }
+      Some('=' | '-' | '>' | '~' | '\u{2192}') => "targets are connected with `->`".to_owned(),
+      Some('.') => return (offset(next) + 1, "expected a port name after `.`".to_owned()),
+      Some('[') => "inline IDs must be identifiers, e.g. `ns::op[ID]`".to_owned(),
+      Some(c) => format!("unexpected `{}`, expected `->`", c),
+      None => "unexpected end of expression".to_owned(),
+    };
+    return (offset(next), hint);
+  }
+}
+
 fn _parse(input: &str) -> IResult<&str, FlowProgram> {
   let (i, expressions) = many0(flow_expression)(input)?;
 
@@ -228,6 +279,30 @@ mod tests {
   use crate::ast::{set_seed, BlockExpression};
   // use crate::ast::BlockExpression;
 
+  #[rstest]
+  #[case("<input>.left => add.left", 13, "targets are connected with `->`")]
+  #[case("<input>.left -> ", 16, "expected a target after `->`")]
+  #[case("<input>.left", 12, "expected `->` followed by another target")]
+  #[case("<input>. -> add.left", 8, "expected a port name after `.`")]
+  #[case("<input>.left -> add.left x", 25, "unexpected `x`, expected `->`")]
+  #[case(
+    "-> add.left",
+    0,
+    "expected a target like `<input>.port`, `name.port`, or `ns::operation`"
+  )]
+  fn test_parse_errors(#[case] input: &str, #[case] offset: usize, #[case] hint: &str) -> Result<()> {
+    let err = parse_flow_expression(input).unwrap_err();
+    assert_eq!(
+      err,
+      Error::FlowExpressionParse {
+        expression: input.to_owned(),
+        offset,
+        hint: hint.to_owned()
+      }
+    );
+    Ok(())
+  }
+
   #[rstest]
   #[case("<>", InstTgt::Default)]
   #[case("<input>", InstTgt::Input)]
@@ -97,6 +97,12 @@ pub(super) fn decorate(
     match operation {
       components::core::pluck::Op::ID => components::core::pluck::Op::decorate(node),
       components::core::collect::Op::ID => components::core::collect::Op::decorate(node),
+      components::core::batch::Op::ID => components::core::batch::Op::decorate(node),
+      components::core::window::Op::ID => components::core::window::Op::decorate(node),
+      components::core::flatten::Op::ID => components::core::flatten::Op::decorate(node),
+      components::core::map::Op::ID => components::core::map::Op::decorate(node),
+      components::core::filter::Op::ID => components::core::filter::Op::decorate(node),
+      components::core::join::Op::ID => components::core::join::Op::decorate(node),
       components::core::merge::Op::ID => components::core::merge::Op::decorate(node),
       components::core::log::Op::ID => components::core::log::Op::decorate(node),
       components::core::sender::Op::ID => components::core::sender::Op::decorate(node),
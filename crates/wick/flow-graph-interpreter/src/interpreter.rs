@@ -10,6 +10,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use flow_component::{Component, ComponentError, LocalScope};
+use futures::future::join_all;
 use futures::{FutureExt, TryFutureExt};
 use parking_lot::Mutex;
 use tracing::{info_span, Span};
@@ -24,6 +25,7 @@ use self::program::Program;
 use crate::graph::types::*;
 use crate::interpreter::channel::InterpreterChannel;
 use crate::interpreter::components::component::ComponentComponent;
+use crate::interpreter::components::core::CoreComponent;
 use crate::interpreter::components::null::NullComponent;
 use crate::interpreter::components::self_component::SelfComponent;
 use crate::interpreter::executor::error::ExecutionError;
@@ -220,6 +222,20 @@ impl Interpreter {
     &self.components
   }
 
+  /// Run the health checks of every component this interpreter hosts, excluding its internal components.
+  pub async fn check_health(&self) -> Vec<(String, Result<(), ComponentError>)> {
+    let internal = [NullComponent::ID, ComponentComponent::ID, CoreComponent::ID];
+    let checks = self
+      .components
+      .inner()
+      .iter()
+      .filter(|(ns, _)| !internal.contains(&ns.as_str()))
+      .map(|(ns, handler)| handler.component().health().map(move |result| (ns.clone(), result)));
+    let mut results = join_all(checks).await;
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    results
+  }
+
   pub fn render_dotviz(&self, op: &str) -> Result<String, Error> {
     self.program.dotviz(op)
   }
@@ -246,6 +262,9 @@ impl Component for Interpreter {
     let from_exposed = self.exposed_ops.get(invocation.target().operation_id());
 
     Box::pin(async move {
+      if invocation.context().is_expired() {
+        return Err(ComponentError::new(Error::DeadlineExceeded(invocation.target().clone())));
+      }
       let stream = match invocation.target() {
         Entity::Operation(ns, _) => {
           if ns == SelfComponent::ID || ns == Entity::LOCAL || Some(ns) == self.namespace.as_ref() {
@@ -288,6 +307,23 @@ impl Component for Interpreter {
   fn shutdown(&self) -> flow_component::BoxFuture<Result<(), ComponentError>> {
     self.stop().map_err(ComponentError::new).boxed()
   }
+
+  fn health(&self) -> flow_component::BoxFuture<Result<(), ComponentError>> {
+    async move {
+      let failed: Vec<_> = self
+        .check_health()
+        .await
+        .into_iter()
+        .filter_map(|(ns, result)| result.err().map(|e| format!("{}: {}", ns, e)))
+        .collect();
+      if failed.is_empty() {
+        Ok(())
+      } else {
+        Err(ComponentError::msg(failed.join(", ")))
+      }
+    }
+    .boxed()
+  }
 }
 
 #[derive(Debug, Clone)]
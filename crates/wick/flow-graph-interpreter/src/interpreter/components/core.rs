@@ -1,19 +1,37 @@
 use flow_component::{Component, ComponentError, Context, LocalScope, Operation, RenderConfiguration};
-use wick_interface_types::{ComponentSignature, TypeDefinition};
+use serde::de::DeserializeOwned;
+use wick_interface_types::{ComponentSignature, Type, TypeDefinition};
 use wick_packet::{InherentData, Invocation, PacketStream, RuntimeConfig};
 
 use crate::graph::types::Network;
 use crate::interpreter::components::dyn_component_id;
 use crate::{BoxFuture, HandlerMap};
 
+pub(crate) mod batch;
 pub(crate) mod collect;
+mod expression;
+pub(crate) mod filter;
+pub(crate) mod flatten;
+pub(crate) mod join;
 pub(crate) mod log;
+pub(crate) mod map;
 pub(crate) mod merge;
 pub(crate) mod pluck;
 pub(crate) mod sender;
 pub(crate) mod switch;
+pub(crate) mod window;
 
-pub(crate) static DYNAMIC_OPERATIONS: &[&str] = &[collect::Op::ID, merge::Op::ID, switch::Op::ID];
+pub(crate) static DYNAMIC_OPERATIONS: &[&str] = &[
+  collect::Op::ID,
+  merge::Op::ID,
+  switch::Op::ID,
+  batch::Op::ID,
+  window::Op::ID,
+  flatten::Op::ID,
+  map::Op::ID,
+  filter::Op::ID,
+  join::Op::ID,
+];
 
 #[derive(Debug)]
 pub(crate) struct CoreComponent {
@@ -23,6 +41,12 @@ pub(crate) struct CoreComponent {
   merge: merge::Op,
   switch: switch::Op,
   collect: collect::Op,
+  batch: batch::Op,
+  window: window::Op,
+  flatten: flatten::Op,
+  map: map::Op,
+  filter: filter::Op,
+  join: join::Op,
   log: log::Op,
 }
 
@@ -52,6 +76,12 @@ enum DynamicOperation {
   Merge,
   Switch,
   Collect,
+  Batch,
+  Window,
+  Flatten,
+  Map,
+  Filter,
+  Join,
 }
 
 impl std::fmt::Display for DynamicOperation {
@@ -60,6 +90,12 @@ impl std::fmt::Display for DynamicOperation {
       DynamicOperation::Merge => f.write_str("merge"),
       DynamicOperation::Switch => f.write_str("switch"),
       DynamicOperation::Collect => f.write_str("collect"),
+      DynamicOperation::Batch => f.write_str("batch"),
+      DynamicOperation::Window => f.write_str("window"),
+      DynamicOperation::Flatten => f.write_str("flatten"),
+      DynamicOperation::Map => f.write_str("map"),
+      DynamicOperation::Filter => f.write_str("filter"),
+      DynamicOperation::Join => f.write_str("join"),
     }
   }
 }
@@ -67,7 +103,7 @@ impl std::fmt::Display for DynamicOperation {
 impl CoreComponent {
   pub(crate) const ID: &str = "core";
 
-  #[allow(clippy::cognitive_complexity)]
+  #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
   pub(crate) fn new(graph: &Network, handlers: &HandlerMap) -> Result<Self, OpInitError> {
     let mut this = Self {
       signature: ComponentSignature::new_named(Self::ID).set_version("0.0.0"),
@@ -77,6 +113,12 @@ impl CoreComponent {
       switch: switch::Op::new(),
       log: log::Op::new(),
       collect: collect::Op::new(),
+      batch: batch::Op::new(),
+      window: window::Op::new(),
+      flatten: flatten::Op::new(),
+      map: map::Op::new(),
+      filter: filter::Op::new(),
+      join: join::Op::new(),
     };
 
     this.signature.operations.push(this.pluck.get_signature(None).clone());
@@ -95,6 +137,12 @@ impl CoreComponent {
           merge::Op::ID => DynamicOperation::Merge,
           switch::Op::ID => DynamicOperation::Switch,
           collect::Op::ID => DynamicOperation::Collect,
+          batch::Op::ID => DynamicOperation::Batch,
+          window::Op::ID => DynamicOperation::Window,
+          flatten::Op::ID => DynamicOperation::Flatten,
+          map::Op::ID => DynamicOperation::Map,
+          filter::Op::ID => DynamicOperation::Filter,
+          join::Op::ID => DynamicOperation::Join,
           _ => continue,
         };
 
@@ -117,6 +165,73 @@ impl CoreComponent {
             }
             Err(e) => Err(OpInitError::new(e, op)),
           },
+          DynamicOperation::Batch => match batch::Op::decode_config(config) {
+            Ok(config) => {
+              let id = dyn_component_id(batch::Op::ID, schematic.name(), operation.id());
+              debug!(%id,%op,"adding type signature for dynamic component");
+              let op_sig = batch::Op::gen_signature(&id, config);
+
+              this.signature.operations.push(op_sig);
+              Ok(())
+            }
+            Err(e) => Err(OpInitError::new(e, op)),
+          },
+          DynamicOperation::Window => match window::Op::decode_config(config) {
+            Ok(config) => {
+              let id = dyn_component_id(window::Op::ID, schematic.name(), operation.id());
+              debug!(%id,%op,"adding type signature for dynamic component");
+              let op_sig = window::Op::gen_signature(&id, config);
+
+              this.signature.operations.push(op_sig);
+              Ok(())
+            }
+            Err(e) => Err(OpInitError::new(e, op)),
+          },
+          DynamicOperation::Flatten => match flatten::Op::decode_config(config) {
+            Ok(config) => {
+              let id = dyn_component_id(flatten::Op::ID, schematic.name(), operation.id());
+              debug!(%id,%op,"adding type signature for dynamic component");
+              let op_sig = flatten::Op::gen_signature(&id, config);
+
+              this.signature.operations.push(op_sig);
+              Ok(())
+            }
+            Err(e) => Err(OpInitError::new(e, op)),
+          },
+          DynamicOperation::Map => match map::Op::decode_config(config) {
+            Ok(config) => {
+              let id = dyn_component_id(map::Op::ID, schematic.name(), operation.id());
+              debug!(%id,%op,"adding type signature for dynamic component");
+              let op_sig = map::Op::gen_signature(&id, config);
+
+              this.signature.operations.push(op_sig);
+              Ok(())
+            }
+            Err(e) => Err(OpInitError::new(e, op)),
+          },
+          DynamicOperation::Filter => match filter::Op::decode_config(config) {
+            Ok(config) => {
+              let id = dyn_component_id(filter::Op::ID, schematic.name(), operation.id());
+              debug!(%id,%op,"adding type signature for dynamic component");
+              let op_sig = filter::Op::gen_signature(&id, config);
+
+              this.signature.operations.push(op_sig);
+              Ok(())
+            }
+            Err(e) => Err(OpInitError::new(e, op)),
+          },
+          DynamicOperation::Join => match join::Op::decode_config(config) {
+            Ok(config) => {
+              let id = dyn_component_id(join::Op::ID, schematic.name(), operation.id());
+              debug!(%id,%op,"adding type signature for dynamic component");
+              let (op_sig, output_sig) = join::Op::gen_signature(id, config);
+
+              this.signature.types.push(TypeDefinition::Struct(output_sig));
+              this.signature.operations.push(op_sig);
+              Ok(())
+            }
+            Err(e) => Err(OpInitError::new(e, op)),
+          },
           DynamicOperation::Merge => match merge::Op::decode_config(config) {
             Ok(config) => {
               let id = dyn_component_id(merge::Op::ID, schematic.name(), operation.id());
@@ -154,6 +269,20 @@ impl CoreComponent {
   }
 }
 
+/// Decode the `type` of the values a stream operation handles, defaulting to `object`.
+pub(crate) fn config_type(config: &RuntimeConfig) -> Result<Type, ComponentError> {
+  optional_key::<String>(config, "type")?.map_or(Ok(Type::Object), |ty| ty.parse().map_err(ComponentError::new))
+}
+
+/// Decode a configuration key that may be left out.
+pub(crate) fn optional_key<T: DeserializeOwned>(config: &RuntimeConfig, key: &str) -> Result<Option<T>, ComponentError> {
+  if config.has(key) {
+    Ok(Some(config.coerce_key(key)?))
+  } else {
+    Ok(None)
+  }
+}
+
 macro_rules! core_op {
   ($type:ty, $inv:expr, $name:expr, $callback:expr, $data:ident) => {{
     let config = <$type>::decode_config($data)?;
@@ -179,6 +308,12 @@ impl Component for CoreComponent {
         merge::Op::ID => core_op! {merge::Op, invocation, self.merge, callback, data},
         switch::Op::ID => core_op! {switch::Op, invocation, self.switch, callback, data},
         collect::Op::ID => core_op! {collect::Op, invocation, self.collect, callback, data},
+        batch::Op::ID => core_op! {batch::Op, invocation, self.batch, callback, data},
+        window::Op::ID => core_op! {window::Op, invocation, self.window, callback, data},
+        flatten::Op::ID => core_op! {flatten::Op, invocation, self.flatten, callback, data},
+        map::Op::ID => core_op! {map::Op, invocation, self.map, callback, data},
+        filter::Op::ID => core_op! {filter::Op, invocation, self.filter, callback, data},
+        join::Op::ID => core_op! {join::Op, invocation, self.join, callback, data},
         _ => {
           panic!("Core operation {} not handled.", invocation.target().operation_id());
         }
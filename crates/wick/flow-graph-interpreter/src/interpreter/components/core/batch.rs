@@ -0,0 +1,277 @@
+use std::time::Duration;
+
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::FutureExt;
+use serde_json::Value;
+use tokio::time::Instant;
+use tokio_stream::StreamExt;
+use wasmrs_rx::Observer;
+use wick_interface_types::{OperationSignature, Type};
+use wick_packet::{InherentData, Invocation, Packet, PacketExt, PacketSender, PacketStream, RuntimeConfig};
+
+use crate::BoxFuture;
+pub(crate) struct Op {}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).finish()
+  }
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    let Ok(config) = node.data().config.render(&InherentData::unsafe_default()) else {
+      return Err(format!("Could not render config for {}", Op::ID));
+    };
+    if let Err(e) = Op::decode_config(config) {
+      return Err(e.to_string());
+    }
+    node.add_input("input");
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct Config {
+  /// The type of the values being batched.
+  #[serde(rename = "type")]
+  ty: Type,
+  /// The number of values that completes a batch.
+  size: Option<usize>,
+  /// The longest time, in milliseconds, to hold the first value of a batch before sending it.
+  max_delay: Option<u64>,
+}
+
+fn gen_signature(id: &str, config: Config) -> OperationSignature {
+  OperationSignature::new_named(id)
+    .add_input("input", config.ty.clone())
+    .add_output("output", Type::List { ty: Box::new(config.ty) })
+}
+
+impl Op {
+  pub(crate) const fn new() -> Self {
+    Self {}
+  }
+  pub(crate) fn gen_signature(id: &str, config: Config) -> OperationSignature {
+    gen_signature(id, config)
+  }
+}
+
+fn flush(tx: &PacketSender, batch: &mut Vec<Value>) {
+  if !batch.is_empty() {
+    let _ = tx.send(Packet::encode("output", std::mem::take(batch)));
+  }
+}
+
+impl Operation for Op {
+  const ID: &'static str = "batch";
+  type Config = Config;
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+
+    tokio::spawn(async move {
+      let size = context.config.size;
+      let max_delay = context.config.max_delay.map(Duration::from_millis);
+      let mut stream = invocation.into_stream();
+      let mut batch: Vec<Value> = Vec::new();
+      let mut deadline: Option<Instant> = None;
+
+      loop {
+        // The outer `None` means the deadline passed, the inner `None` means the stream ended.
+        let next = match deadline {
+          Some(deadline) => {
+            tokio::select! {
+              next = stream.next() => Some(next),
+              _ = tokio::time::sleep_until(deadline) => None,
+            }
+          }
+          None => Some(stream.next().await),
+        };
+
+        let Some(next) = next else {
+          flush(&tx, &mut batch);
+          deadline = None;
+          continue;
+        };
+        let Some(next) = next else {
+          break;
+        };
+
+        let packet = match next {
+          Ok(packet) => packet,
+          Err(e) => {
+            flush(&tx, &mut batch);
+            deadline = None;
+            let _ = tx.send_result(Err(e));
+            continue;
+          }
+        };
+
+        if packet.is_done() {
+          continue;
+        }
+
+        // Batches never span bracket boundaries, so a bracketed stream is batched one group at a time.
+        if packet.is_bracket() || packet.is_error() {
+          flush(&tx, &mut batch);
+          deadline = None;
+          if packet.is_error() {
+            let _ = tx.send(packet.to_port("output"));
+          }
+          continue;
+        }
+
+        match packet.decode_value() {
+          Ok(value) => batch.push(value),
+          Err(e) => {
+            let _ = tx.send(Packet::err("output", e.to_string()));
+            continue;
+          }
+        }
+
+        if batch.len() == 1 {
+          deadline = max_delay.map(|delay| Instant::now() + delay);
+        }
+        if size.map_or(false, |size| batch.len() >= size) {
+          flush(&tx, &mut batch);
+          deadline = None;
+        }
+      }
+
+      flush(&tx, &mut batch);
+      let _ = tx.send(Packet::done("output"));
+    });
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    panic!("{} operation has a dynamic signature", Self::ID);
+  }
+
+  fn input_names(&self, _config: &Self::Config) -> Vec<String> {
+    vec!["input".to_owned()]
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config = data.unwrap_or_default();
+
+    let ty = super::config_type(&config)?;
+    let size: Option<usize> = super::optional_key(&config, "size")?;
+    let max_delay: Option<u64> = super::optional_key(&config, "max_delay")?;
+
+    if size == Some(0) {
+      return Err(anyhow!("Batch component's size must be greater than zero."));
+    }
+    if max_delay == Some(0) {
+      return Err(anyhow!("Batch component's max_delay must be greater than zero."));
+    }
+
+    Ok(Self::Config { ty, size, max_delay })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+  use wick_packet::{Entity, InherentData};
+
+  use super::*;
+
+  async fn run(config: Value, stream: Vec<Packet>) -> Result<Vec<Value>> {
+    let config: HashMap<String, Value> = serde_json::from_value(config)?;
+    let config = Op::decode_config(Some(config.into()))?;
+    let inv = Invocation::test(file!(), Entity::test("noop"), stream, None)?;
+    let packets = Op::new()
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Vec<_>>()
+      .await;
+    let mut values = Vec::new();
+    for packet in packets {
+      let packet = packet?;
+      if packet.has_data() {
+        values.push(packet.decode_value()?);
+      }
+    }
+    Ok(values)
+  }
+
+  #[tokio::test]
+  async fn test_size() -> Result<()> {
+    let stream = vec![
+      Packet::encode("input", 1),
+      Packet::encode("input", 2),
+      Packet::encode("input", 3),
+      Packet::encode("input", 4),
+      Packet::encode("input", 5),
+      Packet::done("input"),
+    ];
+    let actual = run(json!({"type": "u32", "size": 2}), stream).await?;
+    assert_eq!(actual, vec![json!([1, 2]), json!([3, 4]), json!([5])]);
+
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_brackets() -> Result<()> {
+    let stream = vec![
+      Packet::open_bracket("input"),
+      Packet::encode("input", "a"),
+      Packet::encode("input", "b"),
+      Packet::close_bracket("input"),
+      Packet::open_bracket("input"),
+      Packet::encode("input", "c"),
+      Packet::close_bracket("input"),
+      Packet::done("input"),
+    ];
+    let actual = run(json!({"type": "string"}), stream).await?;
+    assert_eq!(actual, vec![json!(["a", "b"]), json!(["c"])]);
+
+    Ok(())
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_max_delay() -> Result<()> {
+    let config = Op::decode_config(Some(HashMap::from([("max_delay".to_owned(), json!(100))]).into()))?;
+    let (tx, stream) = PacketStream::new_channels();
+    let inv = Invocation::test(file!(), Entity::test("noop"), stream, None)?;
+    let mut output = Op::new()
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?;
+
+    tx.send(Packet::encode("input", 1))?;
+    tx.send(Packet::encode("input", 2))?;
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    tx.send(Packet::encode("input", 3))?;
+    tx.send(Packet::done("input"))?;
+    drop(tx);
+
+    assert_eq!(output.next().await.unwrap()?.decode_value()?, json!([1, 2]));
+    assert_eq!(output.next().await.unwrap()?.decode_value()?, json!([3]));
+    assert!(output.next().await.unwrap()?.is_done());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,87 @@
+use liquid_json::LiquidJson;
+use serde_json::Value;
+use wick_interface_types::Type;
+
+/// A liquid expression evaluated against the values of an operation's inputs, e.g. `user.name | upcase`.
+///
+/// Expressions are written without the surrounding `{{ }}` so they pass through configuration rendering
+/// untouched, which still lets configuration values be templated into them.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub(crate) struct Expression(String);
+
+impl Expression {
+  /// Evaluate the expression and return its value.
+  pub(crate) fn evaluate(&self, data: &Value) -> Result<Value, String> {
+    LiquidJson::new(Value::String(format!("{{{{ {} | output }}}}", self.0)))
+      .render(data)
+      .map_err(|e| format!("could not evaluate '{}': {}", self.0, e))
+  }
+
+  /// Evaluate the expression as a condition. Like liquid's `if`, only `false` and `nil` are false.
+  pub(crate) fn test(&self, data: &Value) -> Result<bool, String> {
+    liquid_json::render_string(&format!("{{% if {} %}}true{{% endif %}}", self.0), data)
+      .map(|result| result == "true")
+      .map_err(|e| format!("could not evaluate '{}': {}", self.0, e))
+  }
+}
+
+impl std::fmt::Display for Expression {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+/// Convert an evaluated value to the type it was declared as. Liquid renders most values as strings.
+pub(crate) fn coerce(ty: &Type, value: Value) -> Result<Value, String> {
+  match (ty, value) {
+    (Type::Object, value) => Ok(value),
+    (Type::String, Value::String(value)) => Ok(Value::String(value)),
+    (Type::String, Value::Null) => Ok(Value::Null),
+    (Type::String, value) => Ok(Value::String(value.to_string())),
+    (ty, Value::String(value)) => ty
+      .coerce_str(&value)
+      .map_err(|value| format!("could not convert '{}' to {}", value, ty)),
+    (_, value) => Ok(value),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+
+  use super::*;
+
+  #[rstest::rstest]
+  #[case("input", json!({"input": {"a": 1}}), json!({"a": 1}))]
+  #[case("input.a", json!({"input": {"a": 1}}), json!(1))]
+  #[case("input.name | upcase", json!({"input": {"name": "wick"}}), json!("WICK"))]
+  #[case("first | append: second", json!({"first": "a", "second": "b"}), json!("ab"))]
+  #[case("input.missing", json!({"input": {}}), json!(null))]
+  fn test_evaluate(#[case] expression: &str, #[case] data: Value, #[case] expected: Value) -> Result<()> {
+    let expression = Expression(expression.to_owned());
+    assert_eq!(expression.evaluate(&data).unwrap(), expected);
+    Ok(())
+  }
+
+  #[rstest::rstest]
+  #[case("input.age >= 18", json!({"input": {"age": 21}}), true)]
+  #[case("input.age >= 18", json!({"input": {"age": 12}}), false)]
+  #[case("input.tags contains 'a' and enabled", json!({"input": {"tags": ["a"]}, "enabled": true}), true)]
+  #[case("input.missing", json!({"input": {}}), false)]
+  fn test_condition(#[case] expression: &str, #[case] data: Value, #[case] expected: bool) -> Result<()> {
+    let expression = Expression(expression.to_owned());
+    assert_eq!(expression.test(&data).unwrap(), expected);
+    Ok(())
+  }
+
+  #[test]
+  fn test_errors() {
+    let expression = Expression("input | not_a_filter".to_owned());
+    assert!(expression.evaluate(&json!({"input": 1})).is_err());
+    assert!(coerce(&Type::U32, json!("abc")).is_err());
+    assert_eq!(coerce(&Type::U32, json!("42")).unwrap(), json!(42));
+    assert_eq!(coerce(&Type::String, json!(42)).unwrap(), json!("42"));
+  }
+}
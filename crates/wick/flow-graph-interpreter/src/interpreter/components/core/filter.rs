@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::FutureExt;
+use serde_json::Value;
+use wasmrs_rx::Observer;
+use wick_interface_types::{Field, OperationSignature, Type};
+use wick_packet::{InherentData, Invocation, Packet, PacketExt, PacketStream, RuntimeConfig, StreamMap};
+
+use super::expression::Expression;
+use crate::BoxFuture;
+pub(crate) struct Op {}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).finish()
+  }
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    let Ok(config) = node.data().config.render(&InherentData::unsafe_default()) else {
+      return Err(format!("Could not render config for {}", Op::ID));
+    };
+    let config = match Op::decode_config(config) {
+      Ok(c) => c,
+      Err(e) => {
+        return Err(e.to_string());
+      }
+    };
+    for field in config.inputs {
+      node.add_input(field.name());
+    }
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct Config {
+  /// The inputs the condition can reference. The value of the first is sent when the condition is true.
+  inputs: Vec<Field>,
+  condition: Expression,
+}
+
+fn gen_signature(id: &str, config: Config) -> OperationSignature {
+  let mut signature = OperationSignature::new_named(id);
+  let output = config.inputs[0].ty.clone();
+  for field in config.inputs {
+    signature = signature.add_input(field.name, field.ty);
+  }
+  signature.add_output("output", output)
+}
+
+impl Op {
+  pub(crate) const fn new() -> Self {
+    Self {}
+  }
+  pub(crate) fn gen_signature(id: &str, config: Config) -> OperationSignature {
+    gen_signature(id, config)
+  }
+}
+
+impl Operation for Op {
+  const ID: &'static str = "filter";
+  type Config = Config;
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+    let stream = invocation.into_stream();
+    let mut map = StreamMap::from_stream(stream, self.input_names(&context.config));
+    tokio::spawn(async move {
+      let subject = context.config.inputs[0].name.clone();
+      let condition = &context.config.condition;
+      while let Ok(Some(mut next)) = map.next_set().await {
+        if !next.values().all(|p| p.has_data()) {
+          let outlier = next.into_values().find(|x| !x.has_data()).unwrap();
+          let _ = tx.send(outlier.to_port("output"));
+          continue;
+        }
+
+        let data = next
+          .iter()
+          .map(|(k, v)| Ok((k.clone(), v.decode_value()?)))
+          .collect::<Result<HashMap<_, _>, wick_packet::Error>>();
+        let result = data
+          .map_err(|e| e.to_string())
+          .and_then(|data| condition.test(&Value::Object(data.into_iter().collect())));
+
+        match result {
+          Ok(true) => {
+            let packet = next.remove(&subject).unwrap();
+            let _ = tx.send(packet.to_port("output"));
+          }
+          Ok(false) => {}
+          Err(e) => {
+            let _ = tx.send(Packet::err("output", e));
+          }
+        }
+      }
+    });
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    panic!("{} operation has a dynamic signature", Self::ID);
+  }
+
+  fn input_names(&self, config: &Self::Config) -> Vec<String> {
+    config.inputs.iter().map(|n| n.name.clone()).collect()
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config =
+      data.ok_or_else(|| anyhow!("Filter component requires configuration, please specify configuration."))?;
+
+    let inputs: Vec<Field> =
+      super::optional_key(&config, "inputs")?.unwrap_or_else(|| vec![Field::new("input", Type::Object)]);
+    if inputs.is_empty() {
+      return Err(anyhow!("Filter component requires at least one input."));
+    }
+
+    Ok(Self::Config {
+      inputs,
+      condition: config.coerce_key("condition")?,
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+  use wick_packet::{Entity, InherentData};
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_basic() -> Result<()> {
+    let config = HashMap::from([("condition".to_owned(), json!("input.age >= 18"))]);
+    let config = Op::decode_config(Some(config.into()))?;
+    let stream = vec![
+      Packet::encode("input", json!({"name": "a", "age": 21})),
+      Packet::encode("input", json!({"name": "b", "age": 12})),
+      Packet::encode("input", json!({"name": "c", "age": 18})),
+      Packet::done("input"),
+    ];
+    let inv = Invocation::test(file!(), Entity::test("noop"), stream, None)?;
+    let packets = Op::new()
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Result<Vec<_>, _>>()
+      .await?;
+
+    let names = packets
+      .iter()
+      .filter(|p| p.has_data())
+      .map(|p| Ok(p.decode_value()?["name"].clone()))
+      .collect::<Result<Vec<_>>>()?;
+    assert_eq!(names, vec![json!("a"), json!("c")]);
+    assert!(packets.last().unwrap().is_done());
+
+    Ok(())
+  }
+}
@@ -0,0 +1,184 @@
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::FutureExt;
+use serde_json::Value;
+use tokio_stream::StreamExt;
+use wasmrs_rx::Observer;
+use wick_interface_types::{OperationSignature, Type};
+use wick_packet::{InherentData, Invocation, Packet, PacketExt, PacketStream, RuntimeConfig};
+
+use crate::BoxFuture;
+pub(crate) struct Op {}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).finish()
+  }
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    let Ok(config) = node.data().config.render(&InherentData::unsafe_default()) else {
+      return Err(format!("Could not render config for {}", Op::ID));
+    };
+    if let Err(e) = Op::decode_config(config) {
+      return Err(e.to_string());
+    }
+    node.add_input("input");
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct Config {
+  /// The type of the values in each list.
+  #[serde(rename = "type")]
+  ty: Type,
+  /// Whether to wrap the values of each list in brackets.
+  brackets: bool,
+}
+
+fn gen_signature(id: &str, config: Config) -> OperationSignature {
+  OperationSignature::new_named(id)
+    .add_input("input", Type::List { ty: Box::new(config.ty.clone()) })
+    .add_output("output", config.ty)
+}
+
+impl Op {
+  pub(crate) const fn new() -> Self {
+    Self {}
+  }
+  pub(crate) fn gen_signature(id: &str, config: Config) -> OperationSignature {
+    gen_signature(id, config)
+  }
+}
+
+impl Operation for Op {
+  const ID: &'static str = "flatten";
+  type Config = Config;
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+
+    tokio::spawn(async move {
+      let brackets = context.config.brackets;
+      let mut stream = invocation.into_stream();
+
+      while let Some(next) = stream.next().await {
+        let packet = match next {
+          Ok(packet) => packet,
+          Err(e) => {
+            let _ = tx.send_result(Err(e));
+            continue;
+          }
+        };
+
+        if packet.is_done() {
+          continue;
+        }
+        if !packet.has_data() {
+          let _ = tx.send(packet.to_port("output"));
+          continue;
+        }
+
+        let values = match packet.decode_value() {
+          Ok(Value::Array(values)) => values,
+          Ok(value) => {
+            let _ = tx.send(Packet::err("output", format!("expected a list, got {}", value)));
+            continue;
+          }
+          Err(e) => {
+            let _ = tx.send(Packet::err("output", e.to_string()));
+            continue;
+          }
+        };
+
+        if brackets {
+          let _ = tx.send(Packet::open_bracket("output"));
+        }
+        for value in values {
+          let _ = tx.send(Packet::encode("output", value));
+        }
+        if brackets {
+          let _ = tx.send(Packet::close_bracket("output"));
+        }
+      }
+      let _ = tx.send(Packet::done("output"));
+    });
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    panic!("{} operation has a dynamic signature", Self::ID);
+  }
+
+  fn input_names(&self, _config: &Self::Config) -> Vec<String> {
+    vec!["input".to_owned()]
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config = data.unwrap_or_default();
+
+    Ok(Self::Config {
+      ty: super::config_type(&config)?,
+      brackets: super::optional_key(&config, "brackets")?.unwrap_or(false),
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+  use wick_packet::{Entity, InherentData};
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_brackets() -> Result<()> {
+    let config = HashMap::from([("type".to_owned(), json!("string")), ("brackets".to_owned(), json!(true))]);
+    let config = Op::decode_config(Some(config.into()))?;
+    let stream = vec![
+      Packet::encode("input", ["a", "b"]),
+      Packet::encode("input", ["c"]),
+      Packet::done("input"),
+    ];
+    let inv = Invocation::test(file!(), Entity::test("noop"), stream, None)?;
+    let packets = Op::new()
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Result<Vec<_>, _>>()
+      .await?;
+
+    assert_eq!(
+      packets,
+      vec![
+        Packet::open_bracket("output"),
+        Packet::encode("output", "a"),
+        Packet::encode("output", "b"),
+        Packet::close_bracket("output"),
+        Packet::open_bracket("output"),
+        Packet::encode("output", "c"),
+        Packet::close_bracket("output"),
+        Packet::done("output"),
+      ]
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,622 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::FutureExt;
+use serde_json::{Map, Value};
+use tokio::time::Instant;
+use tokio_stream::StreamExt;
+use wasmrs_rx::Observer;
+use wick_interface_types::{Field, OperationSignature, StructDefinition, Type};
+use wick_packet::{InherentData, Invocation, Packet, PacketExt, PacketSender, PacketStream, RuntimeConfig};
+
+use super::expression::Expression;
+use crate::BoxFuture;
+
+const DEFAULT_MAX_BUFFERED: usize = 1000;
+
+pub(crate) struct Op {}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).finish()
+  }
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    let Ok(config) = node.data().config.render(&InherentData::unsafe_default()) else {
+      return Err(format!("Could not render config for {}", Op::ID));
+    };
+    let config = match Op::decode_config(config) {
+      Ok(c) => c,
+      Err(e) => {
+        return Err(e.to_string());
+      }
+    };
+    for input in config.inputs {
+      node.add_input(input.field.name());
+    }
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+/// An input port and the expression that extracts the key its values are joined on.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct JoinInput {
+  #[serde(flatten)]
+  field: Field,
+  key: Expression,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum JoinKind {
+  /// Only send values that matched a value from every input.
+  #[default]
+  Inner,
+  /// Send every value from the first input, with `null` for inputs that had no match.
+  Left,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct Config {
+  inputs: Vec<JoinInput>,
+  kind: JoinKind,
+  /// The most values held while waiting for a match, across all inputs.
+  max_buffered: usize,
+  /// How long, in milliseconds, a value waits for a match.
+  timeout: Option<u64>,
+}
+
+fn gen_signature(id: String, config: Config) -> (OperationSignature, StructDefinition) {
+  let mut signature = OperationSignature::new_named(&id);
+  let mut output_signature = StructDefinition::new(&id, Vec::new(), None);
+  for (i, input) in config.inputs.into_iter().enumerate() {
+    let field = input.field;
+    let ty = if config.kind == JoinKind::Left && i > 0 {
+      Type::Optional {
+        ty: Box::new(field.ty.clone()),
+      }
+    } else {
+      field.ty.clone()
+    };
+    output_signature.fields.push(Field::new(&field.name, ty));
+    signature = signature.add_input(field.name, field.ty);
+  }
+
+  signature = signature.add_output("output", Type::Named(id));
+
+  (signature, output_signature)
+}
+
+impl Op {
+  pub(crate) const fn new() -> Self {
+    Self {}
+  }
+  pub(crate) fn gen_signature(id: String, config: Config) -> (OperationSignature, StructDefinition) {
+    gen_signature(id, config)
+  }
+}
+
+#[derive(Debug)]
+struct Entry {
+  seq: u64,
+  received: Instant,
+  value: Value,
+}
+
+/// The values waiting for a match, keyed by the top-level substream they arrived in and their join key.
+#[derive(Debug)]
+struct Joiner {
+  names: Vec<String>,
+  kind: JoinKind,
+  timeout: Option<Duration>,
+  max_buffered: usize,
+  buffers: Vec<HashMap<(usize, String), VecDeque<Entry>>>,
+  len: usize,
+  seq: u64,
+}
+
+impl Joiner {
+  fn new(config: &Config) -> Self {
+    Self {
+      names: config.inputs.iter().map(|i| i.field.name.clone()).collect(),
+      kind: config.kind,
+      timeout: config.timeout.map(Duration::from_millis),
+      max_buffered: config.max_buffered,
+      buffers: config.inputs.iter().map(|_| HashMap::new()).collect(),
+      len: 0,
+      seq: 0,
+    }
+  }
+
+  /// Add a value and return the joined values it completed or pushed out of the buffer.
+  fn push(&mut self, input: usize, group: usize, key: Option<String>, value: Value, now: Instant) -> Vec<Value> {
+    let Some(key) = key else {
+      // A `null` key never matches.
+      return self.unmatched(input, value).into_iter().collect();
+    };
+    let slot = (group, key);
+
+    let complete = (0..self.names.len())
+      .filter(|i| *i != input)
+      .all(|i| self.buffers[i].get(&slot).map_or(false, |q| !q.is_empty()));
+
+    if complete {
+      let mut joined = Map::new();
+      for (i, name) in self.names.iter().enumerate() {
+        let value = if i == input {
+          value.clone()
+        } else {
+          let queue = self.buffers[i].get_mut(&slot).unwrap();
+          let entry = queue.pop_front().unwrap();
+          if queue.is_empty() {
+            self.buffers[i].remove(&slot);
+          }
+          self.len -= 1;
+          entry.value
+        };
+        joined.insert(name.clone(), value);
+      }
+      return vec![Value::Object(joined)];
+    }
+
+    self.buffers[input].entry(slot).or_default().push_back(Entry {
+      seq: self.seq,
+      received: now,
+      value,
+    });
+    self.len += 1;
+    self.seq += 1;
+
+    let mut evicted = Vec::new();
+    while self.len > self.max_buffered {
+      let Some((input, slot)) = self.oldest() else {
+        break;
+      };
+      evicted.extend(self.remove_front(input, &slot));
+    }
+    evicted
+  }
+
+  /// When the oldest value will time out.
+  fn deadline(&self) -> Option<Instant> {
+    let timeout = self.timeout?;
+    self
+      .oldest()
+      .and_then(|(input, slot)| self.buffers[input][&slot].front())
+      .map(|entry| entry.received + timeout)
+  }
+
+  /// Remove the values that timed out.
+  fn expire(&mut self, now: Instant) -> Vec<Value> {
+    let Some(timeout) = self.timeout else {
+      return Vec::new();
+    };
+    let mut unmatched = Vec::new();
+    while let Some((input, slot)) = self.oldest() {
+      let received = self.buffers[input][&slot].front().unwrap().received;
+      if now.duration_since(received) < timeout {
+        break;
+      }
+      unmatched.extend(self.remove_front(input, &slot));
+    }
+    unmatched
+  }
+
+  /// Remove the values in a top-level substream, or every value when `group` is `None`.
+  fn flush(&mut self, group: Option<usize>) -> Vec<Value> {
+    let mut removed = Vec::new();
+    for (input, buffer) in self.buffers.iter_mut().enumerate() {
+      buffer.retain(|(g, _), queue| {
+        if group.map_or(false, |group| *g != group) {
+          return true;
+        }
+        removed.extend(queue.drain(..).map(|entry| (input, entry)));
+        false
+      });
+    }
+    self.len -= removed.len();
+    removed.sort_by_key(|(_, entry)| entry.seq);
+    removed
+      .into_iter()
+      .filter_map(|(input, entry)| self.unmatched(input, entry.value))
+      .collect()
+  }
+
+  fn oldest(&self) -> Option<(usize, (usize, String))> {
+    self
+      .buffers
+      .iter()
+      .enumerate()
+      .flat_map(|(input, buffer)| buffer.iter().map(move |(slot, queue)| (input, slot, queue)))
+      .filter_map(|(input, slot, queue)| queue.front().map(|e| (e.seq, input, slot)))
+      .min_by_key(|(seq, _, _)| *seq)
+      .map(|(_, input, slot)| (input, slot.clone()))
+  }
+
+  fn remove_front(&mut self, input: usize, slot: &(usize, String)) -> Option<Value> {
+    let queue = self.buffers[input].get_mut(slot)?;
+    let entry = queue.pop_front()?;
+    if queue.is_empty() {
+      self.buffers[input].remove(slot);
+    }
+    self.len -= 1;
+    self.unmatched(input, entry.value)
+  }
+
+  /// The value sent for an item that found no match, if any.
+  fn unmatched(&self, input: usize, value: Value) -> Option<Value> {
+    if self.kind != JoinKind::Left || input != 0 {
+      return None;
+    }
+    let mut joined = Map::new();
+    joined.insert(self.names[0].clone(), value);
+    for name in &self.names[1..] {
+      joined.insert(name.clone(), Value::Null);
+    }
+    Some(Value::Object(joined))
+  }
+}
+
+/// Tracks the top-level substreams of each input so joins stay within matching substreams.
+#[derive(Debug)]
+struct Groups {
+  depth: Vec<usize>,
+  closed: Vec<usize>,
+  opened_out: usize,
+  closed_out: usize,
+}
+
+impl Groups {
+  fn new(inputs: usize) -> Self {
+    Self {
+      depth: vec![0; inputs],
+      closed: vec![0; inputs],
+      opened_out: 0,
+      closed_out: 0,
+    }
+  }
+
+  /// The substream an input's values currently belong to.
+  fn current(&self, input: usize) -> usize {
+    self.closed[input]
+  }
+
+  /// Record an open bracket and return whether the output should open one.
+  fn open(&mut self, input: usize) -> bool {
+    self.depth[input] += 1;
+    self.depth[input] == 1 && self.closed[input] == self.closed_out && self.opened_out == self.closed_out && {
+      self.opened_out += 1;
+      true
+    }
+  }
+
+  /// Record a close bracket and return whether it closed a substream every input has finished.
+  fn close(&mut self, input: usize) -> bool {
+    if self.depth[input] == 0 {
+      return false;
+    }
+    self.depth[input] -= 1;
+    if self.depth[input] == 0 {
+      self.closed[input] += 1;
+    }
+    self.complete()
+  }
+
+  fn complete(&self) -> bool {
+    self.closed.iter().all(|closed| *closed > self.closed_out)
+  }
+
+  /// Close the oldest open substream on the output and return whether the next one is already open on an input.
+  fn advance(&mut self) -> bool {
+    self.closed_out += 1;
+    let next_open = (0..self.depth.len()).any(|i| self.depth[i] > 0 && self.closed[i] == self.closed_out);
+    if next_open {
+      self.opened_out += 1;
+    }
+    next_open
+  }
+}
+
+fn send(tx: &PacketSender, values: Vec<Value>) {
+  for value in values {
+    let _ = tx.send(Packet::encode("output", value));
+  }
+}
+
+impl Operation for Op {
+  const ID: &'static str = "join";
+  type Config = Config;
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+
+    tokio::spawn(async move {
+      let config = &context.config;
+      let mut joiner = Joiner::new(config);
+      let mut groups = Groups::new(config.inputs.len());
+      let mut stream = invocation.into_stream();
+
+      loop {
+        // The outer `None` means a value timed out, the inner `None` means the stream ended.
+        let next = match joiner.deadline() {
+          Some(deadline) => {
+            tokio::select! {
+              next = stream.next() => Some(next),
+              _ = tokio::time::sleep_until(deadline) => None,
+            }
+          }
+          None => Some(stream.next().await),
+        };
+
+        let Some(next) = next else {
+          send(&tx, joiner.expire(Instant::now()));
+          continue;
+        };
+        let Some(next) = next else {
+          break;
+        };
+
+        let packet = match next {
+          Ok(packet) => packet,
+          Err(e) => {
+            let _ = tx.send_result(Err(e));
+            continue;
+          }
+        };
+
+        if packet.is_error() {
+          let _ = tx.send(packet.to_port("output"));
+          continue;
+        }
+        let Some(input) = config.inputs.iter().position(|i| i.field.name == packet.port()) else {
+          let _ = tx.send(Packet::err("output", format!("received value for invalid port '{}'", packet.port())));
+          continue;
+        };
+
+        if packet.is_open_bracket() {
+          if groups.open(input) {
+            let _ = tx.send(Packet::open_bracket("output"));
+          }
+          continue;
+        }
+        if packet.is_close_bracket() {
+          if groups.close(input) {
+            loop {
+              send(&tx, joiner.flush(Some(groups.closed_out)));
+              let _ = tx.send(Packet::close_bracket("output"));
+              if groups.advance() {
+                let _ = tx.send(Packet::open_bracket("output"));
+              }
+              if !groups.complete() {
+                break;
+              }
+            }
+          }
+          continue;
+        }
+        if !packet.has_data() {
+          continue;
+        }
+
+        let value = match packet.decode_value() {
+          Ok(value) => value,
+          Err(e) => {
+            let _ = tx.send(Packet::err("output", e.to_string()));
+            continue;
+          }
+        };
+
+        let name = &config.inputs[input].field.name;
+        let data = Value::Object(Map::from_iter([(name.clone(), value.clone())]));
+        let key = match config.inputs[input].key.evaluate(&data) {
+          Ok(Value::Null) => None,
+          Ok(Value::String(key)) => Some(key),
+          Ok(key) => Some(key.to_string()),
+          Err(e) => {
+            let _ = tx.send(Packet::err("output", e));
+            continue;
+          }
+        };
+
+        send(
+          &tx,
+          joiner.push(input, groups.current(input), key, value, Instant::now()),
+        );
+      }
+
+      send(&tx, joiner.flush(None));
+      if groups.opened_out > groups.closed_out {
+        let _ = tx.send(Packet::close_bracket("output"));
+      }
+      let _ = tx.send(Packet::done("output"));
+    });
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    panic!("{} operation has a dynamic signature", Self::ID);
+  }
+
+  fn input_names(&self, config: &Self::Config) -> Vec<String> {
+    config.inputs.iter().map(|n| n.field.name.clone()).collect()
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config = data.ok_or_else(|| anyhow!("Join component requires configuration, please specify configuration."))?;
+
+    let inputs: Vec<JoinInput> = config.coerce_key("inputs")?;
+    if inputs.len() < 2 {
+      return Err(anyhow!("Join component requires at least two inputs."));
+    }
+    let max_buffered = super::optional_key(&config, "max_buffered")?.unwrap_or(DEFAULT_MAX_BUFFERED);
+    if max_buffered == 0 {
+      return Err(anyhow!("Join component's max_buffered must be greater than zero."));
+    }
+
+    Ok(Self::Config {
+      inputs,
+      kind: super::optional_key(&config, "kind")?.unwrap_or_default(),
+      max_buffered,
+      timeout: super::optional_key(&config, "timeout")?,
+    })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+  use wick_packet::{Entity, InherentData};
+
+  use super::*;
+
+  fn config(extra: &Value) -> Result<Config> {
+    let mut config = json!({
+      "inputs": [
+        {"name": "rows", "type": "object", "key": "rows.id"},
+        {"name": "details", "type": "object", "key": "details.user_id"},
+      ]
+    });
+    config.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+    let config: HashMap<String, Value> = serde_json::from_value(config)?;
+    Op::decode_config(Some(config.into()))
+  }
+
+  async fn run(config: Config, stream: Vec<Packet>) -> Result<Vec<Packet>> {
+    let inv = Invocation::test(file!(), Entity::test("noop"), stream, None)?;
+    let packets = Op::new()
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Result<Vec<_>, _>>()
+      .await?;
+    Ok(packets)
+  }
+
+  #[tokio::test]
+  async fn test_inner() -> Result<()> {
+    let stream = vec![
+      Packet::encode("rows", json!({"id": 1})),
+      Packet::encode("rows", json!({"id": 2})),
+      Packet::encode("details", json!({"user_id": 2, "name": "b"})),
+      Packet::encode("details", json!({"user_id": "1", "name": "a"})),
+      Packet::encode("details", json!({"user_id": 3, "name": "c"})),
+      Packet::done("rows"),
+      Packet::done("details"),
+    ];
+    let packets = run(config(&json!({}))?, stream).await?;
+
+    assert_eq!(
+      packets,
+      vec![
+        Packet::encode("output", json!({"rows": {"id": 2}, "details": {"user_id": 2, "name": "b"}})),
+        Packet::encode("output", json!({"rows": {"id": 1}, "details": {"user_id": "1", "name": "a"}})),
+        Packet::done("output"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_left_substreams() -> Result<()> {
+    let stream = vec![
+      Packet::open_bracket("rows"),
+      Packet::encode("rows", json!({"id": 1})),
+      Packet::encode("rows", json!({"id": 2})),
+      Packet::close_bracket("rows"),
+      Packet::open_bracket("rows"),
+      Packet::encode("rows", json!({"id": 1})),
+      Packet::close_bracket("rows"),
+      Packet::open_bracket("details"),
+      Packet::encode("details", json!({"user_id": 1})),
+      Packet::close_bracket("details"),
+      Packet::open_bracket("details"),
+      Packet::close_bracket("details"),
+      Packet::done("rows"),
+      Packet::done("details"),
+    ];
+    let packets = run(config(&json!({"kind": "left"}))?, stream).await?;
+
+    assert_eq!(
+      packets,
+      vec![
+        Packet::open_bracket("output"),
+        Packet::encode("output", json!({"rows": {"id": 1}, "details": {"user_id": 1}})),
+        Packet::encode("output", json!({"rows": {"id": 2}, "details": null})),
+        Packet::close_bracket("output"),
+        Packet::open_bracket("output"),
+        Packet::encode("output", json!({"rows": {"id": 1}, "details": null})),
+        Packet::close_bracket("output"),
+        Packet::done("output"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_max_buffered() -> Result<()> {
+    let stream = vec![
+      Packet::encode("rows", json!({"id": 1})),
+      Packet::encode("rows", json!({"id": 2})),
+      Packet::encode("rows", json!({"id": 3})),
+      Packet::encode("details", json!({"user_id": 1})),
+      Packet::done("rows"),
+      Packet::done("details"),
+    ];
+    let packets = run(config(&json!({"kind": "left", "max_buffered": 2}))?, stream).await?;
+
+    assert_eq!(
+      packets,
+      vec![
+        Packet::encode("output", json!({"rows": {"id": 1}, "details": null})),
+        Packet::encode("output", json!({"rows": {"id": 2}, "details": null})),
+        Packet::encode("output", json!({"rows": {"id": 3}, "details": null})),
+        Packet::done("output"),
+      ]
+    );
+    Ok(())
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_timeout() -> Result<()> {
+    let config = config(&json!({"kind": "left", "timeout": 100}))?;
+    let (tx, stream) = PacketStream::new_channels();
+    let inv = Invocation::test(file!(), Entity::test("noop"), stream, None)?;
+    let mut output = Op::new()
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?;
+
+    tx.send(Packet::encode("rows", json!({"id": 1})))?;
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    tx.send(Packet::encode("details", json!({"user_id": 1})))?;
+    tx.send(Packet::done("rows"))?;
+    tx.send(Packet::done("details"))?;
+    drop(tx);
+
+    assert_eq!(
+      output.next().await.unwrap()?.decode_value()?,
+      json!({"rows": {"id": 1}, "details": null})
+    );
+    assert!(output.next().await.unwrap()?.is_done());
+    Ok(())
+  }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::FutureExt;
+use serde_json::Value;
+use wasmrs_rx::Observer;
+use wick_interface_types::{Field, OperationSignature, Type};
+use wick_packet::{InherentData, Invocation, Packet, PacketExt, PacketStream, RuntimeConfig, StreamMap};
+
+use super::expression::{coerce, Expression};
+use crate::BoxFuture;
+pub(crate) struct Op {}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).finish()
+  }
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    let Ok(config) = node.data().config.render(&InherentData::unsafe_default()) else {
+      return Err(format!("Could not render config for {}", Op::ID));
+    };
+    let config = match Op::decode_config(config) {
+      Ok(c) => c,
+      Err(e) => {
+        return Err(e.to_string());
+      }
+    };
+    for field in config.inputs {
+      node.add_input(field.name());
+    }
+    for output in config.outputs {
+      node.add_output(output.field.name());
+    }
+    Ok(())
+  }
+}
+
+/// An output port and the expression that produces its value.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct MapOutput {
+  #[serde(flatten)]
+  field: Field,
+  value: Expression,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct Config {
+  inputs: Vec<Field>,
+  outputs: Vec<MapOutput>,
+}
+
+fn gen_signature(id: &str, config: Config) -> OperationSignature {
+  let mut signature = OperationSignature::new_named(id);
+  for field in config.inputs {
+    signature = signature.add_input(field.name, field.ty);
+  }
+  for output in config.outputs {
+    signature = signature.add_output(output.field.name, output.field.ty);
+  }
+  signature
+}
+
+impl Op {
+  pub(crate) const fn new() -> Self {
+    Self {}
+  }
+  pub(crate) fn gen_signature(id: &str, config: Config) -> OperationSignature {
+    gen_signature(id, config)
+  }
+}
+
+impl Operation for Op {
+  const ID: &'static str = "map";
+  type Config = Config;
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+    let stream = invocation.into_stream();
+    let mut map = StreamMap::from_stream(stream, self.input_names(&context.config));
+    tokio::spawn(async move {
+      let outputs = &context.config.outputs;
+      while let Ok(Some(next)) = map.next_set().await {
+        if !next.values().all(|p| p.has_data()) {
+          // Forward brackets, errors, and done signals to every output.
+          let outlier = next.into_values().find(|x| !x.has_data()).unwrap();
+          for output in outputs {
+            let _ = tx.send(outlier.clone().to_port(output.field.name()));
+          }
+          continue;
+        }
+
+        let data = next
+          .into_iter()
+          .map(|(k, v)| Ok((k, v.decode_value()?)))
+          .collect::<Result<HashMap<_, _>, wick_packet::Error>>();
+        let data = match data {
+          Ok(data) => Value::Object(data.into_iter().collect()),
+          Err(e) => {
+            for output in outputs {
+              let _ = tx.send(Packet::err(output.field.name(), e.to_string()));
+            }
+            continue;
+          }
+        };
+
+        for output in outputs {
+          let port = output.field.name();
+          let packet = match output.value.evaluate(&data).and_then(|v| coerce(&output.field.ty, v)) {
+            Ok(value) => Packet::encode(port, value),
+            Err(e) => Packet::err(port, e),
+          };
+          let _ = tx.send(packet);
+        }
+      }
+    });
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    panic!("{} operation has a dynamic signature", Self::ID);
+  }
+
+  fn input_names(&self, config: &Self::Config) -> Vec<String> {
+    config.inputs.iter().map(|n| n.name.clone()).collect()
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config = data.ok_or_else(|| anyhow!("Map component requires configuration, please specify configuration."))?;
+
+    let inputs = super::optional_key(&config, "inputs")?.unwrap_or_else(|| vec![Field::new("input", Type::Object)]);
+    let outputs: Vec<MapOutput> = config.coerce_key("outputs")?;
+    if outputs.is_empty() {
+      return Err(anyhow!("Map component requires at least one output."));
+    }
+
+    Ok(Self::Config { inputs, outputs })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+  use wick_packet::{packet_stream, Entity, InherentData};
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_basic() -> Result<()> {
+    let config = HashMap::from([
+      (
+        "inputs".to_owned(),
+        json!([{"name": "user", "type": "object"}, {"name": "greeting", "type": "string"}]),
+      ),
+      (
+        "outputs".to_owned(),
+        json!([
+          {"name": "message", "type": "string", "value": "greeting | append: ', ' | append: user.name"},
+          {"name": "age", "type": "u32", "value": "user.age"},
+          {"name": "bad", "type": "u32", "value": "user.name"},
+        ]),
+      ),
+    ]);
+    let config = Op::decode_config(Some(config.into()))?;
+    let stream = packet_stream!(("user", json!({"name": "Jane", "age": "42"})), ("greeting", "Hello"));
+    let inv = Invocation::test(file!(), Entity::test("noop"), stream, None)?;
+    let packets = Op::new()
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?
+      .collect::<Result<Vec<_>, _>>()
+      .await?;
+
+    let message = packets.iter().find(|p| p.port() == "message" && p.has_data()).unwrap();
+    let age = packets.iter().find(|p| p.port() == "age" && p.has_data()).unwrap();
+    let bad = packets.iter().find(|p| p.port() == "bad").unwrap();
+    assert_eq!(message.decode_value()?, json!("Hello, Jane"));
+    assert_eq!(age.decode::<u32>()?, 42);
+    assert!(bad.is_error());
+
+    Ok(())
+  }
+}
@@ -531,7 +531,7 @@ impl Operation for Op {
               new_route_handler(
                 path_to_entity(op),
                 &invocation,
-                InherentData::new(rng.gen(), invocation.timestamp()),
+                InherentData::new(rng.gen(), invocation.timestamp()).with_context(invocation.context().clone()),
                 callback.clone(),
                 op_config,
                 span,
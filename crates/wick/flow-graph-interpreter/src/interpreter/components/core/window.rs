@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use flow_component::{ComponentError, Context, Operation, RenderConfiguration};
+use futures::FutureExt;
+use serde_json::Value;
+use tokio::time::{Instant, MissedTickBehavior};
+use tokio_stream::StreamExt;
+use wasmrs_rx::Observer;
+use wick_interface_types::{OperationSignature, Type};
+use wick_packet::{InherentData, Invocation, Packet, PacketExt, PacketStream, RuntimeConfig};
+
+use crate::BoxFuture;
+pub(crate) struct Op {}
+
+impl std::fmt::Debug for Op {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct(Op::ID).finish()
+  }
+}
+
+impl crate::graph::NodeDecorator for Op {
+  fn decorate(node: &mut crate::graph::types::Node) -> Result<(), String> {
+    let Ok(config) = node.data().config.render(&InherentData::unsafe_default()) else {
+      return Err(format!("Could not render config for {}", Op::ID));
+    };
+    if let Err(e) = Op::decode_config(config) {
+      return Err(e.to_string());
+    }
+    node.add_input("input");
+    node.add_output("output");
+    Ok(())
+  }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub(crate) struct Config {
+  /// The type of the values being windowed.
+  #[serde(rename = "type")]
+  ty: Type,
+  /// The length of each window in milliseconds.
+  duration: u64,
+  /// How often, in milliseconds, a sliding window is sent. Windows tumble when this is not set.
+  slide: Option<u64>,
+}
+
+fn gen_signature(id: &str, config: Config) -> OperationSignature {
+  OperationSignature::new_named(id)
+    .add_input("input", config.ty.clone())
+    .add_output("output", Type::List { ty: Box::new(config.ty) })
+}
+
+impl Op {
+  pub(crate) const fn new() -> Self {
+    Self {}
+  }
+  pub(crate) fn gen_signature(id: &str, config: Config) -> OperationSignature {
+    gen_signature(id, config)
+  }
+}
+
+impl Operation for Op {
+  const ID: &'static str = "window";
+  type Config = Config;
+  fn handle(
+    &self,
+    invocation: Invocation,
+    context: Context<Self::Config>,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let (tx, rx) = invocation.make_response();
+
+    tokio::spawn(async move {
+      let duration = Duration::from_millis(context.config.duration);
+      let sliding = context.config.slide.is_some();
+      let period = context.config.slide.map_or(duration, Duration::from_millis);
+      let mut ticker = tokio::time::interval_at(Instant::now() + period, period);
+      ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+      let mut stream = invocation.into_stream();
+      let mut window: VecDeque<(Instant, Value)> = VecDeque::new();
+      // Whether a value arrived since the last window was sent.
+      let mut fresh = false;
+
+      loop {
+        let next = tokio::select! {
+          next = stream.next() => next,
+          now = ticker.tick() => {
+            if sliding {
+              while window.front().map_or(false, |(received, _)| now.duration_since(*received) >= duration) {
+                window.pop_front();
+              }
+            }
+            if !window.is_empty() {
+              let values: Vec<_> = window.iter().map(|(_, v)| v.clone()).collect();
+              let _ = tx.send(Packet::encode("output", values));
+            }
+            if !sliding {
+              window.clear();
+            }
+            fresh = false;
+            continue;
+          }
+        };
+
+        let Some(next) = next else {
+          break;
+        };
+
+        let packet = match next {
+          Ok(packet) => packet,
+          Err(e) => {
+            let _ = tx.send_result(Err(e));
+            continue;
+          }
+        };
+
+        if packet.is_error() {
+          let _ = tx.send(packet.to_port("output"));
+          continue;
+        }
+        if !packet.has_data() {
+          continue;
+        }
+
+        match packet.decode_value() {
+          Ok(value) => {
+            window.push_back((Instant::now(), value));
+            fresh = true;
+          }
+          Err(e) => {
+            let _ = tx.send(Packet::err("output", e.to_string()));
+          }
+        }
+      }
+
+      // Send the last, partial window when the stream ends if it holds anything not sent yet.
+      if fresh {
+        let values: Vec<_> = window.into_iter().map(|(_, v)| v).collect();
+        let _ = tx.send(Packet::encode("output", values));
+      }
+      let _ = tx.send(Packet::done("output"));
+    });
+
+    async move { Ok(rx) }.boxed()
+  }
+
+  fn get_signature(&self, _config: Option<&Self::Config>) -> &OperationSignature {
+    panic!("{} operation has a dynamic signature", Self::ID);
+  }
+
+  fn input_names(&self, _config: &Self::Config) -> Vec<String> {
+    vec!["input".to_owned()]
+  }
+}
+
+impl RenderConfiguration for Op {
+  type Config = Config;
+  type ConfigSource = RuntimeConfig;
+
+  fn decode_config(data: Option<Self::ConfigSource>) -> Result<Self::Config, ComponentError> {
+    let config =
+      data.ok_or_else(|| anyhow!("Window component requires configuration, please specify configuration."))?;
+
+    let ty = super::config_type(&config)?;
+    let duration: u64 = config.coerce_key("duration")?;
+    let slide: Option<u64> = super::optional_key(&config, "slide")?;
+
+    if duration == 0 {
+      return Err(anyhow!("Window component's duration must be greater than zero."));
+    }
+    if slide == Some(0) {
+      return Err(anyhow!("Window component's slide must be greater than zero."));
+    }
+
+    Ok(Self::Config { ty, duration, slide })
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use serde_json::json;
+  use tokio_stream::StreamExt;
+  use wick_packet::{Entity, InherentData};
+
+  use super::*;
+
+  async fn start(config: Value) -> Result<(wick_packet::PacketSender, PacketStream)> {
+    let config: HashMap<String, Value> = serde_json::from_value(config)?;
+    let config = Op::decode_config(Some(config.into()))?;
+    let (tx, stream) = PacketStream::new_channels();
+    let inv = Invocation::test(file!(), Entity::test("noop"), stream, None)?;
+    let output = Op::new()
+      .handle(
+        inv,
+        Context::new(config, &InherentData::unsafe_default(), Default::default()),
+      )
+      .await?;
+    Ok((tx, output))
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_tumbling() -> Result<()> {
+    let (tx, mut output) = start(json!({"type": "u32", "duration": 100})).await?;
+
+    tx.send(Packet::encode("input", 1))?;
+    tx.send(Packet::encode("input", 2))?;
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    tx.send(Packet::encode("input", 3))?;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    tx.send(Packet::done("input"))?;
+    drop(tx);
+
+    assert_eq!(output.next().await.unwrap()?.decode_value()?, json!([1, 2]));
+    assert_eq!(output.next().await.unwrap()?.decode_value()?, json!([3]));
+    assert!(output.next().await.unwrap()?.is_done());
+
+    Ok(())
+  }
+
+  #[tokio::test(start_paused = true)]
+  async fn test_sliding() -> Result<()> {
+    let (tx, mut output) = start(json!({"type": "u32", "duration": 300, "slide": 100})).await?;
+
+    tx.send(Packet::encode("input", 1))?;
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    tx.send(Packet::encode("input", 2))?;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    tx.send(Packet::encode("input", 3))?;
+    tx.send(Packet::done("input"))?;
+    drop(tx);
+
+    assert_eq!(output.next().await.unwrap()?.decode_value()?, json!([1]));
+    assert_eq!(output.next().await.unwrap()?.decode_value()?, json!([1, 2]));
+    assert_eq!(output.next().await.unwrap()?.decode_value()?, json!([1, 2, 3]));
+    assert!(output.next().await.unwrap()?.is_done());
+
+    Ok(())
+  }
+}
@@ -25,6 +25,9 @@ pub enum Error {
   #[error("Can not invoke non-operation entity: {0}")]
   InvalidEntity(Entity),
 
+  #[error("Could not invoke {0}, the request deadline has passed")]
+  DeadlineExceeded(Entity),
+
   #[error("Error shutting down component: {0}")]
   ComponentShutdown(String),
 
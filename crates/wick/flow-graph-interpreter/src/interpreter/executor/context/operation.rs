@@ -12,6 +12,7 @@ use tracing::Span;
 use tracing_futures::Instrument;
 use uuid::Uuid;
 use wasmrs_rx::{FluxChannel, Observer};
+use wick_metrics::InvocationTimer;
 use wick_packet::{
   Entity,
   InherentData,
@@ -66,7 +67,13 @@ impl FutureInvocation {
         .as_millis() as u64,
     );
 
-    Self::new(value.tx_id, value.target.clone(), target, inherent, value.span.clone())
+    Self::new(
+      value.tx_id,
+      value.target.clone(),
+      target,
+      inherent.with_context(value.inherent.context.clone()),
+      value.span.clone(),
+    )
   }
 }
 
@@ -311,6 +318,16 @@ impl InstanceHandler {
     let invocation = invocation.with_stream(stream);
     let cb = callback.clone();
 
+    if invocation.context().is_expired() {
+      let msg = format!("Operation {} not started, the request deadline has passed", entity);
+      span.in_scope(|| warn!(%msg, "deadline exceeded"));
+      channel.dispatch_op_err(ctx_id, self.index(), PacketPayload::Err(PacketError::new(msg)));
+      return Ok(());
+    }
+
+    // Dropping the timer on any early return records the invocation as failed.
+    let timer = wick_metrics::start_invocation(entity.component_id(), entity.operation_id());
+
     let fut = if namespace == SelfComponent::ID {
       let clone = self.self_component.clone();
       tokio::spawn(async move {
@@ -361,7 +378,7 @@ impl InstanceHandler {
 
     self
       .task
-      .start(ctx_id, self.clone(), stream, channel, timeout, span.clone());
+      .start(ctx_id, self.clone(), stream, channel, timeout, timer, span.clone());
 
     Ok(())
   }
@@ -375,6 +392,7 @@ struct InstanceTask {
 }
 
 impl InstanceTask {
+  #[allow(clippy::too_many_arguments)]
   fn start(
     &self,
     ctx_id: Uuid,
@@ -382,6 +400,7 @@ impl InstanceTask {
     stream: PacketStream,
     channel: InterpreterDispatchChannel,
     timeout: Duration,
+    timer: InvocationTimer,
     span: Span,
   ) {
     if self.has_started() {
@@ -395,7 +414,7 @@ impl InstanceTask {
 
     span.in_scope(|| debug!(instance = instance.id(), "task:start"));
     let task = tokio::spawn(async move {
-      let result = output_handler(ctx_id, &instance, stream, channel, timeout, span.clone()).await;
+      let result = output_handler(ctx_id, &instance, stream, channel, timeout, timer, span.clone()).await;
       if let Err(error) = &result {
         span.in_scope(|| error!(%error, "error in output handler"));
       }
@@ -436,11 +455,13 @@ async fn output_handler(
   mut stream: PacketStream,
   channel: InterpreterDispatchChannel,
   timeout: Duration,
+  timer: InvocationTimer,
   span: Span,
 ) -> Result<()> {
   span.in_scope(|| trace!("starting output task"));
 
   let mut num_received = 0;
+  let mut errored = false;
   let reason = loop {
     let response = tokio::time::timeout(timeout, stream.next());
     let mut hanging = HashMap::new();
@@ -469,6 +490,8 @@ async fn output_handler(
           continue;
         }
 
+        errored |= message.is_error();
+
         let port = match instance.find_output(message.port()) {
           Ok(port) => port,
           Err(e) => {
@@ -512,6 +535,7 @@ async fn output_handler(
       }
     }
   };
+  timer.finish(!errored && matches!(reason, CompletionStatus::Finished));
   instance.handle_stream_complete(reason)?;
   channel.dispatch_call_complete(ctx_id, instance.index());
   Ok(())
@@ -5,7 +5,7 @@ use flow_component::Component;
 use pretty_assertions::assert_eq;
 use serde_json::json;
 use test::*;
-use wick_packet::{packets, Packet, RuntimeConfig};
+use wick_packet::{packets, Packet, PacketExt, RuntimeConfig};
 
 #[test_logger::test(tokio::test)]
 async fn test_senders() -> Result<()> {
@@ -108,6 +108,96 @@ async fn test_merge() -> Result<()> {
   Ok(())
 }
 
+#[test_logger::test(tokio::test)]
+async fn test_batch_flatten() -> Result<()> {
+  test_config(
+    "./tests/manifests/v1/core-batch-flatten.yaml",
+    None,
+    None,
+    vec![
+      Packet::encode("input", 1),
+      Packet::encode("input", 2),
+      Packet::encode("input", 3),
+      Packet::done("input"),
+    ],
+    vec![
+      Packet::open_bracket("output"),
+      Packet::encode("output", 1),
+      Packet::encode("output", 2),
+      Packet::close_bracket("output"),
+      Packet::open_bracket("output"),
+      Packet::encode("output", 3),
+      Packet::close_bracket("output"),
+      Packet::done("output"),
+    ],
+  )
+  .await
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_map_filter() -> Result<()> {
+  let (interpreter, outputs) = test::common_setup(
+    "./tests/manifests/v1/core-map-filter.yaml",
+    "test",
+    vec![
+      Packet::encode("input", json!({"name": "Jane", "age": 42})),
+      Packet::encode("input", json!({"name": "Jimmy", "age": 12})),
+      Packet::done("input"),
+    ],
+  )
+  .await?;
+
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+  let greetings = outputs
+    .iter()
+    .filter(|p| p.port() == "greeting" && p.has_data())
+    .map(|p| p.decode::<String>())
+    .collect::<Result<Vec<_>, _>>()?;
+  let ages = outputs
+    .iter()
+    .filter(|p| p.port() == "age" && p.has_data())
+    .map(|p| p.decode::<u32>())
+    .collect::<Result<Vec<_>, _>>()?;
+  assert_eq!(greetings, vec!["Hello, Jane".to_owned()]);
+  assert_eq!(ages, vec![42]);
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_join() -> Result<()> {
+  let (interpreter, outputs) = test::common_setup(
+    "./tests/manifests/v1/core-join.yaml",
+    "test",
+    vec![
+      Packet::encode("rows", json!({"id": 1, "name": "Jane"})),
+      Packet::encode("rows", json!({"id": 2, "name": "Jimmy"})),
+      Packet::encode("details", json!({"user_id": 2, "email": "jimmy@example.com"})),
+      Packet::done("rows"),
+      Packet::done("details"),
+    ],
+  )
+  .await?;
+
+  let outputs = outputs.into_iter().collect::<Result<Vec<_>, _>>()?;
+  let joined = outputs
+    .iter()
+    .filter(|p| p.has_data())
+    .map(|p| p.decode_value())
+    .collect::<Result<Vec<_>, _>>()?;
+  assert_eq!(
+    joined,
+    vec![
+      json!({"rows": {"id": 2, "name": "Jimmy"}, "details": {"user_id": 2, "email": "jimmy@example.com"}}),
+      json!({"rows": {"id": 1, "name": "Jane"}, "details": null}),
+    ]
+  );
+  interpreter.shutdown().await?;
+
+  Ok(())
+}
+
 #[test_logger::test(tokio::test)]
 async fn test_sender_merge() -> Result<()> {
   let (interpreter, mut outputs) =
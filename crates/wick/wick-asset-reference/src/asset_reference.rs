@@ -12,7 +12,7 @@ use tokio::io::AsyncReadExt;
 use tokio::sync::Mutex;
 use tokio_stream::Stream;
 use tracing::debug;
-use wick_oci_utils::OciOptions;
+use wick_oci_utils::{OciLayout, OciOptions};
 
 use crate::{normalize_path, Error};
 
@@ -181,6 +181,17 @@ impl AssetReference {
     }
   }
 
+  /// Get the location to pull a remote asset from, resolving `oci-layout://` directories relative to the base URL.
+  fn remote_location(&self) -> String {
+    let Some((layout, reference)) = OciLayout::parse_location(&self.location) else {
+      return self.location.clone();
+    };
+    match self.baseurl() {
+      Some(baseurl) if layout.root().is_relative() => OciLayout::new(baseurl.join(layout.root())).location(reference),
+      _ => self.location.clone(),
+    }
+  }
+
   /// Check if the asset exists on disk and isn't in the cache.
   #[must_use]
   pub fn exists_outside_cache(&self) -> bool {
@@ -238,11 +249,11 @@ impl Asset for AssetReference {
         file.read_to_end(&mut bytes).await?;
         Ok(bytes)
       } else {
-        let path = asset.location();
+        let path = asset.remote_location();
         debug!(%path, "fetching remote asset");
-        let (cache_loc, bytes) = retrieve_remote(path, options)
+        let (cache_loc, bytes) = retrieve_remote(&path, options)
           .await
-          .map_err(|err| assets::Error::RemoteFetch(path.to_owned(), err.to_string()))?;
+          .map_err(|err| assets::Error::RemoteFetch(path.clone(), err.to_string()))?;
 
         *asset.cache_location.write() = Some(cache_loc);
         Ok(bytes)
@@ -379,6 +390,19 @@ mod test {
     Ok(())
   }
 
+  #[rstest::rstest]
+  #[case("oci-layout://vendor#org/repo:0.6.0", "oci-layout:///base/vendor#org/repo:0.6.0")]
+  #[case("oci-layout:///vendor#org/repo:0.6.0", "oci-layout:///vendor#org/repo:0.6.0")]
+  #[case("org/repo:0.6.0", "org/repo:0.6.0")]
+  fn test_remote_location(#[case] location: &str, #[case] expected: &str) -> Result<()> {
+    let asset = AssetReference::new(location);
+    asset.update_baseurl(Path::new("/base"));
+
+    assert_eq!(asset.remote_location(), expected);
+
+    Ok(())
+  }
+
   #[rstest::rstest]
   #[case("org/repo:0.6.0")]
   #[case("registry.candle.dev/org/repo:0.6.0")]
@@ -84,7 +84,10 @@ pub async fn start_server(collection: SharedComponent, opts: Option<Options>) ->
 
   cfg_if::cfg_if! {
     if #[cfg(feature="grpc")] {
-      let component_service = wick_invocation_server::InvocationServer::new(collection.clone());
+      let mut component_service = wick_invocation_server::InvocationServer::new(collection.clone());
+      if let Some(auth) = opts.rpc.as_ref().and_then(|rpc| rpc.auth.clone()) {
+        component_service = component_service.with_auth(auth);
+      }
 
       use wick_rpc::rpc::invocation_service_server::InvocationServiceServer;
       let svc = InvocationServiceServer::new(component_service);
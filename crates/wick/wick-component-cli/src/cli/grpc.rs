@@ -9,6 +9,7 @@ use wick_invocation_server::InvocationServer;
 use wick_rpc::rpc::invocation_service_server::InvocationServiceServer;
 
 use super::{Result, ServerMessage};
+use crate::error::CliError;
 use crate::options::ServerOptions;
 
 pub(super) async fn start_rpc_server(
@@ -46,6 +47,12 @@ pub(super) async fn start_rpc_server(
 
   let fetch_opts = FetchOptions::default();
 
+  if options.auth.as_ref().map_or(false, |auth| auth.mtls_enabled()) && options.ca.is_none() {
+    return Err(CliError::Configuration(
+      "RPC mTLS identities require a CA to verify client certificates".to_owned(),
+    ));
+  }
+
   if let (Some(pem), Some(key)) = (&options.pem, &options.key) {
     let server_pem = pem.bytes(&fetch_opts).await?;
     let server_key = key.bytes(&fetch_opts).await?;
@@ -76,6 +76,10 @@ pub struct ServerOptions {
   /// Path to CA file.
   #[cfg(feature = "grpc")]
   pub ca: Option<wick_config::AssetReference>,
+
+  /// Authentication and authorization for requests.
+  #[cfg(feature = "grpc")]
+  pub auth: Option<wick_invocation_server::RpcAuth>,
 }
 
 #[allow(clippy::expect_used)]
@@ -91,6 +95,8 @@ impl From<DefaultCliOptions> for Options {
       key: opts.rpc_key.map(wick_config::AssetReference::new),
       #[cfg(feature = "grpc")]
       ca: opts.rpc_ca.map(wick_config::AssetReference::new),
+      #[cfg(feature = "grpc")]
+      auth: None,
     });
 
     let id = opts
@@ -119,6 +125,7 @@ pub mod env {
   env_var!(WICK_RPC_KEY);
   env_var!(WICK_RPC_PEM);
   env_var!(WICK_RPC_CA);
+  env_var!(WICK_RPC_TOKEN);
 
   env_var!(NATS_URL);
   env_var!(NATS_CREDSFILE);
@@ -49,6 +49,15 @@ fn gen_types<'a>(
     put_impl_in_path(&root, mod_parts, implementation);
   }
 
+  // Generating an inline struct can register more inline structs from its own fields, so keep going until
+  // everything registered (here or while generating operations and config) has been emitted.
+  let mut generated = 0;
+  while let Some(anonymous) = config.anonymous_structs.get(generated).cloned() {
+    generated += 1;
+    let (_, implementation) = templates::gen_struct(config, &anonymous, TypeOptions::Defaults);
+    root.borrow_mut().add(implementation);
+  }
+
   let borrowed = root.borrow();
   borrowed.codegen()
 }
@@ -114,16 +123,30 @@ fn codegen(wick_config: WickConfiguration, gen_config: &mut config::Config) -> R
     _ => panic!("Code generation only supports `wick/component` and `wick/types` configurations"),
   };
 
+  if gen_config.embedded_client {
+    let client = templates::embedded_client(gen_config, ops.iter());
+    let typedefs = gen_types("types", gen_config, types.iter());
+    let expanded = quote! {
+      #client
+
+      #[doc = "Additional generated types"]
+      #typedefs
+    };
+    return format_source(&expanded);
+  }
+
   let component_name = id("Component");
   let wrapper_fns = gen_wrapper_fns(gen_config, &component_name, ops.iter());
   let trait_defs = gen_trait_fns(gen_config, ops.iter());
-  let typedefs = gen_types("types", gen_config, types.iter());
 
   let init = (!ops.is_empty())
     .then(|| templates::gen_component_impls(gen_config, &component_name, ops.iter(), &required, &imported));
 
   let root_config = templates::component_config(gen_config, root_config);
 
+  // Types are generated last so they include inline structs found anywhere above.
+  let typedefs = gen_types("types", gen_config, types.iter());
+
   let imports = gen_config.deps.iter().map(|dep| quote! { #dep }).collect_vec();
   let imports = quote! { #( #imports )* };
 
@@ -157,6 +180,10 @@ fn codegen(wick_config: WickConfiguration, gen_config: &mut config::Config) -> R
     #( #trait_defs )*
     #components
   };
+  format_source(&expanded)
+}
+
+fn format_source(expanded: &TokenStream) -> Result<String> {
   let source = expanded.to_string();
   match syn::parse_file(source.as_str()) {
     Ok(reparsed) => {
@@ -215,4 +242,25 @@ mod test {
 
     Ok(())
   }
+
+  #[tokio::test]
+  async fn test_embedded_client() -> Result<()> {
+    let mut config = ConfigBuilder::new()
+      .spec("./tests/testdata/component.yaml")
+      .embedded_client(true)
+      .build()?;
+    let wick_config = WickConfiguration::fetch(&config.spec, Default::default())
+      .await
+      .unwrap()
+      .finish()?;
+
+    let src = codegen(wick_config, &mut config)?;
+
+    assert!(src.contains("pub struct Client"));
+    assert!(src.contains("pub async fn add(&self, left: u64, right: u64) -> Result<u64, wick_embed::Error>"));
+    assert!(src.contains("pub async fn add_stream("));
+    assert!(!src.contains("pub struct Component"));
+
+    Ok(())
+  }
 }
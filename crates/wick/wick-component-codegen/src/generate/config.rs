@@ -2,8 +2,10 @@ use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 use derive_builder::Builder;
+use wick_interface_types::{Field, StructDefinition};
 
 use super::dependency::Dependency;
+use super::ids::anonymous_struct_name;
 
 #[derive(Debug, Default, Builder, Clone)]
 #[must_use]
@@ -21,8 +23,13 @@ pub struct Config {
   pub(crate) components: bool,
   #[builder(default = "true")]
   pub(crate) output_structs: bool,
+  /// Generate a typed client for a component hosted with `wick_embed` instead of the component's implementation.
+  #[builder(default)]
+  pub(crate) embedded_client: bool,
   #[builder(setter(skip))]
   pub(crate) deps: HashSet<Dependency>,
+  #[builder(setter(skip))]
+  pub(crate) anonymous_structs: Vec<StructDefinition>,
 }
 
 impl Config {
@@ -34,6 +41,27 @@ impl Config {
     self.deps.insert(dep);
   }
 
+  /// Register an inline struct and return the name of the struct generated for it.
+  ///
+  /// Identical field lists share a struct. Different field lists that produce the same name get a numeric suffix
+  /// in the order they are first seen.
+  pub(crate) fn add_anonymous_struct(&mut self, fields: &[Field]) -> String {
+    if let Some(existing) = self.anonymous_structs.iter().find(|s| s.fields == fields) {
+      return existing.name.clone();
+    }
+    let base = anonymous_struct_name(fields);
+    let mut name = base.clone();
+    let mut i = 2;
+    while self.anonymous_structs.iter().any(|s| s.name == name) {
+      name = format!("{}{}", base, i);
+      i += 1;
+    }
+    self
+      .anonymous_structs
+      .push(StructDefinition::new(name.clone(), fields.to_vec(), None));
+    name
+  }
+
   pub fn exec(self) -> anyhow::Result<()> {
     super::build(self)?;
     Ok(())
@@ -69,6 +69,10 @@ pub(super) fn expand_type(
       config.add_dep(Dependency::SerdeJson);
       quote! { wick_component::Value }
     }
-    wick_interface_types::Type::AnonymousStruct(_) => todo!("implement anonymous struct in new codegen"),
+    wick_interface_types::Type::AnonymousStruct(fields) => {
+      // Inline structs are always generated at the root of the `types` module, regardless of where they're used.
+      let ty = Ident::new(&config.add_anonymous_struct(fields), Span::call_site());
+      quote! {types::#ty}
+    }
   }
 }
@@ -1,5 +1,6 @@
 mod component_config;
 mod component_impl;
+mod embedded_client;
 mod imported_components;
 mod op_config;
 mod op_incoming;
@@ -11,6 +12,7 @@ mod wrapper_fn;
 
 pub(crate) use component_config::*;
 pub(crate) use component_impl::*;
+pub(crate) use embedded_client::*;
 pub(crate) use imported_components::*;
 pub(crate) use op_config::*;
 pub(crate) use op_incoming::*;
@@ -0,0 +1,114 @@
+use itertools::Itertools;
+use proc_macro2::TokenStream;
+use quote::quote;
+use wick_interface_types::OperationSignature;
+
+use crate::generate::ids::*;
+use crate::generate::{config, expand_type, f, Direction};
+
+pub(crate) fn embedded_client<'a>(
+  config: &mut config::Config,
+  ops: impl Iterator<Item = &'a OperationSignature>,
+) -> TokenStream {
+  let (outputs, methods): (Vec<_>, Vec<_>) = ops.map(|op| client_method(config, op)).unzip();
+
+  quote! {
+    #[derive(Debug)]
+    #[doc = "A typed client for the operations of a component running in a [wick_embed::EmbeddedHost]."]
+    pub struct Client {
+      host: wick_embed::EmbeddedHost,
+    }
+
+    impl Client {
+      #[doc = "Wrap a running [wick_embed::EmbeddedHost]."]
+      #[must_use]
+      pub const fn new(host: wick_embed::EmbeddedHost) -> Self {
+        Self { host }
+      }
+
+      #[doc = "Get the underlying [wick_embed::EmbeddedHost]."]
+      #[must_use]
+      pub const fn host(&self) -> &wick_embed::EmbeddedHost {
+        &self.host
+      }
+
+      #[doc = "Shut down the underlying host."]
+      pub async fn shutdown(self) -> Result<(), wick_embed::Error> {
+        self.host.shutdown().await
+      }
+
+      #(#methods)*
+    }
+
+    #(#outputs)*
+  }
+}
+
+fn client_method(config: &mut config::Config, op: &OperationSignature) -> (TokenStream, TokenStream) {
+  let name = op.name();
+  let method = id(&snake(name));
+  let stream_method = id(&snake(&format!("{}_stream", name)));
+
+  let params = op
+    .inputs()
+    .iter()
+    .map(|i| {
+      let id = id(&snake(&i.name));
+      let ty = expand_type(config, Direction::In, false, false, &i.ty);
+      quote! { #id: #ty }
+    })
+    .collect_vec();
+  let input_fields = op.inputs().iter().map(f::field_pair(config, false, true, Direction::In)).collect_vec();
+  let input_names = op.inputs().iter().map(|i| id(&snake(&i.name))).collect_vec();
+
+  let inputs = quote! {
+    #[derive(::serde::Serialize)]
+    struct Inputs {
+      #(#input_fields),*
+    }
+    let inputs = Inputs { #(#input_names),* };
+  };
+
+  let desc = format!("Invoke the `{}` operation.", name);
+
+  let (output_def, output_ty, stream) = match op.outputs() {
+    [single] => {
+      let ty = expand_type(config, Direction::Out, false, false, &single.ty);
+      let stream_desc = format!("Invoke the `{}` operation and receive every value it produces.", name);
+      let stream = quote! {
+        #[doc = #stream_desc]
+        pub async fn #stream_method(&self, #(#params),*) -> Result<wick_embed::BoxStream<'static, Result<#ty, wick_embed::Error>>, wick_embed::Error> {
+          #inputs
+          self.host.invoke_stream(#name, inputs).await
+        }
+      };
+      (quote! {}, ty, stream)
+    }
+    outputs => {
+      let struct_name = format!("{}Outputs", pascal(name));
+      let struct_id = id(&struct_name);
+      let fields = outputs.iter().map(f::field_pair(config, false, true, Direction::Out)).collect_vec();
+      let struct_desc = format!("The outputs of the `{}` operation.", name);
+      let def = quote! {
+        #[derive(Debug, Clone, ::serde::Deserialize, PartialEq)]
+        #[doc = #struct_desc]
+        pub struct #struct_id {
+          #(#fields),*
+        }
+      };
+      (def, quote! { #struct_id }, quote! {})
+    }
+  };
+
+  let method = quote! {
+    #[doc = #desc]
+    pub async fn #method(&self, #(#params),*) -> Result<#output_ty, wick_embed::Error> {
+      #inputs
+      self.host.invoke(#name, inputs).await
+    }
+
+    #stream
+  };
+
+  (output_def, method)
+}
@@ -265,7 +265,7 @@ fn generic_type_name(ty: &Type) -> String {
     Type::Object => "Any".to_owned(),
     #[allow(deprecated)]
     Type::Link { .. } => unimplemented!(),
-    Type::AnonymousStruct(_) => unimplemented!(),
+    Type::AnonymousStruct(fields) => anonymous_struct_name(fields),
   }
 }
 
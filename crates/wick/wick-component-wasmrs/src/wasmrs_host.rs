@@ -24,7 +24,6 @@ use wick_packet::{
   ComponentReference,
   ContextTransport,
   Entity,
-  InherentData,
   Invocation,
   PacketStream,
   RuntimeConfig,
@@ -222,7 +221,7 @@ impl WasmHost {
         component_name.to_owned(),
         ctx.get_exports(),
       ))?;
-    let inherent = InherentData::new(invocation.inherent.seed, invocation.inherent.timestamp);
+    let inherent = invocation.inherent.unsafe_clone();
     stream.set_context(config.unwrap_or_default(), inherent);
 
     let wasmrs_stream = packetstream_to_wasmrs(index, stream);
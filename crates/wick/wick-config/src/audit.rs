@@ -2,6 +2,7 @@ use std::path::PathBuf;
 use std::str::FromStr;
 
 use url::Url;
+use wick_interface_types::{check_conformance, ComponentSignature};
 
 use crate::config::{
   Binding,
@@ -13,7 +14,7 @@ use crate::config::{
   UrlResource,
   Volume,
 };
-use crate::WickConfiguration;
+use crate::{Imports, WickConfiguration};
 
 /// An audit report for a component or application.
 #[derive(Debug, Clone, serde::Serialize)]
@@ -61,6 +62,106 @@ impl Audit {
   }
 }
 
+/// A component provided to an import that does not conform to the interface the import requires.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[non_exhaustive]
+pub struct InterfaceMismatch {
+  /// The path of the import that requires the interface.
+  pub component: String,
+  /// The name of the unsatisfied requirement.
+  pub requirement: String,
+  /// The name of the component provided for the requirement.
+  pub provider: String,
+  /// The differences between the interface and the provided component, one diff line per entry.
+  pub differences: Vec<String>,
+}
+
+impl std::fmt::Display for InterfaceMismatch {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{} provided to {} as {} does not conform to its interface:",
+      self.provider, self.component, self.requirement
+    )?;
+    for line in &self.differences {
+      write!(f, "\n  {}", line)?;
+    }
+    Ok(())
+  }
+}
+
+/// Check that every component provided to an import in a configuration tree conforms to the interface the import
+/// requires.
+///
+/// Providers whose signatures are only known once they are instantiated, e.g. native or high-level components, are
+/// not checked.
+#[must_use]
+pub fn check_provided_interfaces(tree: &ConfigurationTreeNode<WickConfiguration>) -> Vec<InterfaceMismatch> {
+  let mut mismatches = Vec::new();
+  check_node_interfaces(tree, &tree.name, &mut mismatches);
+  mismatches
+}
+
+fn check_node_interfaces(
+  node: &ConfigurationTreeNode<WickConfiguration>,
+  path: &str,
+  mismatches: &mut Vec<InterfaceMismatch>,
+) {
+  for import in node.element.imports() {
+    let Some(provide) = import.kind.provide() else {
+      continue;
+    };
+    let Some(WickConfiguration::Component(child)) = find_config(node, import.id()).map(|c| &c.element) else {
+      continue;
+    };
+    let mut provide = provide.iter().collect::<Vec<_>>();
+    provide.sort();
+    for (requirement, provider) in provide {
+      let Some(interface) = child.requires().iter().find(|r| r.id() == requirement) else {
+        continue;
+      };
+      let Some(actual) = provided_signature(node, provider) else {
+        continue;
+      };
+      if let Err(e) = check_conformance(&interface.kind.signature(), &actual) {
+        mismatches.push(InterfaceMismatch {
+          component: format!("{}::{}", path, import.id()),
+          requirement: requirement.clone(),
+          provider: provider.clone(),
+          differences: e.to_string().lines().map(ToOwned::to_owned).collect(),
+        });
+      }
+    }
+  }
+  for child in &node.children {
+    if let ConfigOrDefinition::Config(c) = child {
+      check_node_interfaces(c, &format!("{}::{}", path, c.name), mismatches);
+    }
+  }
+}
+
+fn find_config<'a>(
+  node: &'a ConfigurationTreeNode<WickConfiguration>,
+  id: &str,
+) -> Option<&'a ConfigurationTreeNode<WickConfiguration>> {
+  node.children.iter().find_map(|c| match c {
+    ConfigOrDefinition::Config(c) if c.name == id => Some(c),
+    _ => None,
+  })
+}
+
+fn provided_signature(node: &ConfigurationTreeNode<WickConfiguration>, provider: &str) -> Option<ComponentSignature> {
+  if let WickConfiguration::Component(config) = &node.element {
+    if let Some(required) = config.requires().iter().find(|r| r.id() == provider) {
+      return Some(required.kind.signature());
+    }
+  }
+  match &find_config(node, provider)?.element {
+    WickConfiguration::Component(config) => config.signature().ok(),
+    _ => None,
+  }
+}
+
 impl From<&ResourceDefinition> for AuditedResource {
   fn from(value: &ResourceDefinition) -> Self {
     match value {
@@ -64,6 +64,12 @@ pub struct AppConfiguration {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) package: Option<PackageConfig>,
 
+  #[asset(skip)]
+  #[builder(default)]
+  /// The configuration to use when hosting this application.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) host: Option<config::HostConfig>,
+
   #[builder(default)]
   /// The components that make up the application.
   #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -215,6 +221,7 @@ impl AppConfiguration {
 
     self.resources.render_config(source.as_deref(), root_config, env)?;
     self.import.render_config(source.as_deref(), root_config, env)?;
+    self.host.render_config(source.as_deref(), root_config, env)?;
     self.triggers.render_config(source.as_deref(), root_config, env)?;
 
     Ok(self)
@@ -236,6 +243,7 @@ impl Renderable for AppConfiguration {
   ) -> Result<()> {
     self.resources.render_config(source, root_config, env)?;
     self.import.render_config(source, root_config, env)?;
+    self.host.render_config(source, root_config, env)?;
     self.triggers.render_config(source, root_config, env)?;
     Ok(())
   }
@@ -374,6 +382,8 @@ mod test {
             .response(vec![op.clone()])
             .build()?,
         ),
+        policies: None,
+        auth: None,
         codec: Some(Codec::Json),
         operation: op,
       })])
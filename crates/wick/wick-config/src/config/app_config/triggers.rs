@@ -8,14 +8,34 @@ use std::path::Path;
 
 pub use cli::{CliConfig, CliConfigBuilder, CliConfigBuilderError};
 pub use http::{
+  ApiKey,
+  ApiKeyAuth,
+  ApiKeyAuthBuilder,
+  ApiKeyAuthBuilderError,
+  ApiKeyBuilder,
+  ApiKeyBuilderError,
+  Compression,
   Contact,
+  Cors,
+  CorsBuilder,
+  CorsBuilderError,
   Documentation,
+  HttpAuth,
+  HttpAuthBuilder,
+  HttpAuthBuilderError,
+  HttpPolicies,
+  HttpPoliciesBuilder,
+  HttpPoliciesBuilderError,
   HttpRouterConfig,
   HttpRouterKind,
   HttpTriggerConfig,
   HttpTriggerConfigBuilder,
   HttpTriggerConfigBuilderError,
   Info,
+  JwtAlgorithm,
+  JwtAuth,
+  JwtAuthBuilder,
+  JwtAuthBuilderError,
   License,
   Middleware,
   MiddlewareBuilder,
@@ -23,6 +43,9 @@ pub use http::{
   ProxyRouterConfig,
   ProxyRouterConfigBuilder,
   ProxyRouterConfigBuilderError,
+  RateLimit,
+  RateLimitBuilder,
+  RateLimitBuilderError,
   RawRouterConfig,
   RawRouterConfigBuilder,
   RawRouterConfigBuilderError,
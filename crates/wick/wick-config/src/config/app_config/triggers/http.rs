@@ -1,7 +1,34 @@
 use std::collections::HashMap;
 use std::path::Path;
 
+pub use auth::{
+  ApiKey,
+  ApiKeyAuth,
+  ApiKeyAuthBuilder,
+  ApiKeyAuthBuilderError,
+  ApiKeyBuilder,
+  ApiKeyBuilderError,
+  HttpAuth,
+  HttpAuthBuilder,
+  HttpAuthBuilderError,
+  JwtAlgorithm,
+  JwtAuth,
+  JwtAuthBuilder,
+  JwtAuthBuilderError,
+};
 pub use middleware::{Middleware, MiddlewareBuilder, MiddlewareBuilderError};
+pub use policies::{
+  Compression,
+  Cors,
+  CorsBuilder,
+  CorsBuilderError,
+  HttpPolicies,
+  HttpPoliciesBuilder,
+  HttpPoliciesBuilderError,
+  RateLimit,
+  RateLimitBuilder,
+  RateLimitBuilderError,
+};
 use wick_asset_reference::AssetReference;
 use wick_packet::RuntimeConfig;
 
@@ -25,7 +52,9 @@ use crate::config::{Binding, ImportDefinition};
 use crate::error::ManifestError;
 use crate::ExpandImports;
 
+mod auth;
 mod middleware;
+mod policies;
 mod proxy_router;
 mod raw_router;
 mod rest_router;
@@ -139,6 +168,8 @@ impl HttpRouterConfig {
 pub trait WickRouter {
   fn middleware(&self) -> Option<&Middleware>;
   fn middleware_mut(&mut self) -> Option<&mut Middleware>;
+  fn policies(&self) -> Option<&HttpPolicies>;
+  fn auth(&self) -> Option<&HttpAuth>;
   fn path(&self) -> &str;
 }
 
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use wick_packet::RuntimeConfig;
+
+use crate::config::bindings::BoundIdentifier;
+use crate::config::template_config::Renderable;
+use crate::config::TemplateConfig;
+use crate::error::ManifestError;
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+/// Authentication required for requests to a router.
+pub struct HttpAuth {
+  /// Validate JSON Web Tokens (JWTs) passed as bearer tokens.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) jwt: Option<JwtAuth>,
+  /// Validate API keys passed in a request header.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) api_key: Option<ApiKeyAuth>,
+  /// Scopes every request to this router must be granted.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) scopes: Vec<String>,
+}
+
+impl Renderable for HttpAuth {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.jwt.render_config(source, root_config, env)?;
+    self.api_key.render_config(source, root_config, env)
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+/// JSON Web Token (JWT) validation.
+pub struct JwtAuth {
+  /// The algorithms tokens can be signed with.
+  pub(crate) algorithms: Vec<JwtAlgorithm>,
+  /// A shared secret to validate HMAC (HS*) signed tokens with.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) secret: Option<TemplateConfig<String>>,
+  /// The volume resource to load key files from.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) volume: Option<BoundIdentifier>,
+  /// Path (relative to `volume`) of a PEM encoded public key.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) key: Option<String>,
+  /// Path (relative to `volume`) of a JSON Web Key Set (JWKS) file.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) jwks: Option<String>,
+  /// The required issuer (`iss` claim) of tokens.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) issuer: Option<String>,
+  /// The accepted audiences (`aud` claim) of tokens.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) audience: Vec<String>,
+}
+
+impl Renderable for JwtAuth {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    if let Some(secret) = &mut self.secret {
+      secret.set_value(secret.render(source, root_config, env)?);
+    }
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+/// API key validation.
+pub struct ApiKeyAuth {
+  /// The request header to read API keys from.
+  pub(crate) header: String,
+  /// The accepted API keys.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) keys: Vec<ApiKey>,
+}
+
+impl Renderable for ApiKeyAuth {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.keys.render_config(source, root_config, env)
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+/// An accepted API key.
+pub struct ApiKey {
+  /// The API key.
+  pub(crate) key: TemplateConfig<String>,
+  /// The name of the key's owner.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) name: Option<String>,
+  /// The scopes granted to requests that use this key.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) scopes: Vec<String>,
+}
+
+impl Renderable for ApiKey {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.key.set_value(self.key.render(source, root_config, env)?);
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+/// Algorithms JSON Web Tokens can be signed with.
+#[serde(rename_all = "UPPERCASE")]
+pub enum JwtAlgorithm {
+  /// HMAC using SHA-256
+  Hs256 = 0,
+  /// HMAC using SHA-384
+  Hs384 = 1,
+  /// HMAC using SHA-512
+  Hs512 = 2,
+  /// RSASSA-PKCS1-v1_5 using SHA-256
+  Rs256 = 3,
+  /// RSASSA-PKCS1-v1_5 using SHA-384
+  Rs384 = 4,
+  /// RSASSA-PKCS1-v1_5 using SHA-512
+  Rs512 = 5,
+  /// RSASSA-PSS using SHA-256
+  Ps256 = 6,
+  /// RSASSA-PSS using SHA-384
+  Ps384 = 7,
+  /// RSASSA-PSS using SHA-512
+  Ps512 = 8,
+  /// ECDSA using P-256 and SHA-256
+  Es256 = 9,
+  /// ECDSA using P-384 and SHA-384
+  Es384 = 10,
+}
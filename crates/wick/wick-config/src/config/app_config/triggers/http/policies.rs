@@ -0,0 +1,87 @@
+use crate::config::HttpMethod;
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+/// Policies enforced by the HTTP trigger before any operation is invoked.
+pub struct HttpPolicies {
+  /// Cross-origin resource sharing rules.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cors: Option<Cors>,
+  /// Limit the rate of requests to this router.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) rate_limit: Option<RateLimit>,
+  /// The maximum size (in bytes) of a request body.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_body_size: Option<u64>,
+  /// Timeout (in milliseconds) to handle a request.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) request_timeout: Option<u64>,
+  /// Timeout (in milliseconds) to wait between chunks of a request body.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) idle_timeout: Option<u64>,
+  /// Algorithms to compress responses with, in order of preference.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) compression: Vec<Compression>,
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+/// Cross-origin resource sharing (CORS) rules.
+pub struct Cors {
+  /// The origins allowed to make cross-origin requests.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) allowed_origins: Vec<String>,
+  /// The HTTP methods allowed in cross-origin requests.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) allowed_methods: Vec<HttpMethod>,
+  /// The request headers allowed in cross-origin requests.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) allowed_headers: Vec<String>,
+  /// The response headers exposed to cross-origin requests.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) exposed_headers: Vec<String>,
+  /// Whether or not cross-origin requests can include credentials.
+  #[builder(default)]
+  pub(crate) allow_credentials: bool,
+  /// How long (in seconds) the results of a preflight request can be cached.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_age: Option<u32>,
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+#[builder(setter(into))]
+/// Token bucket rate limiting.
+pub struct RateLimit {
+  /// The maximum number of requests allowed in a burst.
+  pub(crate) burst: u32,
+  /// The number of requests allowed per second once a burst is exhausted.
+  pub(crate) per_second: u32,
+  /// A request header to limit requests by instead of the remote address.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) header: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+/// Response compression algorithms.
+#[serde(rename_all = "kebab-case")]
+pub enum Compression {
+  /// gzip compression
+  Gzip = 0,
+  /// Brotli compression
+  Brotli = 1,
+}
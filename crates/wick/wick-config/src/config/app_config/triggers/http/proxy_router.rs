@@ -26,6 +26,11 @@ pub struct ProxyRouterConfig {
   #[property(get(disable))]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) middleware: Option<super::middleware::Middleware>,
+  /// Policies to enforce on requests to this router.
+  #[asset(skip)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) policies: Option<super::policies::HttpPolicies>,
   /// The URL resource to proxy to.
   #[asset(skip)]
   pub(crate) url: BoundIdentifier,
@@ -54,6 +59,14 @@ impl super::WickRouter for ProxyRouterConfig {
     self.middleware.as_mut()
   }
 
+  fn policies(&self) -> Option<&super::HttpPolicies> {
+    self.policies.as_ref()
+  }
+
+  fn auth(&self) -> Option<&super::HttpAuth> {
+    None
+  }
+
   fn path(&self) -> &str {
     &self.path
   }
@@ -24,6 +24,16 @@ pub struct RawRouterConfig {
   #[property(get(disable), mut(disable))]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) middleware: Option<super::middleware::Middleware>,
+  /// Policies to enforce on requests to this router.
+  #[asset(skip)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) policies: Option<super::policies::HttpPolicies>,
+  /// Authentication required for requests to this router.
+  #[asset(skip)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) auth: Option<super::auth::HttpAuth>,
   #[asset(skip)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) codec: Option<config::common::Codec>,
@@ -39,6 +49,14 @@ impl super::WickRouter for RawRouterConfig {
     self.middleware.as_mut()
   }
 
+  fn policies(&self) -> Option<&super::HttpPolicies> {
+    self.policies.as_ref()
+  }
+
+  fn auth(&self) -> Option<&super::HttpAuth> {
+    self.auth.as_ref()
+  }
+
   fn path(&self) -> &str {
     &self.path
   }
@@ -52,7 +70,8 @@ impl Renderable for RawRouterConfig {
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
     self.operation.render_config(source, root_config, env)?;
-    self.middleware.render_config(source, root_config, env)
+    self.middleware.render_config(source, root_config, env)?;
+    self.auth.render_config(source, root_config, env)
   }
 }
 
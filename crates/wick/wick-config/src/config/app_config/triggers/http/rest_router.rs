@@ -31,6 +31,16 @@ pub struct RestRouterConfig {
   #[property(get(disable), mut(disable))]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) middleware: Option<super::middleware::Middleware>,
+  /// Policies to enforce on requests to this router.
+  #[asset(skip)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) policies: Option<super::policies::HttpPolicies>,
+  /// Authentication required for requests to this router.
+  #[asset(skip)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) auth: Option<super::auth::HttpAuth>,
   /// Additional tools and services to enable.
   #[asset(skip)]
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -52,6 +62,7 @@ impl Renderable for RestRouterConfig {
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
     self.middleware.render_config(source, root_config, env)?;
+    self.auth.render_config(source, root_config, env)?;
     self.routes.render_config(source, root_config, env)
   }
 }
@@ -75,6 +86,14 @@ impl super::WickRouter for RestRouterConfig {
     self.middleware.as_mut()
   }
 
+  fn policies(&self) -> Option<&super::HttpPolicies> {
+    self.policies.as_ref()
+  }
+
+  fn auth(&self) -> Option<&super::HttpAuth> {
+    self.auth.as_ref()
+  }
+
   fn path(&self) -> &str {
     &self.path
   }
@@ -186,6 +205,10 @@ pub struct RestRoute {
   #[asset(skip)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) summary: Option<String>,
+  /// Scopes a request must be granted to access this route.
+  #[asset(skip)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) scopes: Vec<String>,
 }
 
 pub(crate) fn process_runtime_config(
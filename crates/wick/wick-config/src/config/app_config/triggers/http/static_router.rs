@@ -25,6 +25,16 @@ pub struct StaticRouterConfig {
   #[property(get(disable))]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) middleware: Option<super::middleware::Middleware>,
+  /// Policies to enforce on requests to this router.
+  #[asset(skip)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) policies: Option<super::policies::HttpPolicies>,
+  /// Authentication required for requests to this router.
+  #[asset(skip)]
+  #[property(get(disable), mut(disable))]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) auth: Option<super::auth::HttpAuth>,
   #[asset(skip)]
   pub(crate) volume: BoundIdentifier,
   #[asset(skip)]
@@ -43,6 +53,14 @@ impl super::WickRouter for StaticRouterConfig {
     self.middleware.as_mut()
   }
 
+  fn policies(&self) -> Option<&super::HttpPolicies> {
+    self.policies.as_ref()
+  }
+
+  fn auth(&self) -> Option<&super::HttpAuth> {
+    self.auth.as_ref()
+  }
+
   fn path(&self) -> &str {
     &self.path
   }
@@ -55,7 +73,8 @@ impl Renderable for StaticRouterConfig {
     root_config: Option<&RuntimeConfig>,
     env: Option<&HashMap<String, String>>,
   ) -> Result<(), ManifestError> {
-    self.middleware.render_config(source, root_config, env)
+    self.middleware.render_config(source, root_config, env)?;
+    self.auth.render_config(source, root_config, env)
   }
 }
 
@@ -28,7 +28,22 @@ pub use self::component_implementation::{ComponentImplementation, ComponentKind}
 pub use self::error_behavior::ErrorBehavior;
 pub use self::exposed_resources::{ExposedVolume, ExposedVolumeBuilder};
 pub use self::glob::Glob;
-pub use self::host_definition::{HostConfig, HostConfigBuilder, HttpConfig, HttpConfigBuilder};
+pub use self::host_definition::{
+  HealthConfig,
+  HealthConfigBuilder,
+  HostConfig,
+  HostConfigBuilder,
+  HttpConfig,
+  HttpConfigBuilder,
+  MetricsConfig,
+  MetricsConfigBuilder,
+  RpcAuthConfig,
+  RpcAuthConfigBuilder,
+  RpcPolicy,
+  RpcPolicyBuilder,
+  RpcToken,
+  RpcTokenBuilder,
+};
 pub use self::http::{Codec, HttpMethod};
 pub use self::import_definition::ImportDefinition;
 pub use self::interface::InterfaceDefinition;
@@ -154,6 +154,11 @@ pub enum HighLevelComponent {
   #[asset(skip)]
   /// An HTTP Client Component.
   HttpClient(config::components::HttpClientComponentConfig),
+  #[asset(skip)]
+  /// A key/value store Component.
+  KeyValue(config::components::KeyValueComponentConfig),
+  /// A gRPC client Component.
+  GrpcClient(config::components::GrpcClientComponentConfig),
 }
 
 impl OperationSignatures for HighLevelComponent {
@@ -161,6 +166,8 @@ impl OperationSignatures for HighLevelComponent {
     match self {
       HighLevelComponent::Sql(c) => c.operation_signatures(),
       HighLevelComponent::HttpClient(c) => c.operation_signatures(),
+      HighLevelComponent::KeyValue(c) => c.operation_signatures(),
+      HighLevelComponent::GrpcClient(c) => c.operation_signatures(),
     }
   }
 }
@@ -186,7 +193,6 @@ pub enum ComponentDefinition {
   /// External manifests.
   Manifest(config::components::ManifestComponent),
   /// Postgres Component.
-  #[asset(skip)]
   HighLevelComponent(HighLevelComponent),
 }
 
@@ -18,6 +18,10 @@ pub enum ComponentImplementation {
   Sql(config::components::SqlComponentConfig),
   /// An http client component.
   HttpClient(config::components::HttpClientComponentConfig),
+  /// A key/value store component.
+  KeyValue(config::components::KeyValueComponentConfig),
+  /// A gRPC client component.
+  GrpcClient(config::components::GrpcClientComponentConfig),
 }
 
 impl ComponentImplementation {
@@ -29,6 +33,8 @@ impl ComponentImplementation {
       ComponentImplementation::Composite(_) => ComponentKind::Composite,
       ComponentImplementation::Sql(_) => ComponentKind::Sql,
       ComponentImplementation::HttpClient(_) => ComponentKind::HttpClient,
+      ComponentImplementation::KeyValue(_) => ComponentKind::KeyValue,
+      ComponentImplementation::GrpcClient(_) => ComponentKind::GrpcClient,
     }
   }
 
@@ -41,6 +47,8 @@ impl ComponentImplementation {
       ComponentImplementation::Composite(c) => c.operation_signatures(),
       ComponentImplementation::Sql(c) => c.operation_signatures(),
       ComponentImplementation::HttpClient(c) => c.operation_signatures(),
+      ComponentImplementation::KeyValue(c) => c.operation_signatures(),
+      ComponentImplementation::GrpcClient(c) => c.operation_signatures(),
     }
   }
 
@@ -53,6 +61,8 @@ impl ComponentImplementation {
       ComponentImplementation::Composite(_) => panic!("Composite components must be named"),
       ComponentImplementation::Sql(_) => "wick/component/sql",
       ComponentImplementation::HttpClient(_) => "wick/component/http",
+      ComponentImplementation::KeyValue(_) => "wick/component/kv",
+      ComponentImplementation::GrpcClient(_) => "wick/component/grpc",
     }
   }
 }
@@ -78,6 +88,10 @@ pub enum ComponentKind {
   Sql,
   /// An http client component.
   HttpClient,
+  /// A key/value store component.
+  KeyValue,
+  /// A gRPC client component.
+  GrpcClient,
 }
 
 impl std::fmt::Display for ComponentKind {
@@ -88,6 +102,8 @@ impl std::fmt::Display for ComponentKind {
       ComponentKind::Composite => write!(f, "wick/component/composite"),
       ComponentKind::Sql => write!(f, "wick/component/sql"),
       ComponentKind::HttpClient => write!(f, "wick/component/http"),
+      ComponentKind::KeyValue => write!(f, "wick/component/kv"),
+      ComponentKind::GrpcClient => write!(f, "wick/component/grpc"),
     }
   }
 }
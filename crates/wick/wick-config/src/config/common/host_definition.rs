@@ -1,7 +1,13 @@
 #![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::collections::HashMap;
 use std::net::Ipv4Addr;
+use std::path::Path;
 
-use crate::config;
+use wick_packet::RuntimeConfig;
+
+use crate::config::template_config::Renderable;
+use crate::config::{self, TemplateConfig};
+use crate::error::ManifestError;
 
 #[derive(Debug, Clone, Default, derive_builder::Builder, property::Property, serde::Serialize)]
 #[property(get(public), set(public), mut(public, suffix = "_mut"))]
@@ -22,6 +28,27 @@ pub struct HostConfig {
   #[builder(setter(strip_option), default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) rpc: Option<HttpConfig>,
+
+  /// Configuration for the Prometheus metrics server.
+  #[builder(setter(strip_option), default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) metrics: Option<MetricsConfig>,
+
+  /// Configuration for the liveness and readiness server.
+  #[builder(setter(strip_option), default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) health: Option<HealthConfig>,
+}
+
+impl Renderable for HostConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.rpc.render_config(source, root_config, env)
+  }
 }
 
 #[derive(Debug, Default, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
@@ -57,4 +84,134 @@ pub struct HttpConfig {
   #[builder(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) ca: Option<config::AssetReference>,
+
+  /// Authentication and authorization for requests to the server.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) auth: Option<RpcAuthConfig>,
+}
+
+impl Renderable for HttpConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.auth.render_config(source, root_config, env)
+  }
+}
+
+#[derive(Debug, Default, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// Authentication and authorization for the RPC server.
+pub struct RpcAuthConfig {
+  /// Bearer tokens accepted by the server.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) tokens: Vec<RpcToken>,
+
+  /// Accept the common name of a verified client certificate as the caller's identity.
+  #[builder(default)]
+  pub(crate) mtls: bool,
+
+  /// The operations each identity may invoke.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) policy: Vec<RpcPolicy>,
+}
+
+impl Renderable for RpcAuthConfig {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.tokens.render_config(source, root_config, env)
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// A bearer token accepted by the RPC server.
+pub struct RpcToken {
+  /// The identity callers that present this token authenticate as.
+  pub(crate) identity: String,
+
+  /// The token.
+  pub(crate) token: TemplateConfig<String>,
+}
+
+impl Renderable for RpcToken {
+  fn render_config(
+    &mut self,
+    source: Option<&Path>,
+    root_config: Option<&RuntimeConfig>,
+    env: Option<&HashMap<String, String>>,
+  ) -> Result<(), ManifestError> {
+    self.token.set_value(self.token.render(source, root_config, env)?);
+    Ok(())
+  }
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// The operations an identity may invoke over RPC.
+pub struct RpcPolicy {
+  /// The identity this policy applies to.
+  pub(crate) identity: String,
+
+  /// Patterns of the operation names the identity may invoke.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) operations: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// Configuration for the Prometheus metrics server.
+pub struct MetricsConfig {
+  /// Enable/disable the server.
+  #[builder(default)]
+  pub(crate) enabled: bool,
+
+  /// The port to bind to.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) port: Option<u16>,
+
+  /// The address to bind to.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) address: Option<Ipv4Addr>,
+
+  /// The path to serve metrics on. Defaults to `/metrics`.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) path: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, derive_builder::Builder, property::Property, serde::Serialize)]
+#[builder(setter(into))]
+#[property(get(public), set(private), mut(public, suffix = "_mut"))]
+/// Configuration for the server that exposes `/healthz` and `/readyz`.
+pub struct HealthConfig {
+  /// Enable/disable the server.
+  #[builder(default)]
+  pub(crate) enabled: bool,
+
+  /// The port to bind to.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) port: Option<u16>,
+
+  /// The address to bind to.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) address: Option<Ipv4Addr>,
 }
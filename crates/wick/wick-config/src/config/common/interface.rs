@@ -1,7 +1,7 @@
 #![allow(missing_docs)] // delete when we move away from the `property` crate.
 
 use config::common::OperationDefinition;
-use wick_interface_types::{OperationSignatures, TypeDefinition};
+use wick_interface_types::{ComponentSignature, OperationSignatures, TypeDefinition};
 
 use crate::config::components::ComponentConfig;
 use crate::config::{self};
@@ -24,6 +24,16 @@ pub struct InterfaceDefinition {
   pub(crate) operations: Vec<OperationDefinition>,
 }
 
+impl InterfaceDefinition {
+  /// Get the signature a component must conform to in order to satisfy this interface.
+  pub fn signature(&self) -> ComponentSignature {
+    let mut sig = ComponentSignature::default();
+    sig.types = self.types.clone();
+    sig.operations = self.operation_signatures();
+    sig
+  }
+}
+
 impl ComponentConfig for InterfaceDefinition {
   type Operation = OperationDefinition;
 
@@ -59,6 +59,7 @@ impl LiquidJsonConfig {
           "root_config": root,
           "config": config,
           "env": env,
+          "secrets": wick_secrets::all(),
           "inherent": inherent,
       }),
     );
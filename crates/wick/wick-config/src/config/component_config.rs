@@ -253,6 +253,8 @@ impl ComponentConfiguration {
       ComponentImplementation::WasmRs(c) => &c.config,
       ComponentImplementation::Sql(c) => &c.config,
       ComponentImplementation::HttpClient(c) => &c.config,
+      ComponentImplementation::KeyValue(_) => &[],
+      ComponentImplementation::GrpcClient(c) => &c.config,
     }
   }
 
@@ -289,6 +291,7 @@ impl ComponentConfiguration {
 
     self.resources.render_config(source.as_deref(), root_config, None)?;
     self.import.render_config(source.as_deref(), root_config, None)?;
+    self.host.render_config(source.as_deref(), root_config, None)?;
 
     Ok(self)
   }
@@ -314,6 +317,7 @@ impl Renderable for ComponentConfiguration {
   ) -> Result<()> {
     self.resources.render_config(source, root_config, env)?;
     self.import.render_config(source, root_config, env)?;
+    self.host.render_config(source, root_config, env)?;
     Ok(())
   }
 }
@@ -1,5 +1,7 @@
+mod grpc_client;
 mod grpcurl;
 mod http_client;
+mod kv;
 mod manifest;
 mod native;
 mod reference;
@@ -9,8 +11,10 @@ mod wasm;
 
 use std::borrow::Cow;
 
+pub use grpc_client::*;
 pub use grpcurl::*;
 pub use http_client::*;
+pub use kv::*;
 pub use manifest::*;
 pub use native::*;
 pub use reference::*;
@@ -0,0 +1,64 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use std::collections::HashMap;
+
+use wick_interface_types::OperationSignatures;
+
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+#[derive(
+  Debug,
+  Clone,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component whose operations are the RPCs of a gRPC service.
+pub struct GrpcClientComponentConfig {
+  /// The URL of the gRPC server.
+  #[asset(skip)]
+  pub(crate) resource: BoundIdentifier,
+
+  /// The `.proto` file or binary `FileDescriptorSet` that defines the service.
+  pub(crate) proto: config::AssetReference,
+
+  /// The fully qualified name of the service to call.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) service: Option<String>,
+
+  /// The configuration for the component.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) config: Vec<wick_interface_types::Field>,
+
+  /// Timeout (in milliseconds) for each call.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) timeout: Option<u64>,
+
+  /// Metadata to add to every request.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  pub(crate) metadata: HashMap<String, String>,
+}
+
+impl GrpcClientComponentConfig {}
+
+impl OperationSignatures for GrpcClientComponentConfig {
+  /// The operations of a gRPC component are generated from its service definition when the component is
+  /// instantiated, so none are known from the configuration alone.
+  fn operation_signatures(&self) -> Vec<wick_interface_types::OperationSignature> {
+    Vec::new()
+  }
+}
@@ -51,6 +51,24 @@ pub struct HttpClientComponentConfig {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub(crate) timeout: Option<u16>,
 
+  /// The retry policy for requests, unless an operation overrides it.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) retry: Option<HttpRetryConfig>,
+
+  /// The redirect policy for requests.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) redirects: Option<HttpRedirectConfig>,
+
+  /// The maximum size (in bytes) of a response body.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) max_response_size: Option<u64>,
+
   /// A list of operations to expose on this component.
   #[asset(skip)]
   #[builder(default)]
@@ -200,8 +218,84 @@ pub struct HttpClientOperationDefinition {
 
   /// The HTTP method to use.
   pub(crate) method: HttpMethod,
+
+  /// Response caching configuration for this operation.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) cache: Option<HttpCacheConfig>,
+
+  /// Timeout (in milliseconds) to wait for the response to each attempt of the request.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) timeout: Option<u64>,
+
+  /// The retry policy for this operation, overriding the component's retry policy.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) retry: Option<HttpRetryConfig>,
+}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// Configuration for caching the responses of an HTTP client operation.
+pub struct HttpCacheConfig {
+  /// The maximum total size (in bytes) of cached response bodies.
+  pub(crate) max_size: u64,
+
+  /// The volume resource to persist cached responses to. Responses are cached in memory when omitted.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) volume: Option<BoundIdentifier>,
 }
 
+impl HttpCacheConfig {}
+
+#[derive(Debug, Clone, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// Configuration for retrying failed HTTP client requests.
+pub struct HttpRetryConfig {
+  /// The maximum number of attempts, including the first request.
+  pub(crate) max_attempts: u32,
+
+  /// The response statuses to retry. Empty means the default statuses.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) statuses: Vec<u16>,
+
+  /// The methods to retry. Empty means the idempotent methods.
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub(crate) methods: Vec<HttpMethod>,
+
+  /// The delay (in milliseconds) before the first retry.
+  pub(crate) backoff: u64,
+
+  /// The maximum delay (in milliseconds) before a retry.
+  pub(crate) max_backoff: u64,
+}
+
+impl HttpRetryConfig {}
+
+#[derive(Debug, Clone, Copy, derive_builder::Builder, PartialEq, property::Property, serde::Serialize)]
+#[property(get(public), set(private), mut(disable))]
+#[builder(setter(into))]
+#[must_use]
+/// Configuration for following HTTP redirects.
+pub struct HttpRedirectConfig {
+  /// The maximum number of redirects to follow.
+  pub(crate) max: u32,
+
+  /// Only follow redirects to the same scheme, host and port as the original request.
+  #[builder(default)]
+  pub(crate) same_origin: bool,
+}
+
+impl HttpRedirectConfig {}
+
 impl HttpClientOperationDefinition {
   /// Create a new GET operation.
   #[must_use]
@@ -0,0 +1,84 @@
+#![allow(missing_docs)] // delete when we move away from the `property` crate.
+use wick_interface_types::{Field, OperationSignature, OperationSignatures, Type};
+
+use crate::config;
+use crate::config::bindings::BoundIdentifier;
+
+#[derive(
+  Debug,
+  Clone,
+  Default,
+  derive_builder::Builder,
+  PartialEq,
+  derive_asset_container::AssetManager,
+  property::Property,
+  serde::Serialize,
+)]
+#[property(get(public), set(public), mut(public, suffix = "_mut"))]
+#[asset(asset(config::AssetReference))]
+#[builder(setter(into))]
+#[must_use]
+/// A component that stores JSON values by key and shares them across invocations.
+pub struct KeyValueComponentConfig {
+  /// The volume resource to persist the store to. Values are kept in memory when omitted.
+  #[asset(skip)]
+  #[builder(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) volume: Option<BoundIdentifier>,
+}
+
+impl KeyValueComponentConfig {}
+
+fn ttl() -> Field {
+  Field::new("ttl", Type::Optional { ty: Box::new(Type::U64) })
+}
+
+fn value() -> Type {
+  Type::Optional {
+    ty: Box::new(Type::Object),
+  }
+}
+
+impl OperationSignatures for KeyValueComponentConfig {
+  fn operation_signatures(&self) -> Vec<OperationSignature> {
+    vec![
+      OperationSignature::new(
+        "get",
+        vec![Field::new("key", Type::String)],
+        vec![Field::new("output", value())],
+        vec![],
+      ),
+      OperationSignature::new(
+        "set",
+        vec![Field::new("key", Type::String), Field::new("value", Type::Object)],
+        vec![Field::new("output", Type::Bool)],
+        vec![ttl()],
+      ),
+      OperationSignature::new(
+        "delete",
+        vec![Field::new("key", Type::String)],
+        vec![Field::new("output", Type::Bool)],
+        vec![],
+      ),
+      OperationSignature::new(
+        "compare_and_swap",
+        vec![
+          Field::new("key", Type::String),
+          Field::new("expected", value()),
+          Field::new("value", Type::Object),
+        ],
+        vec![Field::new("output", Type::Bool)],
+        vec![ttl()],
+      ),
+      OperationSignature::new(
+        "scan",
+        vec![Field::new("prefix", Type::String)],
+        vec![Field::new(
+          "output",
+          Type::AnonymousStruct(vec![Field::new("key", Type::String), Field::new("value", Type::Object)]),
+        )],
+        vec![],
+      ),
+    ]
+  }
+}
@@ -0,0 +1,162 @@
+use std::fmt::Display;
+use std::path::PathBuf;
+
+/// A problem found in a manifest, located in its source where possible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+#[non_exhaustive]
+pub struct Diagnostic {
+  /// The description of the problem.
+  pub message: String,
+  /// The file the problem was found in.
+  pub path: Option<PathBuf>,
+  /// Where in the file the problem was found.
+  pub location: Option<SourceLocation>,
+  /// A short suggestion shown next to the location.
+  pub hint: Option<String>,
+  /// The expression the problem was found in.
+  pub expression: Option<String>,
+}
+
+/// A span of a single line in a manifest's source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SourceLocation {
+  /// The 1-based line number.
+  pub line: usize,
+  /// The 1-based column, in characters.
+  pub column: usize,
+  /// The length of the span, in characters.
+  pub length: usize,
+  /// The full text of the line.
+  pub text: String,
+}
+
+impl SourceLocation {
+  /// Locate a span of `length` bytes starting at byte `index` of `src`.
+  #[must_use]
+  pub fn new(src: &str, index: usize, length: usize) -> Option<Self> {
+    let span = src.get(index..index + length)?;
+    let line_start = src[..index].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = src[index..].find('\n').map_or(src.len(), |i| index + i);
+    Some(Self {
+      line: src[..index].matches('\n').count() + 1,
+      column: src[line_start..index].chars().count() + 1,
+      length: span.chars().count().max(1),
+      text: src[line_start..line_end].trim_end_matches('\r').to_owned(),
+    })
+  }
+
+  /// Locate the byte `index` of `src` from a [serde_yaml::Location].
+  #[must_use]
+  pub fn from_yaml(src: &str, location: &serde_yaml::Location) -> Option<Self> {
+    Self::new(src, location.index(), 0)
+  }
+}
+
+impl Diagnostic {
+  /// Create a new [Diagnostic] with the passed message.
+  pub fn new<T: Into<String>>(message: T) -> Self {
+    Self {
+      message: message.into(),
+      path: None,
+      location: None,
+      hint: None,
+      expression: None,
+    }
+  }
+
+  /// Set the file the problem was found in.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_path(mut self, path: Option<PathBuf>) -> Self {
+    self.path = path;
+    self
+  }
+
+  /// Set where in the file the problem was found.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_location(mut self, location: Option<SourceLocation>) -> Self {
+    self.location = location;
+    self
+  }
+
+  /// Set the suggestion shown next to the location.
+  pub fn with_hint<T: Into<String>>(mut self, hint: T) -> Self {
+    self.hint = Some(hint.into());
+    self
+  }
+
+  /// Set the expression the problem was found in.
+  pub fn with_expression<T: Into<String>>(mut self, expression: T) -> Self {
+    self.expression = Some(expression.into());
+    self
+  }
+}
+
+impl Display for Diagnostic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "error: {}", self.message)?;
+    let path = self
+      .path
+      .as_ref()
+      .map_or_else(|| "<raw>".to_owned(), |p| p.display().to_string());
+
+    let Some(location) = &self.location else {
+      write!(f, "  --> {}", path)?;
+      if let Some(hint) = &self.hint {
+        write!(f, "\n   = hint: {}", hint)?;
+      }
+      if let Some(expression) = &self.expression {
+        write!(f, "\n   = expression: {}", expression)?;
+      }
+      return Ok(());
+    };
+
+    let gutter = " ".repeat(location.line.to_string().len());
+    writeln!(f, "{}--> {}:{}:{}", gutter, path, location.line, location.column)?;
+    writeln!(f, "{} |", gutter)?;
+    writeln!(f, "{} | {}", location.line, location.text)?;
+    write!(
+      f,
+      "{} | {}{}",
+      gutter,
+      " ".repeat(location.column - 1),
+      "^".repeat(location.length)
+    )?;
+    if let Some(hint) = &self.hint {
+      write!(f, " {}", hint)?;
+    }
+    if let Some(expression) = &self.expression {
+      write!(f, "\n{} |\n{} = expression: {}", gutter, gutter, expression)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_render() -> Result<()> {
+    let src = "flow:\n  - <>.input => <>.output\n";
+    let index = src.find("=>").unwrap();
+    let diagnostic = Diagnostic::new("Could not parse flow expression")
+      .with_path(Some(PathBuf::from("app.wick")))
+      .with_location(SourceLocation::new(src, index, 2))
+      .with_hint("targets are connected with `->`")
+      .with_expression("<>.input => <>.output");
+
+    let expected = r#"error: Could not parse flow expression
+ --> app.wick:2:14
+  |
+2 |   - <>.input => <>.output
+  |              ^^ targets are connected with `->`
+  |
+  = expression: <>.input => <>.output"#;
+    assert_eq!(diagnostic.to_string(), expected);
+    Ok(())
+  }
+}
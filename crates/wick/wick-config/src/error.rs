@@ -61,6 +61,10 @@ pub enum ManifestError {
   #[error("Could not parse manifest {} as YAML: {1} at line {}, column {}", .0.as_ref().map_or("<raw>".to_owned(), |v|v.display().to_string()), .2.as_ref().map_or("unknown".to_owned(),|l|l.line().to_string()), .2.as_ref().map_or("unknown".to_owned(),|l|l.column().to_string()))]
   YamlError(Option<PathBuf>, String, Option<serde_yaml::Location>),
 
+  /// One or more problems found in a manifest, located in its source where possible.
+  #[error("{}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n\n"))]
+  Diagnostics(Vec<crate::diagnostic::Diagnostic>),
+
   /// IP address in manifest is invalid.
   #[error("Invalid IP Address: {0}")]
   BadIpAddress(String),
@@ -97,6 +101,10 @@ pub enum ManifestError {
   #[error("Invalid authority: {0}")]
   InvalidUrl(String),
 
+  /// A router policy has an invalid value.
+  #[error("Invalid router policy: {0}")]
+  InvalidPolicy(String),
+
   /// Identifier not found.
   #[error("id '{id}' undefined, IDs in scope are: {}", .ids.join(", "))]
   IdNotFound {
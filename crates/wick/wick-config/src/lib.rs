@@ -132,6 +132,8 @@ pub mod lockdown;
 /// The main configuration module.
 #[cfg(feature = "config")]
 pub mod config;
+/// Located, renderable problems found in manifests.
+pub mod diagnostic;
 /// Wick Manifest error.
 pub mod error;
 mod utils;
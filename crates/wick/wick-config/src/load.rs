@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use serde::de::DeserializeOwned;
 
+#[cfg(any(feature = "v1", all(feature = "config", feature = "v0")))]
+use crate::diagnostic::{Diagnostic, SourceLocation};
 use crate::{Error, Result};
 
 /// A raw configuration, before it's been converted into a `WickConfiguration`.
@@ -45,9 +47,30 @@ pub fn load_raw_config(src: &str, source: &Option<PathBuf>) -> Result<RawConfig>
     }
     #[cfg(feature = "v1")]
     1 => {
-      let base_config = serde_yaml::from_str::<crate::v1::WickConfig>(src)
-        .map_err(|e| Error::YamlError(source.clone(), e.to_string(), e.location()))?;
-      Ok(RawConfig::V1(Box::new(base_config)))
+      // Check every flow expression up front so all of them are reported instead of the first serde hits.
+      let (mut diagnostics, patched) = check_flow_expressions(src, &raw, source);
+      let Some(patched) = patched else {
+        return Err(Error::Diagnostics(diagnostics));
+      };
+      match serde_yaml::from_str::<crate::v1::WickConfig>(&patched) {
+        Ok(base_config) if diagnostics.is_empty() => Ok(RawConfig::V1(Box::new(base_config))),
+        Ok(_) => Err(Error::Diagnostics(diagnostics)),
+        Err(e) => {
+          let location = e.location();
+          let message = e.to_string();
+          let message = match &location {
+            Some(_) => message.rsplit_once(" at line ").map_or(message.as_str(), |(m, _)| m),
+            None => message.as_str(),
+          };
+          diagnostics.push(
+            Diagnostic::new(message)
+              .with_path(source.clone())
+              .with_location(location.and_then(|l| SourceLocation::from_yaml(&patched, &l))),
+          );
+          diagnostics.sort_by_key(|d| d.location.as_ref().map(|l| (l.line, l.column)));
+          Err(Error::Diagnostics(diagnostics))
+        }
+      }
     }
     -1 => Err(Error::NoFormat(source.clone())),
     _ => Err(Error::VersionError(version.to_string())),
@@ -64,7 +87,8 @@ pub(crate) fn resolve_configuration(
   match raw_config {
     #[cfg(feature = "v0")]
     RawConfig::V0(config) => {
-      let mut config = crate::config::WickConfiguration::Component((*config).try_into()?);
+      let mut config =
+        crate::config::WickConfiguration::Component((*config).try_into().map_err(|e| locate(src, source, e))?);
       if let Some(src) = source {
         config.set_source(src);
       }
@@ -72,7 +96,7 @@ pub(crate) fn resolve_configuration(
     }
     #[cfg(feature = "v1")]
     RawConfig::V1(config) => {
-      let mut config: crate::config::WickConfiguration = (*config).try_into()?;
+      let mut config: crate::config::WickConfiguration = (*config).try_into().map_err(|e| locate(src, source, e))?;
       if let Some(src) = source {
         config.set_source(src);
       }
@@ -85,6 +109,113 @@ pub(crate) fn resolve_configuration(
   }
 }
 
+/// Attach the manifest's path to a conversion error, and its location when the error names text from the source.
+#[cfg(all(feature = "config", any(feature = "v0", feature = "v1")))]
+fn locate(src: &str, source: &Option<PathBuf>, error: Error) -> Error {
+  let text = match &error {
+    Error::InvalidOperationExpression(text) | Error::TypeNotFound(text) => Some(text.as_str()),
+    Error::IdNotFound { id, .. } => Some(id.as_str()),
+    Error::Parser(flow_expression_parser::Error::FlowExpressionParse { expression, .. }) => Some(expression.as_str()),
+    Error::Diagnostics(_) => return error,
+    _ => None,
+  };
+  let location = text.and_then(|text| SourceLocation::new(src, src.find(text)?, text.len()));
+  Error::Diagnostics(vec![Diagnostic::new(error.to_string())
+    .with_path(source.clone())
+    .with_location(location)])
+}
+
+/// Parse every string flow expression in a v1 manifest, in document order.
+///
+/// Returns a diagnostic for each expression that failed along with a copy of the source where the failing
+/// expressions are replaced by valid ones, so the rest of the manifest can still be checked. No copy is returned
+/// when a failing expression couldn't be found in the source.
+#[cfg(feature = "v1")]
+fn check_flow_expressions(
+  src: &str,
+  raw: &serde_yaml::Value,
+  source: &Option<PathBuf>,
+) -> (Vec<Diagnostic>, Option<String>) {
+  use std::str::FromStr;
+
+  use flow_expression_parser::ast;
+  const PLACEHOLDER: &str = "<>.input -> <>.output";
+
+  let mut expressions = Vec::new();
+  collect_flow_expressions(raw, &mut expressions);
+
+  let mut diagnostics = Vec::new();
+  let mut replacements = Vec::new();
+  let mut located_all = true;
+  let mut cursor = 0;
+  for expression in expressions {
+    let found = src[cursor..].find(expression).map(|i| cursor + i);
+    if let Some(index) = found {
+      cursor = index + expression.len();
+    }
+
+    let result = ast::FlowExpression::from_str(expression)
+      .map_err(Error::Parser)
+      .and_then(crate::v1::FlowExpression::try_from);
+    let Err(error) = result else {
+      continue;
+    };
+
+    let (offset, diagnostic) = match error {
+      Error::Parser(flow_expression_parser::Error::FlowExpressionParse { offset, hint, .. }) => (
+        offset,
+        Diagnostic::new("Could not parse flow expression").with_hint(hint),
+      ),
+      error => (0, Diagnostic::new(error.to_string())),
+    };
+    let length = expression[offset..]
+      .split(char::is_whitespace)
+      .next()
+      .map_or(0, str::len);
+    let location = found.and_then(|index| SourceLocation::new(src, index + offset, length));
+    diagnostics.push(
+      diagnostic
+        .with_path(source.clone())
+        .with_location(location)
+        .with_expression(expression),
+    );
+
+    located_all &= found.is_some();
+    replacements.extend(found.map(|index| index..index + expression.len()));
+  }
+
+  if !located_all {
+    return (diagnostics, None);
+  }
+  let mut patched = src.to_owned();
+  for range in replacements.into_iter().rev() {
+    patched.replace_range(range, PLACEHOLDER);
+  }
+  (diagnostics, Some(patched))
+}
+
+#[cfg(feature = "v1")]
+fn collect_flow_expressions<'a>(value: &'a serde_yaml::Value, expressions: &mut Vec<&'a str>) {
+  use serde_yaml::Value;
+  match value {
+    Value::Mapping(map) => {
+      for (key, value) in map {
+        match (key.as_str(), value) {
+          (Some("flow"), Value::Sequence(items)) => expressions.extend(items.iter().filter_map(Value::as_str)),
+          _ => collect_flow_expressions(value, expressions),
+        }
+      }
+    }
+    Value::Sequence(items) => {
+      for item in items {
+        collect_flow_expressions(item, expressions);
+      }
+    }
+    Value::Tagged(tagged) => collect_flow_expressions(&tagged.value, expressions),
+    _ => {}
+  }
+}
+
 pub(crate) fn from_yaml<T>(src: &str, path: &Option<PathBuf>) -> Result<T>
 where
   T: DeserializeOwned,
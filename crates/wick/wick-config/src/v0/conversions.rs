@@ -156,6 +156,8 @@ impl TryFrom<crate::v0::HostConfig> for config::HostConfig {
       allow_latest: def.allow_latest,
       insecure_registries: def.insecure_registries,
       rpc: def.rpc.and_then(|v| v.try_into().ok()),
+      metrics: None,
+      health: None,
     })
   }
 }
@@ -179,6 +181,7 @@ impl TryFrom<crate::v0::HttpConfig> for config::HttpConfig {
         Some(v) => Some(v.try_into()?),
         None => None,
       },
+      auth: None,
     })
   }
 }
@@ -86,6 +86,11 @@ pub struct AppConfiguration {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub package: Option<PackageDefinition>,
+  /// Configuration for the host running this application.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub host: Option<HostConfig>,
   /// Resources and configuration that the application and its components can access.
 
   #[serde(default)]
@@ -405,6 +410,11 @@ pub struct ProxyRouter {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub middleware: Option<Middleware>,
+  /// Policies to enforce on requests to this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub policies: Option<HttpPolicies>,
   /// The URL resource to proxy to.
   pub url: BoundIdentifier,
   /// Whether or not to strip the router&#x27;s path from the proxied request.
@@ -429,6 +439,16 @@ pub struct RestRouter {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub middleware: Option<Middleware>,
+  /// Policies to enforce on requests to this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub policies: Option<HttpPolicies>,
+  /// Authentication required for requests to this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub auth: Option<HttpAuth>,
   /// The routes to serve and operations that handle them.
 
   #[serde(default)]
@@ -474,6 +494,11 @@ pub struct Route {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub summary: Option<String>,
+  /// Scopes a request must be granted to access this route.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub scopes: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -587,6 +612,16 @@ pub struct StaticRouter {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub middleware: Option<Middleware>,
+  /// Policies to enforce on requests to this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub policies: Option<HttpPolicies>,
+  /// Authentication required for requests to this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub auth: Option<HttpAuth>,
   /// The volume to serve static files from.
   pub volume: String,
   /// Fallback path (relative to volume &#x60;resource&#x60;) for files to serve in case of a 404. Useful for SPA&#x27;s. if volume resource is: /www and fallback: index.html, then a 404 will serve /www/index.html
@@ -611,6 +646,16 @@ pub struct RawRouter {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub middleware: Option<Middleware>,
+  /// Policies to enforce on requests to this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub policies: Option<HttpPolicies>,
+  /// Authentication required for requests to this router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub auth: Option<HttpAuth>,
   /// The codec to use when encoding/decoding data.
 
   #[serde(default)]
@@ -641,6 +686,301 @@ pub struct Middleware {
   pub response: Vec<ComponentOperationExpression>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Policies enforced by the HTTP trigger before any operation is invoked.
+pub struct HttpPolicies {
+  /// Cross-origin resource sharing rules. Preflight requests are answered without invoking the router.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cors: Option<Cors>,
+  /// Limit the rate of requests to this router. Requests over the limit are answered with a &#x60;429 Too Many Requests&#x60; response.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub rate_limit: Option<RateLimit>,
+  /// The maximum size (in bytes) of a request body. Larger requests are answered with a &#x60;413 Payload Too Large&#x60; response.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_body_size: Option<u64>,
+  /// Timeout (in milliseconds) to handle a request. Requests that time out are answered with a &#x60;504 Gateway Timeout&#x60; response.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub request_timeout: Option<u64>,
+  /// Timeout (in milliseconds) to wait between chunks of a request body before aborting the request.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub idle_timeout: Option<u64>,
+  /// Algorithms to compress responses with, in order of preference, when accepted by the client.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub compression: Vec<Compression>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Cross-origin resource sharing (CORS) rules.
+pub struct Cors {
+  /// The origins allowed to make cross-origin requests. Use &#x60;*&#x60; to allow any origin.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub allowed_origins: Vec<String>,
+  /// The HTTP methods allowed in cross-origin requests. Allows any method when empty.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub allowed_methods: Vec<HttpMethod>,
+  /// The request headers allowed in cross-origin requests. Use &#x60;*&#x60; to allow any header.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub allowed_headers: Vec<String>,
+  /// The response headers exposed to cross-origin requests.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub exposed_headers: Vec<String>,
+  /// Whether or not cross-origin requests can include credentials.
+
+  #[serde(default)]
+  pub allow_credentials: bool,
+  /// How long (in seconds) the results of a preflight request can be cached.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_age: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Token bucket rate limiting.
+pub struct RateLimit {
+  /// The maximum number of requests allowed in a burst.
+  pub burst: u32,
+  /// The number of requests allowed per second once a burst is exhausted.
+  pub per_second: u32,
+  /// A request header to limit requests by (e.g. an API key). Requests are limited by remote address when omitted or when the header is missing.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub header: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Response compression algorithms.
+pub enum Compression {
+  /// gzip compression
+  Gzip = 0,
+  /// Brotli compression
+  Brotli = 1,
+}
+
+impl Default for Compression {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for Compression {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Gzip,
+      1 => Self::Brotli,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Gzip,
+      1 => Self::Brotli,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Authentication required for requests to a router. Requests are accepted when any configured method succeeds.
+pub struct HttpAuth {
+  /// Validate JSON Web Tokens (JWTs) passed as bearer tokens in the &#x60;Authorization&#x60; header.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jwt: Option<JwtAuth>,
+  /// Validate API keys passed in a request header.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub api_key: Option<ApiKeyAuth>,
+  /// Scopes every request to this router must be granted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// JSON Web Token (JWT) validation.
+pub struct JwtAuth {
+  /// The algorithms tokens can be signed with.
+  pub algorithms: Vec<JwtAlgorithm>,
+  /// A shared secret to validate HMAC (HS*) signed tokens with, e.g. &#x60;{{ ctx.secrets.JWT_SECRET }}&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub secret: Option<LiquidTemplate>,
+  /// The volume resource to load key files from.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub volume: Option<BoundIdentifier>,
+  /// Path (relative to &#x60;volume&#x60;) of a PEM encoded public key to validate RSA (RS*, PS*) or ECDSA (ES*) signed tokens with.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub key: Option<String>,
+  /// Path (relative to &#x60;volume&#x60;) of a JSON Web Key Set (JWKS) file. Tokens are validated with the key that matches their &#x60;kid&#x60; header.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub jwks: Option<String>,
+  /// The required issuer (&#x60;iss&#x60; claim) of tokens.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub issuer: Option<String>,
+  /// The accepted audiences (&#x60;aud&#x60; claim) of tokens.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub audience: Vec<String>,
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn API_KEY_AUTH_HEADER() -> String {
+  "x-api-key".to_owned()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// API key validation.
+pub struct ApiKeyAuth {
+  /// The request header to read API keys from.
+
+  #[serde(default = "API_KEY_AUTH_HEADER")]
+  pub header: String,
+  /// The accepted API keys.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub keys: Vec<ApiKey>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// An accepted API key.
+pub struct ApiKey {
+  /// The API key, e.g. &#x60;{{ ctx.secrets.API_KEY }}&#x60;.
+  pub key: LiquidTemplate,
+  /// The name of the key&#x27;s owner, passed to operations as the request principal.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// The scopes granted to requests that use this key.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub scopes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Algorithms JSON Web Tokens can be signed with.
+pub enum JwtAlgorithm {
+  /// HMAC using SHA-256
+  Hs256 = 0,
+  /// HMAC using SHA-384
+  Hs384 = 1,
+  /// HMAC using SHA-512
+  Hs512 = 2,
+  /// RSASSA-PKCS1-v1_5 using SHA-256
+  Rs256 = 3,
+  /// RSASSA-PKCS1-v1_5 using SHA-384
+  Rs384 = 4,
+  /// RSASSA-PKCS1-v1_5 using SHA-512
+  Rs512 = 5,
+  /// RSASSA-PSS using SHA-256
+  Ps256 = 6,
+  /// RSASSA-PSS using SHA-384
+  Ps384 = 7,
+  /// RSASSA-PSS using SHA-512
+  Ps512 = 8,
+  /// ECDSA using P-256 and SHA-256
+  Es256 = 9,
+  /// ECDSA using P-384 and SHA-384
+  Es384 = 10,
+}
+
+impl Default for JwtAlgorithm {
+  fn default() -> Self {
+    Self::from_u16(0).unwrap()
+  }
+}
+
+impl FromPrimitive for JwtAlgorithm {
+  fn from_i64(n: i64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Hs256,
+      1 => Self::Hs384,
+      2 => Self::Hs512,
+      3 => Self::Rs256,
+      4 => Self::Rs384,
+      5 => Self::Rs512,
+      6 => Self::Ps256,
+      7 => Self::Ps384,
+      8 => Self::Ps512,
+      9 => Self::Es256,
+      10 => Self::Es384,
+      _ => {
+        return None;
+      }
+    })
+  }
+
+  fn from_u64(n: u64) -> Option<Self> {
+    Some(match n {
+      0 => Self::Hs256,
+      1 => Self::Hs384,
+      2 => Self::Hs512,
+      3 => Self::Rs256,
+      4 => Self::Rs384,
+      5 => Self::Rs512,
+      6 => Self::Ps256,
+      7 => Self::Ps384,
+      8 => Self::Ps512,
+      9 => Self::Es256,
+      10 => Self::Es384,
+      _ => {
+        return None;
+      }
+    })
+  }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 /// A type definition for a Wick Components and Operations
@@ -969,6 +1309,12 @@ pub enum ComponentKind {
   /// A variant representing a [HttpClientComponent] type.
   #[serde(rename = "wick/component/http@v1")]
   HttpClientComponent(HttpClientComponent),
+  /// A variant representing a [KeyValueComponent] type.
+  #[serde(rename = "wick/component/kv@v1")]
+  KeyValueComponent(KeyValueComponent),
+  /// A variant representing a [GrpcClientComponent] type.
+  #[serde(rename = "wick/component/grpc@v1")]
+  GrpcClientComponent(GrpcClientComponent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -988,6 +1334,12 @@ pub enum ImportDefinition {
   /// A variant representing a [HttpClientComponent] type.
   #[serde(rename = "wick/component/http@v1")]
   HttpClientComponent(HttpClientComponent),
+  /// A variant representing a [KeyValueComponent] type.
+  #[serde(rename = "wick/component/kv@v1")]
+  KeyValueComponent(KeyValueComponent),
+  /// A variant representing a [GrpcClientComponent] type.
+  #[serde(rename = "wick/component/grpc@v1")]
+  GrpcClientComponent(GrpcClientComponent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1010,6 +1362,12 @@ pub enum ComponentDefinition {
   /// A variant representing a [HttpClientComponent] type.
   #[serde(rename = "wick/component/http@v1")]
   HttpClientComponent(HttpClientComponent),
+  /// A variant representing a [KeyValueComponent] type.
+  #[serde(rename = "wick/component/kv@v1")]
+  KeyValueComponent(KeyValueComponent),
+  /// A variant representing a [GrpcClientComponent] type.
+  #[serde(rename = "wick/component/grpc@v1")]
+  GrpcClientComponent(GrpcClientComponent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1053,6 +1411,16 @@ pub struct HostConfig {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub rpc: Option<HttpConfig>,
+  /// Configuration for the Prometheus metrics server.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub metrics: Option<MetricsConfig>,
+  /// Configuration for the liveness and readiness server.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub health: Option<HealthConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1088,6 +1456,99 @@ pub struct HttpConfig {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub ca: Option<crate::v1::helpers::LocationReference>,
+  /// Authentication and authorization for requests to the server.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub auth: Option<RpcAuthConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Authentication and authorization for the RPC server.
+pub struct RpcAuthConfig {
+  /// Bearer tokens accepted by the server.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub tokens: Vec<RpcToken>,
+  /// Accept the common name (CN) of a client certificate verified against &#x60;ca&#x60; as the caller&#x27;s identity.
+
+  #[serde(default)]
+  pub mtls: bool,
+  /// The operations each identity may invoke. Requests from identities without a policy are denied.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub policy: Vec<RpcPolicy>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A bearer token accepted by the RPC server.
+pub struct RpcToken {
+  /// The identity callers that present this token authenticate as.
+  pub identity: String,
+  /// The token, e.g. &#x60;{{ ctx.secrets.RPC_TOKEN }}&#x60;.
+  pub token: LiquidTemplate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// The operations an identity may invoke over RPC.
+pub struct RpcPolicy {
+  /// The identity this policy applies to.
+  pub identity: String,
+  /// Patterns of the operation names the identity may invoke. &#x60;*&#x60; matches any sequence of characters, e.g. &#x60;db::*&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub operations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Configuration for the Prometheus metrics server.
+pub struct MetricsConfig {
+  /// Enable/disable the server.
+
+  #[serde(default)]
+  pub enabled: bool,
+  /// The port to bind to.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub port: Option<u16>,
+  /// The address to bind to.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub address: Option<String>,
+  /// The path to serve metrics on. Defaults to &#x60;/metrics&#x60;.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Configuration for the server that exposes &#x60;/healthz&#x60; and &#x60;/readyz&#x60;.
+pub struct HealthConfig {
+  /// Enable/disable the server.
+
+  #[serde(default)]
+  pub enabled: bool,
+  /// The port to bind to.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub port: Option<u16>,
+  /// The address to bind to.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub address: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1325,6 +1786,9 @@ pub enum TypeSignature {
   /// A variant representing a [Object] type.
   #[serde(rename = "Object")]
   Object(Object),
+  /// A variant representing a [AnonymousStruct] type.
+  #[serde(rename = "AnonymousStruct")]
+  AnonymousStruct(AnonymousStruct),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1418,6 +1882,16 @@ pub struct Map {
 #[serde(deny_unknown_fields)]
 pub struct Object;
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AnonymousStruct {
+  /// A list of fields in the struct.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub fields: Vec<Field>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(deny_unknown_fields)]
 #[serde(tag = "kind")]
@@ -1979,6 +2453,21 @@ pub struct HttpClientComponent {
   #[serde(default)]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub timeout: Option<u16>,
+  /// The retry policy for requests. Can be overridden by individual operations.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub retry: Option<HttpRetry>,
+  /// The redirect policy for requests. Redirects are followed up to 10 times when omitted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub redirects: Option<HttpRedirects>,
+  /// The maximum size (in bytes) of a response body. Responses are not limited when omitted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_response_size: Option<u64>,
   /// Configuration necessary to provide when instantiating the component.
 
   #[serde(default)]
@@ -2047,6 +2536,103 @@ pub struct HttpClientOperationDefinition {
 
   #[serde(default)]
   pub path: String,
+  /// Cache responses to this operation according to their &#x60;Cache-Control&#x60;, &#x60;ETag&#x60;, and &#x60;Last-Modified&#x60; headers.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub cache: Option<HttpCache>,
+  /// Timeout (in milliseconds) to wait for the response to each attempt of the request.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub timeout: Option<u64>,
+  /// The retry policy for this operation, overriding the component&#x27;s retry policy.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub retry: Option<HttpRetry>,
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn HTTP_CACHE_MAX_SIZE() -> u64 {
+  10485760
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Configuration for caching HTTP client responses.
+pub struct HttpCache {
+  /// The maximum total size (in bytes) of cached response bodies.
+
+  #[serde(default = "HTTP_CACHE_MAX_SIZE")]
+  pub max_size: u64,
+  /// A volume resource to persist cached responses to. Responses are cached in memory when omitted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub volume: Option<BoundIdentifier>,
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn HTTP_RETRY_MAX_ATTEMPTS() -> u32 {
+  3
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn HTTP_RETRY_BACKOFF() -> u64 {
+  100
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn HTTP_RETRY_MAX_BACKOFF() -> u64 {
+  10000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Configuration for retrying failed HTTP client requests.
+pub struct HttpRetry {
+  /// The maximum number of attempts, including the first request.
+
+  #[serde(default = "HTTP_RETRY_MAX_ATTEMPTS")]
+  pub max_attempts: u32,
+  /// The response statuses to retry. Defaults to 429, 502, 503 and 504.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub statuses: Vec<u16>,
+  /// The methods to retry. Defaults to the idempotent methods GET, PUT and DELETE.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub methods: Vec<HttpMethod>,
+  /// The delay (in milliseconds) before the first retry. The delay doubles with each retry and is randomized by up to half.
+
+  #[serde(default = "HTTP_RETRY_BACKOFF")]
+  pub backoff: u64,
+  /// The maximum delay (in milliseconds) before a retry. Responses whose &#x60;Retry-After&#x60; asks for a longer delay are not retried.
+
+  #[serde(default = "HTTP_RETRY_MAX_BACKOFF")]
+  pub max_backoff: u64,
+}
+
+#[allow(non_snake_case)]
+pub(crate) fn HTTP_REDIRECTS_MAX() -> u32 {
+  10
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// Configuration for following HTTP redirects.
+pub struct HttpRedirects {
+  /// The maximum number of redirects to follow. Use 0 to not follow redirects.
+
+  #[serde(default = "HTTP_REDIRECTS_MAX")]
+  pub max: u32,
+  /// Only follow redirects to the same scheme, host and port as the original request.
+
+  #[serde(default)]
+  pub same_origin: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Copy, PartialEq)]
@@ -2140,3 +2726,45 @@ impl FromPrimitive for HttpMethod {
     })
   }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component that stores JSON values by key and shares them across invocations. It provides the &#x60;get&#x60;, &#x60;set&#x60;, &#x60;delete&#x60;, &#x60;compare_and_swap&#x60;, and &#x60;scan&#x60; operations.
+pub struct KeyValueComponent {
+  /// A volume resource to persist the store to. Values are kept in memory when omitted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub volume: Option<BoundIdentifier>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+/// A component whose operations are the RPCs of a gRPC service. Each RPC becomes an operation with an &#x60;input&#x60; and an &#x60;output&#x60; port whose types are generated from the service definition.
+pub struct GrpcClientComponent {
+  /// The URL of the gRPC server.
+  pub resource: BoundIdentifier,
+  /// The &#x60;.proto&#x60; file or binary &#x60;FileDescriptorSet&#x60; that defines the service.
+  pub proto: crate::v1::helpers::LocationReference,
+  /// The fully qualified name of the service to call. Required when the definition includes more than one service.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub service: Option<String>,
+  /// Timeout (in milliseconds) for each call. Calls are only limited by the invocation&#x27;s deadline when omitted.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub timeout: Option<u64>,
+  /// Metadata to add to every request.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "HashMap::is_empty")]
+  #[serde(deserialize_with = "crate::helpers::kv_deserializer")]
+  pub metadata: HashMap<String, String>,
+  /// Configuration necessary to provide when instantiating the component.
+
+  #[serde(default)]
+  #[serde(skip_serializing_if = "Vec::is_empty")]
+  pub with: Vec<Field>,
+}
@@ -39,7 +39,7 @@ use crate::config::{
   UdpPort,
 };
 use crate::error::ManifestError;
-use crate::utils::{opt_str_to_ipv4addr, VecTryMapInto};
+use crate::utils::{opt_str_to_ipv4addr, VecMapInto, VecTryMapInto};
 use crate::{v1, Result};
 
 impl TryFrom<v1::PackageDefinition> for PackageConfig {
@@ -277,6 +277,7 @@ impl TryFrom<v1::AppConfiguration> for AppConfiguration {
       cached_types: Default::default(),
       type_cache: Default::default(),
       package: def.package.try_map_into()?,
+      host: def.host.try_map_into()?,
       root_config: Default::default(),
       env: Default::default(),
     })
@@ -294,6 +295,7 @@ impl TryFrom<AppConfiguration> for v1::AppConfiguration {
       resources: value.resources.try_map_into()?,
       triggers: value.triggers.try_map_into()?,
       package: value.package.try_map_into()?,
+      host: value.host.try_map_into()?,
     })
   }
 }
@@ -388,6 +390,8 @@ impl TryFrom<v1::ComponentKind> for ComponentImplementation {
       v1::ComponentKind::CompositeComponentConfiguration(v) => ComponentImplementation::Composite(v.try_into()?),
       v1::ComponentKind::WasmComponentConfiguration(v) => ComponentImplementation::WasmRs(v.try_into()?),
       v1::ComponentKind::HttpClientComponent(v) => ComponentImplementation::HttpClient(v.try_into()?),
+      v1::ComponentKind::KeyValueComponent(v) => ComponentImplementation::KeyValue(v.into()),
+      v1::ComponentKind::GrpcClientComponent(v) => ComponentImplementation::GrpcClient(v.try_into()?),
       v1::ComponentKind::SqlComponent(v) => ComponentImplementation::Sql(v.try_into()?),
       v1::ComponentKind::WasmComponentModel(v) => ComponentImplementation::Wasm(v.try_into()?),
     })
@@ -403,6 +407,8 @@ impl TryFrom<ComponentImplementation> for v1::ComponentKind {
       ComponentImplementation::WasmRs(v) => v1::ComponentKind::WasmComponentConfiguration(v.try_into()?),
       ComponentImplementation::Sql(v) => v1::ComponentKind::SqlComponent(v.try_into()?),
       ComponentImplementation::HttpClient(v) => v1::ComponentKind::HttpClientComponent(v.try_into()?),
+      ComponentImplementation::KeyValue(v) => v1::ComponentKind::KeyValueComponent(v.into()),
+      ComponentImplementation::GrpcClient(v) => v1::ComponentKind::GrpcClientComponent(v.try_into()?),
     })
   }
 }
@@ -457,6 +463,8 @@ impl TryFrom<config::ImportDefinition> for v1::ImportDefinition {
         ComponentDefinition::HighLevelComponent(c) => match c {
           HighLevelComponent::Sql(c) => v1::ImportDefinition::SqlComponent(c.try_into()?),
           HighLevelComponent::HttpClient(c) => v1::ImportDefinition::HttpClientComponent(c.try_into()?),
+          HighLevelComponent::KeyValue(c) => v1::ImportDefinition::KeyValueComponent(c.into()),
+          HighLevelComponent::GrpcClient(c) => v1::ImportDefinition::GrpcClientComponent(c.try_into()?),
         },
       },
       crate::config::ImportDefinition::Types(c) => v1::ImportDefinition::TypesComponent(c.try_into()?),
@@ -499,6 +507,8 @@ impl TryFrom<ComponentDefinition> for v1::ComponentDefinition {
       ComponentDefinition::HighLevelComponent(v) => match v {
         config::HighLevelComponent::Sql(v) => Self::SqlComponent(v.try_into()?),
         config::HighLevelComponent::HttpClient(v) => Self::HttpClientComponent(v.try_into()?),
+        config::HighLevelComponent::KeyValue(v) => Self::KeyValueComponent(v.into()),
+        config::HighLevelComponent::GrpcClient(v) => Self::GrpcClientComponent(v.try_into()?),
       },
     };
     Ok(def)
@@ -541,6 +551,9 @@ impl TryFrom<config::components::HttpClientComponentConfig> for v1::HttpClientCo
       codec: value.codec.map_into(),
       proxy: value.proxy.try_map_into()?,
       timeout: value.timeout,
+      retry: value.retry.map_into(),
+      redirects: value.redirects.map_into(),
+      max_response_size: value.max_response_size,
       operations: value.operations.try_map_into()?,
     })
   }
@@ -636,6 +649,12 @@ impl TryFrom<crate::v1::ComponentDefinition> for ComponentDefinition {
       v1::ComponentDefinition::HttpClientComponent(v) => {
         ComponentDefinition::HighLevelComponent(HighLevelComponent::HttpClient(v.try_into()?))
       }
+      v1::ComponentDefinition::KeyValueComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::KeyValue(v.into()))
+      }
+      v1::ComponentDefinition::GrpcClientComponent(v) => {
+        ComponentDefinition::HighLevelComponent(HighLevelComponent::GrpcClient(v.try_into()?))
+      }
     };
     Ok(res)
   }
@@ -662,6 +681,8 @@ impl TryFrom<crate::v1::HostConfig> for HostConfig {
       allow_latest: def.allow_latest,
       insecure_registries: def.insecure_registries,
       rpc: def.rpc.try_map_into()?,
+      metrics: def.metrics.try_map_into()?,
+      health: def.health.try_map_into()?,
     })
   }
 }
@@ -673,6 +694,8 @@ impl TryFrom<HostConfig> for crate::v1::HostConfig {
       allow_latest: def.allow_latest,
       insecure_registries: def.insecure_registries,
       rpc: def.rpc.try_map_into()?,
+      metrics: def.metrics.try_map_into()?,
+      health: def.health.try_map_into()?,
     })
   }
 }
@@ -696,6 +719,7 @@ impl TryFrom<crate::v1::HttpConfig> for config::HttpConfig {
         Some(v) => Some(v.try_into()?),
         None => None,
       },
+      auth: def.auth.map_into(),
     })
   }
 }
@@ -710,6 +734,111 @@ impl TryFrom<config::HttpConfig> for crate::v1::HttpConfig {
       pem: def.pem.try_map_into()?,
       key: def.key.try_map_into()?,
       ca: def.ca.try_map_into()?,
+      auth: def.auth.try_map_into()?,
+    })
+  }
+}
+
+impl From<crate::v1::RpcAuthConfig> for config::RpcAuthConfig {
+  fn from(def: crate::v1::RpcAuthConfig) -> Self {
+    Self {
+      tokens: def.tokens.map_into(),
+      mtls: def.mtls,
+      policy: def.policy.map_into(),
+    }
+  }
+}
+
+impl TryFrom<config::RpcAuthConfig> for crate::v1::RpcAuthConfig {
+  type Error = ManifestError;
+  fn try_from(def: config::RpcAuthConfig) -> Result<Self> {
+    Ok(Self {
+      tokens: def.tokens.try_map_into()?,
+      mtls: def.mtls,
+      policy: def.policy.map_into(),
+    })
+  }
+}
+
+impl From<crate::v1::RpcToken> for config::RpcToken {
+  fn from(def: crate::v1::RpcToken) -> Self {
+    Self {
+      identity: def.identity,
+      token: TemplateConfig::new_template(def.token),
+    }
+  }
+}
+
+impl TryFrom<config::RpcToken> for crate::v1::RpcToken {
+  type Error = ManifestError;
+  fn try_from(def: config::RpcToken) -> Result<Self> {
+    Ok(Self {
+      identity: def.identity,
+      token: def.token.unrender()?,
+    })
+  }
+}
+
+impl From<crate::v1::RpcPolicy> for config::RpcPolicy {
+  fn from(def: crate::v1::RpcPolicy) -> Self {
+    Self {
+      identity: def.identity,
+      operations: def.operations,
+    }
+  }
+}
+
+impl From<config::RpcPolicy> for crate::v1::RpcPolicy {
+  fn from(def: config::RpcPolicy) -> Self {
+    Self {
+      identity: def.identity,
+      operations: def.operations,
+    }
+  }
+}
+
+impl TryFrom<crate::v1::MetricsConfig> for config::MetricsConfig {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::MetricsConfig) -> Result<Self> {
+    Ok(Self {
+      enabled: def.enabled,
+      port: def.port,
+      address: opt_str_to_ipv4addr(&def.address)?,
+      path: def.path,
+    })
+  }
+}
+
+impl TryFrom<config::MetricsConfig> for crate::v1::MetricsConfig {
+  type Error = ManifestError;
+  fn try_from(def: config::MetricsConfig) -> Result<Self> {
+    Ok(Self {
+      enabled: def.enabled,
+      port: def.port,
+      address: def.address.map(|v| v.to_string()),
+      path: def.path,
+    })
+  }
+}
+
+impl TryFrom<crate::v1::HealthConfig> for config::HealthConfig {
+  type Error = ManifestError;
+  fn try_from(def: crate::v1::HealthConfig) -> Result<Self> {
+    Ok(Self {
+      enabled: def.enabled,
+      port: def.port,
+      address: opt_str_to_ipv4addr(&def.address)?,
+    })
+  }
+}
+
+impl TryFrom<config::HealthConfig> for crate::v1::HealthConfig {
+  type Error = ManifestError;
+  fn try_from(def: config::HealthConfig) -> Result<Self> {
+    Ok(Self {
+      enabled: def.enabled,
+      port: def.port,
+      address: def.address.map(|v| v.to_string()),
     })
   }
 }
@@ -792,6 +921,8 @@ impl TryFrom<v1::HttpRouter> for HttpRouterConfig {
         codec: v.codec.map_into(),
         operation: v.operation.try_into()?,
         middleware: v.middleware.try_map_into()?,
+        policies: v.policies.try_map_into()?,
+        auth: v.auth.map_into(),
       }),
       v1::HttpRouter::RestRouter(v) => Self::RestRouter(RestRouterConfig {
         path: v.path,
@@ -799,12 +930,16 @@ impl TryFrom<v1::HttpRouter> for HttpRouterConfig {
         routes: v.routes.try_map_into()?,
         info: v.info.try_map_into()?,
         middleware: v.middleware.try_map_into()?,
+        policies: v.policies.try_map_into()?,
+        auth: v.auth.map_into(),
       }),
       v1::HttpRouter::StaticRouter(v) => Self::StaticRouter(StaticRouterConfig {
         path: v.path,
         volume: v.volume.into(),
         fallback: v.fallback,
         middleware: v.middleware.try_map_into()?,
+        policies: v.policies.try_map_into()?,
+        auth: v.auth.map_into(),
         indexes: v.indexes,
       }),
       v1::HttpRouter::ProxyRouter(v) => Self::ProxyRouter(ProxyRouterConfig {
@@ -812,6 +947,7 @@ impl TryFrom<v1::HttpRouter> for HttpRouterConfig {
         url: v.url.into(),
         strip_path: v.strip_path,
         middleware: v.middleware.try_map_into()?,
+        policies: v.policies.try_map_into()?,
       }),
     };
     Ok(rv)
@@ -843,6 +979,12 @@ impl TryFrom<v1::ImportDefinition> for config::ImportDefinition {
       v1::ImportDefinition::HttpClientComponent(c) => config::ImportDefinition::Component(
         config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::HttpClient(c.try_into()?)),
       ),
+      v1::ImportDefinition::KeyValueComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::KeyValue(c.into())),
+      ),
+      v1::ImportDefinition::GrpcClientComponent(c) => config::ImportDefinition::Component(
+        config::ComponentDefinition::HighLevelComponent(config::HighLevelComponent::GrpcClient(c.try_into()?)),
+      ),
     })
   }
 }
@@ -980,10 +1122,73 @@ impl TryFrom<v1::HttpClientOperationDefinition> for components::HttpClientOperat
       method: value.method.into(),
       config: value.with.try_map_into()?,
       headers: value.headers,
+      cache: value.cache.map_into(),
+      timeout: value.timeout,
+      retry: value.retry.map_into(),
     })
   }
 }
 
+impl From<v1::HttpCache> for components::HttpCacheConfig {
+  fn from(value: v1::HttpCache) -> Self {
+    Self {
+      max_size: value.max_size,
+      volume: value.volume.map_into(),
+    }
+  }
+}
+
+impl From<components::HttpCacheConfig> for v1::HttpCache {
+  fn from(value: components::HttpCacheConfig) -> Self {
+    Self {
+      max_size: value.max_size,
+      volume: value.volume.map(|v| v.id().to_owned()),
+    }
+  }
+}
+
+impl From<v1::HttpRetry> for components::HttpRetryConfig {
+  fn from(value: v1::HttpRetry) -> Self {
+    Self {
+      max_attempts: value.max_attempts,
+      statuses: value.statuses,
+      methods: value.methods.map_into(),
+      backoff: value.backoff,
+      max_backoff: value.max_backoff,
+    }
+  }
+}
+
+impl From<components::HttpRetryConfig> for v1::HttpRetry {
+  fn from(value: components::HttpRetryConfig) -> Self {
+    Self {
+      max_attempts: value.max_attempts,
+      statuses: value.statuses,
+      methods: value.methods.map_into(),
+      backoff: value.backoff,
+      max_backoff: value.max_backoff,
+    }
+  }
+}
+
+impl From<v1::HttpRedirects> for components::HttpRedirectConfig {
+  fn from(value: v1::HttpRedirects) -> Self {
+    Self {
+      max: value.max,
+      same_origin: value.same_origin,
+    }
+  }
+}
+
+impl From<components::HttpRedirectConfig> for v1::HttpRedirects {
+  fn from(value: components::HttpRedirectConfig) -> Self {
+    Self {
+      max: value.max,
+      same_origin: value.same_origin,
+    }
+  }
+}
+
 impl From<v1::HttpMethod> for config::HttpMethod {
   fn from(value: v1::HttpMethod) -> Self {
     match value {
@@ -1015,11 +1220,58 @@ impl TryFrom<v1::HttpClientComponent> for components::HttpClientComponentConfig
       codec: value.codec.map_into(),
       proxy: value.proxy.try_map_into()?,
       timeout: value.timeout,
+      retry: value.retry.map_into(),
+      redirects: value.redirects.map_into(),
+      max_response_size: value.max_response_size,
       operations: value.operations.try_map_into()?,
     })
   }
 }
 
+impl From<v1::KeyValueComponent> for components::KeyValueComponentConfig {
+  fn from(value: v1::KeyValueComponent) -> Self {
+    Self {
+      volume: value.volume.map_into(),
+    }
+  }
+}
+
+impl From<components::KeyValueComponentConfig> for v1::KeyValueComponent {
+  fn from(value: components::KeyValueComponentConfig) -> Self {
+    Self {
+      volume: value.volume.map(|v| v.id().to_owned()),
+    }
+  }
+}
+
+impl TryFrom<v1::GrpcClientComponent> for components::GrpcClientComponentConfig {
+  type Error = crate::Error;
+  fn try_from(value: v1::GrpcClientComponent) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.into(),
+      proto: value.proto.try_into()?,
+      service: value.service,
+      config: value.with.try_map_into()?,
+      timeout: value.timeout,
+      metadata: value.metadata,
+    })
+  }
+}
+
+impl TryFrom<components::GrpcClientComponentConfig> for v1::GrpcClientComponent {
+  type Error = ManifestError;
+  fn try_from(value: components::GrpcClientComponentConfig) -> Result<Self> {
+    Ok(Self {
+      resource: value.resource.id().to_owned(),
+      proto: value.proto.try_into()?,
+      service: value.service,
+      timeout: value.timeout,
+      metadata: value.metadata,
+      with: value.config.try_map_into()?,
+    })
+  }
+}
+
 impl TryFrom<v1::Proxy> for components::Proxy {
   type Error = crate::Error;
   fn try_from(value: v1::Proxy) -> Result<Self> {
@@ -1118,6 +1370,9 @@ impl TryFrom<components::HttpClientOperationDefinition> for v1::HttpClientOperat
       method: value.method.into(),
       with: value.config.try_map_into()?,
       headers: value.headers,
+      cache: value.cache.map_into(),
+      timeout: value.timeout,
+      retry: value.retry.map_into(),
     })
   }
 }
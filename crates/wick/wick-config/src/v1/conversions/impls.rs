@@ -8,6 +8,8 @@ impl ComponentDefinition {
       ComponentDefinition::ComponentReference(v) => Some(&v.id),
       ComponentDefinition::SqlComponent(_) => todo!(),
       ComponentDefinition::HttpClientComponent(_) => todo!(),
+      ComponentDefinition::KeyValueComponent(_) => todo!(),
+      ComponentDefinition::GrpcClientComponent(_) => todo!(),
     }
   }
 }
@@ -1,5 +1,6 @@
 use option_utils::OptionUtils;
 
+use crate::config::TemplateConfig;
 use crate::error::ManifestError;
 use crate::utils::{VecMapInto, VecTryMapInto};
 use crate::{config, v1};
@@ -124,6 +125,7 @@ impl TryFrom<config::ProxyRouterConfig> for v1::ProxyRouter {
       url: value.url.id().to_owned(),
       strip_path: value.strip_path,
       middleware: value.middleware.try_map_into()?,
+      policies: value.policies.map_into(),
     })
   }
 }
@@ -136,6 +138,8 @@ impl TryFrom<config::StaticRouterConfig> for v1::StaticRouter {
       volume: value.volume.id().to_owned(),
       fallback: value.fallback,
       middleware: value.middleware.try_map_into()?,
+      policies: value.policies.map_into(),
+      auth: value.auth.try_map_into()?,
       indexes: value.indexes,
     })
   }
@@ -149,6 +153,8 @@ impl TryFrom<config::RawRouterConfig> for v1::RawRouter {
       codec: value.codec.map_into(),
       operation: value.operation.try_into()?,
       middleware: value.middleware.try_map_into()?,
+      policies: value.policies.map_into(),
+      auth: value.auth.try_map_into()?,
     })
   }
 }
@@ -161,6 +167,8 @@ impl TryFrom<config::RestRouterConfig> for v1::RestRouter {
       tools: value.tools.try_map_into()?,
       routes: value.routes.try_map_into()?,
       middleware: value.middleware.try_map_into()?,
+      policies: value.policies.map_into(),
+      auth: value.auth.try_map_into()?,
       info: value.info.try_map_into()?,
     })
   }
@@ -188,6 +196,236 @@ impl TryFrom<v1::Middleware> for config::Middleware {
   }
 }
 
+impl From<config::HttpPolicies> for v1::HttpPolicies {
+  fn from(value: config::HttpPolicies) -> Self {
+    Self {
+      cors: value.cors.map_into(),
+      rate_limit: value.rate_limit.map_into(),
+      max_body_size: value.max_body_size,
+      request_timeout: value.request_timeout,
+      idle_timeout: value.idle_timeout,
+      compression: value.compression.map_into(),
+    }
+  }
+}
+
+impl TryFrom<v1::HttpPolicies> for config::HttpPolicies {
+  type Error = ManifestError;
+
+  fn try_from(value: v1::HttpPolicies) -> Result<Self> {
+    Ok(Self {
+      cors: value.cors.map_into(),
+      rate_limit: value.rate_limit.try_map_into()?,
+      max_body_size: value.max_body_size,
+      request_timeout: value.request_timeout,
+      idle_timeout: value.idle_timeout,
+      compression: value.compression.map_into(),
+    })
+  }
+}
+
+impl From<config::Cors> for v1::Cors {
+  fn from(value: config::Cors) -> Self {
+    Self {
+      allowed_origins: value.allowed_origins,
+      allowed_methods: value.allowed_methods.map_into(),
+      allowed_headers: value.allowed_headers,
+      exposed_headers: value.exposed_headers,
+      allow_credentials: value.allow_credentials,
+      max_age: value.max_age,
+    }
+  }
+}
+
+impl From<v1::Cors> for config::Cors {
+  fn from(value: v1::Cors) -> Self {
+    Self {
+      allowed_origins: value.allowed_origins,
+      allowed_methods: value.allowed_methods.map_into(),
+      allowed_headers: value.allowed_headers,
+      exposed_headers: value.exposed_headers,
+      allow_credentials: value.allow_credentials,
+      max_age: value.max_age,
+    }
+  }
+}
+
+impl From<config::RateLimit> for v1::RateLimit {
+  fn from(value: config::RateLimit) -> Self {
+    Self {
+      burst: value.burst,
+      per_second: value.per_second,
+      header: value.header,
+    }
+  }
+}
+
+impl TryFrom<v1::RateLimit> for config::RateLimit {
+  type Error = ManifestError;
+
+  fn try_from(value: v1::RateLimit) -> Result<Self> {
+    if value.burst == 0 {
+      return Err(ManifestError::InvalidPolicy(
+        "rate_limit.burst must be greater than 0".to_owned(),
+      ));
+    }
+    if value.per_second == 0 {
+      return Err(ManifestError::InvalidPolicy(
+        "rate_limit.per_second must be greater than 0".to_owned(),
+      ));
+    }
+    Ok(Self {
+      burst: value.burst,
+      per_second: value.per_second,
+      header: value.header,
+    })
+  }
+}
+
+impl From<config::Compression> for v1::Compression {
+  fn from(value: config::Compression) -> Self {
+    match value {
+      config::Compression::Gzip => Self::Gzip,
+      config::Compression::Brotli => Self::Brotli,
+    }
+  }
+}
+
+impl From<v1::Compression> for config::Compression {
+  fn from(value: v1::Compression) -> Self {
+    match value {
+      v1::Compression::Gzip => Self::Gzip,
+      v1::Compression::Brotli => Self::Brotli,
+    }
+  }
+}
+
+impl TryFrom<config::HttpAuth> for v1::HttpAuth {
+  type Error = ManifestError;
+  fn try_from(value: config::HttpAuth) -> Result<Self> {
+    Ok(Self {
+      jwt: value.jwt.try_map_into()?,
+      api_key: value.api_key.try_map_into()?,
+      scopes: value.scopes,
+    })
+  }
+}
+
+impl From<v1::HttpAuth> for config::HttpAuth {
+  fn from(value: v1::HttpAuth) -> Self {
+    Self {
+      jwt: value.jwt.map_into(),
+      api_key: value.api_key.map_into(),
+      scopes: value.scopes,
+    }
+  }
+}
+
+impl TryFrom<config::JwtAuth> for v1::JwtAuth {
+  type Error = ManifestError;
+  fn try_from(value: config::JwtAuth) -> Result<Self> {
+    Ok(Self {
+      algorithms: value.algorithms.map_into(),
+      secret: value.secret.map(|v| v.unrender()).transpose()?,
+      volume: value.volume.map(|v| v.id().to_owned()),
+      key: value.key,
+      jwks: value.jwks,
+      issuer: value.issuer,
+      audience: value.audience,
+    })
+  }
+}
+
+impl From<v1::JwtAuth> for config::JwtAuth {
+  fn from(value: v1::JwtAuth) -> Self {
+    Self {
+      algorithms: value.algorithms.map_into(),
+      secret: value.secret.map(TemplateConfig::new_template),
+      volume: value.volume.map_into(),
+      key: value.key,
+      jwks: value.jwks,
+      issuer: value.issuer,
+      audience: value.audience,
+    }
+  }
+}
+
+impl TryFrom<config::ApiKeyAuth> for v1::ApiKeyAuth {
+  type Error = ManifestError;
+  fn try_from(value: config::ApiKeyAuth) -> Result<Self> {
+    Ok(Self {
+      header: value.header,
+      keys: value.keys.try_map_into()?,
+    })
+  }
+}
+
+impl From<v1::ApiKeyAuth> for config::ApiKeyAuth {
+  fn from(value: v1::ApiKeyAuth) -> Self {
+    Self {
+      header: value.header,
+      keys: value.keys.map_into(),
+    }
+  }
+}
+
+impl TryFrom<config::ApiKey> for v1::ApiKey {
+  type Error = ManifestError;
+  fn try_from(value: config::ApiKey) -> Result<Self> {
+    Ok(Self {
+      key: value.key.unrender()?,
+      name: value.name,
+      scopes: value.scopes,
+    })
+  }
+}
+
+impl From<v1::ApiKey> for config::ApiKey {
+  fn from(value: v1::ApiKey) -> Self {
+    Self {
+      key: TemplateConfig::new_template(value.key),
+      name: value.name,
+      scopes: value.scopes,
+    }
+  }
+}
+
+impl From<config::JwtAlgorithm> for v1::JwtAlgorithm {
+  fn from(value: config::JwtAlgorithm) -> Self {
+    match value {
+      config::JwtAlgorithm::Hs256 => Self::Hs256,
+      config::JwtAlgorithm::Hs384 => Self::Hs384,
+      config::JwtAlgorithm::Hs512 => Self::Hs512,
+      config::JwtAlgorithm::Rs256 => Self::Rs256,
+      config::JwtAlgorithm::Rs384 => Self::Rs384,
+      config::JwtAlgorithm::Rs512 => Self::Rs512,
+      config::JwtAlgorithm::Ps256 => Self::Ps256,
+      config::JwtAlgorithm::Ps384 => Self::Ps384,
+      config::JwtAlgorithm::Ps512 => Self::Ps512,
+      config::JwtAlgorithm::Es256 => Self::Es256,
+      config::JwtAlgorithm::Es384 => Self::Es384,
+    }
+  }
+}
+
+impl From<v1::JwtAlgorithm> for config::JwtAlgorithm {
+  fn from(value: v1::JwtAlgorithm) -> Self {
+    match value {
+      v1::JwtAlgorithm::Hs256 => Self::Hs256,
+      v1::JwtAlgorithm::Hs384 => Self::Hs384,
+      v1::JwtAlgorithm::Hs512 => Self::Hs512,
+      v1::JwtAlgorithm::Rs256 => Self::Rs256,
+      v1::JwtAlgorithm::Rs384 => Self::Rs384,
+      v1::JwtAlgorithm::Rs512 => Self::Rs512,
+      v1::JwtAlgorithm::Ps256 => Self::Ps256,
+      v1::JwtAlgorithm::Ps384 => Self::Ps384,
+      v1::JwtAlgorithm::Ps512 => Self::Ps512,
+      v1::JwtAlgorithm::Es256 => Self::Es256,
+      v1::JwtAlgorithm::Es384 => Self::Es384,
+    }
+  }
+}
+
 impl TryFrom<config::Tools> for v1::Tools {
   type Error = ManifestError;
 
@@ -215,6 +453,7 @@ impl TryFrom<v1::Route> for config::RestRoute {
       operation: value.operation.try_into()?,
       description: value.description,
       summary: value.summary,
+      scopes: value.scopes,
     })
   }
 }
@@ -230,6 +469,7 @@ impl TryFrom<config::RestRoute> for v1::Route {
       operation: value.operation.try_into()?,
       description: value.description,
       summary: value.summary,
+      scopes: value.scopes,
     })
   }
 }
@@ -174,6 +174,7 @@ impl TryFrom<v1::TypeSignature> for wick::Type {
         value: Box::new((*t.value).try_into()?),
       },
       v1::TypeSignature::Object(_) => TS::Object,
+      v1::TypeSignature::AnonymousStruct(t) => TS::AnonymousStruct(t.fields.try_map_into()?),
     };
     Ok(v)
   }
@@ -213,7 +214,9 @@ impl TryFrom<wick::Type> for v1::TypeSignature {
       #[allow(deprecated)]
       wick::Type::Link { .. } => unimplemented!(),
       wick::Type::Object => TS::Object(v1::Object {}),
-      wick::Type::AnonymousStruct(_) => unimplemented!(),
+      wick::Type::AnonymousStruct(fields) => TS::AnonymousStruct(v1::AnonymousStruct {
+        fields: fields.try_map_into()?,
+      }),
     };
     Ok(v)
   }
@@ -0,0 +1,36 @@
+mod integration_test {
+
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+  use wick_config::audit::check_provided_interfaces;
+  use wick_config::WickConfiguration;
+
+  #[test_logger::test(tokio::test)]
+  async fn test_provided_interfaces() -> Result<()> {
+    let tree = WickConfiguration::fetch_tree(
+      "./tests/manifests/v1/interfaces/component.wick",
+      None,
+      None,
+      Default::default(),
+    )
+    .await?;
+    let mismatches = check_provided_interfaces(&tree);
+
+    assert_eq!(mismatches.len(), 1);
+    let mismatch = &mismatches[0];
+    assert_eq!(mismatch.requirement, "greeter");
+    assert_eq!(mismatch.provider, "PROVIDER");
+    assert_eq!(
+      mismatch.differences,
+      vec![
+        "- greet input input: string",
+        "+ greet input input: u32",
+        "+ greet input name: string",
+        "- struct Greeting field message: string",
+        "+ struct Greeting field message: u32",
+      ]
+    );
+
+    Ok(())
+  }
+}
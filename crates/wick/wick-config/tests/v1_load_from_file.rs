@@ -6,11 +6,24 @@ use flow_expression_parser::ast::{
   InstanceTarget,
 };
 use wick_config::config::components::ComponentConfig;
-use wick_config::config::{ComponentImplementation, FlowOperationBuilder};
+use wick_config::config::{
+  ComponentImplementation,
+  ComponentDefinition,
+  Compression,
+  FlowOperationBuilder,
+  HighLevelComponent,
+  HttpMethod,
+  ImportDefinition,
+  JwtAlgorithm,
+  TriggerDefinition,
+  WickRouter,
+};
 use wick_config::error::ManifestError;
 use wick_config::*;
+use wick_interface_types::OperationSignatures;
+use wick_packet::RuntimeConfig;
 
-use crate::utils::{load, load_app, load_composite};
+use crate::utils::{load, load_app, load_composite, load_uninitialized};
 mod utils;
 
 #[test_logger::test(tokio::test)]
@@ -58,6 +71,33 @@ async fn test_component() -> Result<(), ManifestError> {
   Ok(())
 }
 
+#[test_logger::test(tokio::test)]
+async fn test_anonymous_structs() -> Result<(), ManifestError> {
+  let path = "./tests/manifests/v1/anonymous-struct.yaml";
+  let limits = RuntimeConfig::from([("limits", serde_json::json!({"max": 10, "window": {"seconds": 60}}))]);
+  let mut config = load_uninitialized(path).await?;
+  config.set_root_config(Some(limits.clone()));
+  let config = config.finish()?;
+  let signature = config.clone().try_component_config()?.signature()?;
+
+  let op = &signature.operations[0];
+  assert_eq!(op.inputs[0].ty.to_string(), "{id: u32, name: string?}");
+  assert_eq!(op.outputs[0].ty.to_string(), "{id: u32, tags: string[]}[]");
+  assert_eq!(signature.config[0].ty.to_string(), "{max: u32, window: {seconds: u64}}");
+
+  let yaml = config.into_v1_yaml()?;
+  let mut roundtrip = WickConfiguration::from_yaml(&yaml, &Some(path.into()))?;
+  roundtrip.set_root_config(Some(limits));
+  let roundtrip = roundtrip
+    .finish()?
+    .try_component_config()?
+    .signature()?;
+  assert_eq!(roundtrip.operations, signature.operations);
+  assert_eq!(roundtrip.config, signature.config);
+
+  Ok(())
+}
+
 #[test_logger::test(tokio::test)]
 async fn test_component_singular_input_field() -> Result<(), ManifestError> {
   let component = load("./tests/manifests/v1/component-old.yaml")
@@ -91,6 +131,168 @@ async fn test_component_extended() -> Result<(), ManifestError> {
   Ok(())
 }
 
+#[test_logger::test(tokio::test)]
+async fn test_http_policies() -> Result<(), ManifestError> {
+  let component = load_app("./tests/manifests/v1/http-policies.yaml").await?;
+  let TriggerDefinition::Http(trigger) = &component.triggers()[0] else {
+    panic!("expected http trigger");
+  };
+  let config::HttpRouterConfig::RawRouter(router) = &trigger.routers()[0] else {
+    panic!("expected raw router");
+  };
+  let policies = router.policies().unwrap();
+  let cors = policies.cors().unwrap();
+  assert_eq!(cors.allowed_origins(), ["https://example.com"]);
+  assert_eq!(cors.allowed_methods(), [HttpMethod::Get, HttpMethod::Post]);
+  assert!(cors.allow_credentials());
+  assert_eq!(cors.max_age(), Some(600));
+  let rate_limit = policies.rate_limit().unwrap();
+  assert_eq!(rate_limit.burst(), 20);
+  assert_eq!(rate_limit.per_second(), 5);
+  assert_eq!(rate_limit.header().map(String::as_str), Some("x-api-key"));
+  assert_eq!(policies.max_body_size(), Some(1_048_576));
+  assert_eq!(policies.request_timeout(), Some(30_000));
+  assert_eq!(policies.idle_timeout(), None);
+  assert_eq!(policies.compression(), [Compression::Brotli, Compression::Gzip]);
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_http_policies_zero_burst() -> Result<(), ManifestError> {
+  let path = "./tests/manifests/v1/http-policies.yaml";
+  let yaml = std::fs::read_to_string(path).unwrap().replace("burst: 20", "burst: 0");
+  let result = WickConfiguration::from_yaml(&yaml, &Some(path.into()));
+  let err = result.err().expect("a rate limit with no burst should not load");
+  assert!(err.to_string().contains("rate_limit.burst must be greater than 0"), "{}", err);
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_http_auth() -> Result<(), ManifestError> {
+  let component = load_app("./tests/manifests/v1/http-auth.yaml").await?;
+  let TriggerDefinition::Http(trigger) = &component.triggers()[0] else {
+    panic!("expected http trigger");
+  };
+  let config::HttpRouterConfig::RestRouter(router) = &trigger.routers()[0] else {
+    panic!("expected rest router");
+  };
+  let auth = router.auth().unwrap();
+  assert_eq!(auth.scopes(), ["read"]);
+  let jwt = auth.jwt().unwrap();
+  assert_eq!(jwt.algorithms(), [JwtAlgorithm::Hs256, JwtAlgorithm::Rs256]);
+  assert_eq!(jwt.secret().unwrap().value().map(String::as_str), Some("wick-config"));
+  assert_eq!(jwt.volume().unwrap().id(), "keys");
+  assert_eq!(jwt.jwks().map(String::as_str), Some("jwks.json"));
+  assert_eq!(jwt.issuer().map(String::as_str), Some("https://auth.example.com"));
+  assert_eq!(jwt.audience(), ["api"]);
+  let api_key = auth.api_key().unwrap();
+  assert_eq!(api_key.header(), "x-api-key");
+  let key = &api_key.keys()[0];
+  assert_eq!(key.key().value().map(String::as_str), Some("wick-config"));
+  assert_eq!(key.name().map(String::as_str), Some("ci"));
+  assert_eq!(key.scopes(), ["deploy"]);
+  assert_eq!(router.routes()[0].scopes(), ["deploy"]);
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_host_metrics() -> Result<(), ManifestError> {
+  let component = load_app("./tests/manifests/v1/host-metrics.yaml").await?;
+  let metrics = component.host().unwrap().metrics().unwrap();
+  assert!(metrics.enabled());
+  assert_eq!(metrics.address(), Some(&std::net::Ipv4Addr::UNSPECIFIED));
+  assert_eq!(metrics.port(), Some(9090));
+  assert_eq!(metrics.path().map(String::as_str), Some("/prometheus"));
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_host_health() -> Result<(), ManifestError> {
+  let component = load_app("./tests/manifests/v1/host-health.yaml").await?;
+  let health = component.host().unwrap().health().unwrap();
+  assert!(health.enabled());
+  assert_eq!(health.address(), Some(&std::net::Ipv4Addr::UNSPECIFIED));
+  assert_eq!(health.port(), Some(8081));
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_host_rpc_auth() -> Result<(), ManifestError> {
+  let component = load_app("./tests/manifests/v1/host-rpc-auth.yaml").await?;
+  let auth = component.host().unwrap().rpc().unwrap().auth().unwrap();
+  assert!(auth.mtls());
+  let token = &auth.tokens()[0];
+  assert_eq!(token.identity(), "ci");
+  assert_eq!(token.token().value().map(String::as_str), Some("wick-config"));
+  assert_eq!(auth.policy().len(), 2);
+  assert_eq!(auth.policy()[0].identity(), "ci");
+  assert_eq!(auth.policy()[0].operations(), ["greet", "db::*"]);
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_kv_component() -> Result<(), ManifestError> {
+  let path = "./tests/manifests/v1/kv.yaml";
+  let config = load(path).await?;
+  let component = config.clone().try_component_config()?;
+  let import = component.import().get(0).unwrap();
+  let ImportDefinition::Component(ComponentDefinition::HighLevelComponent(HighLevelComponent::KeyValue(kv))) =
+    import.kind()
+  else {
+    panic!("expected a kv component, got {:?}", import.kind());
+  };
+  assert_eq!(kv.volume().as_ref().map(|v| v.id()), Some("DATA"));
+
+  let ops = kv.operation_signatures();
+  let names: Vec<_> = ops.iter().map(|op| op.name()).collect();
+  assert_eq!(names, ["get", "set", "delete", "compare_and_swap", "scan"]);
+
+  let yaml = config.into_v1_yaml()?;
+  assert!(yaml.contains("wick/component/kv@v1"));
+  let roundtrip = WickConfiguration::from_yaml(&yaml, &Some(path.into()))?
+    .finish()?
+    .try_component_config()?;
+  assert_eq!(roundtrip.import(), component.import());
+
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_grpc_component() -> Result<(), ManifestError> {
+  let path = "./tests/manifests/v1/grpc.yaml";
+  let config = load(path).await?;
+  let component = config.clone().try_component_config()?;
+  let import = component.import().get(0).unwrap();
+  let ImportDefinition::Component(ComponentDefinition::HighLevelComponent(HighLevelComponent::GrpcClient(grpc))) =
+    import.kind()
+  else {
+    panic!("expected a grpc component, got {:?}", import.kind());
+  };
+  assert_eq!(grpc.resource().id(), "GREETER");
+  assert_eq!(grpc.proto().location(), "./greeter.proto");
+  assert!(grpc.proto().path()?.ends_with("tests/manifests/v1/greeter.proto"));
+  assert_eq!(grpc.service().map(String::as_str), Some("test.Greeter"));
+  assert_eq!(grpc.timeout(), Some(1000));
+  assert_eq!(grpc.metadata().get("x-api-key").map(String::as_str), Some("secret"));
+  // Operations are generated from the service definition when the component is instantiated.
+  assert!(grpc.operation_signatures().is_empty());
+
+  let yaml = config.into_v1_yaml()?;
+  assert!(yaml.contains("wick/component/grpc@v1"));
+  let roundtrip = WickConfiguration::from_yaml(&yaml, &Some(path.into()))?
+    .finish()?
+    .try_component_config()?;
+  assert_eq!(roundtrip.import(), component.import());
+
+  Ok(())
+}
+
 #[test_logger::test(tokio::test)]
 async fn regression_issue_42() -> Result<(), ManifestError> {
   let component = load_app("./tests/manifests/v1/template-expansion.yaml").await?;
@@ -102,3 +304,30 @@ async fn regression_issue_42() -> Result<(), ManifestError> {
   assert_eq!(value, expected);
   Ok(())
 }
+
+#[test_logger::test(tokio::test)]
+async fn test_collects_flow_diagnostics() -> Result<(), ManifestError> {
+  let result = load("./tests/manifests/v1/bad-flows.yaml").await;
+  let Err(ManifestError::Diagnostics(diagnostics)) = result else {
+    panic!("expected diagnostics, got {:?}", result);
+  };
+
+  let found: Vec<_> = diagnostics
+    .iter()
+    .map(|d| {
+      let location = d.location.as_ref().unwrap();
+      (location.line, location.column, d.hint.clone().unwrap())
+    })
+    .collect();
+  assert_eq!(
+    found,
+    vec![
+      (9, 20, "targets are connected with `->`".to_owned()),
+      (13, 22, "expected a target after `->`".to_owned()),
+      (14, 14, "expected a port name after `.`".to_owned()),
+    ]
+  );
+  assert!(diagnostics[0].to_string().contains("bad-flows.yaml:9:20"));
+
+  Ok(())
+}
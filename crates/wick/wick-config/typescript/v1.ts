@@ -67,6 +67,8 @@ export class AppConfiguration implements HasKind {
       _metadata : Metadata| undefined =  undefined;
  // Details about the package for this application. 
       _package : PackageDefinition| undefined =  undefined;
+ // Configuration for the host running this application. 
+      _host : HostConfig| undefined =  undefined;
  // Resources and configuration that the application and its components can access. 
       _resources : ResourceBinding[] =  [];
  // Components that to import and make available to the application. 
@@ -103,6 +105,14 @@ package(value: PackageDefinition| undefined) : AppConfiguration {
     getPackage() : PackageDefinition| undefined {
       return this._package;
 
+    }
+host(value: HostConfig| undefined) : AppConfiguration {
+      this._host = value;
+      return this;
+    }
+    getHost() : HostConfig| undefined {
+      return this._host;
+
     }
 resources(value: ResourceBinding[]) : AppConfiguration {
       this._resources = value;
@@ -136,7 +146,7 @@ triggers(value: TriggerDefinition[]) : AppConfiguration {
     toJSON() : any {
       return {
         kind : "wick/app@v1",
-name: this._name,metadata: this._metadata,package: this._package,resources: this._resources,import: this._import,triggers: this._triggers,      }
+name: this._name,metadata: this._metadata,package: this._package,host: this._host,resources: this._resources,import: this._import,triggers: this._triggers,      }
 
     }
 }
@@ -1002,6 +1012,8 @@ export class ProxyRouter implements HasKind {
       _path : string ;
  // Middleware operations for this router. 
       _middleware : Middleware| undefined =  undefined;
+ // Policies to enforce on requests to this router. 
+      _policies : HttpPolicies| undefined =  undefined;
  // The URL resource to proxy to. 
       _url : BoundIdentifier ;
  // Whether or not to strip the router&#x27;s path from the proxied request. 
@@ -1031,6 +1043,14 @@ middleware(value: Middleware| undefined) : ProxyRouter {
     getMiddleware() : Middleware| undefined {
       return this._middleware;
 
+    }
+policies(value: HttpPolicies| undefined) : ProxyRouter {
+      this._policies = value;
+      return this;
+    }
+    getPolicies() : HttpPolicies| undefined {
+      return this._policies;
+
     }
 url(value: BoundIdentifier) : ProxyRouter {
       this._url = value;
@@ -1056,7 +1076,7 @@ stripPath(value: boolean) : ProxyRouter {
     toJSON() : any {
       return {
         kind : "wick/router/proxy@v1",
-path: this._path,middleware: this._middleware,url: this._url,strip_path: this._stripPath,      }
+path: this._path,middleware: this._middleware,policies: this._policies,url: this._url,strip_path: this._stripPath,      }
 
     }
 }
@@ -1075,6 +1095,10 @@ export class RestRouter implements HasKind {
       _tools : Tools| undefined =  undefined;
  // Middleware operations for this router. 
       _middleware : Middleware| undefined =  undefined;
+ // Policies to enforce on requests to this router. 
+      _policies : HttpPolicies| undefined =  undefined;
+ // Authentication required for requests to this router. 
+      _auth : HttpAuth| undefined =  undefined;
  // The routes to serve and operations that handle them. 
       _routes : Route[] =  [];
  // Information about the router to use when generating documentation and other tools. 
@@ -1109,6 +1133,22 @@ middleware(value: Middleware| undefined) : RestRouter {
     getMiddleware() : Middleware| undefined {
       return this._middleware;
 
+    }
+policies(value: HttpPolicies| undefined) : RestRouter {
+      this._policies = value;
+      return this;
+    }
+    getPolicies() : HttpPolicies| undefined {
+      return this._policies;
+
+    }
+auth(value: HttpAuth| undefined) : RestRouter {
+      this._auth = value;
+      return this;
+    }
+    getAuth() : HttpAuth| undefined {
+      return this._auth;
+
     }
 routes(value: Route[]) : RestRouter {
       this._routes = value;
@@ -1134,7 +1174,7 @@ info(value: Info| undefined) : RestRouter {
     toJSON() : any {
       return {
         kind : "wick/router/rest@v1",
-path: this._path,tools: this._tools,middleware: this._middleware,routes: this._routes,info: this._info,      }
+path: this._path,tools: this._tools,middleware: this._middleware,policies: this._policies,auth: this._auth,routes: this._routes,info: this._info,      }
 
     }
 }
@@ -1159,6 +1199,8 @@ export class Route implements HasKind {
       _description : string| undefined =  undefined;
  // A longer description of the route. 
       _summary : string| undefined =  undefined;
+ // Scopes a request must be granted to access this route. 
+      _scopes : string[] =  [];
     constructor (
 sub_path:
  string,
@@ -1217,6 +1259,14 @@ summary(value: string| undefined) : Route {
       return this._summary;
 
     }
+scopes(value: string[]) : Route {
+      this._scopes = value;
+      return this;
+    }
+    getScopes() : string[] {
+      return this._scopes;
+
+    }
 
     getKind() : string {
       return "";
@@ -1224,7 +1274,7 @@ summary(value: string| undefined) : Route {
 
     toJSON() : any {
       return {
-sub_path: this._subPath,operation: this._operation,methods: this._methods,id: this._id,description: this._description,summary: this._summary,      }
+sub_path: this._subPath,operation: this._operation,methods: this._methods,id: this._id,description: this._description,summary: this._summary,scopes: this._scopes,      }
 
     }
 }
@@ -1514,6 +1564,10 @@ export class StaticRouter implements HasKind {
       _path : string ;
  // Middleware operations for this router. 
       _middleware : Middleware| undefined =  undefined;
+ // Policies to enforce on requests to this router. 
+      _policies : HttpPolicies| undefined =  undefined;
+ // Authentication required for requests to this router. 
+      _auth : HttpAuth| undefined =  undefined;
  // The volume to serve static files from. 
       _volume : string ;
  // Fallback path (relative to volume &#x60;resource&#x60;) for files to serve in case of a 404. Useful for SPA&#x27;s. if volume resource is: /www and fallback: index.html, then a 404 will serve /www/index.html 
@@ -1545,6 +1599,22 @@ middleware(value: Middleware| undefined) : StaticRouter {
     getMiddleware() : Middleware| undefined {
       return this._middleware;
 
+    }
+policies(value: HttpPolicies| undefined) : StaticRouter {
+      this._policies = value;
+      return this;
+    }
+    getPolicies() : HttpPolicies| undefined {
+      return this._policies;
+
+    }
+auth(value: HttpAuth| undefined) : StaticRouter {
+      this._auth = value;
+      return this;
+    }
+    getAuth() : HttpAuth| undefined {
+      return this._auth;
+
     }
 volume(value: string) : StaticRouter {
       this._volume = value;
@@ -1578,7 +1648,7 @@ indexes(value: boolean) : StaticRouter {
     toJSON() : any {
       return {
         kind : "wick/router/static@v1",
-path: this._path,middleware: this._middleware,volume: this._volume,fallback: this._fallback,indexes: this._indexes,      }
+path: this._path,middleware: this._middleware,policies: this._policies,auth: this._auth,volume: this._volume,fallback: this._fallback,indexes: this._indexes,      }
 
     }
 }
@@ -1595,6 +1665,10 @@ export class RawRouter implements HasKind {
       _path : string ;
  // Middleware operations for this router. 
       _middleware : Middleware| undefined =  undefined;
+ // Policies to enforce on requests to this router. 
+      _policies : HttpPolicies| undefined =  undefined;
+ // Authentication required for requests to this router. 
+      _auth : HttpAuth| undefined =  undefined;
  // The codec to use when encoding/decoding data. 
       _codec : Codec| undefined =  undefined;
  // The operation that handles HTTP requests. 
@@ -1624,6 +1698,22 @@ middleware(value: Middleware| undefined) : RawRouter {
     getMiddleware() : Middleware| undefined {
       return this._middleware;
 
+    }
+policies(value: HttpPolicies| undefined) : RawRouter {
+      this._policies = value;
+      return this;
+    }
+    getPolicies() : HttpPolicies| undefined {
+      return this._policies;
+
+    }
+auth(value: HttpAuth| undefined) : RawRouter {
+      this._auth = value;
+      return this;
+    }
+    getAuth() : HttpAuth| undefined {
+      return this._auth;
+
     }
 codec(value: Codec| undefined) : RawRouter {
       this._codec = value;
@@ -1649,7 +1739,7 @@ operation(value: ComponentOperationExpression) : RawRouter {
     toJSON() : any {
       return {
         kind : "wick/router/raw@v1",
-path: this._path,middleware: this._middleware,codec: this._codec,operation: this._operation,      }
+path: this._path,middleware: this._middleware,policies: this._policies,auth: this._auth,codec: this._codec,operation: this._operation,      }
 
     }
 }
@@ -1705,70 +1795,79 @@ request: this._request,response: this._response,      }
 
 
 
-export class TypesConfiguration implements HasKind {
- // The name of this type. 
-      _name : string| undefined =  undefined;
- // Associated metadata for this type. 
-      _metadata : Metadata| undefined =  undefined;
- // Additional types to export and make available to the type. 
-      _types : TypeDefinition[] =  [];
- // A list of operation signatures. 
-      _operations : OperationDefinition[] =  [];
- // Details about the package for this types. 
-      _package : PackageDefinition| undefined =  undefined;
+export class HttpPolicies implements HasKind {
+ // Cross-origin resource sharing rules. Preflight requests are answered without invoking the router. 
+      _cors : Cors| undefined =  undefined;
+ // Limit the rate of requests to this router. Requests over the limit are answered with a &#x60;429 Too Many Requests&#x60; response. 
+      _rateLimit : RateLimit| undefined =  undefined;
+ // The maximum size (in bytes) of a request body. Larger requests are answered with a &#x60;413 Payload Too Large&#x60; response. 
+      _maxBodySize : number| undefined =  undefined;
+ // Timeout (in milliseconds) to handle a request. Requests that time out are answered with a &#x60;504 Gateway Timeout&#x60; response. 
+      _requestTimeout : number| undefined =  undefined;
+ // Timeout (in milliseconds) to wait between chunks of a request body before aborting the request. 
+      _idleTimeout : number| undefined =  undefined;
+ // Algorithms to compress responses with, in order of preference, when accepted by the client. 
+      _compression : Compression[] =  [];
     constructor (
       ) {
     }
 
-name(value: string| undefined) : TypesConfiguration {
-      this._name = value;
+cors(value: Cors| undefined) : HttpPolicies {
+      this._cors = value;
       return this;
     }
-    getName() : string| undefined {
-      return this._name;
+    getCors() : Cors| undefined {
+      return this._cors;
 
     }
-metadata(value: Metadata| undefined) : TypesConfiguration {
-      this._metadata = value;
+rateLimit(value: RateLimit| undefined) : HttpPolicies {
+      this._rateLimit = value;
       return this;
     }
-    getMetadata() : Metadata| undefined {
-      return this._metadata;
+    getRateLimit() : RateLimit| undefined {
+      return this._rateLimit;
 
     }
-types(value: TypeDefinition[]) : TypesConfiguration {
-      this._types = value;
+maxBodySize(value: number| undefined) : HttpPolicies {
+      this._maxBodySize = value;
       return this;
     }
-    getTypes() : TypeDefinition[] {
-      return this._types;
+    getMaxBodySize() : number| undefined {
+      return this._maxBodySize;
 
     }
-operations(value: OperationDefinition[]) : TypesConfiguration {
-      this._operations = value;
+requestTimeout(value: number| undefined) : HttpPolicies {
+      this._requestTimeout = value;
       return this;
     }
-    getOperations() : OperationDefinition[] {
-      return this._operations;
+    getRequestTimeout() : number| undefined {
+      return this._requestTimeout;
 
     }
-package(value: PackageDefinition| undefined) : TypesConfiguration {
-      this._package = value;
+idleTimeout(value: number| undefined) : HttpPolicies {
+      this._idleTimeout = value;
       return this;
     }
-    getPackage() : PackageDefinition| undefined {
-      return this._package;
+    getIdleTimeout() : number| undefined {
+      return this._idleTimeout;
+
+    }
+compression(value: Compression[]) : HttpPolicies {
+      this._compression = value;
+      return this;
+    }
+    getCompression() : Compression[] {
+      return this._compression;
 
     }
 
     getKind() : string {
-      return "wick/types@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/types@v1",
-name: this._name,metadata: this._metadata,types: this._types,operations: this._operations,package: this._package,      }
+cors: this._cors,rate_limit: this._rateLimit,max_body_size: this._maxBodySize,request_timeout: this._requestTimeout,idle_timeout: this._idleTimeout,compression: this._compression,      }
 
     }
 }
@@ -1780,49 +1879,79 @@ name: this._name,metadata: this._metadata,types: this._types,operations: this._o
 
 
 
-export class TestConfiguration implements HasKind {
- // The name of this component. 
-      _name : string| undefined =  undefined;
- // Configuration used to instantiate this component. 
-      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
- // Unit tests to run against components and operations. 
-      _cases : TestDefinition[] =  [];
+export class Cors implements HasKind {
+ // The origins allowed to make cross-origin requests. Use &#x60;*&#x60; to allow any origin. 
+      _allowedOrigins : string[] =  [];
+ // The HTTP methods allowed in cross-origin requests. Allows any method when empty. 
+      _allowedMethods : HttpMethod[] =  [];
+ // The request headers allowed in cross-origin requests. Use &#x60;*&#x60; to allow any header. 
+      _allowedHeaders : string[] =  [];
+ // The response headers exposed to cross-origin requests. 
+      _exposedHeaders : string[] =  [];
+ // Whether or not cross-origin requests can include credentials. 
+      _allowCredentials : boolean =false;
+ // How long (in seconds) the results of a preflight request can be cached. 
+      _maxAge : number| undefined =  undefined;
     constructor (
       ) {
     }
 
-name(value: string| undefined) : TestConfiguration {
-      this._name = value;
+allowedOrigins(value: string[]) : Cors {
+      this._allowedOrigins = value;
       return this;
     }
-    getName() : string| undefined {
-      return this._name;
+    getAllowedOrigins() : string[] {
+      return this._allowedOrigins;
 
     }
-with(value:   Record<string,LiquidJsonValue>| undefined) : TestConfiguration {
-      this._with = value;
+allowedMethods(value: HttpMethod[]) : Cors {
+      this._allowedMethods = value;
       return this;
     }
-    getWith() :   Record<string,LiquidJsonValue>| undefined {
-      return this._with;
+    getAllowedMethods() : HttpMethod[] {
+      return this._allowedMethods;
 
     }
-cases(value: TestDefinition[]) : TestConfiguration {
-      this._cases = value;
+allowedHeaders(value: string[]) : Cors {
+      this._allowedHeaders = value;
       return this;
     }
-    getCases() : TestDefinition[] {
-      return this._cases;
+    getAllowedHeaders() : string[] {
+      return this._allowedHeaders;
+
+    }
+exposedHeaders(value: string[]) : Cors {
+      this._exposedHeaders = value;
+      return this;
+    }
+    getExposedHeaders() : string[] {
+      return this._exposedHeaders;
+
+    }
+allowCredentials(value: boolean) : Cors {
+      this._allowCredentials = value;
+      return this;
+    }
+    getAllowCredentials() : boolean {
+      return this._allowCredentials;
+
+    }
+maxAge(value: number| undefined) : Cors {
+      this._maxAge = value;
+      return this;
+    }
+    getMaxAge() : number| undefined {
+      return this._maxAge;
 
     }
 
     getKind() : string {
-      return "wick/tests@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-name: this._name,with: this._with,cases: this._cases,      }
+allowed_origins: this._allowedOrigins,allowed_methods: this._allowedMethods,allowed_headers: this._allowedHeaders,exposed_headers: this._exposedHeaders,allow_credentials: this._allowCredentials,max_age: this._maxAge,      }
 
     }
 }
@@ -1834,96 +1963,116 @@ name: this._name,with: this._with,cases: this._cases,      }
 
 
 
-export class LockdownConfiguration implements HasKind {
- // Associated metadata for this configuration. 
-      _metadata : Metadata| undefined =  undefined;
- // Restrictions to apply to resources before an application or component can be run. 
-      _resources : ResourceRestriction[] =  [];
+export class RateLimit implements HasKind {
+ // The maximum number of requests allowed in a burst. 
+      _burst : number ;
+ // The number of requests allowed per second once a burst is exhausted. 
+      _perSecond : number ;
+ // A request header to limit requests by (e.g. an API key). Requests are limited by remote address when omitted or when the header is missing. 
+      _header : string| undefined =  undefined;
     constructor (
+burst:
+ number,
+per_second:
+ number,
       ) {
+          this._burst = burst;
+          this._perSecond = per_second;
     }
 
-metadata(value: Metadata| undefined) : LockdownConfiguration {
-      this._metadata = value;
+burst(value: number) : RateLimit {
+      this._burst = value;
       return this;
     }
-    getMetadata() : Metadata| undefined {
-      return this._metadata;
+    getBurst() : number {
+      return this._burst;
 
     }
-resources(value: ResourceRestriction[]) : LockdownConfiguration {
-      this._resources = value;
+perSecond(value: number) : RateLimit {
+      this._perSecond = value;
       return this;
     }
-    getResources() : ResourceRestriction[] {
-      return this._resources;
+    getPerSecond() : number {
+      return this._perSecond;
+
+    }
+header(value: string| undefined) : RateLimit {
+      this._header = value;
+      return this;
+    }
+    getHeader() : string| undefined {
+      return this._header;
 
     }
 
     getKind() : string {
-      return "wick/lockdown@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/lockdown@v1",
-metadata: this._metadata,resources: this._resources,      }
+burst: this._burst,per_second: this._perSecond,header: this._header,      }
 
     }
 }
-
     
     
+
     
     
+export enum Compression {
+Gzip = "Gzip",Brotli = "Brotli",}
 
     
     
     
-export type ResourceRestriction =
-      VolumeRestriction|UrlRestriction|TcpPortRestriction|UdpPortRestriction
-    ;
     
 
 
 
-export class VolumeRestriction implements HasKind {
- // The components this restriction applies to 
-      _components : string[] =  [];
- // The volumes to allow 
-      _allow : LiquidTemplate ;
+export class HttpAuth implements HasKind {
+ // Validate JSON Web Tokens (JWTs) passed as bearer tokens in the &#x60;Authorization&#x60; header. 
+      _jwt : JwtAuth| undefined =  undefined;
+ // Validate API keys passed in a request header. 
+      _apiKey : ApiKeyAuth| undefined =  undefined;
+ // Scopes every request to this router must be granted. 
+      _scopes : string[] =  [];
     constructor (
-allow:
- LiquidTemplate,
       ) {
-          this._allow = allow;
     }
 
-components(value: string[]) : VolumeRestriction {
-      this._components = value;
+jwt(value: JwtAuth| undefined) : HttpAuth {
+      this._jwt = value;
       return this;
     }
-    getComponents() : string[] {
-      return this._components;
+    getJwt() : JwtAuth| undefined {
+      return this._jwt;
 
     }
-allow(value: LiquidTemplate) : VolumeRestriction {
-      this._allow = value;
+apiKey(value: ApiKeyAuth| undefined) : HttpAuth {
+      this._apiKey = value;
       return this;
     }
-    getAllow() : LiquidTemplate {
-      return this._allow;
+    getApiKey() : ApiKeyAuth| undefined {
+      return this._apiKey;
+
+    }
+scopes(value: string[]) : HttpAuth {
+      this._scopes = value;
+      return this;
+    }
+    getScopes() : string[] {
+      return this._scopes;
 
     }
 
     getKind() : string {
-      return "wick/resource/volume@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/resource/volume@v1",
-components: this._components,allow: this._allow,      }
+jwt: this._jwt,api_key: this._apiKey,scopes: this._scopes,      }
 
     }
 }
@@ -1935,43 +2084,92 @@ components: this._components,allow: this._allow,      }
 
 
 
-export class UrlRestriction implements HasKind {
- // The components this restriction applies to 
-      _components : string[] =  [];
- // The URLs to allow 
-      _allow : LiquidTemplate ;
+export class JwtAuth implements HasKind {
+ // The algorithms tokens can be signed with. 
+      _algorithms : JwtAlgorithm[] ;
+ // A shared secret to validate HMAC (HS*) signed tokens with, e.g. &#x60;{{ ctx.secrets.JWT_SECRET }}&#x60;. 
+      _secret : LiquidTemplate| undefined =  undefined;
+ // The volume resource to load key files from. 
+      _volume : BoundIdentifier| undefined =  undefined;
+ // Path (relative to &#x60;volume&#x60;) of a PEM encoded public key to validate RSA (RS*, PS*) or ECDSA (ES*) signed tokens with. 
+      _key : string| undefined =  undefined;
+ // Path (relative to &#x60;volume&#x60;) of a JSON Web Key Set (JWKS) file. Tokens are validated with the key that matches their &#x60;kid&#x60; header. 
+      _jwks : string| undefined =  undefined;
+ // The required issuer (&#x60;iss&#x60; claim) of tokens. 
+      _issuer : string| undefined =  undefined;
+ // The accepted audiences (&#x60;aud&#x60; claim) of tokens. 
+      _audience : string[] =  [];
     constructor (
-allow:
- LiquidTemplate,
+algorithms:
+ JwtAlgorithm[],
       ) {
-          this._allow = allow;
+          this._algorithms = algorithms;
     }
 
-components(value: string[]) : UrlRestriction {
-      this._components = value;
+algorithms(value: JwtAlgorithm[]) : JwtAuth {
+      this._algorithms = value;
       return this;
     }
-    getComponents() : string[] {
-      return this._components;
+    getAlgorithms() : JwtAlgorithm[] {
+      return this._algorithms;
 
     }
-allow(value: LiquidTemplate) : UrlRestriction {
-      this._allow = value;
+secret(value: LiquidTemplate| undefined) : JwtAuth {
+      this._secret = value;
       return this;
     }
-    getAllow() : LiquidTemplate {
-      return this._allow;
+    getSecret() : LiquidTemplate| undefined {
+      return this._secret;
+
+    }
+volume(value: BoundIdentifier| undefined) : JwtAuth {
+      this._volume = value;
+      return this;
+    }
+    getVolume() : BoundIdentifier| undefined {
+      return this._volume;
+
+    }
+key(value: string| undefined) : JwtAuth {
+      this._key = value;
+      return this;
+    }
+    getKey() : string| undefined {
+      return this._key;
+
+    }
+jwks(value: string| undefined) : JwtAuth {
+      this._jwks = value;
+      return this;
+    }
+    getJwks() : string| undefined {
+      return this._jwks;
+
+    }
+issuer(value: string| undefined) : JwtAuth {
+      this._issuer = value;
+      return this;
+    }
+    getIssuer() : string| undefined {
+      return this._issuer;
+
+    }
+audience(value: string[]) : JwtAuth {
+      this._audience = value;
+      return this;
+    }
+    getAudience() : string[] {
+      return this._audience;
 
     }
 
     getKind() : string {
-      return "wick/resource/url@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/resource/url@v1",
-components: this._components,allow: this._allow,      }
+algorithms: this._algorithms,secret: this._secret,volume: this._volume,key: this._key,jwks: this._jwks,issuer: this._issuer,audience: this._audience,      }
 
     }
 }
@@ -1983,56 +2181,39 @@ components: this._components,allow: this._allow,      }
 
 
 
-export class TcpPortRestriction implements HasKind {
- // The components this restriction applies to 
-      _components : string[] =  [];
- // The address to allow 
-      _address : LiquidTemplate ;
- // The port to allow 
-      _port : LiquidTemplate ;
+export class ApiKeyAuth implements HasKind {
+ // The request header to read API keys from. 
+      _header : string ="";
+ // The accepted API keys. 
+      _keys : ApiKey[] =  [];
     constructor (
-address:
- LiquidTemplate,
-port:
- LiquidTemplate,
       ) {
-          this._address = address;
-          this._port = port;
-    }
-
-components(value: string[]) : TcpPortRestriction {
-      this._components = value;
-      return this;
     }
-    getComponents() : string[] {
-      return this._components;
 
-    }
-address(value: LiquidTemplate) : TcpPortRestriction {
-      this._address = value;
+header(value: string) : ApiKeyAuth {
+      this._header = value;
       return this;
     }
-    getAddress() : LiquidTemplate {
-      return this._address;
+    getHeader() : string {
+      return this._header;
 
     }
-port(value: LiquidTemplate) : TcpPortRestriction {
-      this._port = value;
+keys(value: ApiKey[]) : ApiKeyAuth {
+      this._keys = value;
       return this;
     }
-    getPort() : LiquidTemplate {
-      return this._port;
+    getKeys() : ApiKey[] {
+      return this._keys;
 
     }
 
     getKind() : string {
-      return "wick/resource/tcpport@v1";
+      return "";
     }
 
     toJSON() : any {
       return {
-        kind : "wick/resource/tcpport@v1",
-components: this._components,address: this._address,port: this._port,      }
+header: this._header,keys: this._keys,      }
 
     }
 }
@@ -2044,21 +2225,424 @@ components: this._components,address: this._address,port: this._port,      }
 
 
 
-export class UdpPortRestriction implements HasKind {
- // The components this restriction applies to 
-      _components : string[] =  [];
- // The address to allow 
-      _address : LiquidTemplate ;
- // The port to allow 
-      _port : LiquidTemplate ;
+export class ApiKey implements HasKind {
+ // The API key, e.g. &#x60;{{ ctx.secrets.API_KEY }}&#x60;. 
+      _key : LiquidTemplate ;
+ // The name of the key&#x27;s owner, passed to operations as the request principal. 
+      _name : string| undefined =  undefined;
+ // The scopes granted to requests that use this key. 
+      _scopes : string[] =  [];
     constructor (
-address:
- LiquidTemplate,
-port:
+key:
  LiquidTemplate,
       ) {
-          this._address = address;
-          this._port = port;
+          this._key = key;
+    }
+
+key(value: LiquidTemplate) : ApiKey {
+      this._key = value;
+      return this;
+    }
+    getKey() : LiquidTemplate {
+      return this._key;
+
+    }
+name(value: string| undefined) : ApiKey {
+      this._name = value;
+      return this;
+    }
+    getName() : string| undefined {
+      return this._name;
+
+    }
+scopes(value: string[]) : ApiKey {
+      this._scopes = value;
+      return this;
+    }
+    getScopes() : string[] {
+      return this._scopes;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+key: this._key,name: this._name,scopes: this._scopes,      }
+
+    }
+}
+    
+    
+
+    
+    
+export enum JwtAlgorithm {
+Hs256 = "Hs256",Hs384 = "Hs384",Hs512 = "Hs512",Rs256 = "Rs256",Rs384 = "Rs384",Rs512 = "Rs512",Ps256 = "Ps256",Ps384 = "Ps384",Ps512 = "Ps512",Es256 = "Es256",Es384 = "Es384",}
+
+    
+    
+    
+    
+
+
+
+export class TypesConfiguration implements HasKind {
+ // The name of this type. 
+      _name : string| undefined =  undefined;
+ // Associated metadata for this type. 
+      _metadata : Metadata| undefined =  undefined;
+ // Additional types to export and make available to the type. 
+      _types : TypeDefinition[] =  [];
+ // A list of operation signatures. 
+      _operations : OperationDefinition[] =  [];
+ // Details about the package for this types. 
+      _package : PackageDefinition| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+name(value: string| undefined) : TypesConfiguration {
+      this._name = value;
+      return this;
+    }
+    getName() : string| undefined {
+      return this._name;
+
+    }
+metadata(value: Metadata| undefined) : TypesConfiguration {
+      this._metadata = value;
+      return this;
+    }
+    getMetadata() : Metadata| undefined {
+      return this._metadata;
+
+    }
+types(value: TypeDefinition[]) : TypesConfiguration {
+      this._types = value;
+      return this;
+    }
+    getTypes() : TypeDefinition[] {
+      return this._types;
+
+    }
+operations(value: OperationDefinition[]) : TypesConfiguration {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : OperationDefinition[] {
+      return this._operations;
+
+    }
+package(value: PackageDefinition| undefined) : TypesConfiguration {
+      this._package = value;
+      return this;
+    }
+    getPackage() : PackageDefinition| undefined {
+      return this._package;
+
+    }
+
+    getKind() : string {
+      return "wick/types@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/types@v1",
+name: this._name,metadata: this._metadata,types: this._types,operations: this._operations,package: this._package,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class TestConfiguration implements HasKind {
+ // The name of this component. 
+      _name : string| undefined =  undefined;
+ // Configuration used to instantiate this component. 
+      _with :   Record<string,LiquidJsonValue>| undefined =  undefined;
+ // Unit tests to run against components and operations. 
+      _cases : TestDefinition[] =  [];
+    constructor (
+      ) {
+    }
+
+name(value: string| undefined) : TestConfiguration {
+      this._name = value;
+      return this;
+    }
+    getName() : string| undefined {
+      return this._name;
+
+    }
+with(value:   Record<string,LiquidJsonValue>| undefined) : TestConfiguration {
+      this._with = value;
+      return this;
+    }
+    getWith() :   Record<string,LiquidJsonValue>| undefined {
+      return this._with;
+
+    }
+cases(value: TestDefinition[]) : TestConfiguration {
+      this._cases = value;
+      return this;
+    }
+    getCases() : TestDefinition[] {
+      return this._cases;
+
+    }
+
+    getKind() : string {
+      return "wick/tests@v1";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,with: this._with,cases: this._cases,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class LockdownConfiguration implements HasKind {
+ // Associated metadata for this configuration. 
+      _metadata : Metadata| undefined =  undefined;
+ // Restrictions to apply to resources before an application or component can be run. 
+      _resources : ResourceRestriction[] =  [];
+    constructor (
+      ) {
+    }
+
+metadata(value: Metadata| undefined) : LockdownConfiguration {
+      this._metadata = value;
+      return this;
+    }
+    getMetadata() : Metadata| undefined {
+      return this._metadata;
+
+    }
+resources(value: ResourceRestriction[]) : LockdownConfiguration {
+      this._resources = value;
+      return this;
+    }
+    getResources() : ResourceRestriction[] {
+      return this._resources;
+
+    }
+
+    getKind() : string {
+      return "wick/lockdown@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/lockdown@v1",
+metadata: this._metadata,resources: this._resources,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+    
+    
+    
+export type ResourceRestriction =
+      VolumeRestriction|UrlRestriction|TcpPortRestriction|UdpPortRestriction
+    ;
+    
+
+
+
+export class VolumeRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The volumes to allow 
+      _allow : LiquidTemplate ;
+    constructor (
+allow:
+ LiquidTemplate,
+      ) {
+          this._allow = allow;
+    }
+
+components(value: string[]) : VolumeRestriction {
+      this._components = value;
+      return this;
+    }
+    getComponents() : string[] {
+      return this._components;
+
+    }
+allow(value: LiquidTemplate) : VolumeRestriction {
+      this._allow = value;
+      return this;
+    }
+    getAllow() : LiquidTemplate {
+      return this._allow;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/volume@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/volume@v1",
+components: this._components,allow: this._allow,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class UrlRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The URLs to allow 
+      _allow : LiquidTemplate ;
+    constructor (
+allow:
+ LiquidTemplate,
+      ) {
+          this._allow = allow;
+    }
+
+components(value: string[]) : UrlRestriction {
+      this._components = value;
+      return this;
+    }
+    getComponents() : string[] {
+      return this._components;
+
+    }
+allow(value: LiquidTemplate) : UrlRestriction {
+      this._allow = value;
+      return this;
+    }
+    getAllow() : LiquidTemplate {
+      return this._allow;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/url@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/url@v1",
+components: this._components,allow: this._allow,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class TcpPortRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The address to allow 
+      _address : LiquidTemplate ;
+ // The port to allow 
+      _port : LiquidTemplate ;
+    constructor (
+address:
+ LiquidTemplate,
+port:
+ LiquidTemplate,
+      ) {
+          this._address = address;
+          this._port = port;
+    }
+
+components(value: string[]) : TcpPortRestriction {
+      this._components = value;
+      return this;
+    }
+    getComponents() : string[] {
+      return this._components;
+
+    }
+address(value: LiquidTemplate) : TcpPortRestriction {
+      this._address = value;
+      return this;
+    }
+    getAddress() : LiquidTemplate {
+      return this._address;
+
+    }
+port(value: LiquidTemplate) : TcpPortRestriction {
+      this._port = value;
+      return this;
+    }
+    getPort() : LiquidTemplate {
+      return this._port;
+
+    }
+
+    getKind() : string {
+      return "wick/resource/tcpport@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/resource/tcpport@v1",
+components: this._components,address: this._address,port: this._port,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class UdpPortRestriction implements HasKind {
+ // The components this restriction applies to 
+      _components : string[] =  [];
+ // The address to allow 
+      _address : LiquidTemplate ;
+ // The port to allow 
+      _port : LiquidTemplate ;
+    constructor (
+address:
+ LiquidTemplate,
+port:
+ LiquidTemplate,
+      ) {
+          this._address = address;
+          this._port = port;
     }
 
 components(value: string[]) : UdpPortRestriction {
@@ -2580,7 +3164,7 @@ resource: this._resource,path: this._path,      }
     
     
 export type ComponentKind =
-      WasmComponentConfiguration|WasmComponentModel|CompositeComponentConfiguration|SqlComponent|HttpClientComponent
+      WasmComponentConfiguration|WasmComponentModel|CompositeComponentConfiguration|SqlComponent|HttpClientComponent|KeyValueComponent|GrpcClientComponent
     ;
     
 
@@ -2588,7 +3172,7 @@ export type ComponentKind =
     
     
 export type ImportDefinition =
-      TypesComponent|ManifestComponent|SqlComponent|HttpClientComponent
+      TypesComponent|ManifestComponent|SqlComponent|HttpClientComponent|KeyValueComponent|GrpcClientComponent
     ;
     
 
@@ -2596,7 +3180,7 @@ export type ImportDefinition =
     
     
 export type ComponentDefinition =
-      GrpcUrlComponent|ManifestComponent|ComponentReference|SqlComponent|HttpClientComponent
+      GrpcUrlComponent|ManifestComponent|ComponentReference|SqlComponent|HttpClientComponent|KeyValueComponent|GrpcClientComponent
     ;
     
 
@@ -2695,6 +3279,10 @@ export class HostConfig implements HasKind {
       _insecureRegistries : string[] =  [];
  // Configuration for the GRPC server. 
       _rpc : HttpConfig| undefined =  undefined;
+ // Configuration for the Prometheus metrics server. 
+      _metrics : MetricsConfig| undefined =  undefined;
+ // Configuration for the liveness and readiness server. 
+      _health : HealthConfig| undefined =  undefined;
     constructor (
       ) {
     }
@@ -2723,6 +3311,22 @@ rpc(value: HttpConfig| undefined) : HostConfig {
       return this._rpc;
 
     }
+metrics(value: MetricsConfig| undefined) : HostConfig {
+      this._metrics = value;
+      return this;
+    }
+    getMetrics() : MetricsConfig| undefined {
+      return this._metrics;
+
+    }
+health(value: HealthConfig| undefined) : HostConfig {
+      this._health = value;
+      return this;
+    }
+    getHealth() : HealthConfig| undefined {
+      return this._health;
+
+    }
 
     getKind() : string {
       return "";
@@ -2730,7 +3334,7 @@ rpc(value: HttpConfig| undefined) : HostConfig {
 
     toJSON() : any {
       return {
-allow_latest: this._allowLatest,insecure_registries: this._insecureRegistries,rpc: this._rpc,      }
+allow_latest: this._allowLatest,insecure_registries: this._insecureRegistries,rpc: this._rpc,metrics: this._metrics,health: this._health,      }
 
     }
 }
@@ -2755,6 +3359,8 @@ export class HttpConfig implements HasKind {
       _key : string| undefined =  undefined;
  // Path to CA file. 
       _ca : string| undefined =  undefined;
+ // Authentication and authorization for requests to the server. 
+      _auth : RpcAuthConfig| undefined =  undefined;
     constructor (
       ) {
     }
@@ -2807,6 +3413,283 @@ ca(value: string| undefined) : HttpConfig {
       return this._ca;
 
     }
+auth(value: RpcAuthConfig| undefined) : HttpConfig {
+      this._auth = value;
+      return this;
+    }
+    getAuth() : RpcAuthConfig| undefined {
+      return this._auth;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+enabled: this._enabled,port: this._port,address: this._address,pem: this._pem,key: this._key,ca: this._ca,auth: this._auth,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class RpcAuthConfig implements HasKind {
+ // Bearer tokens accepted by the server. 
+      _tokens : RpcToken[] =  [];
+ // Accept the common name (CN) of a client certificate verified against &#x60;ca&#x60; as the caller&#x27;s identity. 
+      _mtls : boolean =false;
+ // The operations each identity may invoke. Requests from identities without a policy are denied. 
+      _policy : RpcPolicy[] =  [];
+    constructor (
+      ) {
+    }
+
+tokens(value: RpcToken[]) : RpcAuthConfig {
+      this._tokens = value;
+      return this;
+    }
+    getTokens() : RpcToken[] {
+      return this._tokens;
+
+    }
+mtls(value: boolean) : RpcAuthConfig {
+      this._mtls = value;
+      return this;
+    }
+    getMtls() : boolean {
+      return this._mtls;
+
+    }
+policy(value: RpcPolicy[]) : RpcAuthConfig {
+      this._policy = value;
+      return this;
+    }
+    getPolicy() : RpcPolicy[] {
+      return this._policy;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+tokens: this._tokens,mtls: this._mtls,policy: this._policy,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class RpcToken implements HasKind {
+ // The identity callers that present this token authenticate as. 
+      _identity : string ;
+ // The token, e.g. &#x60;{{ ctx.secrets.RPC_TOKEN }}&#x60;. 
+      _token : LiquidTemplate ;
+    constructor (
+identity:
+ string,
+token:
+ LiquidTemplate,
+      ) {
+          this._identity = identity;
+          this._token = token;
+    }
+
+identity(value: string) : RpcToken {
+      this._identity = value;
+      return this;
+    }
+    getIdentity() : string {
+      return this._identity;
+
+    }
+token(value: LiquidTemplate) : RpcToken {
+      this._token = value;
+      return this;
+    }
+    getToken() : LiquidTemplate {
+      return this._token;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+identity: this._identity,token: this._token,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class RpcPolicy implements HasKind {
+ // The identity this policy applies to. 
+      _identity : string ;
+ // Patterns of the operation names the identity may invoke. &#x60;*&#x60; matches any sequence of characters, e.g. &#x60;db::*&#x60;. 
+      _operations : string[] =  [];
+    constructor (
+identity:
+ string,
+      ) {
+          this._identity = identity;
+    }
+
+identity(value: string) : RpcPolicy {
+      this._identity = value;
+      return this;
+    }
+    getIdentity() : string {
+      return this._identity;
+
+    }
+operations(value: string[]) : RpcPolicy {
+      this._operations = value;
+      return this;
+    }
+    getOperations() : string[] {
+      return this._operations;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+identity: this._identity,operations: this._operations,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class MetricsConfig implements HasKind {
+ // Enable/disable the server. 
+      _enabled : boolean =false;
+ // The port to bind to. 
+      _port : number| undefined =  undefined;
+ // The address to bind to. 
+      _address : string| undefined =  undefined;
+ // The path to serve metrics on. Defaults to &#x60;/metrics&#x60;. 
+      _path : string| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+enabled(value: boolean) : MetricsConfig {
+      this._enabled = value;
+      return this;
+    }
+    getEnabled() : boolean {
+      return this._enabled;
+
+    }
+port(value: number| undefined) : MetricsConfig {
+      this._port = value;
+      return this;
+    }
+    getPort() : number| undefined {
+      return this._port;
+
+    }
+address(value: string| undefined) : MetricsConfig {
+      this._address = value;
+      return this;
+    }
+    getAddress() : string| undefined {
+      return this._address;
+
+    }
+path(value: string| undefined) : MetricsConfig {
+      this._path = value;
+      return this;
+    }
+    getPath() : string| undefined {
+      return this._path;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+enabled: this._enabled,port: this._port,address: this._address,path: this._path,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class HealthConfig implements HasKind {
+ // Enable/disable the server. 
+      _enabled : boolean =false;
+ // The port to bind to. 
+      _port : number| undefined =  undefined;
+ // The address to bind to. 
+      _address : string| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+enabled(value: boolean) : HealthConfig {
+      this._enabled = value;
+      return this;
+    }
+    getEnabled() : boolean {
+      return this._enabled;
+
+    }
+port(value: number| undefined) : HealthConfig {
+      this._port = value;
+      return this;
+    }
+    getPort() : number| undefined {
+      return this._port;
+
+    }
+address(value: string| undefined) : HealthConfig {
+      this._address = value;
+      return this;
+    }
+    getAddress() : string| undefined {
+      return this._address;
+
+    }
 
     getKind() : string {
       return "";
@@ -2814,7 +3697,7 @@ ca(value: string| undefined) : HttpConfig {
 
     toJSON() : any {
       return {
-enabled: this._enabled,port: this._port,address: this._address,pem: this._pem,key: this._key,ca: this._ca,      }
+enabled: this._enabled,port: this._port,address: this._address,      }
 
     }
 }
@@ -2825,7 +3708,6 @@ enabled: this._enabled,port: this._port,address: this._address,pem: this._pem,ke
     
 
 
-
 export class GrpcUrlComponent implements HasKind {
  // The GRPC URL to connect to. 
       _url : string ;
@@ -3611,6 +4493,40 @@ export const Object = "object";
     
     
 
+
+
+export class AnonymousStruct implements HasKind {
+ // A list of fields in the struct. 
+      _fields : Field[] =  [];
+    constructor (
+      ) {
+    }
+
+fields(value: Field[]) : AnonymousStruct {
+      this._fields = value;
+      return this;
+    }
+    getFields() : Field[] {
+      return this._fields;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+fields: this._fields,      }
+
+    }
+}
+
+    
+    
+    
+    
+
     
     
     
@@ -4711,6 +5627,12 @@ export class HttpClientComponent implements HasKind {
       _proxy : Proxy| undefined =  undefined;
  // The timeout in seconds 
       _timeout : number| undefined =  undefined;
+ // The retry policy for requests. Can be overridden by individual operations. 
+      _retry : HttpRetry| undefined =  undefined;
+ // The redirect policy for requests. Redirects are followed up to 10 times when omitted. 
+      _redirects : HttpRedirects| undefined =  undefined;
+ // The maximum size (in bytes) of a response body. Responses are not limited when omitted. 
+      _maxResponseSize : number| undefined =  undefined;
  // Configuration necessary to provide when instantiating the component. 
       _with : Field[] =  [];
  // A list of operations to expose on this component. 
@@ -4753,6 +5675,30 @@ timeout(value: number| undefined) : HttpClientComponent {
     getTimeout() : number| undefined {
       return this._timeout;
 
+    }
+retry(value: HttpRetry| undefined) : HttpClientComponent {
+      this._retry = value;
+      return this;
+    }
+    getRetry() : HttpRetry| undefined {
+      return this._retry;
+
+    }
+redirects(value: HttpRedirects| undefined) : HttpClientComponent {
+      this._redirects = value;
+      return this;
+    }
+    getRedirects() : HttpRedirects| undefined {
+      return this._redirects;
+
+    }
+maxResponseSize(value: number| undefined) : HttpClientComponent {
+      this._maxResponseSize = value;
+      return this;
+    }
+    getMaxResponseSize() : number| undefined {
+      return this._maxResponseSize;
+
     }
 with(value: Field[]) : HttpClientComponent {
       this._with = value;
@@ -4778,7 +5724,7 @@ operations(value: HttpClientOperationDefinition[]) : HttpClientComponent {
     toJSON() : any {
       return {
         kind : "wick/component/http@v1",
-resource: this._resource,codec: this._codec,proxy: this._proxy,timeout: this._timeout,with: this._with,operations: this._operations,      }
+resource: this._resource,codec: this._codec,proxy: this._proxy,timeout: this._timeout,retry: this._retry,redirects: this._redirects,max_response_size: this._maxResponseSize,with: this._with,operations: this._operations,      }
 
     }
 }
@@ -4861,6 +5807,12 @@ export class HttpClientOperationDefinition implements HasKind {
       _body : LiquidJsonValue| undefined =  undefined;
  // The path to append to our base URL, processed as a liquid template with each input as part of the template data. 
       _path : string ="";
+ // Cache responses to this operation according to their &#x60;Cache-Control&#x60;, &#x60;ETag&#x60;, and &#x60;Last-Modified&#x60; headers. 
+      _cache : HttpCache| undefined =  undefined;
+ // Timeout (in milliseconds) to wait for the response to each attempt of the request. 
+      _timeout : number| undefined =  undefined;
+ // The retry policy for this operation, overriding the component&#x27;s retry policy. 
+      _retry : HttpRetry| undefined =  undefined;
     constructor (
 name:
  string,
@@ -4935,6 +5887,192 @@ path(value: string) : HttpClientOperationDefinition {
       return this._path;
 
     }
+cache(value: HttpCache| undefined) : HttpClientOperationDefinition {
+      this._cache = value;
+      return this;
+    }
+    getCache() : HttpCache| undefined {
+      return this._cache;
+
+    }
+timeout(value: number| undefined) : HttpClientOperationDefinition {
+      this._timeout = value;
+      return this;
+    }
+    getTimeout() : number| undefined {
+      return this._timeout;
+
+    }
+retry(value: HttpRetry| undefined) : HttpClientOperationDefinition {
+      this._retry = value;
+      return this;
+    }
+    getRetry() : HttpRetry| undefined {
+      return this._retry;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+name: this._name,with: this._with,inputs: this._inputs,method: this._method,codec: this._codec,headers: this._headers,body: this._body,path: this._path,cache: this._cache,timeout: this._timeout,retry: this._retry,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class HttpCache implements HasKind {
+ // The maximum total size (in bytes) of cached response bodies. 
+      _maxSize : number =10485760;
+ // A volume resource to persist cached responses to. Responses are cached in memory when omitted. 
+      _volume : BoundIdentifier| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+maxSize(value: number) : HttpCache {
+      this._maxSize = value;
+      return this;
+    }
+    getMaxSize() : number {
+      return this._maxSize;
+
+    }
+volume(value: BoundIdentifier| undefined) : HttpCache {
+      this._volume = value;
+      return this;
+    }
+    getVolume() : BoundIdentifier| undefined {
+      return this._volume;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+max_size: this._maxSize,volume: this._volume,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class HttpRetry implements HasKind {
+ // The maximum number of attempts, including the first request. 
+      _maxAttempts : number =3;
+ // The response statuses to retry. Defaults to 429, 502, 503 and 504. 
+      _statuses : number[] =  [];
+ // The methods to retry. Defaults to the idempotent methods GET, PUT and DELETE. 
+      _methods : HttpMethod[] =  [];
+ // The delay (in milliseconds) before the first retry. The delay doubles with each retry and is randomized by up to half. 
+      _backoff : number =100;
+ // The maximum delay (in milliseconds) before a retry. Responses whose &#x60;Retry-After&#x60; asks for a longer delay are not retried. 
+      _maxBackoff : number =10000;
+    constructor (
+      ) {
+    }
+
+maxAttempts(value: number) : HttpRetry {
+      this._maxAttempts = value;
+      return this;
+    }
+    getMaxAttempts() : number {
+      return this._maxAttempts;
+
+    }
+statuses(value: number[]) : HttpRetry {
+      this._statuses = value;
+      return this;
+    }
+    getStatuses() : number[] {
+      return this._statuses;
+
+    }
+methods(value: HttpMethod[]) : HttpRetry {
+      this._methods = value;
+      return this;
+    }
+    getMethods() : HttpMethod[] {
+      return this._methods;
+
+    }
+backoff(value: number) : HttpRetry {
+      this._backoff = value;
+      return this;
+    }
+    getBackoff() : number {
+      return this._backoff;
+
+    }
+maxBackoff(value: number) : HttpRetry {
+      this._maxBackoff = value;
+      return this;
+    }
+    getMaxBackoff() : number {
+      return this._maxBackoff;
+
+    }
+
+    getKind() : string {
+      return "";
+    }
+
+    toJSON() : any {
+      return {
+max_attempts: this._maxAttempts,statuses: this._statuses,methods: this._methods,backoff: this._backoff,max_backoff: this._maxBackoff,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+
+
+export class HttpRedirects implements HasKind {
+ // The maximum number of redirects to follow. Use 0 to not follow redirects. 
+      _max : number =10;
+ // Only follow redirects to the same scheme, host and port as the original request. 
+      _sameOrigin : boolean =false;
+    constructor (
+      ) {
+    }
+
+max(value: number) : HttpRedirects {
+      this._max = value;
+      return this;
+    }
+    getMax() : number {
+      return this._max;
+
+    }
+sameOrigin(value: boolean) : HttpRedirects {
+      this._sameOrigin = value;
+      return this;
+    }
+    getSameOrigin() : boolean {
+      return this._sameOrigin;
+
+    }
 
     getKind() : string {
       return "";
@@ -4942,7 +6080,7 @@ path(value: string) : HttpClientOperationDefinition {
 
     toJSON() : any {
       return {
-name: this._name,with: this._with,inputs: this._inputs,method: this._method,codec: this._codec,headers: this._headers,body: this._body,path: this._path,      }
+max: this._max,same_origin: this._sameOrigin,      }
 
     }
 }
@@ -4967,3 +6105,125 @@ Get = "Get",Post = "Post",Put = "Put",Delete = "Delete",}
     
 
 
+
+export class KeyValueComponent implements HasKind {
+ // A volume resource to persist the store to. Values are kept in memory when omitted. 
+      _volume : BoundIdentifier| undefined =  undefined;
+    constructor (
+      ) {
+    }
+
+volume(value: BoundIdentifier| undefined) : KeyValueComponent {
+      this._volume = value;
+      return this;
+    }
+    getVolume() : BoundIdentifier| undefined {
+      return this._volume;
+
+    }
+
+    getKind() : string {
+      return "wick/component/kv@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/kv@v1",
+volume: this._volume,      }
+
+    }
+}
+
+    
+    
+    
+    
+
+export class GrpcClientComponent implements HasKind {
+ // The URL of the gRPC server. 
+      _resource : BoundIdentifier ;
+ // The `.proto` file or binary `FileDescriptorSet` that defines the service. 
+      _proto : string ;
+ // The fully qualified name of the service to call. Required when the definition includes more than one service. 
+      _service : string| undefined =  undefined;
+ // Timeout (in milliseconds) for each call. Calls are only limited by the invocation&#x27;s deadline when omitted. 
+      _timeout : number| undefined =  undefined;
+ // Metadata to add to every request. 
+      _metadata :   Record<string,string> =  {};
+ // Configuration necessary to provide when instantiating the component. 
+      _with : Field[] =  [];
+    constructor (
+resource:
+ BoundIdentifier,
+proto:
+ string,
+      ) {
+          this._resource = resource;
+          this._proto = proto;
+    }
+
+resource(value: BoundIdentifier) : GrpcClientComponent {
+      this._resource = value;
+      return this;
+    }
+    getResource() : BoundIdentifier {
+      return this._resource;
+
+    }
+proto(value: string) : GrpcClientComponent {
+      this._proto = value;
+      return this;
+    }
+    getProto() : string {
+      return this._proto;
+
+    }
+service(value: string| undefined) : GrpcClientComponent {
+      this._service = value;
+      return this;
+    }
+    getService() : string| undefined {
+      return this._service;
+
+    }
+timeout(value: number| undefined) : GrpcClientComponent {
+      this._timeout = value;
+      return this;
+    }
+    getTimeout() : number| undefined {
+      return this._timeout;
+
+    }
+metadata(value:   Record<string,string>) : GrpcClientComponent {
+      this._metadata = value;
+      return this;
+    }
+    getMetadata() :   Record<string,string> {
+      return this._metadata;
+
+    }
+with(value: Field[]) : GrpcClientComponent {
+      this._with = value;
+      return this;
+    }
+    getWith() : Field[] {
+      return this._with;
+
+    }
+
+    getKind() : string {
+      return "wick/component/grpc@v1";
+    }
+
+    toJSON() : any {
+      return {
+        kind : "wick/component/grpc@v1",
+resource: this._resource,proto: this._proto,service: this._service,timeout: this._timeout,metadata: this._metadata,with: this._with,      }
+
+    }
+}
+
+    
+    
+    
+    
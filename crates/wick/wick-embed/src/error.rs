@@ -0,0 +1,66 @@
+use thiserror::Error;
+
+/// Errors returned by the embedding API.
+#[derive(Error, Debug)]
+#[non_exhaustive]
+pub enum EmbedError {
+  /// No manifest was passed to the builder.
+  #[error("No manifest configured, set one with `manifest_path()` or `manifest_bytes()`")]
+  NoManifest,
+
+  /// The manifest bytes were not valid UTF-8.
+  #[error("Manifest is not valid UTF-8: {0}")]
+  ManifestEncoding(std::str::Utf8Error),
+
+  /// The host was used after it was shut down.
+  #[error("Host has been shut down")]
+  Shutdown,
+
+  /// The invoked operation does not exist.
+  #[error("Operation '{0}' not found")]
+  OperationNotFound(String),
+
+  /// The inputs could not be split into ports.
+  #[error("Could not serialize the inputs for '{0}', inputs must serialize to an object keyed by input name: {1}")]
+  InvalidInputs(String, String),
+
+  /// The outputs could not be deserialized into the requested type.
+  #[error("Could not deserialize the output of '{0}': {1}")]
+  InvalidOutput(String, String),
+
+  /// A stream was requested from an operation with more than one output.
+  #[error("Operation '{0}' has {1} outputs, use `invoke()` to receive them as a single value")]
+  MultipleOutputs(String, usize),
+
+  /// The operation finished without producing a value.
+  #[error("Operation '{0}' completed without producing a value")]
+  NoOutput(String),
+
+  /// The operation reported an error.
+  #[error("Operation '{0}' failed: {1}")]
+  Operation(String, String),
+
+  /// The manifest could not be loaded.
+  #[error(transparent)]
+  Manifest(#[from] Box<wick_config::Error>),
+
+  /// The runtime failed to start, invoke or stop.
+  #[error(transparent)]
+  Runtime(#[from] Box<wick_runtime::Error>),
+
+  /// A packet could not be decoded.
+  #[error(transparent)]
+  Packet(#[from] wick_packet::Error),
+}
+
+impl From<wick_config::Error> for EmbedError {
+  fn from(e: wick_config::Error) -> Self {
+    EmbedError::Manifest(Box::new(e))
+  }
+}
+
+impl From<wick_runtime::Error> for EmbedError {
+  fn from(e: wick_runtime::Error) -> Self {
+    EmbedError::Runtime(Box::new(e))
+  }
+}
@@ -0,0 +1,316 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use flow_component::Component;
+use flow_graph_interpreter::NamespaceHandler;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use parking_lot::RwLock;
+use seeded_random::Seed;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tracing::Span;
+use wick_config::WickConfiguration;
+use wick_interface_types::{ComponentSignature, OperationSignature};
+use wick_packet::{Entity, InherentData, Invocation, Packet, PacketExt, PacketStream, RuntimeConfig};
+use wick_runtime::{Runtime, RuntimeBuilder};
+
+use crate::error::EmbedError;
+use crate::{NativeComponent, Result};
+
+#[derive(Debug, Clone)]
+enum ManifestSource {
+  Path(PathBuf),
+  Bytes(Vec<u8>),
+}
+
+/// Builds an [EmbeddedHost].
+#[derive(Debug, Default)]
+#[must_use]
+pub struct EmbeddedHostBuilder {
+  manifest: Option<ManifestSource>,
+  components: Vec<NativeComponent>,
+  root_config: Option<RuntimeConfig>,
+  env: Option<HashMap<String, String>>,
+  seed: Option<u64>,
+  span: Option<Span>,
+}
+
+impl EmbeddedHostBuilder {
+  /// Create a new builder.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Load the component manifest from a file. The manifest is read again on [EmbeddedHost::reload].
+  pub fn manifest_path<T: Into<PathBuf>>(mut self, path: T) -> Self {
+    self.manifest = Some(ManifestSource::Path(path.into()));
+    self
+  }
+
+  /// Load the component manifest from YAML bytes. Relative references in the manifest resolve from the current
+  /// working directory.
+  pub fn manifest_bytes<T: Into<Vec<u8>>>(mut self, bytes: T) -> Self {
+    self.manifest = Some(ManifestSource::Bytes(bytes.into()));
+    self
+  }
+
+  /// Register a [NativeComponent] that flows in the manifest can reference by name.
+  pub fn component(mut self, component: NativeComponent) -> Self {
+    self.components.push(component);
+    self
+  }
+
+  /// Set the root configuration passed to the component's `with` block.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn config(mut self, config: RuntimeConfig) -> Self {
+    self.root_config = Some(config);
+    self
+  }
+
+  /// Set the environment variables available to the manifest. Defaults to none.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn env(mut self, env: HashMap<String, String>) -> Self {
+    self.env = Some(env);
+    self
+  }
+
+  /// Set the seed the runtime uses for its random number generation.
+  pub const fn seed(mut self, seed: u64) -> Self {
+    self.seed = Some(seed);
+    self
+  }
+
+  /// Set the parent span for the runtime's traces.
+  pub fn span(mut self, span: Span) -> Self {
+    self.span = Some(span);
+    self
+  }
+
+  /// Load the manifest and start the runtime.
+  pub async fn build(self) -> Result<EmbeddedHost> {
+    let mut host = EmbeddedHost {
+      manifest: self.manifest.ok_or(EmbedError::NoManifest)?,
+      components: self
+        .components
+        .into_iter()
+        .map(|c| {
+          let name = c.name().to_owned();
+          let component: Box<dyn Component + Send + Sync> = Box::new(c);
+          (name, Arc::new(component))
+        })
+        .collect(),
+      root_config: self.root_config,
+      env: self.env,
+      seed: self.seed,
+      span: self.span.unwrap_or_else(Span::current),
+      runtime: RwLock::new(None),
+    };
+    let runtime = host.start().await?;
+    host.runtime = RwLock::new(Some(runtime));
+    Ok(host)
+  }
+}
+
+type SharedComponent = Arc<Box<dyn Component + Send + Sync>>;
+
+/// A Wick component running in-process.
+///
+/// Operations are invoked with serde types, which are split into one packet per input port and reassembled from
+/// the output ports.
+#[must_use]
+pub struct EmbeddedHost {
+  manifest: ManifestSource,
+  components: Vec<(String, SharedComponent)>,
+  root_config: Option<RuntimeConfig>,
+  env: Option<HashMap<String, String>>,
+  seed: Option<u64>,
+  span: Span,
+  runtime: RwLock<Option<Runtime>>,
+}
+
+impl std::fmt::Debug for EmbeddedHost {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("EmbeddedHost")
+      .field("manifest", &self.manifest)
+      .field("components", &self.components.iter().map(|(name, _)| name).collect::<Vec<_>>())
+      .field("running", &self.runtime.read().is_some())
+      .finish()
+  }
+}
+
+impl EmbeddedHost {
+  /// Create a new [EmbeddedHostBuilder].
+  pub fn builder() -> EmbeddedHostBuilder {
+    EmbeddedHostBuilder::new()
+  }
+
+  async fn start(&self) -> Result<Runtime> {
+    let mut config = match &self.manifest {
+      ManifestSource::Path(path) => WickConfiguration::fetch(path, Default::default()).await?,
+      ManifestSource::Bytes(bytes) => {
+        let yaml = std::str::from_utf8(bytes).map_err(EmbedError::ManifestEncoding)?;
+        WickConfiguration::from_yaml(yaml, &std::env::current_dir().ok())?
+      }
+    };
+    config.set_root_config(self.root_config.clone());
+    config.set_env(self.env.clone());
+    let manifest = config.finish()?.try_component_config()?;
+
+    let mut builder = RuntimeBuilder::from_definition(manifest).span(self.span.clone());
+    for (name, component) in &self.components {
+      let name = name.clone();
+      let component = component.clone();
+      builder.add_native_component(Box::new(move |_seed| {
+        Ok(NamespaceHandler::new_from_shared(name.clone(), component.clone()))
+      }));
+    }
+    Ok(builder.build(self.seed.map(Seed::unsafe_new)).await?)
+  }
+
+  fn runtime(&self) -> Result<Runtime> {
+    self.runtime.read().clone().ok_or(EmbedError::Shutdown)
+  }
+
+  /// Get the signature of the running component.
+  pub fn signature(&self) -> Result<ComponentSignature> {
+    Ok(self.runtime()?.get_signature()?)
+  }
+
+  /// Invoke an operation with a raw [PacketStream] and return its output packets unprocessed.
+  pub async fn invoke_packets(&self, operation: &str, packets: PacketStream) -> Result<PacketStream> {
+    let invocation = Invocation::new(
+      Entity::server("embedded"),
+      Entity::local(operation),
+      packets,
+      InherentData::unsafe_default(),
+      &self.span,
+    );
+    Ok(self.runtime()?.invoke(invocation, None).await?)
+  }
+
+  /// Invoke an operation and deserialize its output into a single value.
+  ///
+  /// `inputs` must serialize to an object keyed by input name. An operation with one output produces that output's
+  /// first value, otherwise the result is an object keyed by output name.
+  pub async fn invoke<I, O>(&self, operation: &str, inputs: I) -> Result<O>
+  where
+    I: Serialize + Send,
+    O: DeserializeOwned,
+  {
+    let (signature, stream) = self.start_invocation(operation, inputs).await?;
+    let mut outputs = Map::new();
+    let mut stream = stream;
+    while let Some(packet) = stream.next().await {
+      let packet = packet?;
+      if packet.is_error() {
+        return Err(EmbedError::Operation(operation.to_owned(), packet.unwrap_err().msg().to_owned()));
+      }
+      if packet.is_signal() || packet.is_noop() || outputs.contains_key(packet.port()) {
+        continue;
+      }
+      outputs.insert(packet.port().to_owned(), packet.decode_value()?);
+    }
+
+    let output = if let [single] = signature.outputs() {
+      outputs
+        .remove(single.name())
+        .ok_or_else(|| EmbedError::NoOutput(operation.to_owned()))?
+    } else {
+      Value::Object(outputs)
+    };
+    serde_json::from_value(output).map_err(|e| EmbedError::InvalidOutput(operation.to_owned(), e.to_string()))
+  }
+
+  /// Invoke an operation with a single output and deserialize every value it produces.
+  ///
+  /// `inputs` must serialize to an object keyed by input name.
+  pub async fn invoke_stream<I, O>(&self, operation: &str, inputs: I) -> Result<BoxStream<'static, Result<O>>>
+  where
+    I: Serialize + Send,
+    O: DeserializeOwned + Send + 'static,
+  {
+    let (signature, stream) = self.start_invocation(operation, inputs).await?;
+    if signature.outputs().len() != 1 {
+      return Err(EmbedError::MultipleOutputs(operation.to_owned(), signature.outputs().len()));
+    }
+    let operation = operation.to_owned();
+    let stream = stream.filter_map(move |packet| {
+      let result = match packet {
+        Ok(packet) if packet.is_error() => Some(Err(EmbedError::Operation(
+          operation.clone(),
+          packet.unwrap_err().msg().to_owned(),
+        ))),
+        Ok(packet) if packet.is_signal() || packet.is_noop() => None,
+        Ok(packet) => Some(
+          packet
+            .decode::<O>()
+            .map_err(|e| EmbedError::InvalidOutput(operation.clone(), e.to_string())),
+        ),
+        Err(e) => Some(Err(e.into())),
+      };
+      futures::future::ready(result)
+    });
+    Ok(stream.boxed())
+  }
+
+  async fn start_invocation<I: Serialize + Send>(
+    &self,
+    operation: &str,
+    inputs: I,
+  ) -> Result<(OperationSignature, PacketStream)> {
+    let signature = self
+      .signature()?
+      .get_operation(operation)
+      .cloned()
+      .ok_or_else(|| EmbedError::OperationNotFound(operation.to_owned()))?;
+
+    let packets = if signature.inputs().is_empty() {
+      PacketStream::noop()
+    } else {
+      let invalid = |e: String| EmbedError::InvalidInputs(operation.to_owned(), e);
+      let Value::Object(mut inputs) = serde_json::to_value(inputs).map_err(|e| invalid(e.to_string()))? else {
+        return Err(invalid("not an object".to_owned()));
+      };
+      let mut packets = Vec::new();
+      for input in signature.inputs() {
+        if let Some(value) = inputs.remove(input.name()) {
+          packets.push(Packet::encode(input.name(), value));
+        }
+        packets.push(Packet::done(input.name()));
+      }
+      packets.into()
+    };
+
+    let stream = self.invoke_packets(operation, packets).await?;
+    Ok((signature, stream))
+  }
+
+  /// Re-read the manifest and replace the running component with a fresh instance.
+  ///
+  /// New invocations go to the new instance as soon as it has started. The previous instance is shut down
+  /// afterwards, so invocations still running on it may be cut short.
+  pub async fn reload(&self) -> Result<()> {
+    // Make sure we're not starting a runtime for a host that's already been shut down.
+    if self.runtime.read().is_none() {
+      return Err(EmbedError::Shutdown);
+    }
+    let next = self.start().await?;
+    let previous = self.runtime.write().replace(next);
+    if let Some(previous) = previous {
+      previous.shutdown().await?;
+    }
+    Ok(())
+  }
+
+  /// Shut down the running component. Invocations made afterwards return [EmbedError::Shutdown].
+  pub async fn shutdown(&self) -> Result<()> {
+    let runtime = self.runtime.write().take();
+    if let Some(runtime) = runtime {
+      runtime.shutdown().await?;
+    }
+    Ok(())
+  }
+}
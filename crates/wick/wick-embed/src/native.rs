@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use flow_component::{BoxFuture, Component, ComponentError, LocalScope};
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use wick_interface_types::{ComponentSignature, OperationSignature};
+use wick_packet::{Invocation, Observer, Packet, PacketExt, PacketSender, PacketStream, RuntimeConfig};
+
+type Handler = dyn Fn(Value) -> BoxFuture<'static, Result<Value, ComponentError>> + Send + Sync;
+
+/// A component whose operations are Rust closures.
+///
+/// Each operation waits for one value on every input port, passes them to its handler as a single value keyed by
+/// input name, and sends the handler's result to its outputs. With one output the result is sent as-is, otherwise
+/// it must serialize to an object keyed by output name.
+///
+/// ```
+/// # use wick_embed::NativeComponent;
+/// # use wick_interface_types::{OperationSignature, Type};
+/// #[derive(serde::Deserialize)]
+/// struct Greet {
+///   name: String,
+/// }
+///
+/// let component = NativeComponent::new("greeter").operation(
+///   OperationSignature::new_named("greet")
+///     .add_input("name", Type::String)
+///     .add_output("output", Type::String),
+///   |input: Greet| async move { Ok(format!("Hello, {}!", input.name)) },
+/// );
+/// ```
+#[derive(Clone)]
+#[must_use]
+pub struct NativeComponent {
+  name: String,
+  signature: ComponentSignature,
+  handlers: HashMap<String, Arc<Handler>>,
+}
+
+impl std::fmt::Debug for NativeComponent {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("NativeComponent")
+      .field("name", &self.name)
+      .field("signature", &self.signature)
+      .finish()
+  }
+}
+
+impl NativeComponent {
+  /// Create a component that flows in the manifest can reference as `<name>::<operation>`.
+  pub fn new<T: Into<String>>(name: T) -> Self {
+    let name = name.into();
+    Self {
+      signature: ComponentSignature::new_named(&name),
+      name,
+      handlers: HashMap::new(),
+    }
+  }
+
+  /// The name the component is registered under.
+  #[must_use]
+  pub fn name(&self) -> &str {
+    &self.name
+  }
+
+  /// Add an operation with the passed signature, handled by `handler`.
+  pub fn operation<I, O, F, Fut>(mut self, signature: OperationSignature, handler: F) -> Self
+  where
+    I: DeserializeOwned + Send + 'static,
+    O: Serialize + Send + 'static,
+    F: Fn(I) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<O, ComponentError>> + Send + 'static,
+  {
+    let handler = Arc::new(handler);
+    let handler: Arc<Handler> = Arc::new(move |input: Value| {
+      let handler = handler.clone();
+      Box::pin(async move {
+        let input = serde_json::from_value(input)?;
+        let output = handler(input).await?;
+        Ok(serde_json::to_value(output)?)
+      })
+    });
+    self.handlers.insert(signature.name().to_owned(), handler);
+    self.signature = self.signature.add_operation(signature);
+    self
+  }
+}
+
+impl Component for NativeComponent {
+  fn handle(
+    &self,
+    invocation: Invocation,
+    _data: Option<RuntimeConfig>,
+    _callback: LocalScope,
+  ) -> BoxFuture<Result<PacketStream, ComponentError>> {
+    let op = invocation.target().operation_id().to_owned();
+    let found = self
+      .signature
+      .get_operation(&op)
+      .cloned()
+      .zip(self.handlers.get(&op).cloned());
+
+    Box::pin(async move {
+      let Some((signature, handler)) = found else {
+        return Err(ComponentError::msg(format!("operation '{}' not found", op)));
+      };
+      let (tx, rx) = invocation.make_response();
+      tokio::spawn(async move {
+        let stream = invocation.into_stream();
+        match collect_inputs(&signature, stream).await {
+          Ok(input) => match handler(input).await {
+            Ok(output) => send_outputs(&tx, &signature, output),
+            Err(e) => send_error(&tx, &signature, &e.to_string()),
+          },
+          Err(e) => send_error(&tx, &signature, &e),
+        }
+        tx.complete();
+      });
+      Ok(rx)
+    })
+  }
+
+  fn signature(&self) -> &ComponentSignature {
+    &self.signature
+  }
+}
+
+/// Wait for the first value on every input port and gather them into an object keyed by port name.
+async fn collect_inputs(signature: &OperationSignature, mut stream: PacketStream) -> Result<Value, String> {
+  let mut inputs = Map::new();
+  while inputs.len() < signature.inputs().len() {
+    let Some(packet) = stream.next().await else {
+      break;
+    };
+    let packet = packet.map_err(|e| e.to_string())?;
+    if packet.is_error() {
+      return Err(packet.unwrap_err().msg().to_owned());
+    }
+    if packet.is_signal() || packet.is_noop() || inputs.contains_key(packet.port()) {
+      continue;
+    }
+    if signature.inputs().iter().any(|i| i.name() == packet.port()) {
+      inputs.insert(packet.port().to_owned(), packet.decode_value().map_err(|e| e.to_string())?);
+    }
+  }
+  Ok(Value::Object(inputs))
+}
+
+fn send_outputs(tx: &PacketSender, signature: &OperationSignature, output: Value) {
+  match signature.outputs() {
+    [single] => {
+      let _ = tx.send(Packet::encode(single.name(), output));
+      let _ = tx.send(Packet::done(single.name()));
+    }
+    outputs => {
+      let Value::Object(mut output) = output else {
+        return send_error(
+          tx,
+          signature,
+          "operations with multiple outputs must return an object keyed by output name",
+        );
+      };
+      for port in outputs {
+        if let Some(value) = output.remove(port.name()) {
+          let _ = tx.send(Packet::encode(port.name(), value));
+        }
+        let _ = tx.send(Packet::done(port.name()));
+      }
+    }
+  }
+}
+
+fn send_error(tx: &PacketSender, signature: &OperationSignature, msg: &str) {
+  for port in signature.outputs() {
+    let _ = tx.send(Packet::err(port.name(), msg));
+    let _ = tx.send(Packet::done(port.name()));
+  }
+}
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use wick_embed::{EmbedError, EmbeddedHost, NativeComponent};
+use wick_interface_types::{OperationSignature, Type};
+
+#[derive(Serialize, Deserialize)]
+struct Greet {
+  name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Text {
+  text: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Stats {
+  length: u32,
+  upper: String,
+}
+
+fn greeter() -> NativeComponent {
+  NativeComponent::new("greeter")
+    .operation(
+      OperationSignature::new_named("greet")
+        .add_input("name", Type::String)
+        .add_output("output", Type::String),
+      |input: Greet| async move {
+        if input.name.is_empty() {
+          return Err(anyhow::anyhow!("name is required"));
+        }
+        Ok(format!("Hello, {}!", input.name))
+      },
+    )
+    .operation(
+      OperationSignature::new_named("stats")
+        .add_input("text", Type::String)
+        .add_output("length", Type::U32)
+        .add_output("upper", Type::String),
+      |input: Text| async move {
+        Ok(Stats {
+          length: input.text.len() as _,
+          upper: input.text.to_uppercase(),
+        })
+      },
+    )
+}
+
+async fn host() -> Result<EmbeddedHost> {
+  Ok(
+    EmbeddedHost::builder()
+      .manifest_path("./tests/manifests/greeter.wick")
+      .component(greeter())
+      .build()
+      .await?,
+  )
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_invoke() -> Result<()> {
+  let host = host().await?;
+
+  let greeting: String = host.invoke("greet", Greet { name: "World".to_owned() }).await?;
+  assert_eq!(greeting, "Hello, World!");
+
+  let stats: Stats = host.invoke("stats", Text { text: "wick".to_owned() }).await?;
+  assert_eq!(
+    stats,
+    Stats {
+      length: 4,
+      upper: "WICK".to_owned()
+    }
+  );
+
+  let result = host.invoke::<_, String>("greet", Greet { name: String::new() }).await;
+  assert!(matches!(result, Err(EmbedError::Operation(_, msg)) if msg.contains("name is required")));
+
+  let result = host.invoke::<_, String>("missing", Greet { name: String::new() }).await;
+  assert!(matches!(result, Err(EmbedError::OperationNotFound(_))));
+
+  host.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_invoke_stream() -> Result<()> {
+  let host = host().await?;
+
+  let outputs: Vec<String> = host
+    .invoke_stream("greet", Greet { name: "World".to_owned() })
+    .await?
+    .collect::<Vec<_>>()
+    .await
+    .into_iter()
+    .collect::<Result<_, _>>()?;
+  assert_eq!(outputs, vec!["Hello, World!".to_owned()]);
+
+  let result = host.invoke_stream::<_, String>("stats", Text { text: "wick".to_owned() }).await;
+  assert!(matches!(result, Err(EmbedError::MultipleOutputs(_, 2))));
+
+  host.shutdown().await?;
+  Ok(())
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_manifest_bytes_and_lifecycle() -> Result<()> {
+  let manifest = r#"
+name: passthrough
+kind: wick/component@v1
+component:
+  kind: wick/component/composite@v1
+  operations:
+    - name: echo
+      inputs:
+        - name: input
+          type: object
+      outputs:
+        - name: output
+          type: object
+      flow:
+        - <>.input -> <>.output
+"#;
+  let host = EmbeddedHost::builder().manifest_bytes(manifest).build().await?;
+  let input = serde_json::json!({"input": {"id": 1}});
+
+  let output: serde_json::Value = host.invoke("echo", &input).await?;
+  assert_eq!(output, serde_json::json!({"id": 1}));
+
+  host.reload().await?;
+  let output: serde_json::Value = host.invoke("echo", &input).await?;
+  assert_eq!(output, serde_json::json!({"id": 1}));
+
+  host.shutdown().await?;
+  let result = host.invoke::<_, serde_json::Value>("echo", &input).await;
+  assert!(matches!(result, Err(EmbedError::Shutdown)));
+  assert!(matches!(host.reload().await, Err(EmbedError::Shutdown)));
+
+  Ok(())
+}
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 use futures::future::{join_all, select};
@@ -9,12 +10,15 @@ use tracing::Span;
 use wick_config::config::{AppConfiguration, BoundIdentifier};
 use wick_config::WickConfiguration;
 use wick_interface_types::ComponentSignature;
+use wick_metrics::MetricsServer;
 use wick_packet::{Entity, InherentData, Invocation, PacketStream, RuntimeConfig};
 use wick_runtime::error::RuntimeError;
 use wick_runtime::Runtime;
 use wick_trigger::resources::Resource;
 use wick_trigger::{build_trigger_runtime, Trigger};
 
+use crate::health::{start_health_server, HealthServer, Readiness};
+use crate::metrics::start_metrics_server;
 use crate::triggers::load_trigger;
 use crate::Result;
 
@@ -26,6 +30,12 @@ pub struct AppHost {
   manifest: AppConfiguration,
   #[builder(setter(skip))]
   triggers: Option<TriggerState>,
+  #[builder(setter(skip))]
+  metrics: Option<MetricsServer>,
+  #[builder(setter(skip))]
+  health: Option<HealthServer>,
+  #[builder(setter(skip))]
+  readiness: Arc<Readiness>,
   runtime: Runtime,
   #[builder(default = "tracing::Span::current()")]
   span: Span,
@@ -42,15 +52,51 @@ impl AppHost {
     self.span.in_scope(|| debug!("host starting"));
 
     let resources = self.init_resources()?;
+    self.metrics = self.span.in_scope(|| start_metrics_server(self.manifest.host()))?;
     self.start_triggers(resources)?;
+    self.health = self.span.in_scope(|| {
+      start_health_server(self.manifest.host(), self.runtime.clone(), self.readiness.clone())
+    })?;
 
     Ok(())
   }
 
+  /// Get the address the host's metrics server is bound to.
+  #[must_use]
+  pub fn metrics_address(&self) -> Option<SocketAddr> {
+    self.metrics.as_ref().map(MetricsServer::addr)
+  }
+
+  /// Get the address the host's health server is bound to.
+  #[must_use]
+  pub fn health_address(&self) -> Option<SocketAddr> {
+    self.health.as_ref().map(HealthServer::addr)
+  }
+
   /// Stops a running host.
-  #[allow(clippy::unused_async)]
-  pub async fn stop(self) {
+  pub async fn stop(mut self) {
     self.span.in_scope(|| debug!("host stopping"));
+    self.readiness.stop();
+    if let Some(state) = self.triggers.take() {
+      let triggers: Vec<_> = state.triggers.into_iter().map(|(trigger, _)| trigger).collect();
+      self.drain(&triggers).await;
+    }
+    if let Some(metrics) = self.metrics {
+      metrics.stop();
+    }
+    if let Some(health) = self.health {
+      health.stop();
+    }
+  }
+
+  /// Report the host as not ready, then shut down triggers, letting them finish in-flight work.
+  async fn drain(&self, triggers: &[SharedTrigger]) {
+    self.readiness.stop();
+    for trigger in triggers {
+      if let Err(error) = trigger.shutdown_gracefully().await {
+        self.span.in_scope(|| warn!(%error, "error shutting down trigger"));
+      }
+    }
   }
 
   fn init_resources(&mut self) -> Result<HashMap<BoundIdentifier, Resource>> {
@@ -75,6 +121,7 @@ impl AppHost {
 
     let resources = Arc::new(resources);
     let mut triggers = TriggerState::new();
+    self.readiness.starting(self.manifest.triggers().len());
 
     for trigger_config in self.manifest.triggers() {
       self.span.in_scope(|| debug!(?trigger_config, "loading trigger"));
@@ -88,12 +135,14 @@ impl AppHost {
       let span = info_span!("trigger", kind=%trigger_config.kind());
       span.follows_from(&self.span);
       let rt = self.runtime.clone();
+      let readiness = self.readiness.clone();
 
       let task = tokio::spawn(async move {
         span.in_scope(|| trace!("initializing trigger"));
         match inner.run(name, rt, app_config, config, resources, span.clone()).await {
           Ok(_output) => {
             span.in_scope(|| debug!("trigger initialized"));
+            readiness.started();
           }
           Err(e) => {
             span.in_scope(|| error!("trigger failed to start: {}", e));
@@ -138,6 +187,7 @@ impl AppHost {
       match select(ctrl_c, trigger.wait_for_done()).await {
         futures::future::Either::Left(_) => {
           self.span.in_scope(|| debug!("ctrl-c received, stopping triggers"));
+          self.drain(&triggers).await;
           break;
         }
         futures::future::Either::Right((output, _)) => {
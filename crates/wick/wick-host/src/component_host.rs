@@ -10,13 +10,17 @@ use tracing::Span;
 use uuid::Uuid;
 use wick_component_cli::options::{Options as HostOptions, ServerOptionsBuilder};
 use wick_component_cli::ServerState;
-use wick_config::config::ComponentConfiguration;
+use wick_config::config::{ComponentConfiguration, RpcAuthConfig};
 use wick_config::WickConfiguration;
 use wick_interface_types::ComponentSignature;
+use wick_invocation_server::RpcAuth;
+use wick_metrics::MetricsServer;
 use wick_packet::{Entity, Invocation, PacketStream, RuntimeConfig};
 use wick_runtime::{Runtime, RuntimeBuilder, ScopeComponent};
 
 use crate::error::HostError;
+use crate::health::{start_health_server, HealthServer, Readiness};
+use crate::metrics::start_metrics_server;
 use crate::{Error, Result};
 
 type ServiceMap = HashMap<Uuid, SharedComponent>;
@@ -41,6 +45,12 @@ pub struct ComponentHost {
   manifest: ComponentConfiguration,
   #[builder(default, setter(strip_option))]
   server_metadata: Option<ServerState>,
+  #[builder(setter(skip))]
+  metrics: Option<MetricsServer>,
+  #[builder(setter(skip))]
+  health: Option<HealthServer>,
+  #[builder(setter(skip))]
+  readiness: Arc<Readiness>,
   #[builder(default = "tracing::Span::current()")]
   span: Span,
 }
@@ -56,6 +66,13 @@ impl ComponentHost {
     self.start_runtime(seed.map(Seed::unsafe_new)).await?;
     self.span.in_scope(|| trace!("starting servers"));
     let state = self.start_servers().await?;
+    self.metrics = self
+      .span
+      .in_scope(|| start_metrics_server(self.manifest.host()))?;
+    let runtime = self.get_runtime()?.clone();
+    self.health = self
+      .span
+      .in_scope(|| start_health_server(self.manifest.host(), runtime, self.readiness.clone()))?;
     self.span.in_scope(|| trace!("host started"));
     self.server_metadata = Some(state);
 
@@ -71,6 +88,18 @@ impl ComponentHost {
       .and_then(|state| state.rpc.as_ref().map(|rpc| rpc.addr))
   }
 
+  /// Get the address the host's metrics server is bound to.
+  #[must_use]
+  pub fn metrics_address(&self) -> Option<SocketAddr> {
+    self.metrics.as_ref().map(MetricsServer::addr)
+  }
+
+  /// Get the address the host's health server is bound to.
+  #[must_use]
+  pub fn health_address(&self) -> Option<SocketAddr> {
+    self.health.as_ref().map(HealthServer::addr)
+  }
+
   #[must_use]
   pub const fn get_server_info(&self) -> &Option<ServerState> {
     &self.server_metadata
@@ -79,9 +108,16 @@ impl ComponentHost {
   /// Stops a running host.
   pub async fn stop(self) {
     self.span.in_scope(|| debug!("host stopping"));
+    self.readiness.stop();
+    if let Some(metrics) = self.metrics {
+      metrics.stop();
+    }
     if let Some(runtime) = self.runtime {
       let _ = runtime.shutdown().await;
     }
+    if let Some(health) = self.health {
+      health.stop();
+    }
   }
 
   pub fn get_runtime(&self) -> Result<&Runtime> {
@@ -117,21 +153,22 @@ impl ComponentHost {
 
     let host_config = self.manifest.host().cloned().unwrap_or_default();
 
-    #[allow(clippy::manual_map)]
-    let options = HostOptions::new(
-      self.get_host_id().to_owned(),
-      host_config.rpc().map(|config| {
+    let rpc = match host_config.rpc() {
+      Some(config) => Some(
         ServerOptionsBuilder::default()
           .port(config.port())
           .address(config.address().copied())
           .pem(config.pem().cloned())
           .key(config.key().cloned())
           .ca(config.ca().cloned())
+          .auth(config.auth().map(rpc_auth).transpose()?)
           .enabled(config.enabled())
           .build()
-          .unwrap()
-      }),
-    );
+          .unwrap(),
+      ),
+      None => None,
+    };
+    let options = HostOptions::new(self.get_host_id().to_owned(), rpc);
 
     let collection = from_registry(nuid);
 
@@ -212,6 +249,21 @@ impl ComponentHostBuilder {
   }
 }
 
+fn rpc_auth(config: &RpcAuthConfig) -> Result<RpcAuth> {
+  let mut auth = RpcAuth::default().mtls(config.mtls());
+  for token in config.tokens() {
+    let value = token
+      .token()
+      .value()
+      .ok_or_else(|| Error::Other(format!("RPC token for '{}' has not been rendered", token.identity())))?;
+    auth = auth.token(value, token.identity());
+  }
+  for policy in config.policy() {
+    auth = auth.allow(policy.identity(), policy.operations().iter().cloned());
+  }
+  Ok(auth)
+}
+
 #[cfg(test)]
 mod test {
   use std::net::Ipv4Addr;
@@ -222,7 +274,7 @@ mod test {
   use futures::StreamExt;
   use http::Uri;
   use option_utils::OptionUtils;
-  use wick_config::config::HttpConfigBuilder;
+  use wick_config::config::{HealthConfigBuilder, HostConfigBuilder, HttpConfigBuilder, MetricsConfigBuilder};
   use wick_config::WickConfiguration;
   use wick_invocation_server::connect_rpc_client;
   use wick_packet::{packet_stream, packets, Entity, InherentData, InvocationData, Packet};
@@ -278,6 +330,81 @@ mod test {
     Ok(())
   }
 
+  #[test_logger::test(tokio::test)]
+  async fn request_metrics_server() -> Result<()> {
+    let file = PathBuf::from("manifests/logger.yaml");
+    let mut def = WickConfiguration::fetch(&file, Default::default())
+      .await?
+      .finish()?
+      .try_component_config()?;
+
+    def.set_host(Some(
+      HostConfigBuilder::default()
+        .metrics(MetricsConfigBuilder::default().enabled(true).build()?)
+        .build()?,
+    ));
+
+    let mut host = ComponentHostBuilder::default().manifest(def).build()?;
+    host.start(None).await?;
+    let address = host.metrics_address().unwrap();
+
+    let stream = packet_stream!(("input", "logging output"));
+    let invocation = Invocation::new(
+      Entity::test("request_metrics_server"),
+      Entity::local("logger"),
+      stream,
+      InherentData::unsafe_default(),
+      &Span::current(),
+    );
+    let _packets: Vec<_> = host.invoke(invocation, None).await?.collect().await;
+
+    let res = hyper::Client::new()
+      .get(format!("http://{}/metrics", address).parse()?)
+      .await?;
+    let body = String::from_utf8(hyper::body::to_bytes(res.into_body()).await?.to_vec())?;
+    assert!(body.contains(r#"operation="logger"} 1"#), "unexpected metrics: {}", body);
+
+    host.stop().await;
+
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn request_health_server() -> Result<()> {
+    let file = PathBuf::from("manifests/logger.yaml");
+    let mut def = WickConfiguration::fetch(&file, Default::default())
+      .await?
+      .finish()?
+      .try_component_config()?;
+
+    def.set_host(Some(
+      HostConfigBuilder::default()
+        .health(HealthConfigBuilder::default().enabled(true).build()?)
+        .build()?,
+    ));
+
+    let mut host = ComponentHostBuilder::default().manifest(def).build()?;
+    host.start(None).await?;
+    let address = host.health_address().unwrap();
+    let client = hyper::Client::new();
+
+    let res = client.get(format!("http://{}/healthz", address).parse()?).await?;
+    assert_eq!(res.status(), hyper::StatusCode::OK);
+
+    let res = client.get(format!("http://{}/readyz", address).parse()?).await?;
+    assert_eq!(res.status(), hyper::StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_slice(&hyper::body::to_bytes(res.into_body()).await?)?;
+    assert_eq!(body["status"], "ready");
+
+    host.readiness.stop();
+    let res = client.get(format!("http://{}/readyz", address).parse()?).await?;
+    assert_eq!(res.status(), hyper::StatusCode::SERVICE_UNAVAILABLE);
+
+    host.stop().await;
+
+    Ok(())
+  }
+
   #[test_logger::test(tokio::test)]
   async fn request_rpc_server() -> Result<()> {
     let file = PathBuf::from("manifests/logger.yaml");
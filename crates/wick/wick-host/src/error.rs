@@ -18,6 +18,12 @@ pub enum HostError {
   #[error(transparent)]
   Resource(#[from] wick_trigger::resources::ResourceError),
 
+  #[error(transparent)]
+  Metrics(#[from] wick_metrics::Error),
+
+  #[error("Could not start health server on {0}: {1}")]
+  HealthServer(std::net::SocketAddr, String),
+
   #[error("General error : {0}")]
   Other(String),
 }
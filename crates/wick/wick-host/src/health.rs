@@ -0,0 +1,182 @@
+use std::convert::Infallible;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use serde_json::{json, Map, Value};
+use tokio::task::JoinHandle;
+use wick_config::config::HostConfig;
+use wick_runtime::Runtime;
+
+use crate::error::HostError;
+use crate::Result;
+
+/// How long component health checks may take before the host is reported as not ready.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Tracks whether a host has finished starting and has not begun shutting down.
+#[derive(Debug, Default)]
+pub(crate) struct Readiness {
+  starting: AtomicUsize,
+  stopping: AtomicBool,
+}
+
+impl Readiness {
+  /// Mark `count` triggers as starting.
+  pub(crate) fn starting(&self, count: usize) {
+    self.starting.store(count, Ordering::SeqCst);
+  }
+
+  /// Mark a trigger as started.
+  pub(crate) fn started(&self) {
+    let _ = self
+      .starting
+      .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+  }
+
+  /// Mark the host as stopping. A stopping host never reports ready again.
+  pub(crate) fn stop(&self) {
+    self.stopping.store(true, Ordering::SeqCst);
+  }
+
+  fn pending(&self) -> Option<&'static str> {
+    if self.stopping.load(Ordering::SeqCst) {
+      Some("stopping")
+    } else if self.starting.load(Ordering::SeqCst) > 0 {
+      Some("starting")
+    } else {
+      None
+    }
+  }
+}
+
+/// A running server that exposes `/healthz` and `/readyz`.
+#[derive(Debug)]
+pub(crate) struct HealthServer {
+  addr: SocketAddr,
+  task: JoinHandle<()>,
+}
+
+impl HealthServer {
+  pub(crate) const fn addr(&self) -> SocketAddr {
+    self.addr
+  }
+
+  pub(crate) fn stop(self) {
+    self.task.abort();
+  }
+}
+
+/// Start the health server if it is enabled in the passed [HostConfig].
+pub(crate) fn start_health_server(
+  config: Option<&HostConfig>,
+  runtime: Runtime,
+  readiness: Arc<Readiness>,
+) -> Result<Option<HealthServer>> {
+  let Some(config) = config.and_then(|c| c.health()).filter(|c| c.enabled()) else {
+    return Ok(None);
+  };
+  let address = config.address().copied().unwrap_or(Ipv4Addr::LOCALHOST);
+  let addr = SocketAddr::from((address, config.port().unwrap_or(0)));
+
+  let server = Server::try_bind(&addr).map_err(|e| HostError::HealthServer(addr, e.to_string()))?;
+  let make_svc = make_service_fn(move |_conn| {
+    let runtime = runtime.clone();
+    let readiness = readiness.clone();
+    async move {
+      Ok::<_, Infallible>(service_fn(move |req| {
+        let runtime = runtime.clone();
+        let readiness = readiness.clone();
+        async move { Ok::<_, Infallible>(respond(&req, &runtime, &readiness).await) }
+      }))
+    }
+  });
+  let server = server.serve(make_svc);
+  let addr = server.local_addr();
+  info!(address = %addr, "health server started");
+  let task = tokio::spawn(async move {
+    if let Err(error) = server.await {
+      error!(%error, "health server failed");
+    }
+  });
+  Ok(Some(HealthServer { addr, task }))
+}
+
+async fn respond(req: &Request<Body>, runtime: &Runtime, readiness: &Readiness) -> Response<Body> {
+  if req.method() != Method::GET {
+    return empty(StatusCode::METHOD_NOT_ALLOWED);
+  }
+  match req.uri().path() {
+    "/healthz" => json_response(StatusCode::OK, &json!({"status": "ok"})),
+    "/readyz" => {
+      let (ready, body) = check_readiness(runtime, readiness).await;
+      let status = if ready {
+        StatusCode::OK
+      } else {
+        StatusCode::SERVICE_UNAVAILABLE
+      };
+      json_response(status, &body)
+    }
+    _ => empty(StatusCode::NOT_FOUND),
+  }
+}
+
+async fn check_readiness(runtime: &Runtime, readiness: &Readiness) -> (bool, Value) {
+  if let Some(state) = readiness.pending() {
+    return (false, json!({"status": state}));
+  }
+  let Ok(results) = tokio::time::timeout(CHECK_TIMEOUT, runtime.health()).await else {
+    return (false, json!({"status": "not ready", "error": "health checks timed out"}));
+  };
+  let ready = results.iter().all(|c| c.is_ready());
+  let components: Map<String, Value> = results
+    .into_iter()
+    .map(|c| {
+      let detail = c.error.map_or_else(
+        || json!({"status": "ready"}),
+        |error| json!({"status": "not ready", "error": error}),
+      );
+      (c.id, detail)
+    })
+    .collect();
+  let status = if ready { "ready" } else { "not ready" };
+  (ready, json!({"status": status, "components": components}))
+}
+
+fn json_response(status: StatusCode, body: &Value) -> Response<Body> {
+  let mut response = Response::new(Body::from(body.to_string()));
+  *response.status_mut() = status;
+  response
+    .headers_mut()
+    .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+  response
+}
+
+fn empty(status: StatusCode) -> Response<Body> {
+  let mut response = Response::new(Body::empty());
+  *response.status_mut() = status;
+  response
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_readiness() {
+    let readiness = Readiness::default();
+    assert_eq!(readiness.pending(), None);
+    readiness.starting(2);
+    assert_eq!(readiness.pending(), Some("starting"));
+    readiness.started();
+    readiness.started();
+    readiness.started();
+    assert_eq!(readiness.pending(), None);
+    readiness.stop();
+    assert_eq!(readiness.pending(), Some("stopping"));
+  }
+}
@@ -124,6 +124,8 @@ mod app_host;
 pub mod collection;
 mod component_host;
 mod error;
+mod health;
+mod metrics;
 mod traits;
 pub use app_host::{AppHost, AppHostBuilder, TriggerState};
 pub use collection::HostComponent;
@@ -0,0 +1,18 @@
+use std::net::{Ipv4Addr, SocketAddr};
+
+use wick_config::config::HostConfig;
+use wick_metrics::MetricsServer;
+
+use crate::Result;
+
+/// Start the metrics server if it is enabled in the passed [HostConfig].
+pub(crate) fn start_metrics_server(config: Option<&HostConfig>) -> Result<Option<MetricsServer>> {
+  let Some(config) = config.and_then(|c| c.metrics()).filter(|c| c.enabled()) else {
+    return Ok(None);
+  };
+  let address = config.address().copied().unwrap_or(Ipv4Addr::LOCALHOST);
+  let addr = SocketAddr::from((address, config.port().unwrap_or(0)));
+  let server = wick_metrics::serve(addr, config.path().cloned())?;
+  info!(address = %server.addr(), "metrics server started");
+  Ok(Some(server))
+}
@@ -0,0 +1,322 @@
+use std::fmt::Write;
+
+use crate::{ComponentSignature, EnumDefinition, Field, OperationSignature, StructDefinition, Type, TypeDefinition};
+
+/// A single way a component differs from the interface it is expected to satisfy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Difference {
+  /// The interface declares an item the component does not have.
+  Missing(String),
+  /// The component has an item the interface does not declare.
+  Extra(String),
+  /// The interface and the component declare the same item differently.
+  Changed {
+    /// The item as declared by the interface.
+    expected: String,
+    /// The item as declared by the component.
+    actual: String,
+  },
+}
+
+impl std::fmt::Display for Difference {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Difference::Missing(v) => write!(f, "- {}", v),
+      Difference::Extra(v) => write!(f, "+ {}", v),
+      Difference::Changed { expected, actual } => write!(f, "- {}\n+ {}", expected, actual),
+    }
+  }
+}
+
+/// Error returned when a component does not structurally conform to an interface.
+///
+/// Its [std::fmt::Display] implementation renders a diff where `-` lines are what the interface expects
+/// and `+` lines are what the component provides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct ConformanceError {
+  differences: Vec<Difference>,
+}
+
+impl ConformanceError {
+  /// The differences between the interface and the component.
+  #[must_use]
+  pub fn differences(&self) -> &[Difference] {
+    &self.differences
+  }
+}
+
+impl std::error::Error for ConformanceError {}
+
+impl std::fmt::Display for ConformanceError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (i, diff) in self.differences.iter().enumerate() {
+      if i > 0 {
+        f.write_str("\n")?;
+      }
+      write!(f, "{}", diff)?;
+    }
+    Ok(())
+  }
+}
+
+/// Check that the `actual` component provides every operation and type declared by the `expected` interface.
+///
+/// Operations and types the component exposes beyond the interface are allowed. Ports of the interface's
+/// operations must match exactly, as must the definitions of the interface's types. A component port typed as
+/// `object` accepts any type and descriptions are ignored.
+pub fn check_conformance(expected: &ComponentSignature, actual: &ComponentSignature) -> Result<(), ConformanceError> {
+  let mut differences = Vec::new();
+
+  for op in &expected.operations {
+    match actual.get_operation(&op.name) {
+      Some(actual_op) => diff_operation(op, actual_op, &mut differences),
+      None => differences.push(Difference::Missing(format!("operation {}", op.name))),
+    }
+  }
+
+  for ty in &expected.types {
+    match actual.types.iter().find(|t| t.name() == ty.name()) {
+      Some(actual_ty) => diff_type_definition(ty, actual_ty, &mut differences),
+      None => differences.push(Difference::Missing(render_type_definition(ty))),
+    }
+  }
+
+  if differences.is_empty() {
+    Ok(())
+  } else {
+    Err(ConformanceError { differences })
+  }
+}
+
+fn diff_operation(expected: &OperationSignature, actual: &OperationSignature, out: &mut Vec<Difference>) {
+  diff_fields(&format!("{} input", expected.name), &expected.inputs, &actual.inputs, out);
+  diff_fields(&format!("{} output", expected.name), &expected.outputs, &actual.outputs, out);
+}
+
+fn diff_fields(prefix: &str, expected: &[Field], actual: &[Field], out: &mut Vec<Difference>) {
+  for field in expected {
+    match actual.iter().find(|f| f.name == field.name) {
+      Some(actual_field) if !same_type(&field.ty, &actual_field.ty) => out.push(Difference::Changed {
+        expected: render_field(prefix, field),
+        actual: render_field(prefix, actual_field),
+      }),
+      Some(_) => {}
+      None => out.push(Difference::Missing(render_field(prefix, field))),
+    }
+  }
+  for field in actual {
+    if !expected.iter().any(|f| f.name == field.name) {
+      out.push(Difference::Extra(render_field(prefix, field)));
+    }
+  }
+}
+
+fn diff_type_definition(expected: &TypeDefinition, actual: &TypeDefinition, out: &mut Vec<Difference>) {
+  match (expected, actual) {
+    (TypeDefinition::Struct(expected), TypeDefinition::Struct(actual)) => {
+      diff_fields(
+        &format!("struct {} field", expected.name),
+        &expected.fields,
+        &actual.fields,
+        out,
+      );
+    }
+    (TypeDefinition::Enum(e), TypeDefinition::Enum(a)) if same_variants(e, a) => {}
+    (TypeDefinition::Union(e), TypeDefinition::Union(a))
+      if e.types.len() == a.types.len() && e.types.iter().all(|t| a.types.iter().any(|o| same_type(t, o))) => {}
+    _ => out.push(Difference::Changed {
+      expected: render_type_definition(expected),
+      actual: render_type_definition(actual),
+    }),
+  }
+}
+
+fn same_variants(expected: &EnumDefinition, actual: &EnumDefinition) -> bool {
+  expected.variants.len() == actual.variants.len()
+    && expected.variants.iter().all(|v| {
+      actual
+        .variants
+        .iter()
+        .any(|o| o.name == v.name && o.index == v.index && o.value == v.value)
+    })
+}
+
+fn same_type(expected: &Type, actual: &Type) -> bool {
+  match (expected, actual) {
+    (_, Type::Object) => true,
+    (Type::List { ty: e }, Type::List { ty: a }) => same_type(e, a),
+    (Type::Optional { ty: e }, Type::Optional { ty: a }) => same_type(e, a),
+    (Type::Map { key: ek, value: ev }, Type::Map { key: ak, value: av }) => same_type(ek, ak) && same_type(ev, av),
+    (Type::AnonymousStruct(e), Type::AnonymousStruct(a)) => {
+      e.len() == a.len()
+        && e
+          .iter()
+          .all(|f| a.iter().any(|o| o.name == f.name && same_type(&f.ty, &o.ty)))
+    }
+    (e, a) => e == a,
+  }
+}
+
+fn render_field(prefix: &str, field: &Field) -> String {
+  format!("{} {}: {}", prefix, field.name, field.ty)
+}
+
+fn render_struct(def: &StructDefinition) -> String {
+  let fields: Vec<_> = def.fields.iter().map(|f| format!("{}: {}", f.name, f.ty)).collect();
+  format!("struct {} {{ {} }}", def.name, fields.join(", "))
+}
+
+fn render_type_definition(def: &TypeDefinition) -> String {
+  match def {
+    TypeDefinition::Struct(v) => render_struct(v),
+    TypeDefinition::Enum(v) => {
+      let mut variants = Vec::new();
+      for variant in &v.variants {
+        let mut rendered = variant.name.clone();
+        if let Some(index) = variant.index {
+          let _ = write!(rendered, " = {}", index);
+        }
+        if let Some(value) = &variant.value {
+          let _ = write!(rendered, " ({:?})", value);
+        }
+        variants.push(rendered);
+      }
+      format!("enum {} {{ {} }}", v.name, variants.join(", "))
+    }
+    TypeDefinition::Union(v) => {
+      let types: Vec<_> = v.types.iter().map(ToString::to_string).collect();
+      format!("union {} = {}", v.name, types.join(" | "))
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use pretty_assertions::assert_eq;
+
+  use super::*;
+  use crate::{EnumVariant, UnionDefinition};
+
+  fn interface() -> ComponentSignature {
+    let mut sig = ComponentSignature::new_named("interface")
+      .add_operation(
+        OperationSignature::new_named("greet")
+          .add_input("name", Type::String)
+          .add_output("output", Type::String),
+      )
+      .add_operation(
+        OperationSignature::new_named("add")
+          .add_input("left", Type::U64)
+          .add_input("right", Type::U64)
+          .add_output("output", Type::U64),
+      );
+    sig.types = vec![
+      TypeDefinition::Struct(StructDefinition::new(
+        "User",
+        vec![Field::new("name", Type::String), Field::new("age", Type::U32)],
+        None,
+      )),
+      TypeDefinition::Enum(EnumDefinition::new(
+        "Color",
+        vec![
+          EnumVariant::new("Red", Some(0), None, None),
+          EnumVariant::new("Blue", Some(1), None, None),
+        ],
+        None,
+      )),
+    ];
+    sig
+  }
+
+  #[test]
+  fn test_conforming() -> Result<()> {
+    let expected = interface();
+    let mut actual = interface();
+    actual.name = Some("provider".to_owned());
+    actual.operations.push(OperationSignature::new_named("extra"));
+    actual.types.push(TypeDefinition::Union(UnionDefinition::new(
+      "Extra",
+      vec![Type::String, Type::U8],
+      None,
+    )));
+    actual.operations[1].inputs[0].ty = Type::Object;
+    if let TypeDefinition::Struct(user) = &mut actual.types[0] {
+      user.fields.reverse();
+      user.description = Some("a user".to_owned());
+    }
+
+    check_conformance(&expected, &actual)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_nonconforming() -> Result<()> {
+    let expected = interface();
+    let mut actual = interface();
+    actual.operations.retain(|op| op.name != "add");
+    actual.operations[0].inputs = vec![Field::new("name", Type::U32), Field::new("greeting", Type::String)];
+    actual.operations[0].outputs.clear();
+    actual.types[0] = TypeDefinition::Struct(StructDefinition::new(
+      "User",
+      vec![Field::new("name", Type::String), Field::new("age", Type::I32)],
+      None,
+    ));
+    actual.types.truncate(1);
+
+    let err = check_conformance(&expected, &actual).unwrap_err();
+
+    assert_eq!(
+      err.differences(),
+      &[
+        Difference::Changed {
+          expected: "greet input name: string".to_owned(),
+          actual: "greet input name: u32".to_owned()
+        },
+        Difference::Extra("greet input greeting: string".to_owned()),
+        Difference::Missing("greet output output: string".to_owned()),
+        Difference::Missing("operation add".to_owned()),
+        Difference::Changed {
+          expected: "struct User field age: u32".to_owned(),
+          actual: "struct User field age: i32".to_owned()
+        },
+        Difference::Missing("enum Color { Red = 0, Blue = 1 }".to_owned()),
+      ]
+    );
+    assert_eq!(
+      err.to_string(),
+      [
+        "- greet input name: string",
+        "+ greet input name: u32",
+        "+ greet input greeting: string",
+        "- greet output output: string",
+        "- operation add",
+        "- struct User field age: u32",
+        "+ struct User field age: i32",
+        "- enum Color { Red = 0, Blue = 1 }",
+      ]
+      .join("\n")
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_type_definition_kind_mismatch() -> Result<()> {
+    let expected = interface();
+    let mut actual = interface();
+    actual.types[1] = TypeDefinition::Union(UnionDefinition::new("Color", vec![Type::String, Type::U8], None));
+
+    let err = check_conformance(&expected, &actual).unwrap_err();
+
+    assert_eq!(
+      err.differences(),
+      &[Difference::Changed {
+        expected: "enum Color { Red = 0, Blue = 1 }".to_owned(),
+        actual: "union Color = string | u8".to_owned()
+      }]
+    );
+    Ok(())
+  }
+}
@@ -129,6 +129,9 @@ mod traits;
 
 mod signatures;
 
+mod conformance;
+
+pub use conformance::{check_conformance, ConformanceError, Difference};
 pub use field::Field;
 #[cfg(feature = "value")]
 pub use field::FieldValue;
@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{alpha1, alphanumeric1, char, multispace0};
-use nom::combinator::{opt, recognize};
+use nom::combinator::recognize;
 use nom::error::ParseError;
 use nom::multi::{many0, many0_count, separated_list1};
 use nom::sequence::{delimited, pair, terminated, tuple};
@@ -80,14 +80,20 @@ fn identifier(input: &str) -> IResult<&str, &str> {
   ))(input)
 }
 
-fn list_type(input: &str) -> IResult<&str, Type> {
-  let (i, (t, _)) = pair(typename, ws(square_brackets))(input)?;
-
-  Ok((i, Type::List { ty: Box::new(t) }))
-}
-
 fn valid_type(input: &str) -> IResult<&str, Type> {
-  alt((map_type, struct_type, list_type, typename))(input)
+  let (mut i, mut t) = alt((map_type, struct_type, typename))(input)?;
+  // Any type can be followed by `[]` to make it a list or `?` to make it optional.
+  loop {
+    if let Ok((rest, _)) = ws(square_brackets)(i) {
+      t = Type::List { ty: Box::new(t) };
+      i = rest;
+    } else if let Ok((rest, _)) = tag::<_, _, nom::error::Error<&str>>("?")(i) {
+      t = Type::Optional { ty: Box::new(t) };
+      i = rest;
+    } else {
+      return Ok((i, t));
+    }
+  }
 }
 
 fn typename(input: &str) -> IResult<&str, Type> {
@@ -120,12 +126,7 @@ fn typename(input: &str) -> IResult<&str, Type> {
 }
 
 fn _parse(input: &str) -> IResult<&str, Type> {
-  let (i, (t, optional)) = pair(alt((list_type, map_type, struct_type, typename)), opt(tag("?")))(input)?;
-  if optional.is_some() {
-    Ok((i, Type::Optional { ty: Box::new(t) }))
-  } else {
-    Ok((i, t))
-  }
+  valid_type(input)
 }
 
 #[macro_export]
@@ -241,7 +242,7 @@ mod test {
   #[case("bool [ ]", Type::Bool)]
   #[case("string[]", Type::String)]
   fn test_list_variants(#[case] input: &'static str, #[case] expected: Type) -> Result<()> {
-    let (_i, t) = list_type(input)?;
+    let (_i, t) = valid_type(input)?;
     assert_eq!(t, Type::List { ty: Box::new(expected) });
     Ok(())
   }
@@ -330,6 +331,9 @@ mod test {
   #[case("object", Type::Object)]
   #[case("myType", Type::Named("myType".to_owned()))]
   #[case("name::myType", Type::Named("name::myType".to_owned()))]
+  #[case("{ id: u32, name: string? }", Type::AnonymousStruct(vec![Field::new("id", Type::U32), Field::new("name", Type::Optional{ty:Box::new(Type::String)})]))]
+  #[case("{id: u32}[]?", Type::Optional{ty:Box::new(Type::List{ty:Box::new(Type::AnonymousStruct(vec![Field::new("id", Type::U32)]))})})]
+  #[case("{inner: {id: u32}[]}", Type::AnonymousStruct(vec![Field::new("inner", Type::List{ty:Box::new(Type::AnonymousStruct(vec![Field::new("id", Type::U32)]))})]))]
   fn test_parse(#[case] as_str: &'static str, #[case] ty: Type) -> Result<()> {
     assert_eq!(parse(as_str)?, ty);
     Ok(())
@@ -160,7 +160,7 @@ impl Type {
       Type::Map { .. } => TypeId::of::<std::collections::HashMap<Box<dyn std::any::Any>, Box<dyn std::any::Any>>>(),
       Type::Link { .. } => TypeId::of::<serde_json::Value>(),
       Type::Object => TypeId::of::<serde_json::Value>(),
-      Type::AnonymousStruct(_) => unimplemented!(),
+      Type::AnonymousStruct(_) => TypeId::of::<serde_json::Value>(),
     }
   }
 
@@ -226,9 +226,18 @@ impl std::fmt::Display for Type {
       Type::List { ty } => write!(f, "{}[]", ty),
       Type::Optional { ty } => write!(f, "{}?", ty),
       Type::Map { key, value } => write!(f, "{{{}:{}}}", key, value),
-      Type::Link { .. } => todo!(),
+      Type::Link { .. } => f.write_str("link"),
       Type::Object => f.write_str("object"),
-      Type::AnonymousStruct(_) => todo!(),
+      Type::AnonymousStruct(fields) => {
+        f.write_str("{")?;
+        for (i, field) in fields.iter().enumerate() {
+          if i > 0 {
+            f.write_str(", ")?;
+          }
+          write!(f, "{}: {}", field.name, field.ty)?;
+        }
+        f.write_str("}")
+      }
     }
   }
 }
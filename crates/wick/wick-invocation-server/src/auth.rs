@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use simple_asn1::{oid, ASN1Block};
+use tonic::{Request, Status};
+
+/// The metadata key bearer tokens are passed in.
+const AUTHORIZATION: &str = "authorization";
+
+/// Authentication and authorization for requests to an [crate::InvocationServer].
+///
+/// Callers authenticate with a bearer token in the `authorization` metadata or, when mTLS identities are enabled, with
+/// the common name of their client certificate. An authenticated identity may only invoke the operations its policy
+/// allows.
+#[derive(Clone, Default)]
+#[must_use]
+pub struct RpcAuth {
+  tokens: Vec<(String, String)>,
+  mtls: bool,
+  policy: HashMap<String, Vec<String>>,
+}
+
+impl std::fmt::Debug for RpcAuth {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RpcAuth")
+      .field("tokens", &self.tokens.len())
+      .field("mtls", &self.mtls)
+      .field("policy", &self.policy)
+      .finish()
+  }
+}
+
+impl RpcAuth {
+  /// Accept the passed bearer token as the passed identity.
+  pub fn token<T: Into<String>, I: Into<String>>(mut self, token: T, identity: I) -> Self {
+    self.tokens.push((token.into(), identity.into()));
+    self
+  }
+
+  /// Accept the common name of a verified client certificate as the caller's identity.
+  pub const fn mtls(mut self, enabled: bool) -> Self {
+    self.mtls = enabled;
+    self
+  }
+
+  /// Allow the passed identity to invoke operations that match any of the passed patterns.
+  ///
+  /// Patterns match operation names and may contain `*` wildcards, e.g. `greet`, `db::*` or `*`.
+  pub fn allow<I: Into<String>, T: IntoIterator<Item = P>, P: Into<String>>(mut self, identity: I, patterns: T) -> Self {
+    self
+      .policy
+      .entry(identity.into())
+      .or_default()
+      .extend(patterns.into_iter().map(Into::into));
+    self
+  }
+
+  /// Whether client certificate identities are accepted.
+  #[must_use]
+  pub const fn mtls_enabled(&self) -> bool {
+    self.mtls
+  }
+
+  /// Find the identity of the caller that made the passed request.
+  pub(crate) fn authenticate<T>(&self, request: &Request<T>) -> Result<String, Status> {
+    if let Some(value) = request.metadata().get(AUTHORIZATION) {
+      let token = value
+        .to_str()
+        .ok()
+        .and_then(|v| v.strip_prefix("Bearer ").or_else(|| v.strip_prefix("bearer ")))
+        .ok_or_else(|| Status::unauthenticated("malformed authorization metadata"))?;
+      return self
+        .tokens
+        .iter()
+        .find(|(t, _)| constant_time_eq(t.as_bytes(), token.trim().as_bytes()))
+        .map(|(_, identity)| identity.clone())
+        .ok_or_else(|| Status::unauthenticated("invalid token"));
+    }
+
+    if self.mtls {
+      if let Some(identity) = request
+        .peer_certs()
+        .and_then(|certs| certs.first().and_then(|cert| common_name(cert.get_ref())))
+      {
+        return Ok(identity);
+      }
+    }
+
+    Err(Status::unauthenticated("missing credentials"))
+  }
+
+  /// Check if the passed identity may invoke the passed operation.
+  pub(crate) fn authorize(&self, identity: &str, operation: &str) -> bool {
+    self
+      .policy
+      .get(identity)
+      .map_or(false, |patterns| patterns.iter().any(|p| glob_match(p, operation)))
+  }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+/// Match a name against a pattern where `*` matches any run of characters.
+fn glob_match(pattern: &str, name: &str) -> bool {
+  let mut parts = pattern.split('*');
+  let first = parts.next().unwrap_or_default();
+  let Some(mut rest) = name.strip_prefix(first) else {
+    return false;
+  };
+  let parts: Vec<_> = parts.collect();
+  let Some((last, middle)) = parts.split_last() else {
+    return rest.is_empty();
+  };
+  for part in middle {
+    match rest.find(part) {
+      Some(i) => rest = &rest[i + part.len()..],
+      None => return false,
+    }
+  }
+  rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Read the subject common name (CN) from a DER encoded X.509 certificate.
+fn common_name(der: &[u8]) -> Option<String> {
+  let blocks = simple_asn1::from_der(der).ok()?;
+  let Some(ASN1Block::Sequence(_, cert)) = blocks.first() else {
+    return None;
+  };
+  let Some(ASN1Block::Sequence(_, tbs)) = cert.first() else {
+    return None;
+  };
+  // The subject follows the signature algorithm, issuer and validity sequences.
+  let Some(ASN1Block::Sequence(_, subject)) = tbs.iter().filter(|b| matches!(b, ASN1Block::Sequence(..))).nth(3) else {
+    return None;
+  };
+  let cn = oid!(2, 5, 4, 3);
+  subject.iter().find_map(|rdn| {
+    let ASN1Block::Set(_, attrs) = rdn else { return None };
+    attrs.iter().find_map(|attr| match attr {
+      ASN1Block::Sequence(_, pair) => match pair.as_slice() {
+        [ASN1Block::ObjectIdentifier(_, id), ASN1Block::UTF8String(_, v) | ASN1Block::PrintableString(_, v)]
+          if *id == cn =>
+        {
+          Some(v.clone())
+        }
+        _ => None,
+      },
+      _ => None,
+    })
+  })
+}
+
+#[cfg(test)]
+mod test {
+  use tonic::metadata::MetadataValue;
+
+  use super::*;
+
+  fn request(token: &str) -> Request<()> {
+    let mut request = Request::new(());
+    request
+      .metadata_mut()
+      .insert(AUTHORIZATION, MetadataValue::try_from(token).unwrap());
+    request
+  }
+
+  #[test]
+  fn test_authenticate() {
+    let auth = RpcAuth::default().token("s3cret", "ci");
+    assert_eq!(auth.authenticate(&request("Bearer s3cret")).unwrap(), "ci");
+    let err = auth.authenticate(&request("Bearer wrong")).unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+    let err = auth.authenticate(&request("s3cret")).unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+    let err = auth.authenticate(&Request::new(())).unwrap_err();
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+  }
+
+  #[test]
+  fn test_authorize() {
+    let auth = RpcAuth::default()
+      .allow("ci", ["greet", "db::*"])
+      .allow("admin", ["*"]);
+    assert!(auth.authorize("ci", "greet"));
+    assert!(auth.authorize("ci", "db::query"));
+    assert!(!auth.authorize("ci", "greeter"));
+    assert!(!auth.authorize("ci", "admin::reset"));
+    assert!(auth.authorize("admin", "admin::reset"));
+    assert!(!auth.authorize("nobody", "greet"));
+  }
+
+  #[test]
+  fn test_glob_match() {
+    assert!(glob_match("*", ""));
+    assert!(glob_match("a*c", "abc"));
+    assert!(glob_match("a*b*c", "aXbYc"));
+    assert!(!glob_match("a*b*c", "aXc"));
+    assert!(!glob_match("ab*ba", "aba"));
+  }
+
+  #[test]
+  fn test_common_name() {
+    let der = include_bytes!("../fixtures/client.der");
+    assert_eq!(common_name(der), Some("ci-deployer".to_owned()));
+    assert_eq!(common_name(b"not a certificate"), None);
+  }
+}
@@ -9,15 +9,21 @@ use tokio_stream::StreamExt;
 use tonic::{Response, Status};
 use wick_packet::PacketStream;
 use wick_rpc::rpc::invocation_service_server::InvocationService;
-use wick_rpc::rpc::{InvocationRequest, ListResponse, Packet, StatsResponse};
+use wick_logger::{FilterOptions, LogDestination};
+use wick_rpc::rpc::log_filter_request::Destination;
+use wick_rpc::rpc::{InvocationRequest, ListResponse, LogFilterResponse, Packet, StatsResponse};
 use wick_rpc::{rpc, DurationStatistics, Statistics};
 
+use crate::RpcAuth;
+
 /// A GRPC server for implementers of [flow_component::Component].
 pub struct InvocationServer {
   /// The component that will handle incoming requests.
   pub collection: SharedComponent,
 
   stats: RwLock<HashMap<String, Statistics>>,
+
+  auth: Option<RpcAuth>,
 }
 
 impl std::fmt::Debug for InvocationServer {
@@ -33,6 +39,58 @@ impl InvocationServer {
     Self {
       collection,
       stats: RwLock::new(HashMap::new()),
+      auth: None,
+    }
+  }
+
+  /// Require callers to authenticate and only allow the operations their policy permits.
+  #[must_use]
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_auth(mut self, auth: RpcAuth) -> Self {
+    self.auth = Some(auth);
+    self
+  }
+
+  /// Authenticate the caller of a request, returning their identity if authentication is enabled.
+  fn authenticate<T>(&self, request: &tonic::Request<T>, method: &str) -> Result<Option<String>, Status> {
+    let Some(auth) = &self.auth else {
+      return Ok(None);
+    };
+    match auth.authenticate(request) {
+      Ok(identity) => Ok(Some(identity)),
+      Err(status) => {
+        warn!(
+          method,
+          peer = %peer(request),
+          reason = status.message(),
+          "rpc request denied"
+        );
+        Err(status)
+      }
+    }
+  }
+
+  /// Check the authenticated identity may invoke the passed operation.
+  fn authorize(&self, peer: &str, identity: Option<&str>, operation: &str) -> Result<(), Status> {
+    let (Some(auth), Some(identity)) = (&self.auth, identity) else {
+      return Ok(());
+    };
+    if auth.authorize(identity, operation) {
+      debug!(identity, operation, "rpc invocation allowed");
+      Ok(())
+    } else {
+      warn!(
+        method = "invoke",
+        peer,
+        identity,
+        operation,
+        reason = "not allowed by policy",
+        "rpc request denied"
+      );
+      Err(Status::permission_denied(format!(
+        "'{}' may not invoke operation '{}'",
+        identity, operation
+      )))
     }
   }
 }
@@ -72,6 +130,10 @@ impl InvocationServer {
   }
 }
 
+fn peer<T>(request: &tonic::Request<T>) -> String {
+  request.remote_addr().map_or_else(String::new, |addr| addr.to_string())
+}
+
 fn convert_invocation_stream(mut streaming: tonic::Streaming<InvocationRequest>) -> PacketStream {
   let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
   tokio::spawn(async move {
@@ -102,6 +164,8 @@ impl InvocationService for InvocationServer {
     request: tonic::Request<tonic::Streaming<InvocationRequest>>,
   ) -> Result<Response<Self::InvokeStream>, Status> {
     let start = Instant::now();
+    let identity = self.authenticate(&request, "invoke")?;
+    let peer = peer(&request);
 
     let (tx, rx) = mpsc::channel(4);
     let mut stream = request.into_inner();
@@ -117,6 +181,7 @@ impl InvocationService for InvocationServer {
     } else {
       return Err(Status::invalid_argument("First message must be an invocation"));
     };
+    self.authorize(&peer, identity.as_deref(), invocation.target.operation_id())?;
     let stream = convert_invocation_stream(stream);
     let packet_stream = PacketStream::new(Box::new(stream));
     let invocation = invocation.with_stream(packet_stream);
@@ -150,18 +215,47 @@ impl InvocationService for InvocationServer {
     Ok(Response::new(ReceiverStream::new(rx)))
   }
 
-  async fn list(&self, _request: tonic::Request<rpc::ListRequest>) -> Result<Response<ListResponse>, Status> {
+  async fn list(&self, request: tonic::Request<rpc::ListRequest>) -> Result<Response<ListResponse>, Status> {
+    self.authenticate(&request, "list")?;
     let response = ListResponse {
       components: vec![self.collection.signature().clone().try_into().unwrap()],
     };
     Ok(Response::new(response))
   }
 
-  async fn stats(&self, _request: tonic::Request<rpc::StatsRequest>) -> Result<Response<StatsResponse>, Status> {
+  async fn stats(&self, request: tonic::Request<rpc::StatsRequest>) -> Result<Response<StatsResponse>, Status> {
+    self.authenticate(&request, "stats")?;
     Ok(Response::new(StatsResponse {
       stats: self.stats.read().values().cloned().map(From::from).collect(),
     }))
   }
+
+  async fn log_filter(
+    &self,
+    request: tonic::Request<rpc::LogFilterRequest>,
+  ) -> Result<Response<LogFilterResponse>, Status> {
+    self.authenticate(&request, "log_filter")?;
+    let request = request.into_inner();
+    let destination = match request.destination() {
+      Destination::Stderr => LogDestination::Stderr,
+      Destination::Telemetry => LogDestination::Telemetry,
+      Destination::Logfile => LogDestination::Logfile,
+    };
+    let handle = wick_logger::filter_handle().ok_or_else(|| Status::unavailable("logging has not been initialized"))?;
+
+    if let Some(filter) = request.filter {
+      // Rules without a bare level keep the current default level.
+      let current = handle.get(destination);
+      let filter = FilterOptions::parse(&filter, current.level, &wick_logger::DEFAULT_FILTER)
+        .map_err(|e| Status::invalid_argument(e.to_string()))?;
+      info!(%destination, %filter, "log filter changed");
+      handle.set(destination, filter);
+    }
+
+    Ok(Response::new(LogFilterResponse {
+      filter: handle.get(destination).to_string(),
+    }))
+  }
 }
 
 #[cfg(test)]
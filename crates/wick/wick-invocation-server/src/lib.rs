@@ -117,8 +117,10 @@
 /// Error module.
 pub mod error;
 
+mod auth;
 mod invocation_server;
 
+pub use auth::RpcAuth;
 use flow_component::SharedComponent;
 pub use invocation_server::InvocationServer;
 use tokio::task::JoinHandle;
@@ -0,0 +1,189 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use asset_container::AssetManager;
+use flow_expression_parser::ast::{ConnectionTargetExpression, FlowExpression, InstanceTarget};
+use wick_config::diagnostic::SourceLocation;
+use wick_config::error::ManifestError;
+use wick_config::WickConfiguration;
+
+use crate::model::{Flow, Model};
+use crate::protocol::{Diagnostic, Position, Range, SEVERITY_ERROR};
+use crate::text::{character, flow_expression, flow_operation, lines, value_of};
+
+/// Load a manifest and check it. The model is `None` when the manifest can't be loaded at all.
+pub(crate) async fn analyze(text: &str, path: Option<&Path>) -> (Option<Model>, Vec<Diagnostic>) {
+  match WickConfiguration::from_yaml(text, &path.map(Path::to_path_buf)) {
+    Ok(config) => {
+      let config = config.into_inner();
+      if let Some(dir) = path.and_then(Path::parent) {
+        config.set_baseurl(dir);
+      }
+      let model = Model::load(&config).await;
+      let diagnostics = validate(text, &model);
+      (Some(model), diagnostics)
+    }
+    Err(e) => (None, error_diagnostics(text, &e)),
+  }
+}
+
+fn error_diagnostics(text: &str, error: &ManifestError) -> Vec<Diagnostic> {
+  match error {
+    ManifestError::Diagnostics(diagnostics) => diagnostics
+      .iter()
+      .map(|d| {
+        let message = d
+          .hint
+          .as_ref()
+          .map_or_else(|| d.message.clone(), |hint| format!("{}: {}", d.message, hint));
+        Diagnostic::new(
+          d.location.as_ref().map(location_range).unwrap_or_default(),
+          SEVERITY_ERROR,
+          message,
+        )
+      })
+      .collect(),
+    ManifestError::YamlError(_, message, location) => {
+      let range = location
+        .as_ref()
+        .and_then(|l| SourceLocation::from_yaml(text, l))
+        .as_ref()
+        .map(location_range)
+        .unwrap_or_default();
+      vec![Diagnostic::new(range, SEVERITY_ERROR, message.clone())]
+    }
+    error => vec![Diagnostic::new(Range::default(), SEVERITY_ERROR, error.to_string())],
+  }
+}
+
+fn location_range(location: &SourceLocation) -> Range {
+  let text = &location.text;
+  let start = text
+    .char_indices()
+    .nth(location.column - 1)
+    .map_or(text.len(), |(i, _)| i);
+  let end = text[start..]
+    .char_indices()
+    .nth(location.length)
+    .map_or(text.len(), |(i, _)| start + i);
+  span(location.line - 1, text, start, end)
+}
+
+fn span(line: usize, text: &str, start: usize, end: usize) -> Range {
+  let line = line as u32;
+  Range::new(
+    Position::new(line, character(text, start)),
+    Position::new(line, character(text, end)),
+  )
+}
+
+/// Check that the operations and ports a manifest references exist.
+fn validate(text: &str, model: &Model) -> Vec<Diagnostic> {
+  let lines = lines(text);
+  let mut diagnostics = Vec::new();
+  for (index, line) in lines.iter().enumerate() {
+    if let Some((start, path)) = value_of(line, "operation") {
+      if let Some(message) = check_operation(model, path) {
+        diagnostics.push(Diagnostic::new(
+          span(index, line, start, start + path.len()),
+          SEVERITY_ERROR,
+          message,
+        ));
+      }
+      continue;
+    }
+
+    let Some(flow) = flow_operation(&lines, index).and_then(|name| model.flow(&name)) else {
+      continue;
+    };
+    let Some((start, expression)) = flow_expression(line) else {
+      continue;
+    };
+    let Ok(parsed) = FlowExpression::from_str(expression) else {
+      continue;
+    };
+    let mut problems = Vec::new();
+    check_expression(model, flow, &parsed, &mut problems);
+    for (needle, message) in problems {
+      let (from, to) = expression
+        .find(&needle)
+        .map_or((0, expression.len()), |i| (i + usize::from(needle.starts_with('.')), i + needle.len()));
+      diagnostics.push(Diagnostic::new(
+        span(index, line, start + from, start + to),
+        SEVERITY_ERROR,
+        message,
+      ));
+    }
+  }
+  diagnostics
+}
+
+fn check_operation(model: &Model, path: &str) -> Option<String> {
+  let (namespace, name) = path.split_once("::")?;
+  (model.knows(namespace) && model.operation(path).is_none())
+    .then(|| format!("Component '{}' has no operation named '{}'", namespace, name))
+}
+
+/// Collect the text to highlight and the message for each problem in an expression.
+fn check_expression(model: &Model, flow: &Flow, expression: &FlowExpression, problems: &mut Vec<(String, String)>) {
+  match expression {
+    FlowExpression::ConnectionExpression(c) => {
+      problems.extend(check_target(model, flow, c.from(), false));
+      problems.extend(check_target(model, flow, c.to(), true));
+    }
+    FlowExpression::BlockExpression(b) => {
+      for expression in b.inner() {
+        check_expression(model, flow, expression, problems);
+      }
+    }
+  }
+}
+
+fn check_target(
+  model: &Model,
+  flow: &Flow,
+  target: &ConnectionTargetExpression,
+  downstream: bool,
+) -> Option<(String, String)> {
+  let instance = match target.instance() {
+    InstanceTarget::Input => "<input>".to_owned(),
+    InstanceTarget::Output => "<output>".to_owned(),
+    InstanceTarget::Named(id) => {
+      if flow.instance(id).is_none() {
+        return Some((
+          id.clone(),
+          format!(
+            "'{}' is not declared in the `uses` of operation '{}'",
+            id, flow.signature.name
+          ),
+        ));
+      }
+      id.clone()
+    }
+    InstanceTarget::Path { path, .. } => {
+      if let Some(message) = check_operation(model, path) {
+        return Some((path.clone(), message));
+      }
+      path.clone()
+    }
+    _ => return None,
+  };
+
+  let port = target.port().name()?;
+  let ports = model.ports(flow, &instance, downstream)?;
+  if ports.iter().any(|f| f.name == port) {
+    return None;
+  }
+  let direction = match (instance.as_str(), downstream) {
+    ("<input>", _) | (_, true) => "input",
+    ("<output>", _) | (_, false) => "output",
+  };
+  let expected = ports.iter().map(|f| f.name.as_str()).collect::<Vec<_>>().join(", ");
+  Some((
+    format!(".{}", port),
+    format!(
+      "'{}' has no {} port named '{}', expected one of: {}",
+      instance, direction, port, expected
+    ),
+  ))
+}
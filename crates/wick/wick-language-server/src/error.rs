@@ -0,0 +1,37 @@
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+/// Crate error.
+pub enum Error {
+  /// Reading from or writing to the client failed.
+  #[error(transparent)]
+  Io(#[from] std::io::Error),
+
+  /// A message could not be serialized or deserialized.
+  #[error(transparent)]
+  Json(#[from] serde_json::Error),
+
+  /// The client sent a message that does not follow the protocol.
+  #[error("Invalid message: {0}")]
+  Protocol(String),
+
+  /// The client sent a request the server does not implement.
+  #[error("Unsupported method '{0}'")]
+  MethodNotFound(String),
+
+  /// The client sent a request with parameters the server could not read.
+  #[error("Invalid parameters for '{0}': {1}")]
+  InvalidParams(String, String),
+}
+
+impl Error {
+  /// The JSON-RPC error code for this error.
+  #[must_use]
+  pub const fn code(&self) -> i64 {
+    match self {
+      Error::MethodNotFound(_) => -32601,
+      Error::InvalidParams(..) => -32602,
+      Error::Json(_) | Error::Protocol(_) => -32700,
+      Error::Io(_) => -32603,
+    }
+  }
+}
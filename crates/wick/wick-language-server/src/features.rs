@@ -0,0 +1,172 @@
+use std::path::Path;
+
+use wick_interface_types::Field;
+
+use crate::model::{signature_line, Flow, Model};
+use crate::protocol::{
+  CompletionItem,
+  Hover,
+  Location,
+  Position,
+  Range,
+  KIND_FIELD,
+  KIND_FUNCTION,
+  KIND_MODULE,
+  KIND_VARIABLE,
+};
+use crate::text::{byte_offset, flow_expression, flow_operation, lines, value_of, word_at};
+
+/// The text of the line at a position, the byte offset of the position in it, and the flow the line is part of.
+fn locate<'a>(model: &'a Model, lines: &[&'a str], position: Position) -> Option<(&'a str, usize, Option<&'a Flow>)> {
+  let index = position.line as usize;
+  let line = *lines.get(index)?;
+  let flow = flow_operation(lines, index).and_then(|name| model.flow(&name));
+  Some((line, byte_offset(line, position.character), flow))
+}
+
+/// Complete instances, operations, and ports in flow expressions, and operations in `operation:` values.
+pub(crate) fn completion(model: &Model, text: &str, position: Position) -> Vec<CompletionItem> {
+  let lines = lines(text);
+  let Some((line, cursor, flow)) = locate(model, &lines, position) else {
+    return Vec::new();
+  };
+
+  if let Some(flow) = flow {
+    let Some((start, _)) = flow_expression(line).filter(|(start, _)| *start <= cursor) else {
+      return Vec::new();
+    };
+    let before = &line[start..cursor];
+    let token_start = before.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    let downstream = before.contains("->");
+    let token = before[token_start..]
+      .rsplit_once("->")
+      .map_or(&before[token_start..], |(_, token)| token);
+    return complete_flow(model, flow, token, downstream);
+  }
+
+  let before = &line[..cursor];
+  before.find("operation:").map_or_else(Vec::new, |i| {
+    let token = before[i + "operation:".len()..].trim_start();
+    let token = token.trim_start_matches(['\'', '"']);
+    token.split_once("::").map_or_else(
+      || namespace_items(model),
+      |(namespace, _)| operation_items(model, namespace),
+    )
+  })
+}
+
+fn complete_flow(model: &Model, flow: &Flow, token: &str, downstream: bool) -> Vec<CompletionItem> {
+  if let Some((instance, _)) = token.rsplit_once('.') {
+    return model
+      .ports(flow, instance, downstream)
+      .map_or_else(Vec::new, port_items);
+  }
+  if let Some((namespace, _)) = token.split_once("::") {
+    return operation_items(model, namespace);
+  }
+
+  let mut items: Vec<_> = ["<>", "<input>", "<output>"]
+    .into_iter()
+    .map(|label| CompletionItem::new(label, KIND_VARIABLE, None))
+    .collect();
+  items.extend(
+    flow
+      .instances
+      .iter()
+      .map(|(id, path)| CompletionItem::new(id, KIND_VARIABLE, Some(path.clone()))),
+  );
+  items.extend(namespace_items(model));
+  items
+}
+
+fn namespace_items(model: &Model) -> Vec<CompletionItem> {
+  model
+    .namespaces()
+    .iter()
+    .map(|n| CompletionItem::new(&n.name, KIND_MODULE, Some("component".to_owned())))
+    .collect()
+}
+
+fn operation_items(model: &Model, namespace: &str) -> Vec<CompletionItem> {
+  model
+    .operations(namespace)
+    .into_iter()
+    .map(|op| {
+      let path = format!("{}::{}", namespace, op.name);
+      CompletionItem::new(&op.name, KIND_FUNCTION, Some(signature_line(&path, op)))
+    })
+    .collect()
+}
+
+fn port_items(ports: &[Field]) -> Vec<CompletionItem> {
+  ports
+    .iter()
+    .map(|f| CompletionItem::new(&f.name, KIND_FIELD, Some(f.ty.to_string())))
+    .collect()
+}
+
+/// Describe the operation, instance, port, or component under the cursor.
+pub(crate) fn hover(model: &Model, text: &str, position: Position) -> Option<Hover> {
+  let lines = lines(text);
+  let (line, cursor, flow) = locate(model, &lines, position)?;
+  let (start, end) = word_at(line, cursor)?;
+  let word = &line[start..end];
+  let code = |value: String| Some(Hover::markdown(format!("```\n{}\n```", value)));
+
+  if let Some(flow) = flow {
+    if start > 0 && line[..start].ends_with('.') {
+      let (instance_start, _) = word_at(line, start - 1)?;
+      let instance = &line[instance_start..start - 1];
+      let downstream = line[..instance_start].contains("->");
+      let port = model
+        .ports(flow, instance, downstream)?
+        .iter()
+        .find(|f| f.name == word)?;
+      return code(format!("{}.{}: {}", instance, port.name, port.ty));
+    }
+    if let Some(path) = flow.instance(word) {
+      return model
+        .operation(path)
+        .map_or_else(|| code(format!("{}: {}", word, path)), |op| code(signature_line(path, op)));
+    }
+  }
+
+  if word.contains("::") {
+    return model.operation(word).and_then(|op| code(signature_line(word, op)));
+  }
+
+  let namespace = model.namespace(word)?;
+  let operations = namespace
+    .operations
+    .iter()
+    .map(|op| signature_line(&format!("{}::{}", word, op.name), op))
+    .collect::<Vec<_>>();
+  code(operations.join("\n"))
+}
+
+/// Find the manifest that defines the component under the cursor, or that a `ref:` points to.
+pub(crate) fn definition(model: &Model, text: &str, position: Position, document: Option<&Path>) -> Option<Location> {
+  let lines = lines(text);
+  let (line, cursor, flow) = locate(model, &lines, position)?;
+
+  if let Some((_, reference)) = value_of(line, "ref") {
+    let path = document.and_then(Path::parent).map(|dir| dir.join(reference));
+    if let Some(path) = path.filter(|p| p.is_file()) {
+      return location(&path);
+    }
+  }
+
+  let (start, end) = word_at(line, cursor)?;
+  let word = &line[start..end];
+  let path = flow.and_then(|f| f.instance(word)).unwrap_or(word);
+  let namespace = path.split("::").next().unwrap_or(path);
+  location(model.namespace(namespace)?.definition.as_deref()?)
+}
+
+fn location(path: &Path) -> Option<Location> {
+  let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+  Some(Location {
+    uri: url::Url::from_file_path(path).ok()?.to_string(),
+    range: Range::default(),
+  })
+}
@@ -0,0 +1,185 @@
+use std::path::{Path, PathBuf};
+
+use wick_config::config::components::ComponentConfig;
+use wick_config::config::{ComponentDefinition, ComponentImplementation, ImportDefinition};
+use wick_config::{AssetReference, FetchOptions, WickConfiguration};
+use wick_interface_types::{Field, OperationSignature, OperationSignatures};
+
+/// The namespace a component uses to reference its own operations.
+const SELF: &str = "self";
+
+/// What a manifest can reference from its flows: the components it imports and the operations it defines.
+#[derive(Debug, Default)]
+pub(crate) struct Model {
+  namespaces: Vec<Namespace>,
+  flows: Vec<Flow>,
+}
+
+/// An imported or required component.
+#[derive(Debug)]
+pub(crate) struct Namespace {
+  pub(crate) name: String,
+  /// The manifest the component is defined in, if it is imported from one.
+  pub(crate) definition: Option<PathBuf>,
+  pub(crate) operations: Vec<OperationSignature>,
+}
+
+/// An operation defined by a composite component.
+#[derive(Debug)]
+pub(crate) struct Flow {
+  pub(crate) signature: OperationSignature,
+  /// The instances declared in the operation's `uses`, with the `namespace::operation` path each refers to.
+  pub(crate) instances: Vec<(String, String)>,
+}
+
+impl Flow {
+  /// Get the `namespace::operation` path of an instance.
+  pub(crate) fn instance(&self, id: &str) -> Option<&str> {
+    self.instances.iter().find(|(i, _)| i == id).map(|(_, path)| path.as_str())
+  }
+}
+
+impl Model {
+  /// Resolve the signatures of everything a configuration imports.
+  pub(crate) async fn load(config: &WickConfiguration) -> Self {
+    let mut model = Self::default();
+    let imports = match config {
+      WickConfiguration::Component(c) => c.import(),
+      WickConfiguration::App(c) => c.import(),
+      _ => &[],
+    };
+    for binding in imports {
+      if let Some(namespace) = load_import(binding.id(), binding.kind()).await {
+        model.namespaces.push(namespace);
+      }
+    }
+
+    if let WickConfiguration::Component(c) = config {
+      for binding in c.requires() {
+        model.namespaces.push(Namespace {
+          name: binding.id().to_owned(),
+          definition: None,
+          operations: binding.kind().signature().operations,
+        });
+      }
+      if let ComponentImplementation::Composite(composite) = c.component() {
+        model.flows = composite
+          .operations()
+          .iter()
+          .map(|flow| Flow {
+            instances: flow.instances().iter().map(|(id, r)| (id.clone(), r.id())).collect(),
+            signature: flow.clone().into(),
+          })
+          .collect();
+      }
+    }
+    model
+  }
+
+  pub(crate) fn namespaces(&self) -> &[Namespace] {
+    &self.namespaces
+  }
+
+  pub(crate) fn namespace(&self, name: &str) -> Option<&Namespace> {
+    self.namespaces.iter().find(|n| n.name == name)
+  }
+
+  pub(crate) fn flow(&self, name: &str) -> Option<&Flow> {
+    self.flows.iter().find(|f| f.signature.name == name)
+  }
+
+  /// Whether operations in a namespace can be resolved.
+  pub(crate) fn knows(&self, namespace: &str) -> bool {
+    namespace == SELF || self.namespace(namespace).is_some()
+  }
+
+  /// Get the operations in a namespace. A component's own operations are in the `self` namespace.
+  pub(crate) fn operations(&self, namespace: &str) -> Vec<&OperationSignature> {
+    if namespace == SELF {
+      return self.flows.iter().map(|f| &f.signature).collect();
+    }
+    self
+      .namespace(namespace)
+      .map(|n| n.operations.iter().collect())
+      .unwrap_or_default()
+  }
+
+  /// Get an operation by its `namespace::operation` path, ignoring any inline `[ID]`.
+  pub(crate) fn operation(&self, path: &str) -> Option<&OperationSignature> {
+    let (namespace, name) = path.split_once("::")?;
+    let name = name.split('[').next().unwrap_or(name);
+    self.operations(namespace).into_iter().find(|op| op.name == name)
+  }
+
+  /// Get the ports an instance in a flow exposes. Upstream instances send from their outputs and downstream
+  /// instances receive on their inputs, except for the flow's own `<input>` and `<output>`.
+  pub(crate) fn ports<'a>(&'a self, flow: &'a Flow, instance: &str, downstream: bool) -> Option<&'a [Field]> {
+    let signature = &flow.signature;
+    match instance {
+      "<input>" => Some(&signature.inputs),
+      "<output>" => Some(&signature.outputs),
+      "<>" if downstream => Some(&signature.outputs),
+      "<>" => Some(&signature.inputs),
+      _ => {
+        let path = if instance.contains("::") {
+          instance
+        } else {
+          flow.instance(instance)?
+        };
+        let op = self.operation(path)?;
+        Some(if downstream { &op.inputs } else { &op.outputs })
+      }
+    }
+  }
+}
+
+async fn load_import(name: &str, kind: &ImportDefinition) -> Option<Namespace> {
+  let (definition, operations) = match kind {
+    ImportDefinition::Component(ComponentDefinition::Manifest(manifest)) => load_manifest(manifest.reference()).await,
+    ImportDefinition::Component(definition) => (None, definition.operation_signatures()),
+    ImportDefinition::Types(_) => return None,
+  };
+  Some(Namespace {
+    name: name.to_owned(),
+    definition,
+    operations,
+  })
+}
+
+async fn load_manifest(reference: &AssetReference) -> (Option<PathBuf>, Vec<OperationSignature>) {
+  // Read local manifests directly rather than through the config cache so edits to them show up.
+  let config = match reference.path() {
+    Ok(path) if path.is_file() => read_manifest(&path).await,
+    _ => WickConfiguration::fetch(reference.clone(), FetchOptions::default())
+      .await
+      .ok()
+      .map(|c| c.into_inner()),
+  };
+  let Some(config) = config else {
+    debug!(reference = reference.location(), "could not load imported manifest");
+    return (None, Vec::new());
+  };
+  let operations = match &config {
+    WickConfiguration::Component(c) => c.signature().map(|s| s.operations).unwrap_or_default(),
+    _ => Vec::new(),
+  };
+  (config.source().map(Path::to_path_buf), operations)
+}
+
+async fn read_manifest(path: &Path) -> Option<WickConfiguration> {
+  let text = tokio::fs::read_to_string(path).await.ok()?;
+  let config = WickConfiguration::from_yaml(&text, &Some(path.to_path_buf())).ok()?;
+  Some(config.into_inner())
+}
+
+/// Render an operation as `path(input: type) -> (output: type)`.
+pub(crate) fn signature_line(path: &str, op: &OperationSignature) -> String {
+  let fields = |fields: &[Field]| {
+    fields
+      .iter()
+      .map(|f| format!("{}: {}", f.name, f.ty))
+      .collect::<Vec<_>>()
+      .join(", ")
+  };
+  format!("{}({}) -> ({})", path, fields(&op.inputs), fields(&op.outputs))
+}
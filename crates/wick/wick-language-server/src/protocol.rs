@@ -0,0 +1,191 @@
+//! The subset of the Language Server Protocol the server uses.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use crate::Error;
+
+pub(crate) const SEVERITY_ERROR: u8 = 1;
+
+pub(crate) const KIND_FUNCTION: u8 = 3;
+pub(crate) const KIND_FIELD: u8 = 5;
+pub(crate) const KIND_VARIABLE: u8 = 6;
+pub(crate) const KIND_MODULE: u8 = 9;
+
+/// Read one message, returning `None` when the client closed the stream.
+pub(crate) async fn read_message<R: AsyncBufRead + Unpin + Send>(reader: &mut R) -> Result<Option<Value>, Error> {
+  let mut length = None;
+  loop {
+    let mut header = String::new();
+    if reader.read_line(&mut header).await? == 0 {
+      return Ok(None);
+    }
+    let header = header.trim_end();
+    if header.is_empty() {
+      if length.is_some() {
+        break;
+      }
+      continue;
+    }
+    if let Some((name, value)) = header.split_once(':') {
+      if name.eq_ignore_ascii_case("content-length") {
+        let value = value.trim();
+        length = Some(
+          value
+            .parse::<usize>()
+            .map_err(|_| Error::Protocol(format!("invalid Content-Length '{}'", value)))?,
+        );
+      }
+    }
+  }
+
+  let mut body = vec![0; length.unwrap_or_default()];
+  reader.read_exact(&mut body).await?;
+  Ok(Some(serde_json::from_slice(&body)?))
+}
+
+/// Write one message with its `Content-Length` header.
+pub(crate) async fn write_message<W: AsyncWrite + Unpin + Send>(writer: &mut W, message: &Value) -> Result<(), Error> {
+  let body = serde_json::to_vec(message)?;
+  writer
+    .write_all(format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes())
+    .await?;
+  writer.write_all(&body).await?;
+  writer.flush().await?;
+  Ok(())
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Position {
+  pub(crate) line: u32,
+  pub(crate) character: u32,
+}
+
+impl Position {
+  pub(crate) const fn new(line: u32, character: u32) -> Self {
+    Self { line, character }
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Range {
+  pub(crate) start: Position,
+  pub(crate) end: Position,
+}
+
+impl Range {
+  pub(crate) const fn new(start: Position, end: Position) -> Self {
+    Self { start, end }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Location {
+  pub(crate) uri: String,
+  pub(crate) range: Range,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Diagnostic {
+  pub(crate) range: Range,
+  pub(crate) severity: u8,
+  pub(crate) source: &'static str,
+  pub(crate) message: String,
+}
+
+impl Diagnostic {
+  pub(crate) fn new<T: Into<String>>(range: Range, severity: u8, message: T) -> Self {
+    Self {
+      range,
+      severity,
+      source: "wick",
+      message: message.into(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct CompletionItem {
+  pub(crate) label: String,
+  pub(crate) kind: u8,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub(crate) detail: Option<String>,
+}
+
+impl CompletionItem {
+  pub(crate) fn new<T: Into<String>>(label: T, kind: u8, detail: Option<String>) -> Self {
+    Self {
+      label: label.into(),
+      kind,
+      detail,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct Hover {
+  pub(crate) contents: MarkupContent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub(crate) struct MarkupContent {
+  pub(crate) kind: &'static str,
+  pub(crate) value: String,
+}
+
+impl Hover {
+  pub(crate) const fn markdown(value: String) -> Self {
+    Self {
+      contents: MarkupContent {
+        kind: "markdown",
+        value,
+      },
+    }
+  }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TextDocumentIdentifier {
+  pub(crate) uri: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TextDocumentItem {
+  pub(crate) uri: String,
+  pub(crate) text: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TextDocumentPositionParams {
+  pub(crate) text_document: TextDocumentIdentifier,
+  pub(crate) position: Position,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DidOpenTextDocumentParams {
+  pub(crate) text_document: TextDocumentItem,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DidChangeTextDocumentParams {
+  pub(crate) text_document: TextDocumentIdentifier,
+  pub(crate) content_changes: Vec<TextDocumentContentChangeEvent>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TextDocumentContentChangeEvent {
+  pub(crate) text: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DidCloseTextDocumentParams {
+  pub(crate) text_document: TextDocumentIdentifier,
+}
@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tokio::io::{AsyncRead, AsyncWrite, BufReader};
+
+use crate::analysis::analyze;
+use crate::features::{completion, definition, hover};
+use crate::model::Model;
+use crate::protocol::{
+  read_message,
+  write_message,
+  DidChangeTextDocumentParams,
+  DidCloseTextDocumentParams,
+  DidOpenTextDocumentParams,
+  TextDocumentPositionParams,
+};
+use crate::Error;
+
+/// Full document sync: clients send the whole text on every change.
+const SYNC_FULL: u8 = 1;
+
+/// An open manifest and what was resolved from it the last time it loaded.
+#[derive(Debug)]
+struct Document {
+  text: String,
+  path: Option<PathBuf>,
+  model: Model,
+}
+
+#[derive(Debug)]
+struct Server<W> {
+  output: W,
+  documents: HashMap<String, Document>,
+}
+
+/// Serve the language server protocol on stdin and stdout until the client exits.
+pub async fn serve_stdio() -> Result<(), Error> {
+  serve(tokio::io::stdin(), tokio::io::stdout()).await
+}
+
+/// Serve the language server protocol on a pair of streams until the client exits or closes `input`.
+pub async fn serve<R, W>(input: R, output: W) -> Result<(), Error>
+where
+  R: AsyncRead + Unpin + Send,
+  W: AsyncWrite + Unpin + Send,
+{
+  let mut input = BufReader::new(input);
+  let mut server = Server {
+    output,
+    documents: HashMap::new(),
+  };
+
+  while let Some(message) = read_message(&mut input).await? {
+    let Some(method) = message.get("method").and_then(Value::as_str) else {
+      // The server never sends requests, so there are no responses to handle.
+      continue;
+    };
+    let params = message.get("params").cloned().unwrap_or(Value::Null);
+    match message.get("id") {
+      Some(id) => {
+        trace!(method, "lsp request");
+        let response = match server.request(method, params) {
+          Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+          Err(e) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": e.code(), "message": e.to_string() },
+          }),
+        };
+        write_message(&mut server.output, &response).await?;
+      }
+      None if method == "exit" => break,
+      None => {
+        trace!(method, "lsp notification");
+        server.notification(method, params).await?;
+      }
+    }
+  }
+  Ok(())
+}
+
+fn parse<T: DeserializeOwned>(method: &str, params: Value) -> Result<T, Error> {
+  serde_json::from_value(params).map_err(|e| Error::InvalidParams(method.to_owned(), e.to_string()))
+}
+
+fn document_path(uri: &str) -> Option<PathBuf> {
+  url::Url::parse(uri).ok()?.to_file_path().ok()
+}
+
+impl<W> Server<W>
+where
+  W: AsyncWrite + Unpin + Send,
+{
+  fn request(&self, method: &str, params: Value) -> Result<Value, Error> {
+    let result = match method {
+      "initialize" => json!({
+        "capabilities": {
+          "textDocumentSync": SYNC_FULL,
+          "completionProvider": { "triggerCharacters": [".", ":", "<"] },
+          "hoverProvider": true,
+          "definitionProvider": true,
+        },
+        "serverInfo": { "name": env!("CARGO_PKG_NAME"), "version": env!("CARGO_PKG_VERSION") },
+      }),
+      "shutdown" => Value::Null,
+      "textDocument/completion" => {
+        let params: TextDocumentPositionParams = parse(method, params)?;
+        self
+          .documents
+          .get(&params.text_document.uri)
+          .map_or(Ok(Value::Null), |doc| {
+            serde_json::to_value(completion(&doc.model, &doc.text, params.position))
+          })?
+      }
+      "textDocument/hover" => {
+        let params: TextDocumentPositionParams = parse(method, params)?;
+        let hover = self
+          .documents
+          .get(&params.text_document.uri)
+          .and_then(|doc| hover(&doc.model, &doc.text, params.position));
+        serde_json::to_value(hover)?
+      }
+      "textDocument/definition" => {
+        let params: TextDocumentPositionParams = parse(method, params)?;
+        let location = self
+          .documents
+          .get(&params.text_document.uri)
+          .and_then(|doc| definition(&doc.model, &doc.text, params.position, doc.path.as_deref()));
+        serde_json::to_value(location)?
+      }
+      _ => return Err(Error::MethodNotFound(method.to_owned())),
+    };
+    Ok(result)
+  }
+
+  async fn notification(&mut self, method: &str, params: Value) -> Result<(), Error> {
+    match method {
+      "textDocument/didOpen" => {
+        let params: DidOpenTextDocumentParams = parse(method, params)?;
+        self.update(params.text_document.uri, params.text_document.text).await
+      }
+      "textDocument/didChange" => {
+        let params: DidChangeTextDocumentParams = parse(method, params)?;
+        match params.content_changes.into_iter().last() {
+          Some(change) => self.update(params.text_document.uri, change.text).await,
+          None => Ok(()),
+        }
+      }
+      "textDocument/didSave" => {
+        let params: DidCloseTextDocumentParams = parse(method, params)?;
+        match self.documents.get(&params.text_document.uri) {
+          Some(doc) => {
+            let text = doc.text.clone();
+            self.update(params.text_document.uri, text).await
+          }
+          None => Ok(()),
+        }
+      }
+      "textDocument/didClose" => {
+        let params: DidCloseTextDocumentParams = parse(method, params)?;
+        self.documents.remove(&params.text_document.uri);
+        self.publish(&params.text_document.uri, json!([])).await
+      }
+      _ => Ok(()),
+    }
+  }
+
+  /// Store a document's new text, reload it, and publish its diagnostics.
+  ///
+  /// A document that fails to load keeps the model from its last successful load so completion keeps working
+  /// while it is being edited.
+  async fn update(&mut self, uri: String, text: String) -> Result<(), Error> {
+    let path = document_path(&uri);
+    let (model, diagnostics) = analyze(&text, path.as_deref()).await;
+    let model = match (model, self.documents.remove(&uri)) {
+      (Some(model), _) => model,
+      (None, Some(previous)) => previous.model,
+      (None, None) => Model::default(),
+    };
+    self.documents.insert(uri.clone(), Document { text, path, model });
+    self.publish(&uri, serde_json::to_value(diagnostics)?).await
+  }
+
+  async fn publish(&mut self, uri: &str, diagnostics: Value) -> Result<(), Error> {
+    let notification = json!({
+      "jsonrpc": "2.0",
+      "method": "textDocument/publishDiagnostics",
+      "params": { "uri": uri, "diagnostics": diagnostics },
+    });
+    write_message(&mut self.output, &notification).await
+  }
+}
@@ -0,0 +1,181 @@
+//! Helpers for reading manifests line by line, with positions counted the way LSP clients count them.
+
+/// Split text into lines. A trailing newline yields a final empty line so positions after it still exist.
+pub(crate) fn lines(text: &str) -> Vec<&str> {
+  text.split('\n').map(|l| l.strip_suffix('\r').unwrap_or(l)).collect()
+}
+
+/// Convert an LSP character offset, counted in UTF-16 code units, into a byte offset in `line`.
+pub(crate) fn byte_offset(line: &str, character: u32) -> usize {
+  let mut units = 0;
+  for (index, c) in line.char_indices() {
+    if units >= character as usize {
+      return index;
+    }
+    units += c.len_utf16();
+  }
+  line.len()
+}
+
+/// Convert a byte offset in `line` into an LSP character offset.
+pub(crate) fn character(line: &str, byte: usize) -> u32 {
+  line.get(..byte).map_or(0, |s| s.encode_utf16().count()) as u32
+}
+
+/// Get the byte span of the word at `byte`. Words are names, `ns::op` paths, and instances like `<input>`.
+pub(crate) fn word_at(line: &str, byte: usize) -> Option<(usize, usize)> {
+  let is_word = |c: char| c.is_alphanumeric() || matches!(c, '_' | ':' | '<' | '>');
+  let mut start = line[..byte]
+    .char_indices()
+    .rev()
+    .take_while(|(_, c)| is_word(*c))
+    .last()
+    .map_or(byte, |(i, _)| i);
+  let end = line[byte..]
+    .char_indices()
+    .find(|(_, c)| !is_word(*c))
+    .map_or(line.len(), |(i, _)| byte + i);
+  // The `>` of an unspaced `->` isn't part of the word that follows it.
+  while line[start..end].starts_with('>') {
+    start += 1;
+  }
+  (start < end).then_some((start, end))
+}
+
+fn indent(line: &str) -> usize {
+  line.len() - line.trim_start().len()
+}
+
+fn is_blank(line: &str) -> bool {
+  let trimmed = line.trim();
+  trimmed.is_empty() || trimmed.starts_with('#')
+}
+
+/// Split a `key: value` line into the column its key starts at, the key, and the value.
+fn mapping_entry(line: &str) -> Option<(usize, &str, &str)> {
+  let mut column = indent(line);
+  let mut content = line.trim_start();
+  if let Some(item) = content.strip_prefix("- ") {
+    column += 2 + indent(item);
+    content = item.trim_start();
+  }
+  let (key, value) = match content.split_once(": ") {
+    Some((key, value)) => (key, value.trim()),
+    None => (content.trim_end().strip_suffix(':')?, ""),
+  };
+  (!key.contains(' ')).then_some((column, key, value))
+}
+
+fn unquote(value: &str) -> &str {
+  value.trim_matches(|c| c == '\'' || c == '"')
+}
+
+/// If `lines[index]` is an item in a `flow` sequence, get the name of the operation the flow belongs to.
+pub(crate) fn flow_operation(lines: &[&str], index: usize) -> Option<String> {
+  let line = lines.get(index)?;
+  let item = line.trim_start();
+  if !(item.starts_with("- ") || item == "-") {
+    return None;
+  }
+  let item_indent = indent(line);
+
+  let mut above = lines[..index].iter().rev().filter(|l| !is_blank(l));
+  let parent = above.find(|l| {
+    indent(l) < item_indent || (indent(l) == item_indent && !l.trim_start().starts_with('-'))
+  })?;
+  let (flow_column, key, value) = mapping_entry(parent)?;
+  if key != "flow" || !value.is_empty() {
+    return None;
+  }
+
+  for line in above {
+    if let Some((column, key, value)) = mapping_entry(line) {
+      if column == flow_column && key == "name" {
+        return Some(unquote(value).to_owned());
+      }
+      if column < flow_column {
+        return None;
+      }
+    }
+  }
+  None
+}
+
+/// Get the byte offset and text of the flow expression in a `- <expression>` line.
+pub(crate) fn flow_expression(line: &str) -> Option<(usize, &str)> {
+  let item = line.trim_start().strip_prefix('-')?;
+  let mut start = line.len() - item.len();
+  let mut expression = item.trim_start();
+  start += item.len() - expression.len();
+  if let Some((before, _)) = expression.split_once(" #") {
+    expression = before;
+  }
+  expression = expression.trim_end();
+  if let Some(quoted) = expression.strip_prefix(['\'', '"']) {
+    start += 1;
+    expression = quoted.trim_end_matches(['\'', '"']);
+  }
+  Some((start, expression))
+}
+
+/// Get the value of a `key: value` line if the line's key is `key`.
+pub(crate) fn value_of<'a>(line: &'a str, key: &str) -> Option<(usize, &'a str)> {
+  let (_, found, value) = mapping_entry(line)?;
+  if found != key || value.is_empty() {
+    return None;
+  }
+  let value = unquote(value);
+  Some((line.find(value)?, value))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  const MANIFEST: &str = r#"component:
+  kind: wick/component/composite@v1
+  operations:
+    - name: greet
+      uses:
+        - name: GREETING
+          operation: greeter::greet
+      flow:
+        - <>.name -> GREETING.name
+        - '<>.name -> <>.output' # comment
+    - name: other
+      flow:
+      - <>.x -> <>.y"#;
+
+  #[test]
+  fn test_flow_operation() {
+    let lines = lines(MANIFEST);
+    assert_eq!(flow_operation(&lines, 8).as_deref(), Some("greet"));
+    assert_eq!(flow_operation(&lines, 9).as_deref(), Some("greet"));
+    assert_eq!(flow_operation(&lines, 12).as_deref(), Some("other"));
+    assert_eq!(flow_operation(&lines, 5), None);
+    assert_eq!(flow_operation(&lines, 3), None);
+  }
+
+  #[test]
+  fn test_flow_expression() {
+    let lines = lines(MANIFEST);
+    assert_eq!(flow_expression(lines[8]), Some((10, "<>.name -> GREETING.name")));
+    assert_eq!(flow_expression(lines[9]), Some((11, "<>.name -> <>.output")));
+  }
+
+  #[test]
+  fn test_word_at() {
+    let line = "  - <input>.name->greeter::greet.name";
+    let word = |byte| word_at(line, byte).map(|(s, e)| &line[s..e]);
+    assert_eq!(word(6), Some("<input>"));
+    assert_eq!(word(13), Some("name"));
+    assert_eq!(word(20), Some("greeter::greet"));
+  }
+
+  #[test]
+  fn test_positions() {
+    let line = "é𝄞x";
+    assert_eq!(byte_offset(line, 3), 6);
+    assert_eq!(character(line, 6), 3);
+  }
+}
@@ -0,0 +1,190 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader, DuplexStream, ReadHalf, WriteHalf};
+
+/// A scripted LSP client talking to a server over an in-memory stream.
+struct Client {
+  reader: BufReader<ReadHalf<DuplexStream>>,
+  writer: WriteHalf<DuplexStream>,
+  notifications: Vec<Value>,
+  next_id: i64,
+}
+
+impl Client {
+  fn start() -> (Self, tokio::task::JoinHandle<Result<(), wick_language_server::Error>>) {
+    let (client, server) = tokio::io::duplex(64 * 1024);
+    let (server_read, server_write) = tokio::io::split(server);
+    let handle = tokio::spawn(wick_language_server::serve(server_read, server_write));
+    let (reader, writer) = tokio::io::split(client);
+    let client = Self {
+      reader: BufReader::new(reader),
+      writer,
+      notifications: Vec::new(),
+      next_id: 0,
+    };
+    (client, handle)
+  }
+
+  async fn send(&mut self, message: Value) -> Result<()> {
+    let body = serde_json::to_vec(&message)?;
+    self
+      .writer
+      .write_all(format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes())
+      .await?;
+    self.writer.write_all(&body).await?;
+    Ok(())
+  }
+
+  async fn receive(&mut self) -> Result<Value> {
+    let mut length = 0;
+    loop {
+      let mut header = String::new();
+      if self.reader.read_line(&mut header).await? == 0 {
+        bail!("server closed the stream");
+      }
+      let header = header.trim_end();
+      if header.is_empty() {
+        break;
+      }
+      if let Some(value) = header.strip_prefix("Content-Length: ") {
+        length = value.parse()?;
+      }
+    }
+    let mut body = vec![0; length];
+    self.reader.read_exact(&mut body).await?;
+    Ok(serde_json::from_slice(&body)?)
+  }
+
+  async fn request(&mut self, method: &str, params: Value) -> Result<Value> {
+    self.next_id += 1;
+    let id = self.next_id;
+    self
+      .send(json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }))
+      .await?;
+    loop {
+      let message = self.receive().await?;
+      if message["id"] == id {
+        return Ok(message);
+      }
+      self.notifications.push(message);
+    }
+  }
+
+  async fn notify(&mut self, method: &str, params: Value) -> Result<()> {
+    self
+      .send(json!({ "jsonrpc": "2.0", "method": method, "params": params }))
+      .await
+  }
+
+  async fn diagnostics(&mut self) -> Result<Value> {
+    let message = match self.notifications.pop() {
+      Some(message) => message,
+      None => self.receive().await?,
+    };
+    assert_eq!(message["method"], "textDocument/publishDiagnostics");
+    Ok(message["params"]["diagnostics"].clone())
+  }
+
+  async fn open(&mut self, uri: &str, text: &str) -> Result<Value> {
+    self
+      .notify(
+        "textDocument/didOpen",
+        json!({ "textDocument": { "uri": uri, "languageId": "yaml", "version": 1, "text": text } }),
+      )
+      .await?;
+    self.diagnostics().await
+  }
+
+  async fn change(&mut self, uri: &str, text: &str) -> Result<Value> {
+    self
+      .notify(
+        "textDocument/didChange",
+        json!({ "textDocument": { "uri": uri, "version": 2 }, "contentChanges": [{ "text": text }] }),
+      )
+      .await?;
+    self.diagnostics().await
+  }
+
+  async fn at(&mut self, method: &str, uri: &str, line: u32, character: u32) -> Result<Value> {
+    let params = json!({ "textDocument": { "uri": uri }, "position": { "line": line, "character": character } });
+    Ok(self.request(method, params).await?["result"].clone())
+  }
+}
+
+fn manifest(name: &str) -> Result<(String, String)> {
+  let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    .join("tests/manifests")
+    .join(name);
+  let text = std::fs::read_to_string(&path)?;
+  Ok((format!("file://{}", path.display()), text))
+}
+
+fn labels(items: &Value) -> Vec<&str> {
+  items
+    .as_array()
+    .unwrap()
+    .iter()
+    .map(|i| i["label"].as_str().unwrap())
+    .collect()
+}
+
+#[test_logger::test(tokio::test)]
+async fn test_session() -> Result<()> {
+  let (mut client, server) = Client::start();
+  let (uri, text) = manifest("consumer.wick")?;
+
+  let init = client.request("initialize", json!({ "capabilities": {} })).await?;
+  assert_eq!(init["result"]["capabilities"]["hoverProvider"], true);
+  client.notify("initialized", json!({})).await?;
+
+  assert_eq!(client.open(&uri, &text).await?, json!([]));
+
+  // Hover over the instance in `- <>.user -> GREET.name`.
+  let hover = client.at("textDocument/hover", &uri, 21, 22).await?;
+  let contents = hover["contents"]["value"].as_str().unwrap();
+  assert!(contents.contains("greeter::greet(name: string) -> (output: string)"));
+
+  // Go to the manifest that defines `greeter::greet`.
+  let location = client.at("textDocument/definition", &uri, 19, 24).await?;
+  assert!(location["uri"].as_str().unwrap().ends_with("tests/manifests/greeter.wick"));
+
+  // Complete while the last expression is incomplete and the manifest doesn't load.
+  let editing = text.replace("        - GREET.output -> <>.message", "        - GREET.");
+  let diagnostics = client.change(&uri, &editing).await?;
+  assert_eq!(diagnostics[0]["range"]["start"]["line"], 22);
+  let items = client.at("textDocument/completion", &uri, 22, 16).await?;
+  assert_eq!(labels(&items), vec!["output"]);
+
+  let editing = text.replace("        - GREET.output -> <>.message", "        - GREET.output -> greeter::");
+  client.change(&uri, &editing).await?;
+  let items = client.at("textDocument/completion", &uri, 22, 37).await?;
+  assert_eq!(labels(&items), vec!["greet"]);
+
+  let editing = text.replace("        - GREET.output -> <>.message", "        - GREET.output -> ");
+  client.change(&uri, &editing).await?;
+  let items = client.at("textDocument/completion", &uri, 22, 27).await?;
+  assert_eq!(
+    labels(&items),
+    vec!["<>", "<input>", "<output>", "GREET", "greeter"]
+  );
+
+  // Flag ports the imported operation doesn't have.
+  let broken = text.replace("GREET.name", "GREET.nme");
+  let diagnostics = client.change(&uri, &broken).await?;
+  assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+  assert_eq!(
+    diagnostics[0]["message"],
+    "'GREET' has no input port named 'nme', expected one of: name"
+  );
+  assert_eq!(
+    diagnostics[0]["range"],
+    json!({ "start": { "line": 21, "character": 27 }, "end": { "line": 21, "character": 30 } })
+  );
+
+  client.request("shutdown", Value::Null).await?;
+  client.notify("exit", Value::Null).await?;
+  server.await??;
+  Ok(())
+}
@@ -20,6 +20,10 @@ pub enum LoggerError {
   #[error("Error resolving platform-specific configuration: {0}")]
   Platform(#[from] wick_xdg::Error),
 
+  /// Invalid log filter rule.
+  #[error("Invalid log filter '{0}', expected a level or a rule like 'wick<=trace'")]
+  FilterParse(String),
+
   /// General initialization error.
   #[error("Could not initialize logger: {0}")]
   InitFailed(String),
@@ -122,15 +122,22 @@ mod options;
 
 pub use options::{
   FilterOptions,
+  LogDestination,
+  LogFileOptions,
+  LogFileOptionsBuilder,
+  LogFileOptionsBuilderError,
   LogFilters,
   LogFiltersBuilder,
   LogFiltersBuilderError,
+  LogFormat,
   LogLevel,
   LogModifier,
+  LogRotation,
   LoggingOptions,
   LoggingOptionsBuilder,
   LoggingOptionsBuilderError,
   TargetLevel,
+  DEFAULT_FILTER,
 };
 
 /// The main Logger module.
@@ -138,5 +145,13 @@ mod logger;
 
 pub use crate::logger::{init, init_test, LoggingGuard};
 
+/// Change log filters while the logger is running.
+mod reload;
+
+pub use crate::reload::{filter_handle, LogFilterHandle};
+
+/// Helpers to propagate W3C trace context across process boundaries.
+pub mod propagation;
+
 #[macro_use]
 extern crate tracing;
@@ -3,10 +3,15 @@ use tracing_appender::non_blocking::{NonBlocking, WorkerGuard};
 use tracing_subscriber::fmt::time::UtcTime;
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::Layer;
+mod json;
 mod otel;
+mod rolling;
 
+use self::json::{JsonFields, JsonFormat};
+use self::rolling::RollingWriter;
 use crate::error::LoggerError;
-use crate::LoggingOptions;
+use crate::reload::LogFilterHandle;
+use crate::{LogDestination, LogFormat, LoggingOptions};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum Environment {
@@ -98,11 +103,23 @@ impl Drop for LoggingGuard {
 }
 
 fn get_stderr_writer(_opts: &LoggingOptions) -> (NonBlocking, WorkerGuard) {
-  let (stderr_writer, console_guard) = tracing_appender::non_blocking(std::io::stderr());
+  let (stderr_writer, console_guard) =
+    tracing_appender::non_blocking(wick_secrets::RedactingWriter::new(std::io::stderr()));
 
   (stderr_writer, console_guard)
 }
 
+fn get_logfile_writer(opts: &LoggingOptions) -> Result<Option<(NonBlocking, WorkerGuard)>, LoggerError> {
+  let Some(logfile) = &opts.logfile else {
+    return Ok(None);
+  };
+  let writer = RollingWriter::new(logfile)
+    .map_err(|e| LoggerError::NoLogfile(format!("{}: {}", logfile.directory.display(), e)))?;
+  Ok(Some(tracing_appender::non_blocking(wick_secrets::RedactingWriter::new(
+    writer,
+  ))))
+}
+
 #[allow(clippy::too_many_lines)]
 fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGuard, LoggerError> {
   #[cfg(windows)]
@@ -112,6 +129,8 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
 
   let timer = UtcTime::new(time::format_description::parse("[year]-[month]-[day]T[hour]:[minute]:[second]").unwrap());
   let (stderr_writer, console_guard) = get_stderr_writer(opts);
+  let logfile_writer = get_logfile_writer(opts)?;
+  let filters = LogFilterHandle::new(opts.levels.clone());
 
   let needs_simple_tracer = tokio::runtime::Handle::try_current().is_err() || environment == Environment::Test;
 
@@ -130,17 +149,60 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
       let layer = Some(
         tracing_opentelemetry::layer()
           .with_tracer(tracer)
-          .with_filter(opts.levels.telemetry.clone()),
+          .with_filter(filters.filter(LogDestination::Telemetry)),
       );
       (layer, Some(provider))
     },
   );
 
+  // Log files get their own layer so they can use a different format and filter than STDERR.
+  let (logfile_text_layer, logfile_json_layer, logfile_guard) = match (logfile_writer, &opts.logfile) {
+    (Some((writer, guard)), Some(logfile)) => match logfile.format {
+      LogFormat::Text => (
+        Some(
+          tracing_subscriber::fmt::layer()
+            .with_writer(writer)
+            .with_ansi(false)
+            .with_timer(timer.clone())
+            .with_target(true)
+            .with_filter(filters.filter(LogDestination::Logfile)),
+        ),
+        None,
+        Some(guard),
+      ),
+      LogFormat::Json => (
+        None,
+        Some(
+          tracing_subscriber::fmt::layer()
+            .fmt_fields(JsonFields)
+            .event_format(JsonFormat)
+            .with_writer(writer)
+            .with_filter(filters.filter(LogDestination::Logfile)),
+        ),
+        Some(guard),
+      ),
+    },
+    _ => (None, None, None),
+  };
+
   // This is ugly. If you can improve it, go for it, but
   // start here to understand why it's laid out like this: https://github.com/tokio-rs/tracing/issues/575
-  let (verbose_layer, normal_layer, logfile_guard, test_layer) = match environment {
+  let (verbose_layer, normal_layer, json_layer, test_layer) = match environment {
     Environment::Prod => {
-      if opts.verbose {
+      if opts.format == LogFormat::Json {
+        (
+          None,
+          None,
+          Some(
+            tracing_subscriber::fmt::layer()
+              .fmt_fields(JsonFields)
+              .event_format(JsonFormat)
+              .with_writer(stderr_writer)
+              .with_filter(filters.filter(LogDestination::Stderr)),
+          ),
+          None,
+        )
+      } else if opts.verbose {
         (
           Some(
             tracing_subscriber::fmt::layer()
@@ -151,7 +213,7 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
               .with_target(cfg!(debug_assertions))
               .with_file(cfg!(debug_assertions))
               .with_line_number(cfg!(debug_assertions))
-              .with_filter(opts.levels.stderr.clone()),
+              .with_filter(filters.filter(LogDestination::Stderr)),
           ),
           None,
           None,
@@ -167,7 +229,7 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
               .with_ansi(with_color)
               .with_target(false)
               .with_timer(timer)
-              .with_filter(opts.levels.stderr.clone()),
+              .with_filter(filters.filter(LogDestination::Stderr)),
           ),
           None,
           None,
@@ -185,7 +247,7 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
           .without_time()
           .with_target(true)
           .with_test_writer()
-          .with_filter(opts.levels.stderr.clone()),
+          .with_filter(filters.filter(LogDestination::Stderr)),
       ),
     ),
   };
@@ -194,12 +256,16 @@ fn try_init(opts: &LoggingOptions, environment: Environment) -> Result<LoggingGu
     .with(otel_layer)
     .with(test_layer)
     .with(verbose_layer)
-    .with(normal_layer);
+    .with(normal_layer)
+    .with(json_layer)
+    .with(logfile_text_layer)
+    .with(logfile_json_layer);
 
   #[cfg(feature = "console")]
   let subscriber = subscriber.with(console_subscriber::spawn());
 
   tracing::subscriber::set_global_default(subscriber)?;
+  crate::reload::register(filters);
   let guards = Ok(LoggingGuard::new(
     environment,
     logfile_guard,
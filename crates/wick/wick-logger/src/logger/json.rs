@@ -0,0 +1,169 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::field::RecordFields;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, FormattedFields};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Formats span and event fields as a JSON object.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct JsonFields;
+
+impl<'writer> FormatFields<'writer> for JsonFields {
+  fn format_fields<R: RecordFields>(&self, mut writer: Writer<'writer>, fields: R) -> fmt::Result {
+    let mut map = Map::new();
+    fields.record(&mut JsonVisitor(&mut map));
+    write!(writer, "{}", Value::Object(map))
+  }
+
+  fn add_fields(&self, current: &mut FormattedFields<Self>, fields: &tracing::span::Record<'_>) -> fmt::Result {
+    let mut map = parse_object(current);
+    fields.record(&mut JsonVisitor(&mut map));
+    current.fields = Value::Object(map).to_string();
+    Ok(())
+  }
+}
+
+/// Writes each event as a single line JSON object.
+///
+/// The fields of every span the event is in are flattened into the object, inner spans taking precedence over outer
+/// ones and event fields taking precedence over span fields.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct JsonFormat;
+
+impl<S> FormatEvent<S, JsonFields> for JsonFormat
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+{
+  fn format_event(&self, ctx: &FmtContext<'_, S, JsonFields>, mut writer: Writer<'_>, event: &Event<'_>) -> fmt::Result {
+    let meta = event.metadata();
+    let mut map = Map::new();
+    let timestamp = OffsetDateTime::now_utc().format(&Rfc3339).map_err(|_| fmt::Error)?;
+    map.insert("timestamp".to_owned(), Value::String(timestamp));
+    map.insert("level".to_owned(), Value::String(meta.level().to_string()));
+    map.insert("target".to_owned(), Value::String(meta.target().to_owned()));
+
+    if let Some(scope) = ctx.event_scope() {
+      let mut spans = Vec::new();
+      for span in scope.from_root() {
+        spans.push(Value::String(span.name().to_owned()));
+        if let Some(fields) = span.extensions().get::<FormattedFields<JsonFields>>() {
+          map.extend(parse_object(fields));
+        }
+      }
+      map.insert("spans".to_owned(), Value::Array(spans));
+    }
+
+    event.record(&mut JsonVisitor(&mut map));
+    writeln!(writer, "{}", Value::Object(map))
+  }
+}
+
+fn parse_object(fields: &FormattedFields<JsonFields>) -> Map<String, Value> {
+  match serde_json::from_str(&fields.fields) {
+    Ok(Value::Object(map)) => map,
+    _ => Map::new(),
+  }
+}
+
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl<'a> JsonVisitor<'a> {
+  fn insert(&mut self, field: &Field, value: Value) {
+    let name = field.name();
+    let name = name.strip_prefix("r#").unwrap_or(name);
+    self.0.insert(name.to_owned(), value);
+  }
+}
+
+impl<'a> Visit for JsonVisitor<'a> {
+  fn record_f64(&mut self, field: &Field, value: f64) {
+    self.insert(field, serde_json::Number::from_f64(value).map_or(Value::Null, Value::Number));
+  }
+
+  fn record_i64(&mut self, field: &Field, value: i64) {
+    self.insert(field, Value::from(value));
+  }
+
+  fn record_u64(&mut self, field: &Field, value: u64) {
+    self.insert(field, Value::from(value));
+  }
+
+  fn record_bool(&mut self, field: &Field, value: bool) {
+    self.insert(field, Value::Bool(value));
+  }
+
+  fn record_str(&mut self, field: &Field, value: &str) {
+    self.insert(field, Value::String(value.to_owned()));
+  }
+
+  fn record_error(&mut self, field: &Field, value: &(dyn std::error::Error + 'static)) {
+    self.insert(field, Value::String(value.to_string()));
+  }
+
+  fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+    self.insert(field, Value::String(format!("{:?}", value)));
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::io;
+  use std::sync::{Arc, Mutex};
+
+  use anyhow::Result;
+  use serde_json::json;
+  use tracing_subscriber::prelude::*;
+
+  use super::*;
+
+  #[derive(Clone, Default)]
+  struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+  impl io::Write for Buffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+      self.0.lock().unwrap().extend_from_slice(buf);
+      Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn test_flattens_span_fields() -> Result<()> {
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+    let subscriber = tracing_subscriber::registry().with(
+      tracing_subscriber::fmt::layer()
+        .fmt_fields(JsonFields)
+        .event_format(JsonFormat)
+        .with_writer(move || writer.clone()),
+    );
+
+    tracing::subscriber::with_default(subscriber, || {
+      let outer = tracing::info_span!("invocation", component = "kv", tx_id = 1);
+      let _outer = outer.enter();
+      let inner = tracing::info_span!("operation", operation = "get", tx_id = 2);
+      let _inner = inner.enter();
+      tracing::info!(key = "a", "fetching");
+    });
+
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone())?;
+    let line: Value = serde_json::from_str(output.trim())?;
+    assert_eq!(line["level"], json!("INFO"));
+    assert_eq!(line["message"], json!("fetching"));
+    assert_eq!(line["component"], json!("kv"));
+    assert_eq!(line["operation"], json!("get"));
+    assert_eq!(line["tx_id"], json!(2));
+    assert_eq!(line["key"], json!("a"));
+    assert_eq!(line["spans"], json!(["invocation", "operation"]));
+    Ok(())
+  }
+}
@@ -1,13 +1,88 @@
-use opentelemetry::sdk::trace::{self, Builder, Sampler};
+use std::borrow::Cow;
+
+use futures::future::BoxFuture;
+use opentelemetry::sdk::export::trace::{ExportResult, SpanData, SpanExporter as SdkSpanExporter};
+use opentelemetry::sdk::trace::{self, Builder, EvictedHashMap, EvictedQueue, Sampler};
 use opentelemetry::sdk::{self, Resource};
-use opentelemetry::trace::{TraceError, TracerProvider};
-use opentelemetry::KeyValue;
+use opentelemetry::trace::{Status, TraceError, TracerProvider};
+use opentelemetry::{Array, KeyValue, StringValue, Value};
 use opentelemetry_otlp::{SpanExporter, SpanExporterBuilder, WithExportConfig};
 
-fn exporter_builder(endpoint: &str) -> SpanExporter {
-  SpanExporterBuilder::from(opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint))
-    .build_span_exporter()
-    .unwrap()
+fn exporter_builder(endpoint: &str) -> RedactingExporter<SpanExporter> {
+  RedactingExporter(
+    SpanExporterBuilder::from(opentelemetry_otlp::new_exporter().tonic().with_endpoint(endpoint))
+      .build_span_exporter()
+      .unwrap(),
+  )
+}
+
+/// A [SdkSpanExporter] that redacts secrets from span names, attributes, events and statuses before export.
+#[derive(Debug)]
+pub(super) struct RedactingExporter<E>(pub(super) E);
+
+impl<E> SdkSpanExporter for RedactingExporter<E>
+where
+  E: SdkSpanExporter,
+{
+  fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+    self.0.export(batch.into_iter().map(redact_span).collect())
+  }
+
+  fn shutdown(&mut self) {
+    self.0.shutdown();
+  }
+
+  fn force_flush(&mut self) -> BoxFuture<'static, ExportResult> {
+    self.0.force_flush()
+  }
+}
+
+fn redact_span(mut span: SpanData) -> SpanData {
+  span.name = redact_cow(span.name);
+  let mut attributes = EvictedHashMap::new(u32::try_from(span.attributes.len()).unwrap_or(u32::MAX), 0);
+  for (key, value) in span.attributes {
+    attributes.insert(KeyValue::new(key, redact_value(value)));
+  }
+  span.attributes = attributes;
+  let mut events = EvictedQueue::new(u32::try_from(span.events.len()).unwrap_or(u32::MAX));
+  events.extend(span.events.into_iter().map(|mut event| {
+    event.name = redact_cow(event.name);
+    event.attributes = event
+      .attributes
+      .into_iter()
+      .map(|kv| KeyValue::new(kv.key, redact_value(kv.value)))
+      .collect();
+    event
+  }));
+  span.events = events;
+  if let Status::Error { description } = span.status {
+    span.status = Status::Error {
+      description: redact_cow(description),
+    };
+  }
+  span
+}
+
+fn redact_cow(text: Cow<'static, str>) -> Cow<'static, str> {
+  match wick_secrets::redact(&text) {
+    Cow::Borrowed(_) => text,
+    Cow::Owned(redacted) => Cow::Owned(redacted),
+  }
+}
+
+fn redact_string(value: StringValue) -> StringValue {
+  match wick_secrets::redact(value.as_str()) {
+    Cow::Borrowed(_) => value,
+    Cow::Owned(redacted) => redacted.into(),
+  }
+}
+
+fn redact_value(value: Value) -> Value {
+  match value {
+    Value::String(s) => Value::String(redact_string(s)),
+    Value::Array(Array::String(list)) => Value::Array(Array::String(list.into_iter().map(redact_string).collect())),
+    value => value,
+  }
 }
 
 fn trace_config() -> Option<trace::Config> {
@@ -44,3 +119,58 @@ pub(super) fn build_with_exporter(
   let tracer = provider.versioned_tracer("opentelemetry-otlp", Some(env!("CARGO_PKG_VERSION")), None);
   (tracer, provider)
 }
+
+#[cfg(test)]
+mod test {
+  use std::time::SystemTime;
+
+  use opentelemetry::trace::{Event, SpanContext, SpanId, SpanKind};
+
+  use super::*;
+
+  #[test]
+  fn test_redact_span() {
+    wick_secrets::register("OTEL_TEST", "otel-test-secret").unwrap();
+    let mut attributes = EvictedHashMap::new(10, 10);
+    attributes.insert(KeyValue::new("token", "otel-test-secret"));
+    attributes.insert(KeyValue::new("count", 1_i64));
+    let mut events = EvictedQueue::new(10);
+    events.extend([Event::new(
+      "sent otel-test-secret",
+      SystemTime::now(),
+      vec![KeyValue::new("message", "auth with otel-test-secret")],
+      0,
+    )]);
+    let span = SpanData {
+      span_context: SpanContext::empty_context(),
+      parent_span_id: SpanId::INVALID,
+      span_kind: SpanKind::Internal,
+      name: "login otel-test-secret".into(),
+      start_time: SystemTime::now(),
+      end_time: SystemTime::now(),
+      attributes,
+      events,
+      links: EvictedQueue::new(0),
+      status: Status::error("bad otel-test-secret"),
+      resource: Cow::Owned(Resource::empty()),
+      instrumentation_lib: Default::default(),
+    };
+
+    let span = redact_span(span);
+    assert_eq!(span.name, "login <redacted>");
+    assert_eq!(
+      span.attributes.get(&"token".into()),
+      Some(&Value::String("<redacted>".into()))
+    );
+    assert_eq!(span.attributes.get(&"count".into()), Some(&Value::I64(1)));
+    let event = span.events.iter().next().unwrap();
+    assert_eq!(event.name, "sent <redacted>");
+    assert_eq!(event.attributes[0].value, Value::String("auth with <redacted>".into()));
+    assert_eq!(
+      span.status,
+      Status::Error {
+        description: "bad <redacted>".into()
+      }
+    );
+  }
+}
@@ -0,0 +1,206 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use time::format_description::FormatItem;
+use time::macros::format_description;
+use time::OffsetDateTime;
+
+use crate::{LogFileOptions, LogRotation};
+
+const HOURLY: &[FormatItem<'static>] = format_description!("[year]-[month]-[day]T[hour]");
+const DAILY: &[FormatItem<'static>] = format_description!("[year]-[month]-[day]");
+const INSTANT: &[FormatItem<'static>] = format_description!("[year]-[month]-[day]T[hour]-[minute]-[second].[subsecond digits:3]");
+
+/// A writer that appends to `<prefix>.log` and moves it aside when it rotates.
+///
+/// Rotated files are named `<prefix>.<period>.log` so they sort by age, and the oldest are deleted once there are more
+/// than `max_files` of them.
+#[derive(Debug)]
+pub(crate) struct RollingWriter {
+  directory: PathBuf,
+  prefix: String,
+  rotation: LogRotation,
+  max_files: Option<usize>,
+  file: File,
+  size: u64,
+  period: Option<String>,
+}
+
+impl RollingWriter {
+  pub(crate) fn new(opts: &LogFileOptions) -> io::Result<Self> {
+    fs::create_dir_all(&opts.directory)?;
+    let path = active_path(&opts.directory, &opts.prefix);
+    let (size, last_period) = match fs::metadata(&path) {
+      Ok(meta) => {
+        let modified = meta.modified().map(OffsetDateTime::from)?;
+        (meta.len(), period(opts.rotation, modified))
+      }
+      Err(_) => (0, period(opts.rotation, OffsetDateTime::now_utc())),
+    };
+
+    let mut writer = Self {
+      file: open(&path)?,
+      directory: opts.directory.clone(),
+      prefix: opts.prefix.clone(),
+      rotation: opts.rotation,
+      max_files: opts.max_files,
+      size,
+      period: last_period,
+    };
+
+    // A file left behind from an earlier period belongs in its own rotated file.
+    if writer.period != period(writer.rotation, OffsetDateTime::now_utc()) {
+      writer.rotate()?;
+    }
+    Ok(writer)
+  }
+
+  fn should_rotate(&self, incoming: usize) -> bool {
+    match self.rotation {
+      LogRotation::Never => false,
+      LogRotation::Size(max) => self.size > 0 && self.size + incoming as u64 > max,
+      LogRotation::Hourly | LogRotation::Daily => self.period != period(self.rotation, OffsetDateTime::now_utc()),
+    }
+  }
+
+  fn rotate(&mut self) -> io::Result<()> {
+    self.file.flush()?;
+    let now = OffsetDateTime::now_utc();
+    let suffix = match &self.period {
+      Some(period) => period.clone(),
+      None => now
+        .format(INSTANT)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+    };
+
+    let active = active_path(&self.directory, &self.prefix);
+    let mut rotated = self.directory.join(format!("{}.{}.log", self.prefix, suffix));
+    let mut n = 1;
+    while rotated.exists() {
+      rotated = self.directory.join(format!("{}.{}_{:03}.log", self.prefix, suffix, n));
+      n += 1;
+    }
+    fs::rename(&active, rotated)?;
+
+    self.file = open(&active)?;
+    self.size = 0;
+    self.period = period(self.rotation, now);
+    self.prune()
+  }
+
+  fn prune(&self) -> io::Result<()> {
+    let Some(max_files) = self.max_files else {
+      return Ok(());
+    };
+    let active = format!("{}.log", self.prefix);
+    let prefix = format!("{}.", self.prefix);
+    let mut rotated: Vec<_> = fs::read_dir(&self.directory)?
+      .filter_map(Result::ok)
+      .map(|entry| entry.file_name().to_string_lossy().to_string())
+      .filter(|name| name != &active && name.starts_with(&prefix) && name.ends_with(".log"))
+      .collect();
+    rotated.sort();
+
+    let excess = rotated.len().saturating_sub(max_files);
+    for name in rotated.into_iter().take(excess) {
+      fs::remove_file(self.directory.join(name))?;
+    }
+    Ok(())
+  }
+}
+
+impl Write for RollingWriter {
+  fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    if self.should_rotate(buf.len()) {
+      self.rotate()?;
+    }
+    let written = self.file.write(buf)?;
+    self.size += written as u64;
+    Ok(written)
+  }
+
+  fn flush(&mut self) -> io::Result<()> {
+    self.file.flush()
+  }
+}
+
+fn active_path(directory: &Path, prefix: &str) -> PathBuf {
+  directory.join(format!("{}.log", prefix))
+}
+
+fn open(path: &Path) -> io::Result<File> {
+  OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn period(rotation: LogRotation, at: OffsetDateTime) -> Option<String> {
+  let format = match rotation {
+    LogRotation::Hourly => HOURLY,
+    LogRotation::Daily => DAILY,
+    LogRotation::Never | LogRotation::Size(_) => return None,
+  };
+  at.format(format).ok()
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+  use crate::LogFileOptionsBuilder;
+
+  fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("wick-logger-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+  }
+
+  fn files(dir: &Path) -> Result<Vec<String>> {
+    let mut names: Vec<_> = fs::read_dir(dir)?
+      .map(|e| Ok(e?.file_name().to_string_lossy().to_string()))
+      .collect::<Result<_>>()?;
+    names.sort();
+    Ok(names)
+  }
+
+  #[test]
+  fn test_size_rotation() -> Result<()> {
+    let dir = temp_dir("size");
+    let opts = LogFileOptionsBuilder::default()
+      .directory(&dir)
+      .prefix("test")
+      .rotation(LogRotation::Size(10))
+      .max_files(Some(2))
+      .build()?;
+    let mut writer = RollingWriter::new(&opts)?;
+    for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+      writer.write_all(line.as_bytes())?;
+    }
+    writer.flush()?;
+
+    let names = files(&dir)?;
+    assert_eq!(names.len(), 3);
+    assert_eq!(fs::read_to_string(dir.join("test.log"))?, "fourth\n");
+    assert_eq!(fs::read_to_string(dir.join(&names[0]))?, "second\n");
+    assert_eq!(fs::read_to_string(dir.join(&names[1]))?, "third\n");
+    fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+
+  #[test]
+  fn test_never_rotates() -> Result<()> {
+    let dir = temp_dir("never");
+    let opts = LogFileOptionsBuilder::default().directory(&dir).build()?;
+    let mut writer = RollingWriter::new(&opts)?;
+    writer.write_all(b"one\n")?;
+    drop(writer);
+    let mut writer = RollingWriter::new(&opts)?;
+    writer.write_all(b"two\n")?;
+    writer.flush()?;
+
+    assert_eq!(files(&dir)?, vec!["wick.log"]);
+    assert_eq!(fs::read_to_string(dir.join("wick.log"))?, "one\ntwo\n");
+    fs::remove_dir_all(dir)?;
+    Ok(())
+  }
+}
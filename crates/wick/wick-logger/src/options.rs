@@ -1,9 +1,14 @@
 use std::cmp;
+use std::path::PathBuf;
 use std::str::FromStr;
 
+use once_cell::sync::Lazy;
+use regex::Regex;
 use tracing::{Level, Metadata};
 use tracing_subscriber::layer::Context;
 
+use crate::error::LoggerError;
+
 #[derive(Debug, Clone, derive_builder::Builder)]
 #[non_exhaustive]
 #[builder(default, derive(Debug), setter(into))]
@@ -20,6 +25,12 @@ pub struct LoggingOptions {
 
   /// Log filtering options
   pub levels: LogFilters,
+
+  /// The format of events printed to STDERR.
+  pub format: LogFormat,
+
+  /// Write events to (optionally rotated) log files.
+  pub logfile: Option<LogFileOptions>,
 }
 
 impl LoggingOptions {
@@ -40,6 +51,8 @@ impl Default for LoggingOptions {
       otlp_endpoint: Default::default(),
       app_name: "app".to_owned(),
       levels: Default::default(),
+      format: Default::default(),
+      logfile: Default::default(),
     }
   }
 }
@@ -53,6 +66,8 @@ pub struct LogFilters {
   pub telemetry: FilterOptions,
   /// The log level for the events printed to STDERR.
   pub stderr: FilterOptions,
+  /// The log level for the events written to the log file.
+  pub logfile: FilterOptions,
 }
 
 impl LogFilters {
@@ -68,10 +83,131 @@ impl LogFilters {
         level,
         ..Default::default()
       },
+      logfile: FilterOptions {
+        level,
+        ..Default::default()
+      },
+    }
+  }
+
+  /// Get the filter for the given destination.
+  #[must_use]
+  pub const fn get(&self, destination: LogDestination) -> &FilterOptions {
+    match destination {
+      LogDestination::Stderr => &self.stderr,
+      LogDestination::Telemetry => &self.telemetry,
+      LogDestination::Logfile => &self.logfile,
+    }
+  }
+
+  /// Get a mutable reference to the filter for the given destination.
+  pub fn get_mut(&mut self, destination: LogDestination) -> &mut FilterOptions {
+    match destination {
+      LogDestination::Stderr => &mut self.stderr,
+      LogDestination::Telemetry => &mut self.telemetry,
+      LogDestination::Logfile => &mut self.logfile,
+    }
+  }
+}
+
+/// Where log events are sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum LogDestination {
+  /// Events printed to STDERR.
+  Stderr,
+  /// Events exported to the OpenTelemetry endpoint.
+  Telemetry,
+  /// Events written to the log file.
+  Logfile,
+}
+
+impl std::fmt::Display for LogDestination {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      LogDestination::Stderr => write!(f, "stderr"),
+      LogDestination::Telemetry => write!(f, "telemetry"),
+      LogDestination::Logfile => write!(f, "logfile"),
+    }
+  }
+}
+
+impl FromStr for LogDestination {
+  type Err = ();
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "stderr" => Ok(LogDestination::Stderr),
+      "telemetry" | "otel" => Ok(LogDestination::Telemetry),
+      "logfile" | "file" => Ok(LogDestination::Logfile),
+      _ => Err(()),
+    }
+  }
+}
+
+/// The format log events are written in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum LogFormat {
+  /// Human readable lines.
+  #[default]
+  Text,
+  /// One JSON object per line with the fields of all enclosing spans flattened into the event.
+  Json,
+}
+
+impl FromStr for LogFormat {
+  type Err = ();
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.to_lowercase().as_str() {
+      "text" => Ok(LogFormat::Text),
+      "json" => Ok(LogFormat::Json),
+      _ => Err(()),
     }
   }
 }
 
+/// How often log files are rotated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum LogRotation {
+  /// Never rotate, always append to the same file.
+  #[default]
+  Never,
+  /// Start a new file every hour.
+  Hourly,
+  /// Start a new file every day.
+  Daily,
+  /// Start a new file when the current one grows past this many bytes.
+  Size(u64),
+}
+
+/// Options for writing log events to files.
+#[derive(Debug, Clone, derive_builder::Builder)]
+#[non_exhaustive]
+#[builder(derive(Debug), setter(into))]
+pub struct LogFileOptions {
+  /// The directory log files are written to.
+  pub directory: PathBuf,
+
+  /// The file name prefix. The active file is `<prefix>.log`.
+  #[builder(default = "\"wick\".to_owned()")]
+  pub prefix: String,
+
+  /// When to start a new file.
+  #[builder(default)]
+  pub rotation: LogRotation,
+
+  /// The number of rotated files to keep. Older files are deleted.
+  #[builder(default)]
+  pub max_files: Option<usize>,
+
+  /// The format events are written in.
+  #[builder(default = "LogFormat::Json")]
+  pub format: LogFormat,
+}
+
 /// Options for filtering logs.
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -98,7 +234,44 @@ impl FilterOptions {
     Self { level, filter }
   }
 
-  fn test_enabled(&self, module: &str, level: Level) -> bool {
+  /// Parse a filter string like `info,wick<=trace,flow!=debug`.
+  ///
+  /// A bare level sets the default level, otherwise `default_level` is used. Rules in `default_filter` are appended
+  /// unless the string already contains them.
+  pub fn parse(logstr: &str, default_level: LogLevel, default_filter: &[TargetLevel]) -> Result<Self, LoggerError> {
+    let mut level = default_level;
+    let mut filter = Vec::new();
+
+    for rule in logstr.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+      if !rule.contains(['<', '>', '=', '!']) {
+        level = rule.parse().map_err(|_| LoggerError::FilterParse(rule.to_owned()))?;
+        continue;
+      }
+      let parts = RULE_REGEX
+        .captures(rule)
+        .ok_or_else(|| LoggerError::FilterParse(rule.to_owned()))?;
+      let modifier: Option<LogModifier> = parts.get(2).and_then(|m| m.as_str().parse().ok());
+      let target_level: Option<LogLevel> = parts.get(3).and_then(|m| m.as_str().parse().ok());
+      match (parts.get(1), modifier, target_level) {
+        (Some(target), Some(modifier), Some(target_level)) => {
+          filter.push(TargetLevel::new(target.as_str(), target_level, modifier));
+        }
+        _ => return Err(LoggerError::FilterParse(rule.to_owned())),
+      }
+    }
+
+    // Skip defaults that are already spelled out, e.g. when a printed filter is parsed again.
+    let defaults = default_filter
+      .iter()
+      .filter(|default| !filter.contains(default))
+      .cloned()
+      .collect::<Vec<_>>();
+    filter.extend(defaults);
+
+    Ok(Self::new(level, filter))
+  }
+
+  pub(crate) fn test_enabled(&self, module: &str, level: Level) -> bool {
     let matches = self.filter.iter().filter(|config| module.starts_with(&config.target));
     let match_hit = matches.fold(None, |acc, next| {
       let enabled = next.modifier.compare(filter_as_usize(level), next.level as usize);
@@ -124,22 +297,51 @@ impl FilterOptions {
   }
 }
 
+impl std::fmt::Display for FilterOptions {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.level.to_string().to_lowercase())?;
+    for rule in &self.filter {
+      write!(f, ",{}", rule)?;
+    }
+    Ok(())
+  }
+}
+
+/// The default rules that quiet noisy targets.
+pub static DEFAULT_FILTER: Lazy<Vec<TargetLevel>> = Lazy::new(|| {
+  vec![
+    TargetLevel::lte("flow", LogLevel::Warn),
+    TargetLevel::lte("wick_wascap", LogLevel::Warn),
+    TargetLevel::lte("wasmrs", LogLevel::Error),
+    TargetLevel::lte("wasmrs_runtime", LogLevel::Error),
+    TargetLevel::lte("wasmrs_wasmtime", LogLevel::Error),
+  ]
+});
+
+static RULE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new("^(\\w+)\\s*(<|<=|>|>=|!=|==|=)\\s*(\\w+)$").unwrap());
+
+pub(crate) fn target_enabled(metadata: &Metadata<'_>) -> bool {
+  let enabled = metadata.target().starts_with("wick")
+    || metadata.target().starts_with("flow")
+    || metadata.target().starts_with("wasmrs");
+
+  metadata.is_span() || enabled
+}
+
+pub(crate) fn event_module<'a>(event: &tracing::Event<'a>) -> &'a str {
+  event.metadata().target().split("::").next().unwrap_or_default()
+}
+
 impl<S> tracing_subscriber::layer::Filter<S> for FilterOptions
 where
   S: tracing::Subscriber + for<'lookup> tracing_subscriber::registry::LookupSpan<'lookup>,
 {
   fn enabled(&self, metadata: &Metadata<'_>, _cx: &Context<'_, S>) -> bool {
-    let enabled = metadata.target().starts_with("wick")
-      || metadata.target().starts_with("flow")
-      || metadata.target().starts_with("wasmrs");
-
-    metadata.is_span() || enabled
+    target_enabled(metadata)
   }
 
   fn event_enabled(&self, event: &tracing::Event<'_>, _cx: &Context<'_, S>) -> bool {
-    let module = event.metadata().target().split("::").next().unwrap_or_default();
-    let level = event.metadata().level();
-    self.test_enabled(module, *level)
+    self.test_enabled(event_module(event), *event.metadata().level())
   }
 }
 
@@ -155,6 +357,18 @@ pub struct TargetLevel {
   pub modifier: LogModifier,
 }
 
+impl std::fmt::Display for TargetLevel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{}{}{}",
+      self.target,
+      self.modifier,
+      self.level.to_string().to_lowercase()
+    )
+  }
+}
+
 impl TargetLevel {
   /// Create a new instance for the given target, log level, and modifier.
   pub fn new<T: Into<String>>(target: T, level: LogLevel, modifier: LogModifier) -> Self {
@@ -422,6 +636,46 @@ mod test {
     }
   }
 
+  #[test]
+  fn test_parse_display_roundtrip() {
+    let filter = FilterOptions::parse("debug, wick_runtime <= trace,flow!=info", LogLevel::Info, &[]).unwrap();
+    assert_eq!(filter.level, LogLevel::Debug);
+    assert_eq!(
+      filter.filter,
+      vec![
+        TargetLevel::lte("wick_runtime", LogLevel::Trace),
+        TargetLevel::not("flow", LogLevel::Info)
+      ]
+    );
+    assert_eq!(filter.to_string(), "debug,wick_runtime<=trace,flow!=info");
+    let reparsed = FilterOptions::parse(&filter.to_string(), LogLevel::Info, &[]).unwrap();
+    assert_eq!(reparsed.filter, filter.filter);
+  }
+
+  #[test]
+  fn test_parse_skips_repeated_defaults() {
+    let defaults = [TargetLevel::lte("flow", LogLevel::Warn), TargetLevel::lte("wasmrs", LogLevel::Error)];
+    let filter = FilterOptions::parse("wick<=trace", LogLevel::Info, &defaults).unwrap();
+    assert_eq!(
+      filter.filter,
+      vec![
+        TargetLevel::lte("wick", LogLevel::Trace),
+        TargetLevel::lte("flow", LogLevel::Warn),
+        TargetLevel::lte("wasmrs", LogLevel::Error)
+      ]
+    );
+    let reparsed = FilterOptions::parse(&filter.to_string(), LogLevel::Info, &defaults).unwrap();
+    assert_eq!(reparsed.filter, filter.filter);
+  }
+
+  #[rstest::rstest]
+  #[case("loud")]
+  #[case("wick<=loud")]
+  #[case("wick~trace")]
+  fn test_parse_invalid(#[case] logstr: &str) {
+    assert!(FilterOptions::parse(logstr, LogLevel::Info, &[]).is_err());
+  }
+
   #[test]
   fn test_default_level() {
     assert!(opts(LogLevel::Info, []).test_enabled("wick", Level::INFO));
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::trace::TraceContextExt;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+const TRACEPARENT: &str = "traceparent";
+const TRACESTATE: &str = "tracestate";
+
+/// Set the remote parent of a [tracing::Span] from a W3C `traceparent` and optional `tracestate`.
+///
+/// Invalid or missing values leave the span untouched.
+pub fn set_remote_parent(span: &tracing::Span, traceparent: &str, tracestate: Option<&str>) {
+  let mut carrier = HashMap::new();
+  carrier.insert(TRACEPARENT.to_owned(), traceparent.to_owned());
+  if let Some(tracestate) = tracestate {
+    carrier.insert(TRACESTATE.to_owned(), tracestate.to_owned());
+  }
+  let cx = TraceContextPropagator::new().extract(&carrier);
+  if cx.span().span_context().is_valid() {
+    span.set_parent(cx);
+  }
+}
+
+/// Get the W3C `traceparent` and `tracestate` for a [tracing::Span].
+///
+/// Returns [None] when the span is not being exported to OpenTelemetry.
+#[must_use]
+pub fn trace_context(span: &tracing::Span) -> Option<(String, Option<String>)> {
+  let mut carrier: HashMap<String, String> = HashMap::new();
+  TraceContextPropagator::new().inject_context(&span.context(), &mut carrier);
+  let traceparent = carrier.remove(TRACEPARENT)?;
+  let tracestate = carrier.remove(TRACESTATE).filter(|s| !s.is_empty());
+  Some((traceparent, tracestate))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_no_context_without_otel() {
+    let span = tracing::info_span!("test");
+    set_remote_parent(&span, "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01", None);
+    assert_eq!(trace_context(&span), None);
+  }
+}
@@ -0,0 +1,108 @@
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::RwLock;
+use tracing::Metadata;
+use tracing_subscriber::layer::Context;
+
+use crate::options::{event_module, target_enabled};
+use crate::{FilterOptions, LogDestination, LogFilters};
+
+static HANDLE: OnceLock<LogFilterHandle> = OnceLock::new();
+
+/// Get the handle to the filters of the global logger, if one has been initialized.
+#[must_use]
+pub fn filter_handle() -> Option<LogFilterHandle> {
+  HANDLE.get().cloned()
+}
+
+pub(crate) fn register(handle: LogFilterHandle) {
+  // Only the first logger becomes the global subscriber, so only its handle is kept.
+  let _ = HANDLE.set(handle);
+}
+
+/// A handle to change the filters of a running logger.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct LogFilterHandle {
+  filters: Arc<RwLock<LogFilters>>,
+}
+
+impl LogFilterHandle {
+  pub(crate) fn new(filters: LogFilters) -> Self {
+    Self {
+      filters: Arc::new(RwLock::new(filters)),
+    }
+  }
+
+  /// Get a copy of the filters currently in use.
+  #[must_use]
+  pub fn current(&self) -> LogFilters {
+    self.filters.read().clone()
+  }
+
+  /// Get the filter currently in use for the given destination.
+  #[must_use]
+  pub fn get(&self, destination: LogDestination) -> FilterOptions {
+    self.filters.read().get(destination).clone()
+  }
+
+  /// Replace the filter for the given destination. The change applies to the next event.
+  pub fn set(&self, destination: LogDestination, filter: FilterOptions) {
+    debug!(%destination, %filter, "updating log filter");
+    *self.filters.write().get_mut(destination) = filter;
+  }
+
+  pub(crate) fn filter(&self, destination: LogDestination) -> ReloadableFilter {
+    ReloadableFilter {
+      filters: self.filters.clone(),
+      destination,
+    }
+  }
+}
+
+/// A per-layer filter that reads its [FilterOptions] from a [LogFilterHandle] on every event.
+#[derive(Debug, Clone)]
+pub(crate) struct ReloadableFilter {
+  filters: Arc<RwLock<LogFilters>>,
+  destination: LogDestination,
+}
+
+impl<S> tracing_subscriber::layer::Filter<S> for ReloadableFilter
+where
+  S: tracing::Subscriber + for<'lookup> tracing_subscriber::registry::LookupSpan<'lookup>,
+{
+  fn enabled(&self, metadata: &Metadata<'_>, _cx: &Context<'_, S>) -> bool {
+    target_enabled(metadata)
+  }
+
+  fn event_enabled(&self, event: &tracing::Event<'_>, _cx: &Context<'_, S>) -> bool {
+    self
+      .filters
+      .read()
+      .get(self.destination)
+      .test_enabled(event_module(event), *event.metadata().level())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use tracing::Level;
+
+  use super::*;
+  use crate::{LogLevel, TargetLevel};
+
+  #[test]
+  fn test_set_filter() {
+    let handle = LogFilterHandle::new(LogFilters::with_level(LogLevel::Info));
+    let filter = handle.filter(LogDestination::Stderr);
+    assert!(!filter.filters.read().stderr.test_enabled("wick_runtime", Level::TRACE));
+
+    handle.set(
+      LogDestination::Stderr,
+      FilterOptions::new(LogLevel::Info, vec![TargetLevel::lte("wick_runtime", LogLevel::Trace)]),
+    );
+    assert!(filter.filters.read().stderr.test_enabled("wick_runtime", Level::TRACE));
+    assert!(!filter.filters.read().stderr.test_enabled("wick_config", Level::TRACE));
+    assert_eq!(handle.get(LogDestination::Telemetry).filter, vec![]);
+  }
+}
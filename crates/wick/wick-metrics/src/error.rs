@@ -0,0 +1,10 @@
+use std::net::SocketAddr;
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+/// Crate error.
+pub enum Error {
+  /// The metrics server could not bind to the requested address.
+  #[error("Could not bind metrics server to {0}: {1}")]
+  Bind(SocketAddr, String),
+}
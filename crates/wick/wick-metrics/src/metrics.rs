@@ -0,0 +1,144 @@
+//! The metrics recorded by Wick.
+
+use std::time::Instant;
+
+use crate::registry::{CounterVec, GaugeVec, HistogramVec, DEFAULT_BUCKETS};
+
+const OPERATION_LABELS: &[&str] = &["component", "operation"];
+
+/// The number of operation invocations started.
+pub static INVOCATIONS: CounterVec = CounterVec::new(
+  "wick_invocations_total",
+  "The number of operation invocations started.",
+  OPERATION_LABELS,
+);
+
+/// The number of operation invocations that failed, timed out, or were cancelled.
+pub static INVOCATION_ERRORS: CounterVec = CounterVec::new(
+  "wick_invocation_errors_total",
+  "The number of operation invocations that failed, timed out, or were cancelled.",
+  OPERATION_LABELS,
+);
+
+/// The time from the start of an invocation until its output stream completes.
+pub static INVOCATION_DURATION: HistogramVec = HistogramVec::new(
+  "wick_invocation_duration_seconds",
+  "The time from the start of an invocation until its output stream completes.",
+  OPERATION_LABELS,
+  DEFAULT_BUCKETS,
+);
+
+/// The number of operation invocations currently executing.
+pub static INVOCATIONS_IN_FLIGHT: GaugeVec = GaugeVec::new(
+  "wick_invocations_in_flight",
+  "The number of operation invocations currently executing.",
+  OPERATION_LABELS,
+);
+
+/// The number of responses sent by the HTTP trigger.
+pub static HTTP_RESPONSES: CounterVec = CounterVec::new(
+  "wick_http_responses_total",
+  "The number of responses sent by the HTTP trigger.",
+  &["router", "route", "status"],
+);
+
+/// The number of jobs run by the time trigger.
+pub static TIME_TRIGGER_JOBS: CounterVec = CounterVec::new(
+  "wick_time_trigger_jobs_total",
+  "The number of jobs run by the time trigger.",
+  &["operation", "outcome"],
+);
+
+/// Render every metric in the Prometheus text format.
+#[must_use]
+pub fn render() -> String {
+  let mut out = String::new();
+  INVOCATIONS.render(&mut out);
+  INVOCATION_ERRORS.render(&mut out);
+  INVOCATION_DURATION.render(&mut out);
+  INVOCATIONS_IN_FLIGHT.render(&mut out);
+  HTTP_RESPONSES.render(&mut out);
+  TIME_TRIGGER_JOBS.render(&mut out);
+  out
+}
+
+/// Record the start of an invocation of `component::operation`.
+///
+/// The returned [InvocationTimer] records the invocation's outcome and duration when it is finished. A timer
+/// that is dropped without being finished is recorded as a failure.
+pub fn start_invocation(component: &str, operation: &str) -> InvocationTimer {
+  let labels = [component, operation];
+  INVOCATIONS.inc(&labels);
+  INVOCATIONS_IN_FLIGHT.inc(&labels);
+  InvocationTimer {
+    component: component.to_owned(),
+    operation: operation.to_owned(),
+    start: Instant::now(),
+    done: false,
+  }
+}
+
+/// Tracks an in-flight invocation started with [start_invocation].
+#[derive(Debug)]
+#[must_use]
+pub struct InvocationTimer {
+  component: String,
+  operation: String,
+  start: Instant,
+  done: bool,
+}
+
+impl InvocationTimer {
+  /// Record the end of the invocation.
+  pub fn finish(mut self, success: bool) {
+    self.record(success);
+  }
+
+  fn record(&mut self, success: bool) {
+    if self.done {
+      return;
+    }
+    self.done = true;
+    let labels = [self.component.as_str(), self.operation.as_str()];
+    INVOCATIONS_IN_FLIGHT.dec(&labels);
+    INVOCATION_DURATION.observe(&labels, self.start.elapsed().as_secs_f64());
+    if !success {
+      INVOCATION_ERRORS.inc(&labels);
+    }
+  }
+}
+
+impl Drop for InvocationTimer {
+  fn drop(&mut self) {
+    self.record(false);
+  }
+}
+
+/// Record a response sent by an HTTP router.
+pub fn record_http_response(router: &str, route: &str, status: u16) {
+  HTTP_RESPONSES.inc(&[router, route, &status.to_string()]);
+}
+
+/// Record the outcome of a time trigger job.
+pub fn record_time_trigger_job(operation: &str, success: bool) {
+  TIME_TRIGGER_JOBS.inc(&[operation, if success { "success" } else { "error" }]);
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_invocation_timer() {
+    let labels = ["test_timer", "op"];
+    let timer = start_invocation("test_timer", "op");
+    assert_eq!(INVOCATIONS_IN_FLIGHT.get(&labels), 1);
+    timer.finish(true);
+    drop(start_invocation("test_timer", "op"));
+
+    assert_eq!(INVOCATIONS.get(&labels), 2);
+    assert_eq!(INVOCATION_ERRORS.get(&labels), 1);
+    assert_eq!(INVOCATIONS_IN_FLIGHT.get(&labels), 0);
+    assert_eq!(INVOCATION_DURATION.count(&labels), 2);
+  }
+}
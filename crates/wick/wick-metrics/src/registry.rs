@@ -0,0 +1,295 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use parking_lot::Mutex;
+
+type Labels = Vec<String>;
+
+/// The default histogram buckets (in seconds), matching the Prometheus client defaults.
+pub const DEFAULT_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+#[derive(Debug)]
+struct Descriptor {
+  name: &'static str,
+  help: &'static str,
+  labels: &'static [&'static str],
+}
+
+impl Descriptor {
+  fn key(&self, values: &[&str]) -> Labels {
+    debug_assert_eq!(
+      values.len(),
+      self.labels.len(),
+      "wrong number of label values for {}",
+      self.name
+    );
+    values.iter().map(|v| (*v).to_owned()).collect()
+  }
+
+  fn header(&self, out: &mut String, kind: &str) {
+    let _ = writeln!(out, "# HELP {} {}", self.name, self.help);
+    let _ = writeln!(out, "# TYPE {} {}", self.name, kind);
+  }
+
+  fn sample(&self, out: &mut String, suffix: &str, values: &[String], extra: Option<(&str, &str)>, value: &str) {
+    out.push_str(self.name);
+    out.push_str(suffix);
+    let mut pairs = self
+      .labels
+      .iter()
+      .zip(values)
+      .map(|(k, v)| (*k, v.as_str()))
+      .chain(extra)
+      .peekable();
+    if pairs.peek().is_some() {
+      out.push('{');
+      for (i, (k, v)) in pairs.enumerate() {
+        if i > 0 {
+          out.push(',');
+        }
+        let _ = write!(out, "{}=\"{}\"", k, escape(v));
+      }
+      out.push('}');
+    }
+    out.push(' ');
+    out.push_str(value);
+    out.push('\n');
+  }
+}
+
+fn escape(value: &str) -> String {
+  value.replace('\\', r"\\").replace('"', r#"\""#).replace('\n', r"\n")
+}
+
+fn format_float(value: f64) -> String {
+  if value.is_infinite() {
+    if value.is_sign_positive() { "+Inf" } else { "-Inf" }.to_owned()
+  } else {
+    value.to_string()
+  }
+}
+
+/// A family of monotonically increasing counters, one per set of label values.
+#[derive(Debug)]
+pub struct CounterVec {
+  desc: Descriptor,
+  values: Mutex<BTreeMap<Labels, u64>>,
+}
+
+impl CounterVec {
+  /// Create a new counter family.
+  #[must_use]
+  pub const fn new(name: &'static str, help: &'static str, labels: &'static [&'static str]) -> Self {
+    Self {
+      desc: Descriptor { name, help, labels },
+      values: Mutex::new(BTreeMap::new()),
+    }
+  }
+
+  /// Increment the counter for the passed label values by one.
+  pub fn inc(&self, labels: &[&str]) {
+    self.inc_by(labels, 1);
+  }
+
+  /// Increment the counter for the passed label values.
+  pub fn inc_by(&self, labels: &[&str], value: u64) {
+    *self.values.lock().entry(self.desc.key(labels)).or_default() += value;
+  }
+
+  /// Get the current value of the counter for the passed label values.
+  #[must_use]
+  pub fn get(&self, labels: &[&str]) -> u64 {
+    self.values.lock().get(&self.desc.key(labels)).copied().unwrap_or_default()
+  }
+
+  /// Render the family in the Prometheus text format.
+  pub fn render(&self, out: &mut String) {
+    self.desc.header(out, "counter");
+    for (labels, value) in self.values.lock().iter() {
+      self.desc.sample(out, "", labels, None, &value.to_string());
+    }
+  }
+}
+
+/// A family of gauges that can go up and down, one per set of label values.
+#[derive(Debug)]
+pub struct GaugeVec {
+  desc: Descriptor,
+  values: Mutex<BTreeMap<Labels, i64>>,
+}
+
+impl GaugeVec {
+  /// Create a new gauge family.
+  #[must_use]
+  pub const fn new(name: &'static str, help: &'static str, labels: &'static [&'static str]) -> Self {
+    Self {
+      desc: Descriptor { name, help, labels },
+      values: Mutex::new(BTreeMap::new()),
+    }
+  }
+
+  /// Increment the gauge for the passed label values by one.
+  pub fn inc(&self, labels: &[&str]) {
+    *self.values.lock().entry(self.desc.key(labels)).or_default() += 1;
+  }
+
+  /// Decrement the gauge for the passed label values by one.
+  pub fn dec(&self, labels: &[&str]) {
+    *self.values.lock().entry(self.desc.key(labels)).or_default() -= 1;
+  }
+
+  /// Set the gauge for the passed label values.
+  pub fn set(&self, labels: &[&str], value: i64) {
+    self.values.lock().insert(self.desc.key(labels), value);
+  }
+
+  /// Get the current value of the gauge for the passed label values.
+  #[must_use]
+  pub fn get(&self, labels: &[&str]) -> i64 {
+    self.values.lock().get(&self.desc.key(labels)).copied().unwrap_or_default()
+  }
+
+  /// Render the family in the Prometheus text format.
+  pub fn render(&self, out: &mut String) {
+    self.desc.header(out, "gauge");
+    for (labels, value) in self.values.lock().iter() {
+      self.desc.sample(out, "", labels, None, &value.to_string());
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+  buckets: Vec<u64>,
+  sum: f64,
+  count: u64,
+}
+
+/// A family of histograms that count observations into buckets, one per set of label values.
+#[derive(Debug)]
+pub struct HistogramVec {
+  desc: Descriptor,
+  buckets: &'static [f64],
+  values: Mutex<BTreeMap<Labels, Histogram>>,
+}
+
+impl HistogramVec {
+  /// Create a new histogram family with the passed (ascending) bucket upper bounds.
+  #[must_use]
+  pub const fn new(
+    name: &'static str,
+    help: &'static str,
+    labels: &'static [&'static str],
+    buckets: &'static [f64],
+  ) -> Self {
+    Self {
+      desc: Descriptor { name, help, labels },
+      buckets,
+      values: Mutex::new(BTreeMap::new()),
+    }
+  }
+
+  /// Record an observation for the passed label values.
+  pub fn observe(&self, labels: &[&str], value: f64) {
+    let mut values = self.values.lock();
+    let histogram = values.entry(self.desc.key(labels)).or_insert_with(|| Histogram {
+      buckets: vec![0; self.buckets.len()],
+      ..Default::default()
+    });
+    for (count, bound) in histogram.buckets.iter_mut().zip(self.buckets) {
+      if value <= *bound {
+        *count += 1;
+      }
+    }
+    histogram.sum += value;
+    histogram.count += 1;
+  }
+
+  /// Get the number of observations recorded for the passed label values.
+  #[must_use]
+  pub fn count(&self, labels: &[&str]) -> u64 {
+    self
+      .values
+      .lock()
+      .get(&self.desc.key(labels))
+      .map_or(0, |histogram| histogram.count)
+  }
+
+  /// Render the family in the Prometheus text format.
+  pub fn render(&self, out: &mut String) {
+    self.desc.header(out, "histogram");
+    for (labels, histogram) in self.values.lock().iter() {
+      for (count, bound) in histogram.buckets.iter().zip(self.buckets) {
+        self
+          .desc
+          .sample(out, "_bucket", labels, Some(("le", &format_float(*bound))), &count.to_string());
+      }
+      self.desc.sample(
+        out,
+        "_bucket",
+        labels,
+        Some(("le", "+Inf")),
+        &histogram.count.to_string(),
+      );
+      self
+        .desc
+        .sample(out, "_sum", labels, None, &format_float(histogram.sum));
+      self
+        .desc
+        .sample(out, "_count", labels, None, &histogram.count.to_string());
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_counter() {
+    let counter = CounterVec::new("test_total", "A test counter.", &["op"]);
+    counter.inc(&["a"]);
+    counter.inc_by(&["a"], 2);
+    counter.inc(&["b\"c"]);
+    assert_eq!(counter.get(&["a"]), 3);
+    let mut out = String::new();
+    counter.render(&mut out);
+    assert_eq!(
+      out,
+      "# HELP test_total A test counter.\n# TYPE test_total counter\ntest_total{op=\"a\"} 3\ntest_total{op=\"b\\\"c\"} 1\n"
+    );
+  }
+
+  #[test]
+  fn test_gauge() {
+    let gauge = GaugeVec::new("test_gauge", "A test gauge.", &[]);
+    gauge.inc(&[]);
+    gauge.inc(&[]);
+    gauge.dec(&[]);
+    assert_eq!(gauge.get(&[]), 1);
+    let mut out = String::new();
+    gauge.render(&mut out);
+    assert!(out.ends_with("test_gauge 1\n"), "unexpected output: {}", out);
+  }
+
+  #[test]
+  fn test_histogram() {
+    let histogram = HistogramVec::new("test_seconds", "A test histogram.", &["op"], &[0.1, 1.0]);
+    histogram.observe(&["a"], 0.05);
+    histogram.observe(&["a"], 0.5);
+    histogram.observe(&["a"], 5.0);
+    assert_eq!(histogram.count(&["a"]), 3);
+    let mut out = String::new();
+    histogram.render(&mut out);
+    let expected = [
+      "test_seconds_bucket{op=\"a\",le=\"0.1\"} 1",
+      "test_seconds_bucket{op=\"a\",le=\"1\"} 2",
+      "test_seconds_bucket{op=\"a\",le=\"+Inf\"} 3",
+      "test_seconds_sum{op=\"a\"} 5.55",
+      "test_seconds_count{op=\"a\"} 3",
+    ];
+    for line in expected {
+      assert!(out.contains(line), "missing {} in {}", line, out);
+    }
+  }
+}
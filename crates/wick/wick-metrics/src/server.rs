@@ -0,0 +1,106 @@
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use hyper::header::CONTENT_TYPE;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use tokio::task::JoinHandle;
+
+use crate::Error;
+
+/// The path metrics are served on when none is configured.
+pub const DEFAULT_PATH: &str = "/metrics";
+
+const CONTENT_TYPE_TEXT: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// A running metrics server started with [serve].
+#[derive(Debug)]
+pub struct MetricsServer {
+  addr: SocketAddr,
+  task: JoinHandle<()>,
+}
+
+impl MetricsServer {
+  /// The address the server is bound to.
+  #[must_use]
+  pub const fn addr(&self) -> SocketAddr {
+    self.addr
+  }
+
+  /// Stop the server.
+  pub fn stop(self) {
+    self.task.abort();
+  }
+}
+
+fn respond(path: &str, req: &Request<Body>) -> Response<Body> {
+  let builder = Response::builder();
+  let response = if req.uri().path() != path {
+    builder.status(StatusCode::NOT_FOUND).body(Body::empty())
+  } else if req.method() != Method::GET {
+    builder.status(StatusCode::METHOD_NOT_ALLOWED).body(Body::empty())
+  } else {
+    builder
+      .status(StatusCode::OK)
+      .header(CONTENT_TYPE, CONTENT_TYPE_TEXT)
+      .body(Body::from(crate::render()))
+  };
+  #[allow(clippy::expect_used)]
+  response.expect("static response parts are valid")
+}
+
+/// Serve metrics at `path` (or [DEFAULT_PATH]) on the passed address.
+///
+/// Must be called from within a Tokio runtime.
+pub fn serve(addr: SocketAddr, path: Option<String>) -> Result<MetricsServer, Error> {
+  let path: Arc<str> = path.unwrap_or_else(|| DEFAULT_PATH.to_owned()).into();
+  let server = Server::try_bind(&addr).map_err(|e| Error::Bind(addr, e.to_string()))?;
+  let make_svc = make_service_fn(move |_conn| {
+    let path = path.clone();
+    async move {
+      Ok::<_, Infallible>(service_fn(move |req| {
+        let response = respond(&path, &req);
+        async move { Ok::<_, Infallible>(response) }
+      }))
+    }
+  });
+  let server = server.serve(make_svc);
+  let addr = server.local_addr();
+  debug!(%addr, "metrics server started");
+  let task = tokio::spawn(async move {
+    if let Err(error) = server.await {
+      error!(%error, "metrics server failed");
+    }
+  });
+  Ok(MetricsServer { addr, task })
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use hyper::Client;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_serve() -> Result<()> {
+    crate::record_http_response("/api", "/users", 200);
+    let server = serve(([127, 0, 0, 1], 0).into(), None)?;
+    let client = Client::new();
+
+    let res = client
+      .get(format!("http://{}{}", server.addr(), DEFAULT_PATH).parse()?)
+      .await?;
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.headers()[CONTENT_TYPE], CONTENT_TYPE_TEXT);
+    let body = String::from_utf8(hyper::body::to_bytes(res.into_body()).await?.to_vec())?;
+    assert!(body.contains(r#"wick_http_responses_total{router="/api",route="/users",status="200"} 1"#));
+
+    let res = client.get(format!("http://{}/other", server.addr()).parse()?).await?;
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    server.stop();
+    Ok(())
+  }
+}
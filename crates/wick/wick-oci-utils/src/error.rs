@@ -105,7 +105,44 @@ pub enum OciError {
   #[error("Failed to push the package: {0}")]
   PushFailed(String),
 
+  /// Returned when a lockfile could not be read.
+  #[error("Invalid lockfile '{}': {1}", .0.display())]
+  Lockfile(PathBuf, String),
+
+  /// Returned when a pulled package does not match the content its lockfile pins it to.
+  #[error("'{reference}' does not match its lockfile, {item} should be {expected} but was {actual}")]
+  LockMismatch {
+    /// The reference that was pulled.
+    reference: String,
+    /// The part of the package that differs.
+    item: String,
+    /// The locked digest.
+    expected: String,
+    /// The pulled digest.
+    actual: String,
+  },
+
+  /// Returned when pulling a reference that is not in the lockfile while running in locked mode.
+  #[error("'{0}' is not pinned in wick.lock, run `wick lock` to update it")]
+  NotLocked(String),
+
+  /// Returned when an OCI image layout directory could not be read or written.
+  #[error("Invalid OCI image layout '{}': {1}", .0.display())]
+  Layout(PathBuf, String),
+
+  /// Returned when a reference is not in an OCI image layout.
+  #[error("'{0}' was not found in OCI image layout '{}'", .1.display())]
+  NotInLayout(String, PathBuf),
+
   /// Returned when a pull would overwrite existing files and 'overwrite' is not set.
   #[error("Refusing to overwrite {}. Set 'overwrite' to true to force.", .0.iter().map(|v|v.display().to_string()).collect::<Vec<_>>().join(", "))]
   WouldOverwrite(Vec<PathBuf>),
+
+  /// Returned when a package could not be signed.
+  #[error("Failed to sign package: {0}")]
+  Sign(String),
+
+  /// Returned when a pulled package fails the signature policy for its reference.
+  #[error("'{0}' failed signature verification: {1}")]
+  SignatureRejected(String, String),
 }
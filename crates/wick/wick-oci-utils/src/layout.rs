@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use oci_distribution::client::{Config, ImageData, ImageLayer};
+use oci_distribution::manifest::{ImageIndexEntry, OciImageIndex, OciImageManifest};
+use oci_distribution::Reference;
+
+use crate::package::{annotations, media_types};
+use crate::Error;
+
+/// The scheme for locations that point into an OCI image layout, e.g. `oci-layout://./vendor#org/component:0.1.0`.
+pub const LAYOUT_SCHEME: &str = "oci-layout://";
+
+const LAYOUT_FILE: &str = "oci-layout";
+const INDEX_FILE: &str = "index.json";
+const LAYOUT_VERSION: &str = r#"{"imageLayoutVersion":"1.0.0"}"#;
+
+/// A directory that stores packages in the [OCI image layout](https://github.com/opencontainers/image-spec/blob/main/image-layout.md) format.
+///
+/// Images are tagged in the layout's index with their fully qualified reference so one layout can hold packages from
+/// any number of registries and repositories.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct OciLayout {
+  root: PathBuf,
+}
+
+impl OciLayout {
+  /// Create a new [OciLayout] rooted at the passed directory.
+  pub fn new<T: Into<PathBuf>>(root: T) -> Self {
+    Self { root: root.into() }
+  }
+
+  /// Split an `oci-layout://<dir>#<reference>` location into its layout and reference.
+  #[must_use]
+  pub fn parse_location(location: &str) -> Option<(Self, &str)> {
+    let (dir, reference) = location.strip_prefix(LAYOUT_SCHEME)?.split_once('#')?;
+    Some((Self::new(dir), reference))
+  }
+
+  /// The directory the layout is stored in.
+  #[must_use]
+  pub fn root(&self) -> &Path {
+    &self.root
+  }
+
+  /// Get the `oci-layout://` location of a reference in this layout.
+  #[must_use]
+  pub fn location(&self, reference: &str) -> String {
+    format!("{}{}#{}", LAYOUT_SCHEME, self.root.display(), reference)
+  }
+
+  /// List the references stored in the layout.
+  pub async fn references(&self) -> Result<Vec<String>, Error> {
+    let index = self.read_index().await?;
+    Ok(index.manifests.iter().filter_map(ref_name).map(ToOwned::to_owned).collect())
+  }
+
+  /// Read an image from the layout.
+  ///
+  /// Every blob is checked against its digest as it is read.
+  pub async fn pull(&self, reference: &Reference) -> Result<ImageData, Error> {
+    let name = reference.whole();
+    let index = self.read_index().await?;
+    let entry = index
+      .manifests
+      .iter()
+      .find(|e| ref_name(e) == Some(name.as_str()))
+      .ok_or_else(|| Error::NotInLayout(name.clone(), self.root.clone()))?;
+
+    let manifest: OciImageManifest = serde_json::from_slice(&self.read_blob(&entry.digest).await?)
+      .map_err(|e| self.error(format!("invalid manifest for '{}': {}", name, e)))?;
+    let config = Config::new(
+      self.read_blob(&manifest.config.digest).await?,
+      manifest.config.media_type.clone(),
+      None,
+    );
+    let mut layers = Vec::with_capacity(manifest.layers.len());
+    for layer in &manifest.layers {
+      layers.push(ImageLayer::new(
+        self.read_blob(&layer.digest).await?,
+        layer.media_type.clone(),
+        layer.annotations.clone(),
+      ));
+    }
+
+    let digest = entry
+      .annotations
+      .as_ref()
+      .and_then(|a| a.get(annotations::SOURCE_DIGEST))
+      .unwrap_or(&entry.digest)
+      .clone();
+
+    Ok(ImageData {
+      layers,
+      digest: Some(digest),
+      config,
+      manifest: Some(manifest),
+    })
+  }
+
+  /// Write an image to the layout and tag it with the passed reference, replacing any image already tagged with it.
+  ///
+  /// Pass `source_digest` when copying an image from a registry so pulls from the layout report the digest the
+  /// registry served it with. Returns the digest of the manifest in the layout.
+  pub async fn push(
+    &self,
+    reference: &Reference,
+    manifest: &OciImageManifest,
+    config: &Config,
+    layers: &[ImageLayer],
+    source_digest: Option<&str>,
+  ) -> Result<String, Error> {
+    self.init().await?;
+    for layer in layers {
+      self.write_blob(&layer.data).await?;
+    }
+    self.write_blob(&config.data).await?;
+    let manifest_bytes = serde_json::to_vec(manifest)?;
+    let digest = self.write_blob(&manifest_bytes).await?;
+
+    let name = reference.whole();
+    let mut entry_annotations = HashMap::from([(annotations::REF_NAME.to_owned(), name.clone())]);
+    if let Some(source_digest) = source_digest {
+      entry_annotations.insert(annotations::SOURCE_DIGEST.to_owned(), source_digest.to_owned());
+    }
+
+    let mut index = self.read_index().await?;
+    index.manifests.retain(|e| ref_name(e) != Some(name.as_str()));
+    index.manifests.push(ImageIndexEntry {
+      media_type: media_types::MANIFEST.to_owned(),
+      digest: digest.clone(),
+      size: manifest_bytes.len() as i64,
+      platform: None,
+      annotations: Some(entry_annotations),
+    });
+    self.write(INDEX_FILE, &serde_json::to_vec_pretty(&index)?).await?;
+
+    debug!(reference = %name, %digest, layout = %self.root.display(), "wrote image to layout");
+    Ok(digest)
+  }
+
+  async fn init(&self) -> Result<(), Error> {
+    if !self.root.join(LAYOUT_FILE).exists() {
+      self.write(LAYOUT_FILE, LAYOUT_VERSION.as_bytes()).await?;
+    }
+    Ok(())
+  }
+
+  async fn read_index(&self) -> Result<OciImageIndex, Error> {
+    let path = self.root.join(INDEX_FILE);
+    if !path.exists() {
+      return Ok(OciImageIndex {
+        schema_version: 2,
+        media_type: Some(media_types::INDEX.to_owned()),
+        manifests: Vec::new(),
+        annotations: None,
+      });
+    }
+    let bytes = tokio::fs::read(&path).await.map_err(|e| self.error(e))?;
+    serde_json::from_slice(&bytes).map_err(|e| self.error(format!("invalid {}: {}", INDEX_FILE, e)))
+  }
+
+  async fn read_blob(&self, digest: &str) -> Result<Vec<u8>, Error> {
+    let bytes = tokio::fs::read(self.blob_path(digest)?)
+      .await
+      .map_err(|e| self.error(format!("could not read blob {}: {}", digest, e)))?;
+    let actual = format!("sha256:{}", sha256::digest(bytes.as_slice()));
+    if actual != digest {
+      return Err(self.error(format!("blob {} has digest {}", digest, actual)));
+    }
+    Ok(bytes)
+  }
+
+  async fn write_blob(&self, data: &[u8]) -> Result<String, Error> {
+    let digest = format!("sha256:{}", sha256::digest(data));
+    let path = self.blob_path(&digest)?;
+    if !path.exists() {
+      let relative = path.strip_prefix(&self.root).unwrap_or(&path).to_path_buf();
+      self.write(relative, data).await?;
+    }
+    Ok(digest)
+  }
+
+  async fn write<T: AsRef<Path> + Send>(&self, relative: T, data: &[u8]) -> Result<(), Error> {
+    let path = self.root.join(relative);
+    if let Some(parent) = path.parent() {
+      tokio::fs::create_dir_all(parent)
+        .await
+        .map_err(|e| Error::CreateDir(parent.to_path_buf(), e))?;
+    }
+    tokio::fs::write(&path, data)
+      .await
+      .map_err(|e| Error::WriteFile(path, e))
+  }
+
+  fn blob_path(&self, digest: &str) -> Result<PathBuf, Error> {
+    match digest.split_once(':') {
+      Some(("sha256", hex)) if !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+        Ok(self.root.join("blobs").join("sha256").join(hex))
+      }
+      _ => Err(self.error(format!("unsupported digest '{}'", digest))),
+    }
+  }
+
+  fn error<T: std::fmt::Display>(&self, msg: T) -> Error {
+    Error::Layout(self.root.clone(), msg.to_string())
+  }
+}
+
+fn ref_name(entry: &ImageIndexEntry) -> Option<&str> {
+  entry
+    .annotations
+    .as_ref()
+    .and_then(|a| a.get(annotations::REF_NAME))
+    .map(String::as_str)
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use oci_distribution::manifest::OciDescriptor;
+
+  use super::*;
+
+  fn image() -> (OciImageManifest, Config, Vec<ImageLayer>) {
+    let config = Config::new(b"{}".to_vec(), media_types::CONFIG.to_owned(), None);
+    let layer = ImageLayer::new(b"name: test".to_vec(), media_types::COMPONENT.to_owned(), None);
+    let manifest = OciImageManifest {
+      schema_version: 2,
+      media_type: Some(media_types::MANIFEST.to_owned()),
+      config: OciDescriptor {
+        media_type: config.media_type.clone(),
+        digest: config.sha256_digest(),
+        size: config.data.len() as i64,
+        urls: None,
+        annotations: None,
+      },
+      layers: vec![OciDescriptor {
+        media_type: layer.media_type.clone(),
+        digest: layer.sha256_digest(),
+        size: layer.data.len() as i64,
+        urls: None,
+        annotations: Some(HashMap::from([(
+          annotations::TITLE.to_owned(),
+          "component.wick".to_owned(),
+        )])),
+      }],
+      annotations: None,
+    };
+    (manifest, config, vec![layer])
+  }
+
+  fn temp_layout(name: &str) -> OciLayout {
+    OciLayout::new(std::env::temp_dir().join(format!("wick-layout-{}-{}", name, std::process::id())))
+  }
+
+  #[test]
+  fn test_parse_location() {
+    let (layout, reference) = OciLayout::parse_location("oci-layout://./vendor#org/component:0.1.0").unwrap();
+    assert_eq!(layout.root(), Path::new("./vendor"));
+    assert_eq!(reference, "org/component:0.1.0");
+    assert_eq!(layout.location(reference), "oci-layout://./vendor#org/component:0.1.0");
+    assert!(OciLayout::parse_location("oci-layout://./vendor").is_none());
+    assert!(OciLayout::parse_location("org/component:0.1.0").is_none());
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_roundtrip() -> Result<()> {
+    let layout = temp_layout("roundtrip");
+    let reference = crate::parse_reference("example.com/org/component:0.1.0")?;
+    let (manifest, config, layers) = image();
+
+    let digest = layout.push(&reference, &manifest, &config, &layers, None).await?;
+    layout.push(&reference, &manifest, &config, &layers, None).await?;
+    let pulled = layout.pull(&reference).await?;
+    let references = layout.references().await?;
+    let missing = layout.pull(&crate::parse_reference("example.com/org/other:0.1.0")?).await;
+    tokio::fs::remove_dir_all(layout.root()).await?;
+
+    assert_eq!(references, vec!["example.com/org/component:0.1.0".to_owned()]);
+    assert_eq!(pulled.digest, Some(digest));
+    assert_eq!(pulled.layers[0].data, layers[0].data);
+    assert_eq!(pulled.config.data, config.data);
+    assert!(matches!(missing, Err(Error::NotInLayout(..))));
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_source_digest_and_tampering() -> Result<()> {
+    let layout = temp_layout("tamper");
+    let reference = crate::parse_reference("example.com/org/component:0.1.0")?;
+    let (manifest, config, layers) = image();
+
+    layout
+      .push(&reference, &manifest, &config, &layers, Some("sha256:abcd"))
+      .await?;
+    let pulled = layout.pull(&reference).await?;
+    tokio::fs::write(layout.blob_path(&layers[0].sha256_digest())?, b"tampered").await?;
+    let tampered = layout.pull(&reference).await;
+    tokio::fs::remove_dir_all(layout.root()).await?;
+
+    assert_eq!(pulled.digest.as_deref(), Some("sha256:abcd"));
+    assert!(matches!(tampered, Err(Error::Layout(..))));
+    Ok(())
+  }
+}
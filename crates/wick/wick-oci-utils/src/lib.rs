@@ -116,21 +116,27 @@
 
 /// This crate's error module.
 pub mod error;
+mod layout;
+mod lockfile;
 mod manifest;
 mod options;
 /// OCI utilities related to pushing and pulling Wick packages.
 pub mod package;
 mod pull;
 mod push;
+mod signature;
 pub mod utils;
 
 pub use error::OciError as Error;
+pub use layout::*;
+pub use lockfile::*;
 pub use manifest::*;
 pub use oci_distribution::client::ClientProtocol;
 pub use oci_distribution::manifest::{OciDescriptor, OciImageIndex, OciImageManifest, OciManifest};
 pub use options::*;
 pub use pull::*;
 pub use push::*;
+pub use signature::*;
 use serde::{Deserialize, Serialize};
 pub use utils::{
   get_cache_directory,
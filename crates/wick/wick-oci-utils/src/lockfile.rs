@@ -0,0 +1,282 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// The contents of a `wick.lock` file, pinning OCI references to the content they resolved to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[must_use]
+#[non_exhaustive]
+pub struct LockFile {
+  /// The version of the lockfile format.
+  pub version: u32,
+  /// Locked packages, keyed by their fully qualified reference.
+  #[serde(default)]
+  pub packages: BTreeMap<String, LockedPackage>,
+}
+
+impl Default for LockFile {
+  fn default() -> Self {
+    Self {
+      version: Self::VERSION,
+      packages: BTreeMap::new(),
+    }
+  }
+}
+
+impl LockFile {
+  /// The name of a lockfile that sits alongside a manifest.
+  pub const FILENAME: &str = "wick.lock";
+
+  /// The lockfile format version this crate reads and writes.
+  pub const VERSION: u32 = 1;
+
+  const HEADER: &str = "# This file is generated by `wick lock`. Do not edit it by hand.\n";
+
+  /// Read a lockfile from disk.
+  pub async fn load(path: &Path) -> Result<Self, Error> {
+    let contents = tokio::fs::read_to_string(path)
+      .await
+      .map_err(|e| Error::Lockfile(path.to_path_buf(), e.to_string()))?;
+    let lockfile: Self =
+      serde_yaml::from_str(&contents).map_err(|e| Error::Lockfile(path.to_path_buf(), e.to_string()))?;
+    if lockfile.version != Self::VERSION {
+      return Err(Error::Lockfile(
+        path.to_path_buf(),
+        format!("unsupported lockfile version {}", lockfile.version),
+      ));
+    }
+    Ok(lockfile)
+  }
+
+  /// Write the lockfile to disk.
+  pub async fn save(&self, path: &Path) -> Result<(), Error> {
+    let contents = format!("{}{}", Self::HEADER, serde_yaml::to_string(self)?);
+    tokio::fs::write(path, contents)
+      .await
+      .map_err(|e| Error::WriteFile(path.to_path_buf(), e))
+  }
+
+  /// Get the locked package for a reference.
+  #[must_use]
+  pub fn get(&self, reference: &str) -> Option<&LockedPackage> {
+    self.packages.get(reference)
+  }
+}
+
+/// A package pinned to a manifest digest and the digests of its layers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct LockedPackage {
+  /// The digest of the package's OCI manifest.
+  pub digest: String,
+  /// The package's layers.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub layers: Vec<LockedLayer>,
+}
+
+impl LockedPackage {
+  /// Create a new [LockedPackage].
+  #[must_use]
+  pub fn new(digest: impl Into<String>, layers: Vec<LockedLayer>) -> Self {
+    Self {
+      digest: digest.into(),
+      layers,
+    }
+  }
+
+  /// Verify that `actual` is the same content as this locked package.
+  pub fn verify(&self, reference: &str, actual: &LockedPackage) -> Result<(), Error> {
+    let mismatch = |item: String, expected: &str, actual: &str| Error::LockMismatch {
+      reference: reference.to_owned(),
+      item,
+      expected: expected.to_owned(),
+      actual: actual.to_owned(),
+    };
+    if self.digest != actual.digest {
+      return Err(mismatch("manifest digest".to_owned(), &self.digest, &actual.digest));
+    }
+    for layer in &self.layers {
+      let found = actual.layers.iter().find(|l| l.path == layer.path);
+      let found_digest = found.map_or("<missing>", |l| l.digest.as_str());
+      if found_digest != layer.digest {
+        return Err(mismatch(format!("layer '{}'", layer.path), &layer.digest, found_digest));
+      }
+    }
+    if let Some(extra) = actual
+      .layers
+      .iter()
+      .find(|l| !self.layers.iter().any(|o| o.path == l.path))
+    {
+      return Err(mismatch(format!("layer '{}'", extra.path), "<missing>", &extra.digest));
+    }
+    Ok(())
+  }
+}
+
+/// A single file in a locked package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct LockedLayer {
+  /// The path of the layer within the package.
+  pub path: String,
+  /// The digest of the layer's contents.
+  pub digest: String,
+}
+
+impl LockedLayer {
+  /// Create a new [LockedLayer].
+  #[must_use]
+  pub fn new(path: impl Into<String>, digest: impl Into<String>) -> Self {
+    Self {
+      path: path.into(),
+      digest: digest.into(),
+    }
+  }
+}
+
+/// How pulls treat a lockfile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum LockMode {
+  /// Pull locked references at their locked digest and resolve references that aren't locked.
+  #[default]
+  Verify,
+  /// Like [LockMode::Verify] but fail on references that aren't locked.
+  Locked,
+  /// Ignore locked digests and resolve every reference again.
+  Update,
+}
+
+/// A lockfile shared between pulls that also records what each pull resolved to.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct Lock {
+  mode: LockMode,
+  pinned: Arc<LockFile>,
+  resolved: Arc<Mutex<LockFile>>,
+}
+
+impl Lock {
+  /// Create a new [Lock] from the passed [LockFile].
+  pub fn new(pinned: LockFile, mode: LockMode) -> Self {
+    Self {
+      mode,
+      pinned: Arc::new(pinned),
+      resolved: Default::default(),
+    }
+  }
+
+  /// Get the [LockMode] pulls should use.
+  #[must_use]
+  pub const fn mode(&self) -> LockMode {
+    self.mode
+  }
+
+  /// Get the package a reference must resolve to, if it is pinned.
+  #[must_use]
+  pub fn pinned(&self, reference: &str) -> Option<&LockedPackage> {
+    match self.mode {
+      LockMode::Update => None,
+      LockMode::Verify | LockMode::Locked => self.pinned.get(reference),
+    }
+  }
+
+  pub(crate) fn record(&self, reference: String, package: LockedPackage) {
+    let _ = self.resolved.lock().unwrap().packages.insert(reference, package);
+  }
+
+  /// Get a [LockFile] containing every reference pulled so far.
+  pub fn resolved(&self) -> LockFile {
+    self.resolved.lock().unwrap().clone()
+  }
+
+  /// Get the references pulled so far that the lockfile does not pin.
+  #[must_use]
+  pub fn unpinned(&self) -> Vec<String> {
+    self
+      .resolved
+      .lock()
+      .unwrap()
+      .packages
+      .keys()
+      .filter(|r| self.pinned(r).is_none())
+      .cloned()
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  fn package() -> LockedPackage {
+    LockedPackage::new(
+      "sha256:aaaa",
+      vec![
+        LockedLayer::new("component.wick", "sha256:bbbb"),
+        LockedLayer::new("build/component.wasm", "sha256:cccc"),
+      ],
+    )
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_roundtrip() -> Result<()> {
+    let mut lockfile = LockFile::default();
+    lockfile
+      .packages
+      .insert("registry.candle.dev/test/component:0.1.0".to_owned(), package());
+    let path = std::env::temp_dir().join(format!("wick-lock-{}.lock", std::process::id()));
+
+    lockfile.save(&path).await?;
+    let loaded = LockFile::load(&path).await?;
+    tokio::fs::remove_file(&path).await?;
+
+    assert_eq!(loaded, lockfile);
+    Ok(())
+  }
+
+  #[test]
+  fn test_verify() -> Result<()> {
+    let reference = "registry.candle.dev/test/component:0.1.0";
+    let locked = package();
+    locked.verify(reference, &package())?;
+
+    let mut actual = package();
+    actual.digest = "sha256:dddd".to_owned();
+    let err = locked.verify(reference, &actual).unwrap_err();
+    assert!(matches!(err, Error::LockMismatch { item, .. } if item == "manifest digest"));
+
+    let mut actual = package();
+    actual.layers[1].digest = "sha256:dddd".to_owned();
+    let err = locked.verify(reference, &actual).unwrap_err();
+    assert!(matches!(err, Error::LockMismatch { item, .. } if item == "layer 'build/component.wasm'"));
+
+    let mut actual = package();
+    actual.layers.push(LockedLayer::new("extra.txt", "sha256:eeee"));
+    let err = locked.verify(reference, &actual).unwrap_err();
+    assert!(matches!(err, Error::LockMismatch { item, .. } if item == "layer 'extra.txt'"));
+    Ok(())
+  }
+
+  #[test]
+  fn test_modes() {
+    let reference = "registry.candle.dev/test/component:0.1.0";
+    let mut lockfile = LockFile::default();
+    lockfile.packages.insert(reference.to_owned(), package());
+
+    assert!(Lock::new(lockfile.clone(), LockMode::Verify).pinned(reference).is_some());
+    assert!(Lock::new(lockfile.clone(), LockMode::Locked).pinned(reference).is_some());
+    let lock = Lock::new(lockfile, LockMode::Update);
+    assert!(lock.pinned(reference).is_none());
+
+    lock.record(reference.to_owned(), package());
+    assert_eq!(lock.resolved().get(reference), Some(&package()));
+    assert_eq!(lock.unpinned(), vec![reference.to_owned()]);
+  }
+}
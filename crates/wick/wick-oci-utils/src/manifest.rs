@@ -2,17 +2,34 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use crate::LockedPackage;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[must_use]
 pub struct AssetManifest {
   pub(crate) root: PathBuf,
   pub(crate) version: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) package: Option<LockedPackage>,
 }
 
 impl AssetManifest {
   pub const FILENAME: &str = ".wick-manifest.json";
   pub const fn new(root: PathBuf, version: String) -> Self {
-    Self { root, version }
+    Self {
+      root,
+      version,
+      package: None,
+    }
+  }
+
+  /// Record the digests the cached package was pulled with.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_package(self, package: LockedPackage) -> Self {
+    Self {
+      package: Some(package),
+      ..self
+    }
   }
 
   #[must_use]
@@ -24,4 +41,10 @@ impl AssetManifest {
   pub const fn version(&self) -> &String {
     &self.version
   }
+
+  /// Get the digests the cached package was pulled with, if they were recorded.
+  #[must_use]
+  pub const fn package(&self) -> Option<&LockedPackage> {
+    self.package.as_ref()
+  }
 }
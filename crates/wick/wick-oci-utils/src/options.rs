@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use oci_distribution::secrets::RegistryAuth;
 
+use crate::{Lock, SignaturePolicy};
+
 #[derive(Clone, Debug, Copy, serde::Serialize)]
 #[non_exhaustive]
 pub enum OnExisting {
@@ -29,6 +31,15 @@ pub struct OciOptions {
   pub(crate) on_existing: OnExisting,
   #[getset(get = "pub", set = "pub")]
   pub(crate) ignore_manifest: bool,
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) locked: bool,
+  #[getset(get = "pub", set = "pub")]
+  #[serde(skip)]
+  pub(crate) lock: Option<Lock>,
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) mirror: Option<PathBuf>,
+  #[getset(get = "pub", set = "pub")]
+  pub(crate) signatures: Vec<SignaturePolicy>,
 }
 
 impl Default for OciOptions {
@@ -43,6 +54,10 @@ impl Default for OciOptions {
       cache_dir: xdg.global().cache().clone(),
       on_existing: OnExisting::Ignore,
       ignore_manifest: false,
+      locked: false,
+      lock: None,
+      mirror: None,
+      signatures: vec![],
     }
   }
 }
@@ -64,6 +79,10 @@ impl std::fmt::Debug for OciOptions {
       .field("allow_insecure", &self.allow_insecure)
       .field("username", &self.username)
       .field("password", &self.password.as_ref().map(|_| "********"))
+      .field("locked", &self.locked)
+      .field("lock", &self.lock.as_ref().map(Lock::mode))
+      .field("mirror", &self.mirror)
+      .field("signatures", &self.signatures)
       .finish()
   }
 }
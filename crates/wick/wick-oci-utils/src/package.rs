@@ -1,10 +1,12 @@
 mod pull;
 mod push;
+mod vendor;
 
 use std::path::PathBuf;
 
 pub use pull::*;
 pub use push::*;
+pub use vendor::*;
 /// Annotation types associated with Wick packages.
 pub mod annotations;
 /// Media types associated with Wick packages.
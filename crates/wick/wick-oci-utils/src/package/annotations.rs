@@ -18,6 +18,14 @@ pub const DOCUMENTATION: &str = "org.opencontainers.image.documentation";
 pub const LICENSES: &str = "org.opencontainers.image.licenses";
 /// Wick package title annotation string.
 pub const TITLE: &str = "org.opencontainers.image.title";
+/// OCI image layout reference name annotation string.
+pub const REF_NAME: &str = "org.opencontainers.image.ref.name";
+/// Annotation string holding the registry digest of a package copied into an OCI image layout.
+pub const SOURCE_DIGEST: &str = "dev.candle.wick.package.source-digest";
+/// Annotation string holding the base64 encoded signature of a Wick package.
+pub const SIGNATURE: &str = "dev.candle.wick.package.signature";
+/// Annotation string holding the public key that signed a Wick package.
+pub const SIGNER: &str = "dev.candle.wick.package.signer";
 
 #[derive(Debug, Clone)]
 /// Annotation object for Wick packages.
@@ -34,4 +42,9 @@ impl Annotations {
   pub const fn new(map: HashMap<String, String>) -> Self {
     Self(map)
   }
+
+  /// Add or replace an annotation.
+  pub fn insert(&mut self, key: &str, value: String) {
+    self.0.insert(key.to_owned(), value);
+  }
 }
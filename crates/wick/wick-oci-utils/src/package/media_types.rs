@@ -2,6 +2,8 @@
 pub const CONFIG: &str = "application/vnd.wick.config.v1+yaml";
 /// Wick manifest media type.
 pub const MANIFEST: &str = "application/vnd.oci.image.manifest.v1+json";
+/// OCI image index media type.
+pub const INDEX: &str = "application/vnd.oci.image.index.v1+json";
 /// Wick application configuration media type.
 pub const APPLICATION: &str = "application/vnd.wick.application.v1+yaml";
 /// Wick component configuration media type.
@@ -1,14 +1,15 @@
 use std::io::Cursor;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use flate2::bufread::GzDecoder;
-use oci_distribution::client::ClientConfig;
-use oci_distribution::Client;
+use oci_distribution::client::{ClientConfig, ClientProtocol, ImageData};
+use oci_distribution::{Client, Reference};
 use tar::Archive;
 
 use super::{annotations, media_types};
+use crate::signature::check_signature;
 use crate::utils::{create_directory_structure, get_cache_directory};
-use crate::{AssetManifest, Error, OciOptions, OnExisting};
+use crate::{AssetManifest, Error, LockMode, LockedLayer, LockedPackage, OciLayout, OciOptions, OnExisting};
 
 /// Result of a pull operation.
 #[derive(Debug, Clone)]
@@ -20,11 +21,19 @@ pub struct PullResult {
   pub root_path: PathBuf,
   /// Whether the package was pulled from the cache.
   pub cached: bool,
+  /// The digest of the package's manifest, if known.
+  pub digest: Option<String>,
 }
 
 #[allow(clippy::too_many_lines)]
 /// Pull a Wick package from a registry.
+///
+/// If the passed [OciOptions] hold a [crate::Lock], references it pins are pulled by digest and verified against it.
+///
+/// References in the form `oci-layout://<dir>#<reference>` and all references when [OciOptions] has a mirror
+/// are read from an [OciLayout] instead.
 pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, Error> {
+  let (layout, reference) = image_source(reference, options);
   let (image_ref, protocol) = crate::utils::parse_reference_and_protocol(reference, &options.allow_insecure)?;
 
   let cache_dir = if options.flatten {
@@ -33,66 +42,56 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
     get_cache_directory(reference, &options.cache_dir)?
   };
 
+  let lock_key = image_ref.whole();
+  let pinned = options.lock.as_ref().and_then(|l| l.pinned(&lock_key)).cloned();
+  if pinned.is_none() && options.lock.as_ref().map_or(false, |l| l.mode() == LockMode::Locked) {
+    return Err(Error::NotLocked(lock_key));
+  }
+
   let manifest_file = cache_dir.join(AssetManifest::FILENAME);
 
-  if !options.ignore_manifest && manifest_file.exists() {
+  let use_cache = !options.ignore_manifest && !options.lock.as_ref().map_or(false, |l| l.mode() == LockMode::Update);
+  if use_cache && manifest_file.exists() {
     debug!(cache_hit = true, "remote asset");
     let json = tokio::fs::read_to_string(&manifest_file).await?;
 
     //check if manifest file is valid json, if not then break out of if statement and continue
     let manifest: Result<AssetManifest, serde_json::Error> = serde_json::from_str(&json);
-    if let Err(e) = manifest {
-      warn!(
-        "Invalid root manifest file at {}, repulling - error was {}",
-        manifest_file.display(),
-        e
-      );
-    } else {
-      //check if manifest.root file exists, if it does then return otherwise continue
-      let root_file = manifest.unwrap().root;
-      let root_filepath = cache_dir.join(&root_file);
-      debug!(file = %root_filepath.display(), "using cache");
-      if root_filepath.exists() {
-        return Ok(PullResult {
-          cached: true,
-          base_dir: cache_dir.clone(),
-          root_path: root_file,
-        });
+    match manifest {
+      Err(e) => {
+        warn!(
+          "Invalid root manifest file at {}, repulling - error was {}",
+          manifest_file.display(),
+          e
+        );
+      }
+      Ok(manifest) => {
+        //check if manifest.root file exists, if it does then return otherwise continue
+        let root_filepath = cache_dir.join(&manifest.root);
+        debug!(file = %root_filepath.display(), "using cache");
+        if root_filepath.exists() && cache_satisfies_lock(&cache_dir, &manifest, options, pinned.as_ref()).await {
+          let digest = manifest.package.as_ref().map(|p| p.digest.clone());
+          if let (Some(lock), Some(package)) = (&options.lock, manifest.package) {
+            lock.record(lock_key, package);
+          }
+          return Ok(PullResult {
+            cached: true,
+            base_dir: cache_dir.clone(),
+            root_path: manifest.root,
+            digest,
+          });
+        }
       }
     }
   }
   debug!(cache_hit = false, "remote asset");
 
-  let client_config = ClientConfig {
-    protocol,
-    ..Default::default()
-  };
+  let (image_data, pulled) = pull_image(layout.as_ref(), &image_ref, pinned.as_ref(), protocol, options).await?;
 
-  let mut client = Client::new(client_config);
-  let auth = options.get_auth();
-
-  let accepted_media_types = vec![
-    media_types::CONFIG,
-    media_types::MANIFEST,
-    media_types::APPLICATION,
-    media_types::COMPONENT,
-    media_types::TESTS,
-    media_types::TYPES,
-    media_types::WASM,
-    media_types::OTHER,
-    media_types::TARGZ,
-  ];
-
-  let result = client.pull(&image_ref, &auth, accepted_media_types).await;
-
-  let image_data = match result {
-    Ok(pull_response) => {
-      debug!(reference = %image_ref.whole(), "image successfully pulled from the registry");
-      pull_response
-    }
-    Err(e) => {
-      return Err(Error::PullFailed(e.to_string()));
-    }
+  // A lock decides what the cache should contain, so stale files from a previous pull must be replaced.
+  let on_existing = match options.on_existing {
+    OnExisting::Ignore if options.lock.is_some() => OnExisting::Overwrite,
+    on_existing => on_existing,
   };
 
   let version = match image_data.manifest {
@@ -125,7 +124,7 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
     }
   }
 
-  if !would_overwrite.is_empty() && matches!(options.on_existing, OnExisting::Error) {
+  if !would_overwrite.is_empty() && matches!(on_existing, OnExisting::Error) {
     return Err(Error::WouldOverwrite(would_overwrite));
   }
 
@@ -156,7 +155,7 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
         .map_err(|e| Error::CreateDir(parent_dir.to_path_buf(), e))?;
 
       if layer_path.exists() {
-        match options.on_existing {
+        match on_existing {
           OnExisting::Ignore => {
             debug!(file = %layer_path.display(), bytes = layer.data.len(), "cache:not_overwriting");
           }
@@ -199,8 +198,13 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
 
   let root_file = root_file.ok_or_else(|| Error::PackageReadFailed("No root file found".to_owned()))?;
 
+  let digest = Some(pulled.digest.clone());
+  if let Some(lock) = &options.lock {
+    lock.record(lock_key, pulled.clone());
+  }
+
   if !options.ignore_manifest {
-    let manifest = AssetManifest::new(PathBuf::from(&root_file), version);
+    let manifest = AssetManifest::new(PathBuf::from(&root_file), version).with_package(pulled);
     let contents = serde_json::to_string(&manifest).unwrap();
     tokio::fs::write(cache_dir.join(AssetManifest::FILENAME), contents).await?;
   }
@@ -210,9 +214,125 @@ pub async fn pull(reference: &str, options: &OciOptions) -> Result<PullResult, E
     cached: false,
     base_dir: cache_dir.clone(),
     root_path: PathBuf::from(root_file),
+    digest,
   })
 }
 
+/// Get the layout a reference should be read from, if any, and the reference without its `oci-layout://` prefix.
+pub(super) fn image_source<'a>(reference: &'a str, options: &OciOptions) -> (Option<OciLayout>, &'a str) {
+  OciLayout::parse_location(reference).map_or_else(
+    || (options.mirror.as_ref().map(OciLayout::new), reference),
+    |(layout, reference)| (Some(layout), reference),
+  )
+}
+
+/// Pull an image from its layout or registry and check it against the package it is pinned to.
+pub(super) async fn pull_image(
+  layout: Option<&OciLayout>,
+  image_ref: &Reference,
+  pinned: Option<&LockedPackage>,
+  protocol: ClientProtocol,
+  options: &OciOptions,
+) -> Result<(ImageData, LockedPackage), Error> {
+  let image_data = if let Some(layout) = layout {
+    debug!(reference = %image_ref.whole(), layout = %layout.root().display(), "pulling from OCI image layout");
+    layout.pull(image_ref).await?
+  } else {
+    let pull_ref = pinned.map_or_else(
+      || image_ref.clone(),
+      |p| Reference::with_digest(image_ref.registry().to_owned(), image_ref.repository().to_owned(), p.digest.clone()),
+    );
+    pull_from_registry(&pull_ref, protocol, options).await?
+  };
+
+  check_signature(&image_ref.whole(), &image_data, &options.signatures)?;
+
+  let pulled = LockedPackage::new(
+    image_data.digest.clone().unwrap_or_default(),
+    image_data
+      .layers
+      .iter()
+      .map(|layer| {
+        let title = layer.annotations.as_ref().and_then(|v| v.get(annotations::TITLE));
+        LockedLayer::new(title.map_or("", String::as_str), layer.sha256_digest())
+      })
+      .collect(),
+  );
+  if let Some(pinned) = pinned {
+    pinned.verify(&image_ref.whole(), &pulled)?;
+  }
+  Ok((image_data, pulled))
+}
+
+async fn pull_from_registry(
+  reference: &Reference,
+  protocol: ClientProtocol,
+  options: &OciOptions,
+) -> Result<ImageData, Error> {
+  let client_config = ClientConfig {
+    protocol,
+    ..Default::default()
+  };
+
+  let mut client = Client::new(client_config);
+  let auth = options.get_auth();
+
+  let accepted_media_types = vec![
+    media_types::CONFIG,
+    media_types::MANIFEST,
+    media_types::APPLICATION,
+    media_types::COMPONENT,
+    media_types::TESTS,
+    media_types::TYPES,
+    media_types::WASM,
+    media_types::OTHER,
+    media_types::TARGZ,
+  ];
+
+  let result = client.pull(reference, &auth, accepted_media_types).await;
+
+  match result {
+    Ok(pull_response) => {
+      debug!(reference = %reference.whole(), "image successfully pulled from the registry");
+      Ok(pull_response)
+    }
+    Err(e) => Err(Error::PullFailed(e.to_string())),
+  }
+}
+
+/// Check that a cached package can be used with the passed options' lock.
+async fn cache_satisfies_lock(
+  cache_dir: &Path,
+  manifest: &AssetManifest,
+  options: &OciOptions,
+  pinned: Option<&LockedPackage>,
+) -> bool {
+  if options.lock.is_none() {
+    return true;
+  }
+  // Caches written before digests were recorded can't be verified or locked.
+  let Some(cached) = &manifest.package else {
+    return false;
+  };
+  let Some(pinned) = pinned else {
+    return true;
+  };
+  if cached.digest != pinned.digest {
+    debug!(cached = %cached.digest, locked = %pinned.digest, "cache does not match lock");
+    return false;
+  }
+  for layer in &pinned.layers {
+    // Archive layers are unpacked, so only files stored as-is can be rehashed.
+    if let Ok(bytes) = tokio::fs::read(cache_dir.join(&layer.path)).await {
+      if format!("sha256:{}", sha256::digest(bytes.as_slice())) != layer.digest {
+        debug!(file = %layer.path, "cached file does not match lock");
+        return false;
+      }
+    }
+  }
+  true
+}
+
 #[cfg(test)]
 mod integration_test {
   use std::time::{SystemTime, UNIX_EPOCH};
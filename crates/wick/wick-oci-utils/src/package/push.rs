@@ -6,8 +6,8 @@ use sha256::digest;
 
 use super::annotations::Annotations;
 use super::{annotations, media_types, PackageFile};
-use crate::{Error, OciOptions};
-/// Push a Wick package to a registry.
+use crate::{Error, OciLayout, OciOptions};
+/// Push a Wick package to a registry or, for `oci-layout://<dir>#<reference>` references, an [OciLayout].
 pub async fn push(
   reference: &str,
   config_json: String,
@@ -67,6 +67,17 @@ pub async fn push(
     annotations: Some(image_annotations),
   };
 
+  if let Some((layout, reference)) = OciLayout::parse_location(reference) {
+    let image_ref = crate::parse_reference(reference)?;
+    let digest = layout
+      .push(&image_ref, &image_manifest, &image_config, &image_layers, None)
+      .await?;
+    return Ok(PushResponse {
+      config_url: layout.location(&image_config.sha256_digest()),
+      manifest_url: layout.location(&format!("{}@{}", image_ref.whole(), digest)),
+    });
+  }
+
   let (image_ref, protocol) = crate::utils::parse_reference_and_protocol(reference, &options.allow_insecure)?;
   let client_config = ClientConfig {
     protocol,
@@ -0,0 +1,87 @@
+use super::pull::{image_source, pull_image};
+use crate::{Error, OciLayout, OciOptions};
+
+/// Copy a Wick package into an [OciLayout] so it can be pulled without access to its registry.
+///
+/// If the passed [OciOptions] hold a [crate::Lock] that pins the reference, the pinned content is copied.
+/// Returns the digest the package's registry serves it with.
+pub async fn vendor(reference: &str, target: &OciLayout, options: &OciOptions) -> Result<String, Error> {
+  let (layout, reference) = image_source(reference, options);
+  let (image_ref, protocol) = crate::utils::parse_reference_and_protocol(reference, &options.allow_insecure)?;
+  let pinned = options.lock.as_ref().and_then(|l| l.pinned(&image_ref.whole())).cloned();
+
+  let (image_data, pulled) = pull_image(layout.as_ref(), &image_ref, pinned.as_ref(), protocol, options).await?;
+  let manifest = image_data.manifest.ok_or(Error::NoManifest)?;
+
+  target
+    .push(
+      &image_ref,
+      &manifest,
+      &image_data.config,
+      &image_data.layers,
+      Some(&pulled.digest),
+    )
+    .await?;
+  Ok(pulled.digest)
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+  use std::path::PathBuf;
+
+  use anyhow::Result;
+
+  use super::*;
+  use crate::package::annotations::{self, Annotations};
+  use crate::package::{media_types, pull, push, PackageFile};
+  use crate::{Lock, LockFile, LockMode, OnExisting};
+
+  async fn publish(layout: &OciLayout, reference: &str) -> Result<()> {
+    let contents = bytes::Bytes::from_static(b"kind: wick/component@v1\nname: test\n");
+    let file = PackageFile::new(
+      PathBuf::from("component.wick"),
+      format!("sha256:{}", sha256::digest(contents.as_ref())),
+      media_types::COMPONENT.to_owned(),
+      contents,
+    );
+    let config = r#"{"kind":"COMPONENT","root":"component.wick"}"#.to_owned();
+    let annotations = Annotations::new(HashMap::from([(annotations::VERSION.to_owned(), "0.1.0".to_owned())]));
+    push(&layout.location(reference), config, vec![file], annotations, &OciOptions::default()).await?;
+    Ok(())
+  }
+
+  #[test_logger::test(tokio::test)]
+  async fn test_vendor_offline() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("wick-vendor-{}", std::process::id()));
+    let upstream = OciLayout::new(dir.join("upstream"));
+    let vendored = OciLayout::new(dir.join("vendor"));
+    let reference = "example.com/org/component:0.1.0";
+    publish(&upstream, reference).await?;
+
+    let mut options = OciOptions::default();
+    options
+      .set_cache_dir(dir.join("cache"))
+      .set_on_existing(OnExisting::Overwrite)
+      .set_mirror(Some(upstream.root().to_path_buf()));
+    let digest = vendor(reference, &vendored, &options).await?;
+
+    let lock = Lock::new(LockFile::default(), LockMode::Update);
+    options.set_mirror(Some(vendored.root().to_path_buf())).set_lock(Some(lock.clone()));
+    let from_mirror = pull(reference, &options).await?;
+    let pinned = lock.resolved();
+
+    options
+      .set_mirror(None)
+      .set_cache_dir(dir.join("cache2"))
+      .set_lock(Some(Lock::new(pinned.clone(), LockMode::Locked)));
+    let from_location = pull(&vendored.location(reference), &options).await?;
+    let contents = tokio::fs::read_to_string(from_location.base_dir.join(&from_location.root_path)).await?;
+    tokio::fs::remove_dir_all(&dir).await?;
+
+    assert_eq!(from_mirror.root_path, PathBuf::from("component.wick"));
+    assert_eq!(pinned.get(reference).map(|p| p.digest.as_str()), Some(digest.as_str()));
+    assert_eq!(contents, "kind: wick/component@v1\nname: test\n");
+    Ok(())
+  }
+}
@@ -0,0 +1,241 @@
+use std::fmt::Write;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use nkeys::KeyPair;
+use oci_distribution::client::ImageData;
+use serde::Serialize;
+
+use crate::package::annotations::{self, Annotations};
+use crate::package::PackageFile;
+use crate::Error;
+
+/// Prefix of the signed payload, versioned so the format can change without old signatures verifying as new ones.
+static PAYLOAD_HEADER: &str = "wick-package-signature:v1";
+
+/// What to do with a pulled package whose signature can't be verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub enum SignatureMode {
+  /// Log a warning and use the package anyway.
+  Warn,
+  /// Refuse to use the package.
+  Enforce,
+}
+
+/// The issuers trusted to sign packages pulled from references that start with `scope`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[must_use]
+#[non_exhaustive]
+pub struct SignaturePolicy {
+  /// The reference prefix the policy applies to, e.g. `registry.candle.dev/common/`.
+  pub scope: String,
+  /// Public keys of the trusted signers.
+  pub issuers: Vec<String>,
+  /// How verification failures are handled.
+  pub mode: SignatureMode,
+}
+
+impl SignaturePolicy {
+  /// Create a new signature policy.
+  pub const fn new(scope: String, issuers: Vec<String>, mode: SignatureMode) -> Self {
+    Self { scope, issuers, mode }
+  }
+}
+
+/// Sign a package's config and files with the passed key, adding the signature and signer to its annotations.
+pub fn sign_package(
+  key: &KeyPair,
+  config_json: &str,
+  files: &[PackageFile],
+  annotations: &mut Annotations,
+) -> Result<(), Error> {
+  let layers: Vec<_> = files
+    .iter()
+    .map(|file| {
+      (
+        file.package_path().display().to_string(),
+        format!("sha256:{}", sha256::digest(file.contents())),
+      )
+    })
+    .collect();
+  let config_digest = format!("sha256:{}", sha256::digest(config_json));
+  let signature = key
+    .sign(&payload(&config_digest, layers))
+    .map_err(|e| Error::Sign(e.to_string()))?;
+
+  annotations.insert(annotations::SIGNER, key.public_key());
+  annotations.insert(annotations::SIGNATURE, STANDARD.encode(signature));
+  Ok(())
+}
+
+/// Check a pulled image against the policy for its reference, if there is one.
+///
+/// The most specific policy, the one with the longest matching scope, applies.
+pub(crate) fn check_signature(reference: &str, image: &ImageData, policies: &[SignaturePolicy]) -> Result<(), Error> {
+  let Some(policy) = policies
+    .iter()
+    .filter(|p| reference.starts_with(&p.scope))
+    .max_by_key(|p| p.scope.len())
+  else {
+    return Ok(());
+  };
+
+  match verify(image, &policy.issuers) {
+    Ok(signer) => {
+      debug!(reference, signer, "verified package signature");
+      Ok(())
+    }
+    Err(reason) => match policy.mode {
+      SignatureMode::Warn => {
+        warn!(
+          reference,
+          scope = policy.scope,
+          reason,
+          "package signature not verified"
+        );
+        Ok(())
+      }
+      SignatureMode::Enforce => Err(Error::SignatureRejected(reference.to_owned(), reason)),
+    },
+  }
+}
+
+/// Verify an image was signed by one of the passed issuers, returning the signer or why it could not be verified.
+fn verify(image: &ImageData, issuers: &[String]) -> Result<String, String> {
+  let manifest_annotations = image.manifest.as_ref().and_then(|m| m.annotations.as_ref());
+  let (Some(signer), Some(signature)) = (
+    manifest_annotations.and_then(|a| a.get(annotations::SIGNER)),
+    manifest_annotations.and_then(|a| a.get(annotations::SIGNATURE)),
+  ) else {
+    return Err("package is not signed".to_owned());
+  };
+  if !issuers.contains(signer) {
+    return Err(format!("signer {} is not a trusted issuer", signer));
+  }
+
+  let key = KeyPair::from_public_key(signer).map_err(|e| format!("invalid signer {}: {}", signer, e))?;
+  let signature = STANDARD
+    .decode(signature)
+    .map_err(|e| format!("invalid signature encoding: {}", e))?;
+  let layers = image
+    .layers
+    .iter()
+    .map(|layer| {
+      let title = layer.annotations.as_ref().and_then(|a| a.get(annotations::TITLE));
+      (title.cloned().unwrap_or_default(), layer.sha256_digest())
+    })
+    .collect();
+  key
+    .verify(&payload(&image.config.sha256_digest(), layers), &signature)
+    .map_err(|_| format!("signature from {} does not match the package contents", signer))?;
+  Ok(signer.clone())
+}
+
+/// The bytes a package signature covers: the config digest and every layer's path and digest, in path order.
+fn payload(config_digest: &str, mut layers: Vec<(String, String)>) -> Vec<u8> {
+  layers.sort();
+  let mut payload = format!("{}\nconfig:{}\n", PAYLOAD_HEADER, config_digest);
+  for (path, digest) in layers {
+    let _ = writeln!(payload, "layer:{}:{}", digest, path);
+  }
+  payload.into_bytes()
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::HashMap;
+
+  use anyhow::Result;
+  use oci_distribution::client::{Config, ImageLayer};
+  use oci_distribution::manifest::OciImageManifest;
+
+  use super::*;
+
+  fn files() -> Vec<PackageFile> {
+    vec![
+      PackageFile::new(
+        "component.wick".into(),
+        String::new(),
+        "application/vnd.candle.wick.component.v1+yaml".to_owned(),
+        bytes::Bytes::from_static(b"kind: wick/component@v1"),
+      ),
+      PackageFile::new(
+        "sql/schema.sql".into(),
+        String::new(),
+        "application/vnd.candle.wick.other.v1".to_owned(),
+        bytes::Bytes::from_static(b"create table users;"),
+      ),
+    ]
+  }
+
+  fn image(config_json: &str, files: &[PackageFile], annotations: &Annotations) -> ImageData {
+    ImageData {
+      layers: files
+        .iter()
+        .map(|f| {
+          ImageLayer::new(
+            f.contents().to_vec(),
+            f.media_type().to_owned(),
+            Some(HashMap::from([(
+              annotations::TITLE.to_owned(),
+              f.package_path().display().to_string(),
+            )])),
+          )
+        })
+        .collect(),
+      digest: None,
+      config: Config::new(config_json.as_bytes().to_vec(), "application/json".to_owned(), None),
+      manifest: Some(OciImageManifest {
+        annotations: Some(annotations.inner().clone()),
+        ..Default::default()
+      }),
+    }
+  }
+
+  fn policy(issuers: Vec<String>, mode: SignatureMode) -> Vec<SignaturePolicy> {
+    vec![
+      SignaturePolicy::new("registry.example.com/".to_owned(), vec![], SignatureMode::Warn),
+      SignaturePolicy::new("registry.example.com/acme/".to_owned(), issuers, mode),
+    ]
+  }
+
+  #[test]
+  fn test_sign_and_verify() -> Result<()> {
+    let key = KeyPair::new_account();
+    let config = r#"{"kind":"COMPONENT","root":"component.wick"}"#;
+    let mut annotations = Annotations::new(HashMap::new());
+    sign_package(&key, config, &files(), &mut annotations)?;
+    let reference = "registry.example.com/acme/component:0.1.0";
+
+    let trusted = policy(vec![key.public_key()], SignatureMode::Enforce);
+    check_signature(reference, &image(config, &files(), &annotations), &trusted)?;
+
+    let untrusted = policy(vec![KeyPair::new_account().public_key()], SignatureMode::Enforce);
+    let result = check_signature(reference, &image(config, &files(), &annotations), &untrusted);
+    assert!(matches!(result, Err(Error::SignatureRejected(..))));
+
+    let mut tampered = files();
+    tampered[1] = PackageFile::new(
+      "sql/schema.sql".into(),
+      String::new(),
+      "application/vnd.candle.wick.other.v1".to_owned(),
+      bytes::Bytes::from_static(b"drop table users;"),
+    );
+    let result = check_signature(reference, &image(config, &tampered, &annotations), &trusted);
+    assert!(matches!(result, Err(Error::SignatureRejected(..))));
+
+    let unsigned = Annotations::new(HashMap::new());
+    let result = check_signature(reference, &image(config, &files(), &unsigned), &trusted);
+    assert!(matches!(result, Err(Error::SignatureRejected(..))));
+
+    let warn = policy(vec![key.public_key()], SignatureMode::Warn);
+    check_signature(reference, &image(config, &tampered, &annotations), &warn)?;
+    check_signature(
+      "other.example.com/acme/component:0.1.0",
+      &image(config, &tampered, &annotations),
+      &trusted,
+    )?;
+    Ok(())
+  }
+}
@@ -91,7 +91,12 @@ pub fn get_cache_directory<T: AsRef<Path>>(input: &str, basedir: T) -> Result<Pa
     "repository was not in org/repo format".to_owned(),
   ))?;
 
-  let version = image_ref.tag().ok_or(Error::NoName)?;
+  // Digests contain a colon, which isn't valid in Windows paths.
+  let version = image_ref
+    .tag()
+    .map(ToOwned::to_owned)
+    .or_else(|| image_ref.digest().map(|digest| digest.replace(':', "-")))
+    .ok_or(Error::NoName)?;
 
   // Create the wick_components directory if it doesn't exist
   let target_dir = basedir.as_ref().join(registry).join(org).join(repo).join(version);
@@ -130,6 +135,11 @@ mod tests {
     "/foo/bar/example.com/myorg/myrepo/1.0.0"
   )]
   #[case("org/myrepo:1.0.1", "", "registry.candle.dev/org/myrepo/1.0.1")]
+  #[case(
+    "example.com/myorg/myrepo@sha256:0f2cb2e1e6d7c4b1a8f85d2c34b0ff9e0de8d0f4c8b2a1e3f7a6c5d4e3b2a190",
+    "",
+    "example.com/myorg/myrepo/sha256-0f2cb2e1e6d7c4b1a8f85d2c34b0ff9e0de8d0f4c8b2a1e3f7a6c5d4e3b2a190"
+  )]
   fn directory_structure_positive(#[case] input: &str, #[case] basedir: &str, #[case] expected: &str) {
     let expected_dir = Path::new(expected);
     let result = get_cache_directory(input, basedir).unwrap();
@@ -3,6 +3,7 @@ use std::future::Future;
 use std::path::{Path, PathBuf};
 
 use asset_container::{Asset, AssetFlags, AssetManager, Assets};
+use nkeys::KeyPair;
 use normpath::PathExt;
 use sha256::digest;
 use tokio::fs;
@@ -123,6 +124,7 @@ pub struct WickPackage {
   registry: Option<RegistryConfig>,
   root: String,
   basedir: Option<PathBuf>,
+  digest: Option<String>,
 }
 
 impl WickPackage {
@@ -247,6 +249,7 @@ impl WickPackage {
       registry,
       root: root_file_path,
       basedir,
+      digest: None,
     })
   }
 
@@ -268,6 +271,12 @@ impl WickPackage {
     &self.absolute_path
   }
 
+  #[must_use]
+  /// Returns the digest of the registry manifest the WickPackage was pulled with, if known.
+  pub fn digest(&self) -> Option<&str> {
+    self.digest.as_deref()
+  }
+
   #[must_use]
   /// Returns the reference.
   pub fn registry_reference(&self) -> Option<String> {
@@ -302,16 +311,9 @@ impl WickPackage {
   ///
   /// The username and password are optional. If not provided, the function falls back to anonymous authentication.
   pub async fn push(&mut self, reference: &str, options: &OciOptions) -> Result<String, Error> {
-    let kind = match self.kind {
-      wick_config::config::ConfigurationKind::App => wick_oci_utils::WickPackageKind::APPLICATION,
-      wick_config::config::ConfigurationKind::Component => wick_oci_utils::WickPackageKind::COMPONENT,
-      wick_config::config::ConfigurationKind::Types => wick_oci_utils::WickPackageKind::TYPES,
-      _ => {
-        return Err(Error::InvalidWickConfig(reference.to_owned()));
-      }
-    };
-    let config = wick_oci_utils::WickOciConfig::new(kind, self.root.clone());
-    let image_config_contents = serde_json::to_string(&config).unwrap();
+    let image_config_contents = self
+      .image_config()
+      .ok_or_else(|| Error::InvalidWickConfig(reference.to_owned()))?;
     let files = self.files.drain(..).collect();
 
     let push_response = wick_oci_utils::package::push(
@@ -326,6 +328,29 @@ impl WickPackage {
     Ok(push_response.manifest_url)
   }
 
+  /// Signs the WickPackage's config and files with the passed key.
+  ///
+  /// The signature and the key's public key are stored as annotations on the package's manifest when it is pushed.
+  pub fn sign(&mut self, key: &KeyPair) -> Result<(), Error> {
+    let image_config_contents = self
+      .image_config()
+      .ok_or_else(|| Error::InvalidWickConfig(self.absolute_path.display().to_string()))?;
+    wick_oci_utils::sign_package(key, &image_config_contents, &self.files, &mut self.annotations)?;
+    Ok(())
+  }
+
+  /// Returns the contents of the package's OCI image config, if the package's kind can be pushed.
+  fn image_config(&self) -> Option<String> {
+    let kind = match self.kind {
+      wick_config::config::ConfigurationKind::App => wick_oci_utils::WickPackageKind::APPLICATION,
+      wick_config::config::ConfigurationKind::Component => wick_oci_utils::WickPackageKind::COMPONENT,
+      wick_config::config::ConfigurationKind::Types => wick_oci_utils::WickPackageKind::TYPES,
+      _ => return None,
+    };
+    let config = wick_oci_utils::WickOciConfig::new(kind, self.root.clone());
+    Some(serde_json::to_string(&config).unwrap())
+  }
+
   /// This function pulls a WickPackage from a specified registry using the provided reference, username, and password.
   pub async fn pull(reference: &str, options: &OciOptions) -> Result<Self, Error> {
     let result = wick_oci_utils::package::pull(reference, options).await?;
@@ -333,7 +358,10 @@ impl WickPackage {
     let package = Self::from_path(Some(result.base_dir), &result.root_path).await;
 
     match package {
-      Ok(package) => Ok(package),
+      Ok(package) => Ok(Self {
+        digest: result.digest,
+        ..package
+      }),
       Err(e) => Err(Error::PackageReadFailed(e.to_string())),
     }
   }
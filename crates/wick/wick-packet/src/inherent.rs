@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::InvocationContext;
+
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 #[allow(missing_copy_implementations)]
 /// Data inherent to an invocation. Meant to be supplied by a runtime, not a user.
@@ -10,12 +12,31 @@ pub struct InherentData {
   pub seed: u64,
   /// The timestamp to associate with an invocation.
   pub timestamp: u64,
+  /// The request context propagated to every invocation in the transaction.
+  #[serde(default, skip_serializing_if = "InvocationContext::is_empty")]
+  pub context: InvocationContext,
 }
 
 impl InherentData {
   /// Constructor for [InherentData]
   pub const fn new(seed: u64, timestamp: u64) -> Self {
-    Self { seed, timestamp }
+    Self {
+      seed,
+      timestamp,
+      context: InvocationContext::new(),
+    }
+  }
+
+  /// Set the [InvocationContext] to propagate with this data.
+  #[allow(clippy::missing_const_for_fn)]
+  pub fn with_context(mut self, context: InvocationContext) -> Self {
+    self.context = context;
+    self
+  }
+
+  /// Get the [InvocationContext] propagated with this data.
+  pub const fn context(&self) -> &InvocationContext {
+    &self.context
   }
 
   #[cfg(all(feature = "rng", not(target_family = "wasm")))]
@@ -28,6 +49,7 @@ impl InherentData {
         .as_millis()
         .try_into()
         .unwrap(),
+      context: self.context.clone(),
     }
   }
 
@@ -45,6 +67,7 @@ impl InherentData {
         .as_millis()
         .try_into()
         .unwrap(),
+      context: InvocationContext::new(),
     }
   }
 
@@ -52,10 +75,11 @@ impl InherentData {
   ///
   /// This is not "unsafe" in the Rust sense. It is unsafe because it should
   /// only be used if you are sure you know what you're doing. If you don't know why this is unsafe, don't use it.
-  pub const fn unsafe_clone(&self) -> Self {
+  pub fn unsafe_clone(&self) -> Self {
     Self {
       seed: self.seed,
       timestamp: self.timestamp,
+      context: self.context.clone(),
     }
   }
 }
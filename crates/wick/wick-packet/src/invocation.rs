@@ -2,7 +2,7 @@ use parking_lot::Mutex;
 use tracing::{info_span, Span};
 use uuid::Uuid;
 
-use crate::{Entity, InherentData, PacketSender, PacketStream};
+use crate::{Entity, InherentData, InvocationContext, PacketSender, PacketStream};
 
 /// A complete invocation request.
 #[derive(Debug)]
@@ -113,6 +113,11 @@ impl InvocationData {
     &self.inherent
   }
 
+  /// Returns the request context propagated with the invocation.
+  pub const fn context(&self) -> &InvocationContext {
+    &self.inherent.context
+  }
+
   /// Return the span associated with the [Invocation].
   #[must_use]
   pub const fn span(&self) -> &Span {
@@ -257,6 +262,11 @@ impl Invocation {
     &self.data.inherent
   }
 
+  /// Returns the request context propagated with the invocation.
+  pub const fn context(&self) -> &InvocationContext {
+    &self.data.inherent.context
+  }
+
   /// Get the origin [Entity].
   pub const fn origin(&self) -> &Entity {
     &self.data.origin
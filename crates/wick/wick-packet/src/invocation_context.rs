@@ -0,0 +1,175 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+/// Request-scoped values that are propagated along with every invocation in a transaction.
+///
+/// Triggers populate the context (e.g. from HTTP headers or authentication) and it is forwarded
+/// through composite flows, WebAssembly components, RPC, and outbound HTTP requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+#[must_use]
+pub struct InvocationContext(BTreeMap<String, String>);
+
+impl InvocationContext {
+  /// The key for the W3C `traceparent` of the originating request.
+  pub const TRACEPARENT: &'static str = "traceparent";
+  /// The key for the W3C `tracestate` of the originating request.
+  pub const TRACESTATE: &'static str = "tracestate";
+  /// The key for the deadline, in milliseconds since the UNIX epoch.
+  pub const DEADLINE: &'static str = "deadline";
+  /// The key for the authenticated principal.
+  pub const PRINCIPAL: &'static str = "principal";
+  /// The key for the tenant the request belongs to.
+  pub const TENANT: &'static str = "tenant";
+  /// The key for the JSON encoded claims of the authenticated principal.
+  pub const CLAIMS: &'static str = "claims";
+
+  /// Create an empty context.
+  pub const fn new() -> Self {
+    Self(BTreeMap::new())
+  }
+
+  /// Get a value from the context.
+  #[must_use]
+  pub fn get(&self, key: &str) -> Option<&str> {
+    self.0.get(key).map(|v| v.as_str())
+  }
+
+  /// Set a value in the context, returning the previous value.
+  pub fn insert<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) -> Option<String> {
+    self.0.insert(key.into(), value.into())
+  }
+
+  /// Remove a value from the context.
+  pub fn remove(&mut self, key: &str) -> Option<String> {
+    self.0.remove(key)
+  }
+
+  /// Check if the context has no values.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Iterate over the context's keys and values.
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+  }
+
+  /// The W3C `traceparent` of the originating request.
+  #[must_use]
+  pub fn traceparent(&self) -> Option<&str> {
+    self.get(Self::TRACEPARENT)
+  }
+
+  /// The W3C `tracestate` of the originating request.
+  #[must_use]
+  pub fn tracestate(&self) -> Option<&str> {
+    self.get(Self::TRACESTATE)
+  }
+
+  /// The authenticated principal that initiated the request.
+  #[must_use]
+  pub fn principal(&self) -> Option<&str> {
+    self.get(Self::PRINCIPAL)
+  }
+
+  /// The JSON encoded claims of the authenticated principal (e.g. a decoded JWT payload).
+  #[must_use]
+  pub fn claims(&self) -> Option<&str> {
+    self.get(Self::CLAIMS)
+  }
+
+  /// The tenant the request belongs to.
+  #[must_use]
+  pub fn tenant(&self) -> Option<&str> {
+    self.get(Self::TENANT)
+  }
+
+  /// The deadline for the request, in milliseconds since the UNIX epoch.
+  #[must_use]
+  pub fn deadline(&self) -> Option<u64> {
+    self.get(Self::DEADLINE).and_then(|v| v.parse().ok())
+  }
+
+  /// Set the deadline for the request, in milliseconds since the UNIX epoch.
+  ///
+  /// An existing deadline is only ever shortened, never extended.
+  pub fn set_deadline(&mut self, deadline: u64) {
+    let deadline = self.deadline().map_or(deadline, |current| current.min(deadline));
+    self.insert(Self::DEADLINE, deadline.to_string());
+  }
+
+  /// The time remaining until the deadline, if one is set. Returns [std::time::Duration::ZERO] when it has passed.
+  #[cfg(not(target_family = "wasm"))]
+  #[must_use]
+  pub fn remaining(&self) -> Option<std::time::Duration> {
+    self.deadline().map(|deadline| {
+      let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+      std::time::Duration::from_millis(deadline.saturating_sub(now))
+    })
+  }
+
+  /// Check if the deadline has passed.
+  #[cfg(not(target_family = "wasm"))]
+  #[must_use]
+  pub fn is_expired(&self) -> bool {
+    self.remaining().map_or(false, |remaining| remaining.is_zero())
+  }
+}
+
+impl From<HashMap<String, String>> for InvocationContext {
+  fn from(value: HashMap<String, String>) -> Self {
+    Self(value.into_iter().collect())
+  }
+}
+
+impl From<InvocationContext> for HashMap<String, String> {
+  fn from(value: InvocationContext) -> Self {
+    value.0.into_iter().collect()
+  }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for InvocationContext
+where
+  K: Into<String>,
+  V: Into<String>,
+{
+  fn from(arr: [(K, V); N]) -> Self {
+    Self(arr.into_iter().map(|(k, v)| (k.into(), v.into())).collect())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_deadline_only_shortens() {
+    let mut ctx = InvocationContext::new();
+    assert_eq!(ctx.deadline(), None);
+    ctx.set_deadline(2000);
+    ctx.set_deadline(3000);
+    assert_eq!(ctx.deadline(), Some(2000));
+    ctx.set_deadline(1000);
+    assert_eq!(ctx.deadline(), Some(1000));
+    assert!(ctx.is_expired());
+  }
+
+  #[test]
+  fn test_serde() {
+    let ctx = InvocationContext::from([
+      (InvocationContext::PRINCIPAL, "user"),
+      (InvocationContext::TENANT, "acme"),
+    ]);
+    let json = serde_json::to_value(&ctx).unwrap();
+    assert_eq!(json, serde_json::json!({"principal": "user", "tenant": "acme"}));
+    let ctx2: InvocationContext = serde_json::from_value(json).unwrap();
+    assert_eq!(ctx, ctx2);
+    assert_eq!(ctx2.principal(), Some("user"));
+  }
+}
@@ -125,6 +125,7 @@ mod inherent;
 mod input;
 #[cfg(feature = "invocation")]
 mod invocation;
+mod invocation_context;
 mod macros;
 mod metadata;
 mod output;
@@ -147,6 +148,7 @@ pub use inherent::InherentData;
 pub use input::{BinaryInputs, UnaryInputs};
 #[cfg(feature = "invocation")]
 pub use invocation::{Invocation, InvocationData};
+pub use invocation_context::InvocationContext;
 pub use metadata::{Flags, WickMetadata, CLOSE_BRACKET, DONE_FLAG, OPEN_BRACKET};
 pub use output::{OutgoingPort, OutputIterator, Port, ValuePort, WasmRsChannel};
 pub use packet::{from_raw_wasmrs, from_wasmrs, packetstream_to_wasmrs, Packet, PacketError, PacketExt, PacketPayload};
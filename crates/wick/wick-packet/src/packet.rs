@@ -483,7 +483,7 @@ impl From<Packet> for Result<RawPayload, PayloadError> {
 mod test {
   use anyhow::Result;
   use serde_json::Value;
-  use wick_interface_types::Type;
+  use wick_interface_types::{Field, Type};
 
   use super::PacketPayload;
   use crate::{Base64Bytes, Packet};
@@ -516,6 +516,14 @@ mod test {
   #[rstest::rstest]
   #[case("2", Type::String, Value::String("2".into()))]
   #[case(2, Type::String, Value::String("2".into()))]
+  #[case(
+    serde_json::json!({"id": "2", "extra": true}),
+    Type::AnonymousStruct(vec![
+      Field::new("id", Type::U32),
+      Field::new("name", Type::Optional { ty: Box::new(Type::String) }),
+    ]),
+    serde_json::json!({"id": 2, "extra": true})
+  )]
   fn test_type_wrapper<T>(#[case] value: T, #[case] ty: Type, #[case] expected: Value) -> Result<()>
   where
     T: serde::Serialize + std::fmt::Debug,
@@ -121,7 +121,21 @@ pub(crate) fn coerce(val: Value, ty: &Type) -> Result<Value, Error> {
       _ => coersion_err!(val, ty.clone()),
     },
     Type::Object => val,
-    Type::AnonymousStruct(_) => unimplemented!(),
+    Type::AnonymousStruct(fields) => {
+      let Value::Object(mut obj) = val else {
+        coersion_err!(val, ty.clone())
+      };
+
+      for field in fields {
+        let v = obj.remove(&field.name).unwrap_or(Value::Null);
+        let v = coerce(v, &field.ty)?;
+        if !v.is_null() {
+          obj.insert(field.name.clone(), v);
+        }
+      }
+
+      Value::Object(obj)
+    }
   };
   Ok(val)
 }
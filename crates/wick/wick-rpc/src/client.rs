@@ -3,13 +3,14 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use tokio_stream::{Stream, StreamExt};
+use tonic::metadata::{Ascii, MetadataValue};
 use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity, Uri};
 use tracing::debug;
 use wick_packet::{Invocation, Packet, PacketStream};
 
 use crate::error::RpcClientError;
 use crate::rpc::invocation_service_client::InvocationServiceClient;
-use crate::rpc::{InvocationRequest, ListRequest, StatsRequest, StatsResponse};
+use crate::rpc::{InvocationRequest, ListRequest, LogFilterRequest, LogFilterResponse, StatsRequest, StatsResponse};
 use crate::{convert_tonic_streaming, generated};
 
 /// Create an RPC client form common configuration
@@ -77,6 +78,7 @@ pub async fn make_rpc_client<T: TryInto<Uri> + Send>(
 #[must_use]
 pub struct RpcClient {
   inner: InvocationServiceClient<Channel>,
+  token: Option<MetadataValue<Ascii>>,
 }
 
 impl RpcClient {
@@ -86,12 +88,35 @@ impl RpcClient {
       .await
       .map_err(|e| RpcClientError::ConnectionFailed(e.to_string()))?;
 
-    Ok(Self { inner: client })
+    Ok(Self {
+      inner: client,
+      token: None,
+    })
   }
 
   /// Instantiate a new [RpcClient] from an existing InvocationServiceClient.
   pub const fn from_channel(channel: InvocationServiceClient<Channel>) -> Self {
-    Self { inner: channel }
+    Self {
+      inner: channel,
+      token: None,
+    }
+  }
+
+  /// Authenticate every request with the passed bearer token.
+  pub fn with_token(mut self, token: &str) -> Result<Self, RpcClientError> {
+    let value = format!("Bearer {}", token)
+      .parse()
+      .map_err(|_| RpcClientError::Other("token contains invalid characters".to_owned()))?;
+    self.token = Some(value);
+    Ok(self)
+  }
+
+  fn request<T>(&self, message: T) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    if let Some(token) = &self.token {
+      request.metadata_mut().insert("authorization", token.clone());
+    }
+    request
   }
 
   /// Make a request to the stats RPC method
@@ -99,18 +124,34 @@ impl RpcClient {
     debug!("making stats request");
     let result = self
       .inner
-      .stats(request)
+      .stats(self.request(request))
       .await
       .map_err(RpcClientError::StatsCallFailed)?;
     debug!("stats result: {:?}", result);
     Ok(result.into_inner())
   }
 
+  /// Make a request to the log filter RPC method
+  pub async fn log_filter(&mut self, request: LogFilterRequest) -> Result<LogFilterResponse, RpcClientError> {
+    debug!("making log filter request");
+    let result = self
+      .inner
+      .log_filter(self.request(request))
+      .await
+      .map_err(RpcClientError::LogFilterCallFailed)?;
+    debug!("log filter result: {:?}", result);
+    Ok(result.into_inner())
+  }
+
   /// Make a request to the list RPC method
   pub async fn list(&mut self) -> Result<Vec<wick_interface_types::ComponentSignature>, RpcClientError> {
     let request = ListRequest {};
     debug!("making list request");
-    let result = self.inner.list(request).await.map_err(RpcClientError::ListCallFailed)?;
+    let result = self
+      .inner
+      .list(self.request(request))
+      .await
+      .map_err(RpcClientError::ListCallFailed)?;
     debug!("list result: {:?}", result);
     let response = result.into_inner();
 
@@ -130,7 +171,7 @@ impl RpcClient {
     debug!("making invocation ");
     let result = self
       .inner
-      .invoke(request)
+      .invoke(self.request(request))
       .await
       .map_err(RpcClientError::InvocationFailed)?;
     debug!("invocation result: {:?}", result);
@@ -126,6 +126,10 @@ pub enum RpcClientError {
   #[error("RPC Stats call failed: {0}")]
   StatsCallFailed(tonic::Status),
 
+  /// An error originating from a LogFilter RPC call.
+  #[error("RPC LogFilter call failed: {0}")]
+  LogFilterCallFailed(tonic::Status),
+
   /// Invalid response from RPC call.
   #[error("RPC response invalid: {0}")]
   ResponseInvalid(String),
@@ -90,6 +90,8 @@ pub struct InherentData {
   pub seed: u64,
   #[prost(uint64, tag = "2")]
   pub timestamp: u64,
+  #[prost(map = "string, string", tag = "3")]
+  pub context: ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -241,6 +243,53 @@ pub struct DurationStatistics {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogFilterRequest {
+  #[prost(enumeration = "log_filter_request::Destination", tag = "1")]
+  pub destination: i32,
+  /// Replaces the current filter when set, e.g. "info,wick_runtime<=trace".
+  #[prost(string, optional, tag = "2")]
+  pub filter: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Nested message and enum types in `LogFilterRequest`.
+pub mod log_filter_request {
+  #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+  #[repr(i32)]
+  pub enum Destination {
+    Stderr = 0,
+    Telemetry = 1,
+    Logfile = 2,
+  }
+  impl Destination {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+      match self {
+        Destination::Stderr => "STDERR",
+        Destination::Telemetry => "TELEMETRY",
+        Destination::Logfile => "LOGFILE",
+      }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+      match value {
+        "STDERR" => Some(Self::Stderr),
+        "TELEMETRY" => Some(Self::Telemetry),
+        "LOGFILE" => Some(Self::Logfile),
+        _ => None,
+      }
+    }
+  }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LogFilterResponse {
+  #[prost(string, tag = "1")]
+  pub filter: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StructSignature {
   #[prost(string, tag = "1")]
   pub name: ::prost::alloc::string::String,
@@ -529,6 +578,23 @@ pub mod invocation_service_client {
         .insert(GrpcMethod::new("wick.InvocationService", "Stats"));
       self.inner.unary(req, path, codec).await
     }
+    pub async fn log_filter(
+      &mut self,
+      request: impl tonic::IntoRequest<super::LogFilterRequest>,
+    ) -> std::result::Result<tonic::Response<super::LogFilterResponse>, tonic::Status> {
+      self
+        .inner
+        .ready()
+        .await
+        .map_err(|e| tonic::Status::new(tonic::Code::Unknown, format!("Service was not ready: {}", e.into())))?;
+      let codec = tonic::codec::ProstCodec::default();
+      let path = http::uri::PathAndQuery::from_static("/wick.InvocationService/LogFilter");
+      let mut req = request.into_request();
+      req
+        .extensions_mut()
+        .insert(GrpcMethod::new("wick.InvocationService", "LogFilter"));
+      self.inner.unary(req, path, codec).await
+    }
   }
 }
 /// Generated server implementations.
@@ -552,6 +618,10 @@ pub mod invocation_service_server {
       &self,
       request: tonic::Request<super::StatsRequest>,
     ) -> std::result::Result<tonic::Response<super::StatsResponse>, tonic::Status>;
+    async fn log_filter(
+      &self,
+      request: tonic::Request<super::LogFilterRequest>,
+    ) -> std::result::Result<tonic::Response<super::LogFilterResponse>, tonic::Status>;
   }
   #[derive(Debug)]
   pub struct InvocationServiceServer<T: InvocationService> {
@@ -714,6 +784,35 @@ pub mod invocation_service_server {
           };
           Box::pin(fut)
         }
+        "/wick.InvocationService/LogFilter" => {
+          #[allow(non_camel_case_types)]
+          struct LogFilterSvc<T: InvocationService>(pub Arc<T>);
+          impl<T: InvocationService> tonic::server::UnaryService<super::LogFilterRequest> for LogFilterSvc<T> {
+            type Response = super::LogFilterResponse;
+            type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+            fn call(&mut self, request: tonic::Request<super::LogFilterRequest>) -> Self::Future {
+              let inner = Arc::clone(&self.0);
+              let fut = async move { (*inner).log_filter(request).await };
+              Box::pin(fut)
+            }
+          }
+          let accept_compression_encodings = self.accept_compression_encodings;
+          let send_compression_encodings = self.send_compression_encodings;
+          let max_decoding_message_size = self.max_decoding_message_size;
+          let max_encoding_message_size = self.max_encoding_message_size;
+          let inner = self.inner.clone();
+          let fut = async move {
+            let inner = inner.0;
+            let method = LogFilterSvc(inner);
+            let codec = tonic::codec::ProstCodec::default();
+            let mut grpc = tonic::server::Grpc::new(codec)
+              .apply_compression_config(accept_compression_encodings, send_compression_encodings)
+              .apply_max_message_size_config(max_decoding_message_size, max_encoding_message_size);
+            let res = grpc.unary(method, req).await;
+            Ok(res)
+          };
+          Box::pin(fut)
+        }
         _ => Box::pin(async move {
           Ok(
             http::Response::builder()
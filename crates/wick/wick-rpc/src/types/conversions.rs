@@ -166,6 +166,7 @@ impl From<wick_packet::InvocationData> for rpc::Invocation {
       inherent: Some(rpc::InherentData {
         seed: inv.inherent.seed,
         timestamp: inv.inherent.timestamp,
+        context: inv.inherent.context.into(),
       }),
     }
   }
@@ -181,7 +182,7 @@ impl TryFrom<rpc::Invocation> for wick_packet::InvocationData {
       Entity::from_str(&inv.target).map_err(|_e| RpcError::TypeConversion)?,
       uuid::Uuid::from_str(&inv.id).map_err(|e| RpcError::UuidParseError(inv.id, e))?,
       uuid::Uuid::from_str(&inv.tx_id).map_err(|e| RpcError::UuidParseError(inv.tx_id, e))?,
-      InherentData::new(inherent.seed, inherent.timestamp),
+      InherentData::new(inherent.seed, inherent.timestamp).with_context(inherent.context.into()),
       tracing::Span::current(),
     ))
   }
@@ -225,6 +225,26 @@ pub(crate) async fn init_impl(
       )
       .await
     }
+    config::ComponentImplementation::KeyValue(c) => {
+      init_hlc_component(
+        id,
+        opts.root_config.clone(),
+        metadata.cloned(),
+        wick_config::config::HighLevelComponent::KeyValue(c.clone()),
+        manifest.resolver(),
+      )
+      .await
+    }
+    config::ComponentImplementation::GrpcClient(c) => {
+      init_hlc_component(
+        id,
+        opts.root_config.clone(),
+        metadata.cloned(),
+        wick_config::config::HighLevelComponent::GrpcClient(c.clone()),
+        manifest.resolver(),
+      )
+      .await
+    }
   }
 }
 
@@ -245,6 +265,12 @@ pub(crate) async fn init_hlc_component(
       metadata,
       &resolver,
     )?),
+    config::HighLevelComponent::KeyValue(comp) => {
+      Box::new(wick_kv::KeyValueComponent::new(comp, metadata, &resolver)?)
+    }
+    config::HighLevelComponent::GrpcClient(comp) => {
+      Box::new(wick_grpc_client::GrpcClientComponent::new(comp, metadata, &resolver).await?)
+    }
   };
   Ok(NamespaceHandler::new(id, comp))
 }
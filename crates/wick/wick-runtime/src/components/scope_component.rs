@@ -73,6 +73,14 @@ impl Component for ScopeComponent {
   fn signature(&self) -> &ComponentSignature {
     &self.signature
   }
+
+  fn health(&self) -> flow_component::BoxFuture<Result<(), flow_component::ComponentError>> {
+    Box::pin(async move {
+      let scope = Scope::for_id(&self.scope_id)
+        .ok_or_else(|| flow_component::ComponentError::msg(format!("scope '{}' not found", self.scope_id)))?;
+      scope.health().await
+    })
+  }
 }
 
 #[cfg(test)]
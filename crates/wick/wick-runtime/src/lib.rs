@@ -128,7 +128,7 @@ pub(crate) mod utils;
 pub use components::error::ComponentError;
 pub use components::scope_component::ScopeComponent;
 pub use runtime::scope::error::ScopeError;
-pub use runtime::{Runtime, RuntimeBuilder, RuntimeConstraint};
+pub use runtime::{ComponentHealth, Runtime, RuntimeBuilder, RuntimeConstraint};
 
 pub type Error = error::RuntimeError;
 
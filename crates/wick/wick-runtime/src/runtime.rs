@@ -8,6 +8,7 @@ pub(crate) mod scope;
 use scope::{ComponentFactory, ComponentRegistry, ScopeInit};
 
 use crate::dev::prelude::*;
+use crate::utils::metered;
 
 type Result<T> = std::result::Result<T, RuntimeError>;
 #[derive(Debug, Clone)]
@@ -74,10 +75,12 @@ impl Runtime {
     let time = std::time::SystemTime::now();
     trace!(start_time=%time.duration_since(std::time::UNIX_EPOCH).unwrap().as_millis() ,"invocation start");
 
+    let target = invocation.target();
+    let timer = wick_metrics::start_invocation(target.component_id(), target.operation_id());
     let response = self.root.invoke(invocation, config)?.await?;
     trace!(duration_ms=%time.elapsed().unwrap().as_millis(),"invocation complete");
 
-    response.ok()
+    Ok(metered(response.ok()?, timer))
   }
 
   fn get_scope(&self, path: Option<&[&str]>) -> Option<Scope> {
@@ -113,7 +116,9 @@ impl Runtime {
     config: Option<RuntimeConfig>,
   ) -> Result<PacketStream> {
     if let Some(scope) = self.get_scope(path) {
-      scope.invoke(invocation, config)?.await?.ok()
+      let target = invocation.target();
+      let timer = wick_metrics::start_invocation(target.component_id(), target.operation_id());
+      Ok(metered(scope.invoke(invocation, config)?.await?.ok()?, timer))
     } else {
       Err(RuntimeError::ScopeNotFound(
         path.map(|p| p.iter().copied().map(Into::into).collect()),
@@ -139,6 +144,20 @@ impl Runtime {
       })
   }
 
+  /// Run the health checks of the components imported into the root scope.
+  pub async fn health(&self) -> Vec<ComponentHealth> {
+    self
+      .root
+      .check_health()
+      .await
+      .into_iter()
+      .map(|(id, result)| ComponentHealth {
+        id,
+        error: result.err().map(|e| e.to_string()),
+      })
+      .collect()
+  }
+
   pub async fn shutdown(&self) -> Result<()> {
     trace!("runtime scope shutting down");
     self.root.shutdown().await?;
@@ -178,6 +197,24 @@ impl std::fmt::Debug for RuntimeBuilder {
   }
 }
 
+/// The result of a component's health check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ComponentHealth {
+  /// The id the component is imported as.
+  pub id: String,
+  /// The reason the component is not ready, if it failed its check.
+  pub error: Option<String>,
+}
+
+impl ComponentHealth {
+  /// Whether the component passed its health check.
+  #[must_use]
+  pub const fn is_ready(&self) -> bool {
+    self.error.is_none()
+  }
+}
+
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum RuntimeConstraint {
@@ -79,6 +79,14 @@ impl Scope {
     Ok(())
   }
 
+  pub(crate) async fn check_health(&self) -> Vec<(String, std::result::Result<(), flow_component::ComponentError>)> {
+    self.inner.interpreter.check_health().await
+  }
+
+  pub(crate) async fn health(&self) -> std::result::Result<(), flow_component::ComponentError> {
+    self.inner.interpreter.health().await
+  }
+
   pub(crate) fn render_dotviz(&self, op: &str) -> std::result::Result<String, RuntimeError> {
     self.inner.interpreter.render_dotviz(op).map_err(RuntimeError::DotViz)
   }
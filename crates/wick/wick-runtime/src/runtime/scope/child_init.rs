@@ -3,6 +3,7 @@ use seeded_random::Seed;
 use tracing::Span;
 use uuid::Uuid;
 use wick_config::config::ComponentConfiguration;
+use wick_interface_types::check_conformance;
 use wick_packet::RuntimeConfig;
 
 use super::{ComponentRegistry, Scope, ScopeInit};
@@ -50,6 +51,8 @@ pub(crate) fn init_child(
     for req in manifest.requires() {
       let ns = req.id();
       if let Some(handler) = opts.provided.as_ref().and_then(|p| p.get(ns).cloned()) {
+        check_conformance(&req.kind().signature(), handler.component().signature())
+          .map_err(|e| ScopeError::InterfaceMismatch(ns.to_owned(), e))?;
         components.add(Box::new(move |_| Ok(handler.clone())));
       } else {
         return Err(ScopeError::RequirementUnsatisfied(ns.to_owned()));
@@ -46,6 +46,9 @@ pub enum ScopeError {
   #[error("requirement {0} not fulfilled")]
   RequirementUnsatisfied(String),
 
+  #[error("component provided for requirement {0} does not conform to its interface:\n{1}")]
+  InterfaceMismatch(String, ConformanceError),
+
   #[error(transparent)]
   NativeComponent(#[from] flow_component::ComponentError),
 
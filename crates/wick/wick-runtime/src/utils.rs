@@ -1,6 +1,25 @@
+use futures::StreamExt;
 use seeded_random::{Random, Seed};
+use wick_metrics::InvocationTimer;
+use wick_packet::PacketStream;
 
 #[must_use]
 pub(crate) fn new_seed() -> Seed {
   Random::new().seed()
 }
+
+/// Wrap a stream so `timer` records the invocation's outcome when the stream completes.
+pub(crate) fn metered(stream: PacketStream, timer: InvocationTimer) -> PacketStream {
+  PacketStream::new(Box::pin(futures::stream::unfold(
+    (stream, timer, false),
+    |(mut stream, timer, mut errored)| async move {
+      if let Some(packet) = stream.next().await {
+        errored |= packet.as_ref().map_or(true, |p| p.is_error());
+        Some((packet, (stream, timer, errored)))
+      } else {
+        timer.finish(!errored);
+        None
+      }
+    },
+  )))
+}
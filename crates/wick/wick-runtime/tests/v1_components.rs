@@ -3,7 +3,8 @@ use std::collections::HashMap;
 
 use serde_json::json;
 use utils::*;
-use wick_packet::{packet_stream, packets, Packet, RuntimeConfig};
+use futures::StreamExt;
+use wick_packet::{packet_stream, packets, Entity, Invocation, Packet, RuntimeConfig};
 
 type Result<T> = anyhow::Result<T, anyhow::Error>;
 
@@ -135,3 +136,25 @@ async fn composite_imports() -> Result<()> {
   )
   .await
 }
+
+#[test_logger::test(tokio::test)]
+async fn kv_component_shares_state() -> Result<()> {
+  let (engine, _) = init_engine_from_yaml("./tests/manifests/v1/kv-component.wick", None).await?;
+  let invoke = |op: &str, stream| {
+    let target = Entity::operation(engine.namespace(), op);
+    engine.invoke(Invocation::test("kv", target, stream, None).unwrap(), None)
+  };
+
+  let stored: Vec<_> = invoke("store", packet_stream!(("key", "greeting"), ("value", json!({"text": "hi"}))))
+    .await?
+    .collect()
+    .await;
+  assert_eq!(stored.len(), 2);
+  assert_eq!(stored[0].as_ref().unwrap().decode_value()?, json!(true));
+
+  let fetched: Vec<_> = invoke("fetch", packet_stream!(("key", "greeting"))).await?.collect().await;
+  assert_eq!(fetched.len(), 2);
+  assert_eq!(fetched[0].as_ref().unwrap().decode_value()?, json!({"text": "hi"}));
+
+  Ok(())
+}
@@ -11,3 +11,14 @@ async fn missing_collection() -> Result<()> {
   assert!(result.is_err());
   Ok(())
 }
+
+#[test_logger::test(tokio::test)]
+async fn provided_interface_mismatch() -> Result<()> {
+  let result = init_engine_from_yaml("./tests/manifests/v1/validation/interface-mismatch.wick", None).await;
+
+  let err = result.unwrap_err().to_string();
+  println!("error: {}", err);
+  assert!(err.contains("- echo output output: string"));
+  assert!(err.contains("+ echo output out: object"));
+  Ok(())
+}
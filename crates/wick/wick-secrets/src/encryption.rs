@@ -0,0 +1,127 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::Error;
+
+const PREFIX: &str = "ENC[AES256_GCM,";
+const SUFFIX: &str = "]";
+
+/// Generate a new base64-encoded AES-256 key suitable for [encrypt] and [decrypt].
+pub fn generate_key() -> Result<String, Error> {
+  let mut key = [0u8; 32];
+  SystemRandom::new().fill(&mut key).map_err(|_| Error::KeyGen)?;
+  Ok(STANDARD.encode(key))
+}
+
+/// Check whether a value is in the `ENC[AES256_GCM,...]` format produced by [encrypt].
+#[must_use]
+pub fn is_encrypted(value: &str) -> bool {
+  value.starts_with(PREFIX) && value.ends_with(SUFFIX)
+}
+
+/// Encrypt a value with a base64-encoded AES-256 key.
+///
+/// The result is a sops-style `ENC[AES256_GCM,data:...,iv:...,tag:...,type:str]` string.
+pub fn encrypt(key: &str, plaintext: &str) -> Result<String, Error> {
+  let key = make_key(key)?;
+  let mut iv = [0u8; NONCE_LEN];
+  SystemRandom::new().fill(&mut iv).map_err(|_| Error::Encrypt)?;
+
+  let mut data = plaintext.as_bytes().to_vec();
+  let tag = key
+    .seal_in_place_separate_tag(Nonce::assume_unique_for_key(iv), Aad::empty(), &mut data)
+    .map_err(|_| Error::Encrypt)?;
+
+  Ok(format!(
+    "{}data:{},iv:{},tag:{},type:str{}",
+    PREFIX,
+    STANDARD.encode(data),
+    STANDARD.encode(iv),
+    STANDARD.encode(tag.as_ref()),
+    SUFFIX
+  ))
+}
+
+/// Decrypt a value produced by [encrypt] with a base64-encoded AES-256 key.
+pub fn decrypt(key: &str, value: &str) -> Result<String, Error> {
+  let key = make_key(key)?;
+  let fields = value
+    .strip_prefix(PREFIX)
+    .and_then(|v| v.strip_suffix(SUFFIX))
+    .ok_or_else(|| Error::InvalidValue("missing ENC[AES256_GCM,...] wrapper".to_owned()))?;
+
+  let (mut data, mut iv, mut tag) = (None, None, None);
+  for field in fields.split(',') {
+    let (name, value) = field
+      .split_once(':')
+      .ok_or_else(|| Error::InvalidValue(format!("malformed field '{}'", field)))?;
+    match name {
+      "data" => data = Some(decode(name, value)?),
+      "iv" => iv = Some(decode(name, value)?),
+      "tag" => tag = Some(decode(name, value)?),
+      _ => {}
+    }
+  }
+
+  let mut data = data.ok_or_else(|| Error::InvalidValue("missing data".to_owned()))?;
+  let iv = iv.ok_or_else(|| Error::InvalidValue("missing iv".to_owned()))?;
+  let tag = tag.ok_or_else(|| Error::InvalidValue("missing tag".to_owned()))?;
+
+  let nonce = Nonce::try_assume_unique_for_key(&iv).map_err(|_| Error::InvalidValue("invalid iv length".to_owned()))?;
+  data.extend(tag);
+  let plaintext = key
+    .open_in_place(nonce, Aad::empty(), &mut data)
+    .map_err(|_| Error::Decrypt)?;
+
+  String::from_utf8(plaintext.to_vec())
+    .map_err(|_| Error::InvalidValue("decrypted value is not valid UTF-8".to_owned()))
+}
+
+fn make_key(key: &str) -> Result<LessSafeKey, Error> {
+  let bytes = STANDARD
+    .decode(key.trim())
+    .map_err(|e| Error::InvalidKey(e.to_string()))?;
+  let key = UnboundKey::new(&AES_256_GCM, &bytes).map_err(|_| {
+    Error::InvalidKey(format!(
+      "expected {} bytes, found {}",
+      AES_256_GCM.key_len(),
+      bytes.len()
+    ))
+  })?;
+  Ok(LessSafeKey::new(key))
+}
+
+fn decode(name: &str, value: &str) -> Result<Vec<u8>, Error> {
+  STANDARD
+    .decode(value)
+    .map_err(|e| Error::InvalidValue(format!("could not decode {}: {}", name, e)))
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_roundtrip() -> Result<()> {
+    let key = generate_key()?;
+    let encrypted = encrypt(&key, "hunter22")?;
+    assert!(is_encrypted(&encrypted));
+    assert!(!encrypted.contains("hunter22"));
+    assert_eq!(decrypt(&key, &encrypted)?, "hunter22");
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_wrong_key() -> Result<()> {
+    let encrypted = encrypt(&generate_key()?, "hunter22")?;
+    let result = decrypt(&generate_key()?, &encrypted);
+    assert!(matches!(result, Err(Error::Decrypt)));
+
+    Ok(())
+  }
+}
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+/// Crate error.
+pub enum Error {
+  /// Could not read a secrets file or directory.
+  #[error("Could not read secrets from {}: {}", .0.display(), .1)]
+  Read(PathBuf, std::io::Error),
+
+  /// A dotenv file contained a line that could not be parsed.
+  #[error("Invalid entry on line {1} of {}", .0.display())]
+  Dotenv(PathBuf, usize),
+
+  /// An encrypted secrets file could not be parsed.
+  #[error("Could not parse encrypted secrets file {}: {}", .0.display(), .1)]
+  Parse(PathBuf, String),
+
+  /// No key was available to decrypt an encrypted secrets file.
+  #[error("No decryption key found for {}, set the {1} environment variable or configure a key_file", .0.display())]
+  MissingKey(PathBuf, String),
+
+  /// The encryption key was malformed.
+  #[error("Invalid encryption key: {0}")]
+  InvalidKey(String),
+
+  /// An encrypted value was malformed.
+  #[error("Invalid encrypted value: {0}")]
+  InvalidValue(String),
+
+  /// An encrypted value could not be decrypted with the provided key.
+  #[error("Could not decrypt value, the key may be incorrect")]
+  Decrypt,
+
+  /// Could not encrypt a value.
+  #[error("Could not encrypt value")]
+  Encrypt,
+
+  /// A secret value was too short to be redacted reliably.
+  #[error("{0} is shorter than {1} characters and could not be redacted")]
+  TooShort(String, usize),
+
+  /// Could not generate a new key.
+  #[error("Could not generate a new encryption key")]
+  KeyGen,
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::{decrypt, is_encrypted, Error};
+
+/// The environment variable read for the key of [EncryptedProvider]s by default.
+pub const DEFAULT_KEY_ENV: &str = "WICK_SECRETS_KEY";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[allow(clippy::exhaustive_enums)]
+#[serde(rename_all = "snake_case")]
+#[serde(tag = "type")]
+/// A source of secret values.
+pub enum SecretProvider {
+  /// A file, or a directory of files, where each file name is a secret name and its content the value.
+  File(FileProvider),
+  /// A dotenv-style file of `NAME=value` lines.
+  Dotenv(DotenvProvider),
+  /// A YAML map of secret names to values encrypted with [crate::encrypt].
+  Encrypted(EncryptedProvider),
+}
+
+impl SecretProvider {
+  /// Read all secrets from this provider.
+  pub fn load(&self) -> Result<HashMap<String, String>, Error> {
+    let secrets = match self {
+      SecretProvider::File(p) => p.load()?,
+      SecretProvider::Dotenv(p) => p.load()?,
+      SecretProvider::Encrypted(p) => p.load()?,
+    };
+    debug!(provider = ?self, count = secrets.len(), "loaded secrets");
+    Ok(secrets)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+/// Secrets read from plain files, e.g. mounted Kubernetes or Docker secrets.
+pub struct FileProvider {
+  /// A single file or a directory of files.
+  pub path: PathBuf,
+}
+
+impl FileProvider {
+  /// Create a new file provider.
+  #[must_use]
+  pub const fn new(path: PathBuf) -> Self {
+    Self { path }
+  }
+
+  fn load(&self) -> Result<HashMap<String, String>, Error> {
+    let mut secrets = HashMap::new();
+    let metadata = std::fs::metadata(&self.path).map_err(|e| Error::Read(self.path.clone(), e))?;
+
+    let files = if metadata.is_dir() {
+      let entries = std::fs::read_dir(&self.path).map_err(|e| Error::Read(self.path.clone(), e))?;
+      let mut files = Vec::new();
+      for entry in entries {
+        let entry = entry.map_err(|e| Error::Read(self.path.clone(), e))?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.path().is_file() {
+          files.push(entry.path());
+        }
+      }
+      files
+    } else {
+      vec![self.path.clone()]
+    };
+
+    for file in files {
+      let Some(name) = file.file_name().map(|n| n.to_string_lossy().into_owned()) else {
+        continue;
+      };
+      let value = read(&file)?;
+      secrets.insert(name, value.trim_end_matches(['\r', '\n']).to_owned());
+    }
+
+    Ok(secrets)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+/// Secrets read from a dotenv-style file.
+pub struct DotenvProvider {
+  /// The path to the dotenv file.
+  pub path: PathBuf,
+}
+
+impl DotenvProvider {
+  /// Create a new dotenv provider.
+  #[must_use]
+  pub const fn new(path: PathBuf) -> Self {
+    Self { path }
+  }
+
+  fn load(&self) -> Result<HashMap<String, String>, Error> {
+    parse_dotenv(&self.path, &read(&self.path)?)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+/// Secrets read from a YAML file of encrypted values.
+pub struct EncryptedProvider {
+  /// The path to the encrypted secrets file.
+  pub path: PathBuf,
+  /// A file containing the decryption key.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub key_file: Option<PathBuf>,
+  /// The environment variable containing the decryption key, used when `key_file` is not set.
+  #[serde(default = "default_key_env")]
+  pub key_env: String,
+}
+
+fn default_key_env() -> String {
+  DEFAULT_KEY_ENV.to_owned()
+}
+
+impl EncryptedProvider {
+  /// Create a new encrypted file provider that reads its key from the default environment variable.
+  #[must_use]
+  pub fn new(path: PathBuf) -> Self {
+    Self {
+      path,
+      key_file: None,
+      key_env: default_key_env(),
+    }
+  }
+
+  fn key(&self) -> Result<String, Error> {
+    self.key_file.as_ref().map_or_else(
+      || std::env::var(&self.key_env).map_err(|_| Error::MissingKey(self.path.clone(), self.key_env.clone())),
+      |file| read(file),
+    )
+  }
+
+  fn load(&self) -> Result<HashMap<String, String>, Error> {
+    let src = read(&self.path)?;
+    let values: HashMap<String, serde_yaml::Value> =
+      serde_yaml::from_str(&src).map_err(|e| Error::Parse(self.path.clone(), e.to_string()))?;
+
+    let mut key = None;
+    let mut secrets = HashMap::new();
+    for (name, value) in values {
+      let value = match value {
+        serde_yaml::Value::String(v) => v,
+        serde_yaml::Value::Bool(v) => v.to_string(),
+        serde_yaml::Value::Number(v) => v.to_string(),
+        _ => {
+          return Err(Error::Parse(
+            self.path.clone(),
+            format!("value for '{}' must be a string", name),
+          ))
+        }
+      };
+      let value = if is_encrypted(&value) {
+        if key.is_none() {
+          key = Some(self.key()?);
+        }
+        decrypt(key.as_deref().unwrap_or_default(), &value)
+          .map_err(|e| Error::Parse(self.path.clone(), format!("{}: {}", name, e)))?
+      } else {
+        value
+      };
+      secrets.insert(name, value);
+    }
+
+    Ok(secrets)
+  }
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+  std::fs::read_to_string(path).map_err(|e| Error::Read(path.to_path_buf(), e))
+}
+
+fn parse_dotenv(path: &Path, src: &str) -> Result<HashMap<String, String>, Error> {
+  let mut secrets = HashMap::new();
+  for (i, line) in src.lines().enumerate() {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let line = line.strip_prefix("export ").unwrap_or(line);
+    let (name, value) = line
+      .split_once('=')
+      .ok_or_else(|| Error::Dotenv(path.to_path_buf(), i + 1))?;
+    let name = name.trim();
+    if name.is_empty() {
+      return Err(Error::Dotenv(path.to_path_buf(), i + 1));
+    }
+    let value = value.trim();
+    let value = unquote(value, '"')
+      .or_else(|| unquote(value, '\''))
+      .unwrap_or_else(|| value.split_once(" #").map_or(value, |(v, _)| v.trim_end()));
+    secrets.insert(name.to_owned(), value.to_owned());
+  }
+  Ok(secrets)
+}
+
+fn unquote(value: &str, quote: char) -> Option<&str> {
+  value.strip_prefix(quote).and_then(|v| v.strip_suffix(quote))
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_parse_dotenv() -> Result<()> {
+    let src = r#"
+# comment
+export TOKEN=abc123
+PASSWORD = "with spaces"
+SINGLE='quoted # value'
+INLINE=value # comment
+EMPTY=
+"#;
+    let secrets = parse_dotenv(Path::new(".env"), src)?;
+    assert_eq!(secrets["TOKEN"], "abc123");
+    assert_eq!(secrets["PASSWORD"], "with spaces");
+    assert_eq!(secrets["SINGLE"], "quoted # value");
+    assert_eq!(secrets["INLINE"], "value");
+    assert_eq!(secrets["EMPTY"], "");
+    assert_eq!(secrets.len(), 5);
+
+    let result = parse_dotenv(Path::new(".env"), "VALID=1\nnot a pair");
+    assert!(matches!(result, Err(Error::Dotenv(_, 2))));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_deserialize() -> Result<()> {
+    let providers: Vec<SecretProvider> = serde_yaml::from_str(
+      r#"
+- type: file
+  path: /run/secrets
+- type: dotenv
+  path: .env
+- type: encrypted
+  path: secrets.enc.yaml
+"#,
+    )?;
+    assert_eq!(
+      providers,
+      vec![
+        SecretProvider::File(FileProvider::new("/run/secrets".into())),
+        SecretProvider::Dotenv(DotenvProvider::new(".env".into())),
+        SecretProvider::Encrypted(EncryptedProvider::new("secrets.enc.yaml".into())),
+      ]
+    );
+
+    Ok(())
+  }
+}
@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::Write;
+
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde_json::Value;
+
+use crate::{Error, SecretProvider};
+
+/// The text secret values are replaced with.
+pub const REDACTED: &str = "<redacted>";
+
+/// Values shorter than this are too likely to match unrelated text to be redacted, so they are rejected.
+pub const MIN_REDACTED_LENGTH: usize = 4;
+
+static REGISTRY: Lazy<RwLock<Registry>> = Lazy::new(|| RwLock::new(Registry::default()));
+
+#[derive(Debug, Default)]
+struct Registry {
+  secrets: HashMap<String, String>,
+  // Kept sorted longest first so a secret containing another is redacted as a whole.
+  tracked: Vec<String>,
+}
+
+impl Registry {
+  fn track(&mut self, what: &str, value: &str) -> Result<(), Error> {
+    if value.len() < MIN_REDACTED_LENGTH {
+      return Err(Error::TooShort(what.to_owned(), MIN_REDACTED_LENGTH));
+    }
+    if let Err(i) = self
+      .tracked
+      .binary_search_by(|v| value.len().cmp(&v.len()).then_with(|| v.as_str().cmp(value)))
+    {
+      self.tracked.insert(i, value.to_owned());
+    }
+    Ok(())
+  }
+}
+
+/// Load the secrets from every provider into the global registry.
+pub fn load(providers: &[SecretProvider]) -> Result<(), Error> {
+  for provider in providers {
+    for (name, value) in provider.load()? {
+      register(&name, &value)?;
+    }
+  }
+  Ok(())
+}
+
+/// Add a named secret to the global registry, making it available via [get] and [all] and redacting its value.
+///
+/// Fails if the value is shorter than [MIN_REDACTED_LENGTH] and so could not be redacted.
+pub fn register(name: &str, value: &str) -> Result<(), Error> {
+  let mut registry = REGISTRY.write();
+  registry.track(&format!("Secret {}", name), value)?;
+  registry.secrets.insert(name.to_owned(), value.to_owned());
+  Ok(())
+}
+
+/// Redact a sensitive value that is not otherwise available by name, e.g. a registry password.
+///
+/// `what` describes the value for the error returned when it is shorter than [MIN_REDACTED_LENGTH].
+pub fn track(what: &str, value: &str) -> Result<(), Error> {
+  REGISTRY.write().track(what, value)
+}
+
+/// Retrieve a registered secret by name.
+#[must_use]
+pub fn get(name: &str) -> Option<String> {
+  REGISTRY.read().secrets.get(name).cloned()
+}
+
+/// Retrieve all registered secrets, keyed by name.
+#[must_use]
+pub fn all() -> HashMap<String, String> {
+  REGISTRY.read().secrets.clone()
+}
+
+/// Replace every tracked secret value in `text` with [REDACTED].
+#[must_use]
+pub fn redact(text: &str) -> Cow<'_, str> {
+  let registry = REGISTRY.read();
+  let mut text = Cow::Borrowed(text);
+  for value in &registry.tracked {
+    if text.contains(value.as_str()) {
+      text = Cow::Owned(text.replace(value.as_str(), REDACTED));
+    }
+  }
+  text
+}
+
+/// Replace every tracked secret value in the strings and keys of a JSON value with [REDACTED].
+#[must_use]
+pub fn redact_json(value: Value) -> Value {
+  match value {
+    Value::String(s) => match redact(&s) {
+      Cow::Borrowed(_) => Value::String(s),
+      Cow::Owned(s) => Value::String(s),
+    },
+    Value::Array(a) => Value::Array(a.into_iter().map(redact_json).collect()),
+    Value::Object(o) => Value::Object(
+      o.into_iter()
+        .map(|(k, v)| (redact(&k).into_owned(), redact_json(v)))
+        .collect(),
+    ),
+    v => v,
+  }
+}
+
+/// A [Write] adapter that redacts tracked secrets from everything written through it.
+///
+/// Each write is redacted independently so secrets must not be split across writes,
+/// which holds for line-oriented writers like loggers.
+#[derive(Debug)]
+pub struct RedactingWriter<W>
+where
+  W: Write,
+{
+  inner: W,
+}
+
+impl<W> RedactingWriter<W>
+where
+  W: Write,
+{
+  /// Wrap a [Write] implementation.
+  pub const fn new(inner: W) -> Self {
+    Self { inner }
+  }
+}
+
+impl<W> Write for RedactingWriter<W>
+where
+  W: Write,
+{
+  fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    match std::str::from_utf8(buf) {
+      Ok(text) => {
+        self.inner.write_all(redact(text).as_bytes())?;
+        Ok(buf.len())
+      }
+      Err(_) => self.inner.write(buf),
+    }
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    self.inner.flush()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+  use crate::{encrypt, generate_key, EncryptedProvider};
+
+  #[test]
+  fn test_redact() {
+    register("REDACT_TEST", "redact-test-secret").unwrap();
+    track("A test value", "redact-test-secret-longer").unwrap();
+    assert!(matches!(track("A test value", "abc"), Err(Error::TooShort(..))));
+    assert!(matches!(register("SHORT_TEST", "abc"), Err(Error::TooShort(..))));
+    assert_eq!(get("SHORT_TEST"), None);
+
+    assert_eq!(get("REDACT_TEST").as_deref(), Some("redact-test-secret"));
+    assert_eq!(
+      redact("a=redact-test-secret b=redact-test-secret-longer c=abc"),
+      "a=<redacted> b=<redacted> c=abc"
+    );
+    assert!(matches!(redact("nothing to see here"), Cow::Borrowed(_)));
+
+    let json = redact_json(serde_json::json!({"password": "redact-test-secret", "list": [1, "x redact-test-secret"]}));
+    assert_eq!(
+      json,
+      serde_json::json!({"password": "<redacted>", "list": [1, "x <redacted>"]})
+    );
+  }
+
+  #[test]
+  fn test_writer() -> Result<()> {
+    track("A test value", "writer-test-secret")?;
+    let mut writer = RedactingWriter::new(Vec::new());
+    writeln!(writer, "token: writer-test-secret")?;
+    assert_eq!(String::from_utf8(writer.inner)?, "token: <redacted>\n");
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_load_encrypted() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("wick-secrets-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let key = generate_key()?;
+    let key_file = dir.join("key");
+    std::fs::write(&key_file, &key)?;
+    let path = dir.join("secrets.enc.yaml");
+    std::fs::write(
+      &path,
+      format!(
+        "ENCRYPTED_TEST: {}\nPLAIN_TEST: plain-test-value\n",
+        encrypt(&key, "encrypted-test-value")?
+      ),
+    )?;
+
+    let mut provider = EncryptedProvider::new(path);
+    provider.key_file = Some(key_file);
+    load(&[SecretProvider::Encrypted(provider)])?;
+    std::fs::remove_dir_all(&dir)?;
+
+    assert_eq!(get("ENCRYPTED_TEST").as_deref(), Some("encrypted-test-value"));
+    assert_eq!(get("PLAIN_TEST").as_deref(), Some("plain-test-value"));
+    assert_eq!(redact("encrypted-test-value"), REDACTED);
+
+    Ok(())
+  }
+}
@@ -19,6 +19,17 @@ pub struct Settings {
   /// Registry credentials.
   #[serde(default, skip_serializing_if = "Vec::is_empty")]
   pub credentials: Vec<Credential>,
+  /// OCI image layout directory to read remote packages from instead of their registries.
+  ///
+  /// Relative paths are resolved against the directory of the settings file.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub mirror: Option<PathBuf>,
+  /// Issuers trusted to sign packages pulled from registries.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub signatures: Vec<SignaturePolicy>,
+  /// Secret providers.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub secrets: Vec<wick_secrets::SecretProvider>,
   #[serde(skip)]
   /// Where this configuration was loaded from.
   pub source: Option<PathBuf>,
@@ -45,6 +56,12 @@ pub struct TraceSettings {
   /// STDERR logging settings.
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub stderr: Option<LogSettings>,
+  /// Format of events printed to STDERR.
+  #[serde(default)]
+  pub format: LogFormat,
+  /// Log file settings.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub logfile: Option<LogFileSettings>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
@@ -57,6 +74,36 @@ pub struct LogSettings {
   pub filter: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+/// Log file settings.
+pub struct LogFileSettings {
+  /// Directory to write log files to.
+  ///
+  /// Relative paths are resolved against the directory of the settings file.
+  pub dir: PathBuf,
+  /// File name prefix, defaults to `wick`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub prefix: Option<String>,
+  /// When to start a new log file.
+  #[serde(default)]
+  pub rotation: LogRotation,
+  /// Size in bytes at which `size` rotation starts a new file, defaults to 10 MiB.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max_size: Option<u64>,
+  /// Number of rotated files to keep.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub max_files: Option<usize>,
+  /// Format of events written to the file, defaults to `json`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub format: Option<LogFormat>,
+  /// Log event filter.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub filter: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 #[serde(rename_all = "snake_case")]
@@ -77,6 +124,42 @@ impl Credential {
   }
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[non_exhaustive]
+#[serde(rename_all = "snake_case")]
+#[serde(deny_unknown_fields)]
+/// Signature verification policy for packages pulled from a registry scope.
+pub struct SignaturePolicy {
+  /// Reference prefix the policy applies to. The policy with the longest matching scope wins.
+  pub scope: String,
+  /// Public keys of the issuers trusted to sign packages in this scope.
+  #[serde(default)]
+  pub issuers: Vec<String>,
+  /// What to do with packages that aren't signed by a trusted issuer.
+  #[serde(default)]
+  pub mode: SignatureMode,
+}
+
+impl SignaturePolicy {
+  /// Create a new signature policy.
+  #[must_use]
+  pub const fn new(scope: String, issuers: Vec<String>, mode: SignatureMode) -> Self {
+    Self { scope, issuers, mode }
+  }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[allow(clippy::exhaustive_enums)]
+#[serde(rename_all = "snake_case")]
+/// Signature verification modes.
+pub enum SignatureMode {
+  /// Log a warning and use the package anyway.
+  #[default]
+  Warn,
+  /// Refuse to use the package.
+  Enforce,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[allow(clippy::exhaustive_enums)]
 #[serde(rename_all = "snake_case")]
@@ -158,6 +241,34 @@ impl Default for LogModifier {
   }
 }
 
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[allow(clippy::exhaustive_enums)]
+#[serde(rename_all = "snake_case")]
+/// Log event formats.
+pub enum LogFormat {
+  /// Human readable lines.
+  #[default]
+  Text,
+  /// One JSON object per line.
+  Json,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[allow(clippy::exhaustive_enums)]
+#[serde(rename_all = "snake_case")]
+/// Log file rotation policies.
+pub enum LogRotation {
+  /// Always append to the same file.
+  #[default]
+  Never,
+  /// Start a new file every hour.
+  Hourly,
+  /// Start a new file every day.
+  Daily,
+  /// Start a new file when the current one grows past `max_size`.
+  Size,
+}
+
 impl Settings {
   pub fn new() -> Self {
     let _span = tracing::info_span!("settings").entered();
@@ -186,6 +297,16 @@ impl Settings {
     }
   }
 
+  /// Load secrets from the configured providers and mark credential passwords as secret so they are redacted.
+  pub fn load_secrets(&self) -> Result<(), wick_secrets::Error> {
+    for credential in &self.credentials {
+      match &credential.auth {
+        Auth::Basic(auth) => wick_secrets::track(&format!("The password for {}", credential.scope), &auth.password)?,
+      }
+    }
+    wick_secrets::load(&self.secrets)
+  }
+
   pub fn save(&self) -> Result<(), Error> {
     let source = self.source.as_ref().ok_or(Error::NoSource)?;
     let yaml = serde_yaml::to_string(self).unwrap();
@@ -206,6 +327,16 @@ fn find_settings(config_locations: &[PathBuf], extensions: &[&str]) -> Vec<Setti
           Ok(src) => match serde_yaml::from_str::<Settings>(&src) {
             Ok(mut settings) => {
               debug!(file=%path.display(),"found config");
+              if let (Some(mirror), Some(dir)) = (&mut settings.mirror, path.parent()) {
+                if mirror.is_relative() {
+                  *mirror = dir.join(&mirror);
+                }
+              }
+              if let (Some(logfile), Some(dir)) = (&mut settings.trace.logfile, path.parent()) {
+                if logfile.dir.is_relative() {
+                  logfile.dir = dir.join(&logfile.dir);
+                }
+              }
               settings.source = Some(path.clone());
               files.push(settings);
 
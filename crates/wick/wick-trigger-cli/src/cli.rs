@@ -125,7 +125,7 @@ impl Trigger for Cli {
     Ok(StructuredOutput::default())
   }
 
-  async fn shutdown_gracefully(self) -> Result<(), wick_trigger::Error> {
+  async fn shutdown_gracefully(&self) -> Result<(), wick_trigger::Error> {
     Ok(())
   }
 
@@ -1,9 +1,11 @@
 #![allow(clippy::needless_pass_by_value)]
 
+mod auth;
 mod component_utils;
 mod conversions;
 pub(crate) mod error;
 mod middleware;
+mod policies;
 mod routers;
 mod service_factory;
 
@@ -147,7 +149,7 @@ impl Trigger for Http {
         info!(path = router.path(), kind = %router.kind(), "registering http router");
 
         let router = match router {
-          config::HttpRouterConfig::RawRouter(r) => routers::raw::register_raw_router(i, r)?,
+          config::HttpRouterConfig::RawRouter(r) => routers::raw::register_raw_router(i, resources.clone(), r)?,
           config::HttpRouterConfig::StaticRouter(r) => {
             routers::static_::register_static_router(i, resources.clone(), r)?
           }
@@ -177,7 +179,7 @@ impl Trigger for Http {
     Ok(output)
   }
 
-  async fn shutdown_gracefully(self) -> Result<(), Error> {
+  async fn shutdown_gracefully(&self) -> Result<(), Error> {
     self
       .span
       .clone()
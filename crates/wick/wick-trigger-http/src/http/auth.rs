@@ -0,0 +1,414 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use hyper::header::{AUTHORIZATION, WWW_AUTHENTICATE};
+use hyper::http::HeaderName;
+use hyper::{Body, Request, Response, StatusCode};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde_json::{json, Value};
+use wick_config::config::{ApiKeyAuth, BoundIdentifier, JwtAlgorithm, JwtAuth, WickRouter};
+use wick_packet::InvocationContext;
+use wick_trigger::resources::Resource;
+
+use super::HttpError;
+
+/// The authenticated identity of a request, stored in the request's extensions.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Principal {
+  subject: Option<String>,
+  scopes: Vec<String>,
+  claims: Value,
+}
+
+impl Principal {
+  fn from_claims(claims: Value) -> Self {
+    let subject = claims.get("sub").and_then(Value::as_str).map(ToOwned::to_owned);
+    let scopes = match claims.get("scope").or_else(|| claims.get("scp")) {
+      Some(Value::String(scopes)) => scopes.split_whitespace().map(ToOwned::to_owned).collect(),
+      Some(Value::Array(scopes)) => scopes.iter().filter_map(Value::as_str).map(ToOwned::to_owned).collect(),
+      _ => Vec::new(),
+    };
+    Self {
+      subject,
+      scopes,
+      claims,
+    }
+  }
+
+  /// Check if the principal was granted every one of the passed scopes.
+  pub(crate) fn has_scopes(&self, required: &[String]) -> bool {
+    required.iter().all(|scope| self.scopes.contains(scope))
+  }
+}
+
+/// Why a request was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AuthFailure {
+  /// No valid credentials were passed.
+  Unauthenticated,
+  /// The credentials were valid but not granted the required scopes.
+  Forbidden,
+}
+
+#[derive(Clone)]
+struct JwtValidator {
+  algorithms: Vec<Algorithm>,
+  secret: Option<DecodingKey>,
+  key: Option<DecodingKey>,
+  jwks: Option<JwkSet>,
+  issuer: Option<String>,
+  audience: Vec<String>,
+}
+
+impl std::fmt::Debug for JwtValidator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("JwtValidator")
+      .field("algorithms", &self.algorithms)
+      .field("issuer", &self.issuer)
+      .field("audience", &self.audience)
+      .finish()
+  }
+}
+
+impl JwtValidator {
+  fn validate(&self, token: &str) -> Option<Value> {
+    let header = jsonwebtoken::decode_header(token).ok()?;
+    if !self.algorithms.contains(&header.alg) {
+      return None;
+    }
+    let jwk_key;
+    let key = match header.alg {
+      Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => self.secret.as_ref()?,
+      _ => match (&self.jwks, &header.kid) {
+        (Some(jwks), Some(kid)) => {
+          jwk_key = DecodingKey::from_jwk(jwks.find(kid)?).ok()?;
+          &jwk_key
+        }
+        _ => self.key.as_ref()?,
+      },
+    };
+    let mut validation = Validation::new(header.alg);
+    if let Some(issuer) = &self.issuer {
+      validation.set_issuer(&[issuer]);
+    }
+    if !self.audience.is_empty() {
+      validation.set_audience(&self.audience);
+    }
+    jsonwebtoken::decode::<Value>(token, key, &validation)
+      .map(|data| data.claims)
+      .ok()
+  }
+}
+
+#[derive(Clone)]
+struct ApiKeyValidator {
+  header: HeaderName,
+  keys: Vec<(String, Option<String>, Vec<String>)>,
+}
+
+impl std::fmt::Debug for ApiKeyValidator {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ApiKeyValidator")
+      .field("header", &self.header)
+      .field("keys", &self.keys.len())
+      .finish()
+  }
+}
+
+impl ApiKeyValidator {
+  fn validate(&self, value: &str) -> Option<Value> {
+    self
+      .keys
+      .iter()
+      .find(|(key, _, _)| constant_time_eq(key.as_bytes(), value.as_bytes()))
+      .map(|(_, name, scopes)| json!({ "sub": name, "scope": scopes.join(" ") }))
+  }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+/// Authentication enforced on a router's requests before any middleware or operation is invoked.
+#[derive(Debug, Clone)]
+pub(crate) struct RouterAuth {
+  jwt: Option<JwtValidator>,
+  api_key: Option<ApiKeyValidator>,
+  scopes: Vec<String>,
+}
+
+impl RouterAuth {
+  fn authenticate<B>(&self, req: &Request<B>) -> Option<Principal> {
+    if let Some(jwt) = &self.jwt {
+      let token = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer ").or_else(|| v.strip_prefix("bearer ")));
+      if let Some(claims) = token.and_then(|token| jwt.validate(token.trim())) {
+        return Some(Principal::from_claims(claims));
+      }
+    }
+    if let Some(api_key) = &self.api_key {
+      let value = req.headers().get(&api_key.header).and_then(|v| v.to_str().ok());
+      if let Some(claims) = value.and_then(|value| api_key.validate(value)) {
+        return Some(Principal::from_claims(claims));
+      }
+    }
+    None
+  }
+
+  /// Authenticate a request, adding its [Principal] to the request and its invocation context.
+  pub(crate) fn authorize<B>(&self, req: &mut Request<B>) -> Result<(), AuthFailure> {
+    let principal = self.authenticate(req).ok_or(AuthFailure::Unauthenticated)?;
+    if !principal.has_scopes(&self.scopes) {
+      return Err(AuthFailure::Forbidden);
+    }
+    if let Some(context) = req.extensions_mut().get_mut::<InvocationContext>() {
+      if let Some(subject) = &principal.subject {
+        context.insert(InvocationContext::PRINCIPAL, subject);
+      }
+      context.insert(InvocationContext::CLAIMS, principal.claims.to_string());
+    }
+    req.extensions_mut().insert(principal);
+    Ok(())
+  }
+
+  /// The response to send for a rejected request.
+  pub(crate) fn reject(&self, failure: AuthFailure) -> Response<Body> {
+    let mut builder = Response::builder();
+    builder = match failure {
+      AuthFailure::Unauthenticated => {
+        let builder = builder.status(StatusCode::UNAUTHORIZED);
+        if self.jwt.is_some() {
+          builder.header(WWW_AUTHENTICATE, "Bearer")
+        } else {
+          builder
+        }
+      }
+      AuthFailure::Forbidden => builder.status(StatusCode::FORBIDDEN),
+    };
+    builder.body(Body::empty()).unwrap()
+  }
+}
+
+const fn algorithm(algorithm: JwtAlgorithm) -> Algorithm {
+  match algorithm {
+    JwtAlgorithm::Hs256 => Algorithm::HS256,
+    JwtAlgorithm::Hs384 => Algorithm::HS384,
+    JwtAlgorithm::Hs512 => Algorithm::HS512,
+    JwtAlgorithm::Rs256 => Algorithm::RS256,
+    JwtAlgorithm::Rs384 => Algorithm::RS384,
+    JwtAlgorithm::Rs512 => Algorithm::RS512,
+    JwtAlgorithm::Ps256 => Algorithm::PS256,
+    JwtAlgorithm::Ps384 => Algorithm::PS384,
+    JwtAlgorithm::Ps512 => Algorithm::PS512,
+    JwtAlgorithm::Es256 => Algorithm::ES256,
+    JwtAlgorithm::Es384 => Algorithm::ES384,
+  }
+}
+
+fn read_volume_file(
+  resources: &HashMap<BoundIdentifier, Resource>,
+  volume: Option<&BoundIdentifier>,
+  path: &str,
+) -> Result<Vec<u8>, HttpError> {
+  let volume = volume.ok_or_else(|| HttpError::InvalidAuth(format!("a volume is required to load {}", path)))?;
+  let root: PathBuf = match resources.get(volume) {
+    Some(Resource::Volume(root)) => root.clone(),
+    _ => {
+      return Err(HttpError::InvalidAuth(format!(
+        "{} is not a volume resource",
+        volume.id()
+      )))
+    }
+  };
+  let path = root.join(path);
+  std::fs::read(&path).map_err(|e| HttpError::InvalidAuth(format!("could not read {}: {}", path.display(), e)))
+}
+
+fn resolve_jwt(resources: &HashMap<BoundIdentifier, Resource>, config: &JwtAuth) -> Result<JwtValidator, HttpError> {
+  if config.algorithms().is_empty() {
+    return Err(HttpError::InvalidAuth("jwt.algorithms must not be empty".to_owned()));
+  }
+  let algorithms: Vec<_> = config.algorithms().iter().copied().map(algorithm).collect();
+  let secret = config
+    .secret()
+    .map(|secret| {
+      secret
+        .value()
+        .map(|v| DecodingKey::from_secret(v.as_bytes()))
+        .ok_or_else(|| HttpError::InvalidAuth("jwt.secret has not been rendered".to_owned()))
+    })
+    .transpose()?;
+  let key = config
+    .key()
+    .map(|path| {
+      let pem = read_volume_file(resources, config.volume(), path)?;
+      DecodingKey::from_rsa_pem(&pem)
+        .or_else(|_| DecodingKey::from_ec_pem(&pem))
+        .map_err(|e| HttpError::InvalidAuth(format!("invalid public key in {}: {}", path, e)))
+    })
+    .transpose()?;
+  let jwks = config
+    .jwks()
+    .map(|path| {
+      let bytes = read_volume_file(resources, config.volume(), path)?;
+      serde_json::from_slice::<JwkSet>(&bytes)
+        .map_err(|e| HttpError::InvalidAuth(format!("invalid JWKS in {}: {}", path, e)))
+    })
+    .transpose()?;
+
+  let hmac = algorithms
+    .iter()
+    .any(|a| matches!(a, Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512));
+  if hmac && secret.is_none() {
+    return Err(HttpError::InvalidAuth(
+      "jwt.secret is required for HMAC algorithms".to_owned(),
+    ));
+  }
+  if algorithms.len() > usize::from(hmac) && key.is_none() && jwks.is_none() {
+    return Err(HttpError::InvalidAuth(
+      "jwt.key or jwt.jwks is required for RSA and ECDSA algorithms".to_owned(),
+    ));
+  }
+
+  Ok(JwtValidator {
+    algorithms,
+    secret,
+    key,
+    jwks,
+    issuer: config.issuer().cloned(),
+    audience: config.audience().to_vec(),
+  })
+}
+
+fn resolve_api_key(config: &ApiKeyAuth) -> Result<ApiKeyValidator, HttpError> {
+  let header = config
+    .header()
+    .parse::<HeaderName>()
+    .map_err(|_| HttpError::InvalidAuth(format!("invalid API key header: {}", config.header())))?;
+  let keys = config
+    .keys()
+    .iter()
+    .map(|key| {
+      let value = key
+        .key()
+        .value()
+        .cloned()
+        .ok_or_else(|| HttpError::InvalidAuth("api_key.keys has not been rendered".to_owned()))?;
+      if value.is_empty() {
+        return Err(HttpError::InvalidAuth("API keys must not be empty".to_owned()));
+      }
+      Ok((value, key.name().cloned(), key.scopes().to_vec()))
+    })
+    .collect::<Result<_, _>>()?;
+  Ok(ApiKeyValidator { header, keys })
+}
+
+pub(super) fn resolve_router_auth(
+  router: &impl WickRouter,
+  resources: &HashMap<BoundIdentifier, Resource>,
+) -> Result<Option<Arc<RouterAuth>>, HttpError> {
+  let Some(auth) = router.auth() else {
+    return Ok(None);
+  };
+  if auth.jwt().is_none() && auth.api_key().is_none() {
+    return Err(HttpError::InvalidAuth("auth requires jwt or api_key".to_owned()));
+  }
+  Ok(Some(Arc::new(RouterAuth {
+    jwt: auth.jwt().map(|jwt| resolve_jwt(resources, jwt)).transpose()?,
+    api_key: auth.api_key().map(resolve_api_key).transpose()?,
+    scopes: auth.scopes().to_vec(),
+  })))
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+  use jsonwebtoken::{EncodingKey, Header};
+
+  use super::*;
+
+  const SECRET: &str = "test-secret";
+
+  fn jwt_auth(scopes: Vec<String>) -> RouterAuth {
+    RouterAuth {
+      jwt: Some(JwtValidator {
+        algorithms: vec![Algorithm::HS256],
+        secret: Some(DecodingKey::from_secret(SECRET.as_bytes())),
+        key: None,
+        jwks: None,
+        issuer: Some("wick".to_owned()),
+        audience: Vec::new(),
+      }),
+      api_key: None,
+      scopes,
+    }
+  }
+
+  fn token(claims: &Value, secret: &str) -> Result<String> {
+    Ok(jsonwebtoken::encode(
+      &Header::new(Algorithm::HS256),
+      claims,
+      &EncodingKey::from_secret(secret.as_bytes()),
+    )?)
+  }
+
+  fn request(header: &str, value: &str) -> Request<()> {
+    let mut req = Request::builder().header(header, value).body(()).unwrap();
+    req.extensions_mut().insert(InvocationContext::new());
+    req
+  }
+
+  #[test]
+  fn test_jwt() -> Result<()> {
+    let exp = jsonwebtoken::get_current_timestamp() + 600;
+    let claims = json!({"sub": "user", "iss": "wick", "exp": exp, "scope": "read write"});
+    let auth = jwt_auth(vec!["read".to_owned()]);
+
+    let mut req = request("authorization", &format!("Bearer {}", token(&claims, SECRET)?));
+    auth.authorize(&mut req).unwrap();
+    let context = req.extensions().get::<InvocationContext>().unwrap();
+    assert_eq!(context.principal(), Some("user"));
+    let principal = req.extensions().get::<Principal>().unwrap();
+    assert!(principal.has_scopes(&["write".to_owned()]));
+    assert!(!principal.has_scopes(&["admin".to_owned()]));
+
+    let mut req = request("authorization", &format!("Bearer {}", token(&claims, "wrong")?));
+    assert_eq!(auth.authorize(&mut req), Err(AuthFailure::Unauthenticated));
+
+    let wrong_issuer = json!({"sub": "user", "iss": "other", "exp": exp});
+    let mut req = request("authorization", &format!("Bearer {}", token(&wrong_issuer, SECRET)?));
+    assert_eq!(auth.authorize(&mut req), Err(AuthFailure::Unauthenticated));
+
+    let auth = jwt_auth(vec!["admin".to_owned()]);
+    let mut req = request("authorization", &format!("Bearer {}", token(&claims, SECRET)?));
+    assert_eq!(auth.authorize(&mut req), Err(AuthFailure::Forbidden));
+    Ok(())
+  }
+
+  #[test]
+  fn test_api_key() {
+    let auth = RouterAuth {
+      jwt: None,
+      api_key: Some(ApiKeyValidator {
+        header: HeaderName::from_static("x-api-key"),
+        keys: vec![("abc123".to_owned(), Some("ci".to_owned()), vec!["deploy".to_owned()])],
+      }),
+      scopes: Vec::new(),
+    };
+    let mut req = request("x-api-key", "abc123");
+    auth.authorize(&mut req).unwrap();
+    let principal = req.extensions().get::<Principal>().unwrap();
+    assert_eq!(principal.subject.as_deref(), Some("ci"));
+    assert!(principal.has_scopes(&["deploy".to_owned()]));
+
+    let mut req = request("x-api-key", "abc124");
+    assert_eq!(auth.authorize(&mut req), Err(AuthFailure::Unauthenticated));
+    let mut req = request("authorization", "Bearer abc123");
+    assert_eq!(auth.authorize(&mut req), Err(AuthFailure::Unauthenticated));
+  }
+}
@@ -18,6 +18,7 @@ use wick_packet::{
   Entity,
   InherentData,
   Invocation,
+  InvocationContext,
   Packet,
   PacketExt,
   PacketPayload,
@@ -43,7 +44,7 @@ pub(super) async fn handle_request_middleware(
     Entity::server("http_client"),
     target.clone(),
     packets,
-    InherentData::unsafe_default(),
+    InherentData::unsafe_default().with_context(InvocationContext::from(req.context.clone())),
     span,
   );
 
@@ -102,7 +103,7 @@ pub(super) async fn handle_response_middleware(
     Entity::server("http_client"),
     target.clone(),
     packets,
-    InherentData::unsafe_default(),
+    InherentData::unsafe_default().with_context(InvocationContext::from(req.context.clone())),
     span,
   );
 
@@ -6,6 +6,7 @@ use hyper::http::response::Builder;
 use hyper::http::{HeaderName, HeaderValue};
 use hyper::{Body, Request, Response, StatusCode, Uri};
 use wick_interface_http::types as wick_http;
+use wick_packet::InvocationContext;
 
 use super::HttpError;
 
@@ -89,6 +90,15 @@ pub(super) fn headers_to_wick(headers: &hyper::http::HeaderMap) -> Result<HashMa
   Ok(map)
 }
 
+pub(super) fn context_to_wick<B>(req: &Request<B>) -> HashMap<String, String> {
+  req
+    .extensions()
+    .get::<InvocationContext>()
+    .cloned()
+    .map(Into::into)
+    .unwrap_or_default()
+}
+
 pub(super) fn request_and_body_to_wick<B>(
   req: Request<B>,
   remote_addr: SocketAddr,
@@ -107,6 +117,7 @@ where
       version: version_to_wick(req.version())?,
       headers: headers_to_wick(req.headers())?,
       remote_addr: remote_addr.to_string(),
+      context: context_to_wick(&req),
     },
     req.into_body(),
   ))
@@ -126,6 +137,7 @@ where
     version: version_to_wick(req.version())?,
     headers: headers_to_wick(req.headers())?,
     remote_addr: remote_addr.to_string(),
+    context: context_to_wick(req),
   })
 }
 
@@ -174,6 +186,9 @@ where
     .path_and_query(format!("{}?{}", wick.path, query_string).as_str())
     .build()
     .map_err(|e| HttpError::InvalidUri(e.to_string()))?;
+  hyper
+    .extensions_mut()
+    .insert(InvocationContext::from(wick.context.clone()));
   Ok(hyper)
 }
 
@@ -75,6 +75,24 @@ pub enum HttpError {
 
   #[error("error in configuration: {0}")]
   Config(Box<wick_config::Error>),
+
+  #[error("Invalid router policy: {0}")]
+  InvalidPolicy(String),
+
+  #[error("Invalid router authentication: {0}")]
+  InvalidAuth(String),
+
+  #[error("Request body exceeds the maximum size of {0} bytes")]
+  RequestBodyTooLarge(u64),
+
+  #[error("Timed out waiting for the request body")]
+  RequestBodyIdle,
+
+  #[error("Error reading request body: {0}")]
+  RequestBody(String),
+
+  #[error("Could not compress response: {0}")]
+  Compression(String),
 }
 
 #[derive(Debug, Clone, Copy)]
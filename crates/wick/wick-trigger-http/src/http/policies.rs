@@ -0,0 +1,140 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use hyper::header::CONTENT_LENGTH;
+use hyper::http::HeaderName;
+use hyper::{Body, HeaderMap, Request, Response, StatusCode};
+use wick_config::config::{Compression, WickRouter};
+use wick_packet::InvocationContext;
+
+pub(crate) use self::body::body_error;
+use self::cors::CorsPolicy;
+use self::rate_limit::RateLimiter;
+use super::HttpError;
+
+mod body;
+mod compression;
+mod cors;
+mod rate_limit;
+
+/// Built-in policies enforced on a router's requests before any operation is invoked.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RouterPolicies {
+  cors: Option<CorsPolicy>,
+  rate_limit: Option<Arc<RateLimiter>>,
+  max_body_size: Option<u64>,
+  request_timeout: Option<Duration>,
+  idle_timeout: Option<Duration>,
+  compression: Vec<Compression>,
+}
+
+impl RouterPolicies {
+  /// Check a request against the policies, returning the response to send instead of handling the request.
+  pub(crate) fn check<B>(&self, req: &Request<B>, remote_addr: SocketAddr) -> Option<Response<Body>> {
+    if let Some(response) = self.cors.as_ref().and_then(|cors| cors.preflight(req)) {
+      return Some(response);
+    }
+    if let Some(limiter) = &self.rate_limit {
+      if let Err(retry_after) = limiter.check(req, remote_addr) {
+        return Some(
+          Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header("retry-after", retry_after.as_secs().max(1))
+            .body(Body::empty())
+            .unwrap(),
+        );
+      }
+    }
+    if let Some(max) = self.max_body_size {
+      let content_length = req
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<u64>().ok());
+      if content_length.map_or(false, |len| len > max) {
+        return Some(status(StatusCode::PAYLOAD_TOO_LARGE));
+      }
+    }
+    None
+  }
+
+  /// Apply the request deadline and body limits to a request that passed [RouterPolicies::check].
+  pub(crate) fn prepare(&self, mut req: Request<Body>) -> Request<Body> {
+    if let Some(timeout) = self.request_timeout {
+      if let Some(context) = req.extensions_mut().get_mut::<InvocationContext>() {
+        let deadline = std::time::SystemTime::now() + timeout;
+        let millis = deadline
+          .duration_since(std::time::UNIX_EPOCH)
+          .unwrap_or_default()
+          .as_millis() as u64;
+        context.set_deadline(millis);
+      }
+    }
+    if self.max_body_size.is_none() && self.idle_timeout.is_none() {
+      return req;
+    }
+    let (parts, req_body) = req.into_parts();
+    Request::from_parts(parts, body::limit(req_body, self.max_body_size, self.idle_timeout))
+  }
+
+  /// The time allowed to handle a request.
+  pub(crate) const fn request_timeout(&self) -> Option<Duration> {
+    self.request_timeout
+  }
+
+  /// Apply compression and CORS headers to a response for a request with the passed headers.
+  pub(crate) async fn finalize(
+    &self,
+    req_headers: &HeaderMap,
+    response: Response<Body>,
+  ) -> Result<Response<Body>, HttpError> {
+    let mut response = if self.compression.is_empty() {
+      response
+    } else {
+      compression::compress(&self.compression, req_headers, response).await?
+    };
+    if let Some(cors) = &self.cors {
+      cors.apply(req_headers, response.headers_mut());
+    }
+    Ok(response)
+  }
+}
+
+fn status(status: StatusCode) -> Response<Body> {
+  Response::builder().status(status).body(Body::empty()).unwrap()
+}
+
+pub(super) fn resolve_router_policies(router: &impl WickRouter) -> Result<RouterPolicies, HttpError> {
+  let Some(policies) = router.policies() else {
+    return Ok(RouterPolicies::default());
+  };
+  let rate_limit = match policies.rate_limit() {
+    Some(config) => {
+      if config.burst() == 0 {
+        return Err(HttpError::InvalidPolicy("rate_limit.burst must be greater than 0".to_owned()));
+      }
+      if config.per_second() == 0 {
+        return Err(HttpError::InvalidPolicy(
+          "rate_limit.per_second must be greater than 0".to_owned(),
+        ));
+      }
+      let header = config
+        .header()
+        .map(|h| h.parse::<HeaderName>())
+        .transpose()
+        .map_err(|_| HttpError::InvalidPolicy(format!("invalid rate limit header: {:?}", config.header())))?;
+      Some(Arc::new(RateLimiter::new(config.burst(), config.per_second(), header)))
+    }
+    None => None,
+  };
+
+  Ok(RouterPolicies {
+    cors: policies.cors().map(CorsPolicy::new),
+    rate_limit,
+    max_body_size: policies.max_body_size(),
+    request_timeout: policies.request_timeout().map(Duration::from_millis),
+    idle_timeout: policies.idle_timeout().map(Duration::from_millis),
+    compression: policies.compression().to_vec(),
+  })
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use hyper::{Body, Response, StatusCode};
+
+use super::status;
+use crate::http::HttpError;
+
+/// Wrap a request body so reading it fails once it exceeds `max_size` bytes or no data arrives within `idle_timeout`.
+pub(super) fn limit(body: Body, max_size: Option<u64>, idle_timeout: Option<Duration>) -> Body {
+  let stream = futures::stream::unfold(Some((body, 0_u64)), move |state| async move {
+    let (mut body, read) = state?;
+    let next = match idle_timeout {
+      Some(timeout) => match tokio::time::timeout(timeout, body.next()).await {
+        Ok(next) => next,
+        Err(_) => return Some((Err(HttpError::RequestBodyIdle), None)),
+      },
+      None => body.next().await,
+    };
+    match next? {
+      Ok(chunk) => {
+        let read = read + chunk.len() as u64;
+        match max_size {
+          Some(max) if read > max => Some((Err(HttpError::RequestBodyTooLarge(max)), None)),
+          _ => Some((Ok(chunk), Some((body, read)))),
+        }
+      }
+      Err(e) => Some((Err(HttpError::RequestBody(e.to_string())), None)),
+    }
+  });
+  Body::wrap_stream(stream)
+}
+
+/// The response for a request whose body could not be read, e.g. because it broke the body limits.
+pub(crate) fn body_error(error: hyper::Error) -> Response<Body> {
+  let cause = error.into_cause().and_then(|cause| cause.downcast::<HttpError>().ok());
+  match cause.as_deref() {
+    Some(HttpError::RequestBodyTooLarge(_)) => status(StatusCode::PAYLOAD_TOO_LARGE),
+    Some(HttpError::RequestBodyIdle) => status(StatusCode::REQUEST_TIMEOUT),
+    _ => status(StatusCode::BAD_REQUEST),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[tokio::test]
+  async fn test_max_size() -> Result<()> {
+    let body = limit(Body::from("hello world"), Some(20), None);
+    assert_eq!(hyper::body::to_bytes(body).await?, "hello world");
+
+    let body = limit(Body::from("hello world"), Some(5), None);
+    let err = hyper::body::to_bytes(body).await.unwrap_err();
+    assert_eq!(body_error(err).status(), StatusCode::PAYLOAD_TOO_LARGE);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_idle_timeout() -> Result<()> {
+    let (mut sender, body) = Body::channel();
+    let body = limit(body, None, Some(Duration::from_millis(10)));
+    sender.send_data("hello".into()).await?;
+    let err = hyper::body::to_bytes(body).await.unwrap_err();
+    assert_eq!(body_error(err).status(), StatusCode::REQUEST_TIMEOUT);
+    Ok(())
+  }
+}
@@ -0,0 +1,154 @@
+use std::io::Write;
+
+use hyper::header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH, CONTENT_TYPE, VARY};
+use hyper::body::HttpBody;
+use hyper::http::HeaderValue;
+use hyper::{Body, HeaderMap, Response, StatusCode};
+use wick_config::config::Compression;
+
+use crate::http::HttpError;
+
+const fn encoding(algorithm: Compression) -> &'static str {
+  match algorithm {
+    Compression::Gzip => "gzip",
+    Compression::Brotli => "br",
+  }
+}
+
+/// Choose the first configured algorithm accepted by the client's `Accept-Encoding` header.
+fn negotiate(algorithms: &[Compression], accept_encoding: Option<&HeaderValue>) -> Option<Compression> {
+  let accept_encoding = accept_encoding?.to_str().ok()?;
+  let accepted: Vec<&str> = accept_encoding
+    .split(',')
+    .filter_map(|entry| {
+      let mut parts = entry.split(';');
+      let name = parts.next()?.trim();
+      let quality = parts
+        .find_map(|p| p.trim().strip_prefix("q="))
+        .and_then(|q| q.parse::<f32>().ok())
+        .unwrap_or(1.0);
+      (quality > 0.0).then_some(name)
+    })
+    .collect();
+  algorithms.iter().copied().find(|algorithm| {
+    accepted
+      .iter()
+      .any(|e| *e == "*" || e.eq_ignore_ascii_case(encoding(*algorithm)))
+  })
+}
+
+fn should_compress(response: &Response<Body>) -> bool {
+  let status = response.status();
+  let headers = response.headers();
+  // Streamed bodies are passed through as they are, compressing them would mean buffering them whole.
+  let streamed = response.body().size_hint().exact().is_none();
+  !(streamed
+    || status == StatusCode::NO_CONTENT
+    || status == StatusCode::NOT_MODIFIED
+    || status == StatusCode::PARTIAL_CONTENT
+    || headers.contains_key(CONTENT_ENCODING)
+    || headers
+      .get(CONTENT_TYPE)
+      .map_or(false, |v| v.as_bytes().starts_with(b"text/event-stream")))
+}
+
+fn encode(algorithm: Compression, bytes: &[u8]) -> std::io::Result<Vec<u8>> {
+  match algorithm {
+    Compression::Gzip => {
+      let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+      encoder.write_all(bytes)?;
+      encoder.finish()
+    }
+    Compression::Brotli => {
+      let mut output = Vec::new();
+      {
+        let mut encoder = brotli::CompressorWriter::new(&mut output, 4096, 5, 22);
+        encoder.write_all(bytes)?;
+      }
+      Ok(output)
+    }
+  }
+}
+
+/// Compress a response with the first configured algorithm the client accepts.
+pub(super) async fn compress(
+  algorithms: &[Compression],
+  req_headers: &HeaderMap,
+  response: Response<Body>,
+) -> Result<Response<Body>, HttpError> {
+  let Some(algorithm) = negotiate(algorithms, req_headers.get(ACCEPT_ENCODING)) else {
+    return Ok(response);
+  };
+  if !should_compress(&response) {
+    return Ok(response);
+  }
+  let (mut parts, body) = response.into_parts();
+  let bytes = hyper::body::to_bytes(body)
+    .await
+    .map_err(|e| HttpError::Compression(e.to_string()))?;
+  parts.headers.append(VARY, HeaderValue::from_static("accept-encoding"));
+  if bytes.is_empty() {
+    return Ok(Response::from_parts(parts, Body::from(bytes)));
+  }
+  let compressed = encode(algorithm, &bytes).map_err(|e| HttpError::Compression(e.to_string()))?;
+  parts
+    .headers
+    .insert(CONTENT_ENCODING, HeaderValue::from_static(encoding(algorithm)));
+  parts
+    .headers
+    .insert(CONTENT_LENGTH, HeaderValue::from(compressed.len()));
+  Ok(Response::from_parts(parts, Body::from(compressed)))
+}
+
+#[cfg(test)]
+mod test {
+  use std::io::Read;
+
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_negotiate() {
+    let both = [Compression::Brotli, Compression::Gzip];
+    let header = HeaderValue::from_static;
+    assert_eq!(negotiate(&both, Some(&header("gzip, br"))), Some(Compression::Brotli));
+    assert_eq!(negotiate(&both, Some(&header("gzip"))), Some(Compression::Gzip));
+    assert_eq!(negotiate(&both, Some(&header("br;q=0, gzip"))), Some(Compression::Gzip));
+    assert_eq!(negotiate(&both, Some(&header("*"))), Some(Compression::Brotli));
+    assert_eq!(negotiate(&both, Some(&header("identity"))), None);
+    assert_eq!(negotiate(&both, None), None);
+  }
+
+  #[tokio::test]
+  async fn test_compress() -> Result<()> {
+    let mut req_headers = HeaderMap::new();
+    req_headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+    let body = "hello world ".repeat(100);
+    let response = Response::new(Body::from(body.clone()));
+
+    let response = compress(&[Compression::Gzip], &req_headers, response).await?;
+    assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+    let bytes = hyper::body::to_bytes(response.into_body()).await?;
+    assert!(bytes.len() < body.len());
+
+    let mut decoded = String::new();
+    flate2::read::GzDecoder::new(bytes.as_ref()).read_to_string(&mut decoded)?;
+    assert_eq!(decoded, body);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_skip_streamed() -> Result<()> {
+    let mut req_headers = HeaderMap::new();
+    req_headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("gzip"));
+    let (mut sender, body) = Body::channel();
+    sender.send_data("hello".into()).await?;
+    drop(sender);
+
+    let response = compress(&[Compression::Gzip], &req_headers, Response::new(body)).await?;
+    assert!(!response.headers().contains_key(CONTENT_ENCODING));
+    assert_eq!(hyper::body::to_bytes(response.into_body()).await?, "hello");
+    Ok(())
+  }
+}
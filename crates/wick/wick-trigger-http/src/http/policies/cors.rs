@@ -0,0 +1,204 @@
+use hyper::header::{
+  ACCESS_CONTROL_ALLOW_CREDENTIALS,
+  ACCESS_CONTROL_ALLOW_HEADERS,
+  ACCESS_CONTROL_ALLOW_METHODS,
+  ACCESS_CONTROL_ALLOW_ORIGIN,
+  ACCESS_CONTROL_EXPOSE_HEADERS,
+  ACCESS_CONTROL_MAX_AGE,
+  ACCESS_CONTROL_REQUEST_HEADERS,
+  ACCESS_CONTROL_REQUEST_METHOD,
+  ORIGIN,
+  VARY,
+};
+use hyper::http::HeaderValue;
+use hyper::{Body, HeaderMap, Method, Request, Response, StatusCode};
+use wick_config::config::{Cors, HttpMethod};
+
+use super::status;
+
+const WILDCARD: &str = "*";
+
+#[derive(Debug, Clone)]
+pub(super) struct CorsPolicy {
+  allowed_origins: Vec<String>,
+  allowed_methods: Vec<&'static str>,
+  allowed_headers: Vec<String>,
+  exposed_headers: Vec<String>,
+  allow_credentials: bool,
+  max_age: Option<u32>,
+}
+
+impl CorsPolicy {
+  pub(super) fn new(config: &Cors) -> Self {
+    Self {
+      allowed_origins: config.allowed_origins().to_vec(),
+      allowed_methods: config.allowed_methods().iter().copied().map(method_name).collect(),
+      allowed_headers: config.allowed_headers().to_vec(),
+      exposed_headers: config.exposed_headers().to_vec(),
+      allow_credentials: config.allow_credentials(),
+      max_age: config.max_age(),
+    }
+  }
+
+  /// The value of the `Access-Control-Allow-Origin` header for an origin, if it is allowed.
+  fn allow_origin(&self, origin: &HeaderValue) -> Option<HeaderValue> {
+    if self.allowed_origins.iter().any(|o| o == WILDCARD) {
+      // Browsers reject a wildcard origin on requests with credentials so the origin is echoed instead.
+      return Some(if self.allow_credentials {
+        origin.clone()
+      } else {
+        HeaderValue::from_static(WILDCARD)
+      });
+    }
+    let origin_str = origin.to_str().ok()?;
+    self
+      .allowed_origins
+      .iter()
+      .any(|o| o.trim_end_matches('/').eq_ignore_ascii_case(origin_str))
+      .then(|| origin.clone())
+  }
+
+  fn allows_method(&self, method: &str) -> bool {
+    self.allowed_methods.is_empty() || self.allowed_methods.iter().any(|m| m.eq_ignore_ascii_case(method))
+  }
+
+  /// Respond to a CORS preflight request.
+  pub(super) fn preflight<B>(&self, req: &Request<B>) -> Option<Response<Body>> {
+    if req.method() != Method::OPTIONS {
+      return None;
+    }
+    let origin = req.headers().get(ORIGIN)?;
+    let requested_method = req.headers().get(ACCESS_CONTROL_REQUEST_METHOD)?;
+
+    let Some(allow_origin) = self.allow_origin(origin) else {
+      return Some(status(StatusCode::FORBIDDEN));
+    };
+    if !self.allows_method(requested_method.to_str().unwrap_or_default()) {
+      return Some(status(StatusCode::FORBIDDEN));
+    }
+
+    // `Vary` is added along with the other response headers by [CorsPolicy::apply].
+    let mut builder = Response::builder()
+      .status(StatusCode::NO_CONTENT)
+      .header(ACCESS_CONTROL_ALLOW_ORIGIN, allow_origin);
+
+    builder = if self.allowed_methods.is_empty() {
+      builder.header(ACCESS_CONTROL_ALLOW_METHODS, requested_method.clone())
+    } else {
+      builder.header(ACCESS_CONTROL_ALLOW_METHODS, self.allowed_methods.join(", "))
+    };
+
+    if self.allowed_headers.iter().any(|h| h == WILDCARD) {
+      if let Some(requested_headers) = req.headers().get(ACCESS_CONTROL_REQUEST_HEADERS) {
+        builder = builder.header(ACCESS_CONTROL_ALLOW_HEADERS, requested_headers.clone());
+      }
+    } else if !self.allowed_headers.is_empty() {
+      builder = builder.header(ACCESS_CONTROL_ALLOW_HEADERS, self.allowed_headers.join(", "));
+    }
+    if self.allow_credentials {
+      builder = builder.header(ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+    }
+    if let Some(max_age) = self.max_age {
+      builder = builder.header(ACCESS_CONTROL_MAX_AGE, max_age);
+    }
+
+    Some(builder.body(Body::empty()).unwrap())
+  }
+
+  /// Add CORS headers to the response for a request with the passed headers.
+  pub(super) fn apply(&self, req_headers: &HeaderMap, headers: &mut HeaderMap) {
+    let Some(allow_origin) = req_headers.get(ORIGIN).and_then(|origin| self.allow_origin(origin)) else {
+      return;
+    };
+    headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, allow_origin);
+    headers.append(VARY, HeaderValue::from_static("origin"));
+    if self.allow_credentials {
+      headers.insert(ACCESS_CONTROL_ALLOW_CREDENTIALS, HeaderValue::from_static("true"));
+    }
+    if !self.exposed_headers.is_empty() {
+      if let Ok(value) = HeaderValue::from_str(&self.exposed_headers.join(", ")) {
+        headers.insert(ACCESS_CONTROL_EXPOSE_HEADERS, value);
+      }
+    }
+  }
+}
+
+const fn method_name(method: HttpMethod) -> &'static str {
+  match method {
+    HttpMethod::Get => "GET",
+    HttpMethod::Post => "POST",
+    HttpMethod::Put => "PUT",
+    HttpMethod::Delete => "DELETE",
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn policy(origins: &[&str], credentials: bool) -> CorsPolicy {
+    CorsPolicy {
+      allowed_origins: origins.iter().map(|o| (*o).to_owned()).collect(),
+      allowed_methods: vec!["GET", "POST"],
+      allowed_headers: vec!["content-type".to_owned()],
+      exposed_headers: vec!["x-request-id".to_owned()],
+      allow_credentials: credentials,
+      max_age: Some(600),
+    }
+  }
+
+  fn preflight_request(origin: &str, method: &str) -> Request<Body> {
+    Request::builder()
+      .method(Method::OPTIONS)
+      .header(ORIGIN, origin)
+      .header(ACCESS_CONTROL_REQUEST_METHOD, method)
+      .body(Body::empty())
+      .unwrap()
+  }
+
+  #[test]
+  fn test_preflight() {
+    let policy = policy(&["https://example.com"], false);
+
+    let res = policy
+      .preflight(&preflight_request("https://example.com", "POST"))
+      .unwrap();
+    assert_eq!(res.status(), StatusCode::NO_CONTENT);
+    assert_eq!(res.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "https://example.com");
+    assert_eq!(res.headers()[ACCESS_CONTROL_ALLOW_METHODS], "GET, POST");
+    assert_eq!(res.headers()[ACCESS_CONTROL_ALLOW_HEADERS], "content-type");
+    assert_eq!(res.headers()[ACCESS_CONTROL_MAX_AGE], "600");
+
+    let res = policy
+      .preflight(&preflight_request("https://example.com", "DELETE"))
+      .unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    let res = policy.preflight(&preflight_request("https://evil.com", "GET")).unwrap();
+    assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+    let req = Request::builder().method(Method::GET).body(Body::empty()).unwrap();
+    assert!(policy.preflight(&req).is_none());
+  }
+
+  #[test]
+  fn test_apply() {
+    let mut req_headers = HeaderMap::new();
+    req_headers.insert(ORIGIN, HeaderValue::from_static("https://example.com"));
+
+    let mut headers = HeaderMap::new();
+    policy(&["*"], false).apply(&req_headers, &mut headers);
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+    assert_eq!(headers[ACCESS_CONTROL_EXPOSE_HEADERS], "x-request-id");
+    assert!(headers.get(ACCESS_CONTROL_ALLOW_CREDENTIALS).is_none());
+
+    let mut headers = HeaderMap::new();
+    policy(&["*"], true).apply(&req_headers, &mut headers);
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_ORIGIN], "https://example.com");
+    assert_eq!(headers[ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+
+    let mut headers = HeaderMap::new();
+    policy(&["https://other.com"], false).apply(&req_headers, &mut headers);
+    assert!(headers.is_empty());
+  }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use hyper::http::HeaderName;
+use hyper::Request;
+use parking_lot::Mutex;
+
+/// The number of clients to track before forgetting those whose buckets have refilled.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+  tokens: f64,
+  updated: Instant,
+}
+
+/// A token bucket rate limiter keyed by remote address or a request header.
+#[derive(Debug)]
+pub(super) struct RateLimiter {
+  burst: f64,
+  per_second: f64,
+  header: Option<HeaderName>,
+  buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+  pub(super) fn new(burst: u32, per_second: u32, header: Option<HeaderName>) -> Self {
+    Self {
+      burst: f64::from(burst),
+      per_second: f64::from(per_second),
+      header,
+      buckets: Mutex::new(HashMap::new()),
+    }
+  }
+
+  fn key<B>(&self, req: &Request<B>, remote_addr: SocketAddr) -> String {
+    self
+      .header
+      .as_ref()
+      .and_then(|header| req.headers().get(header))
+      .and_then(|v| v.to_str().ok())
+      .map_or_else(|| remote_addr.ip().to_string(), |v| format!("header:{}", v))
+  }
+
+  /// Take a token for the request, returning how long to wait before retrying if none are available.
+  pub(super) fn check<B>(&self, req: &Request<B>, remote_addr: SocketAddr) -> Result<(), Duration> {
+    self.take(self.key(req, remote_addr), Instant::now())
+  }
+
+  fn take(&self, key: String, now: Instant) -> Result<(), Duration> {
+    let mut buckets = self.buckets.lock();
+    if buckets.len() >= MAX_TRACKED_CLIENTS && !buckets.contains_key(&key) {
+      let (burst, per_second) = (self.burst, self.per_second);
+      buckets.retain(|_, bucket| {
+        bucket.tokens + now.saturating_duration_since(bucket.updated).as_secs_f64() * per_second < burst
+      });
+    }
+    let bucket = buckets.entry(key).or_insert(Bucket {
+      tokens: self.burst,
+      updated: now,
+    });
+    let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+    bucket.tokens = elapsed.mul_add(self.per_second, bucket.tokens).min(self.burst);
+    bucket.updated = now;
+
+    if bucket.tokens >= 1.0 {
+      bucket.tokens -= 1.0;
+      Ok(())
+    } else {
+      Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.per_second))
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn test_token_bucket() {
+    let limiter = RateLimiter::new(2, 1, None);
+    let start = Instant::now();
+    assert!(limiter.take("a".to_owned(), start).is_ok());
+    assert!(limiter.take("a".to_owned(), start).is_ok());
+    let retry = limiter.take("a".to_owned(), start).unwrap_err();
+    assert_eq!(retry, Duration::from_secs(1));
+    assert!(limiter.take("b".to_owned(), start).is_ok());
+
+    let later = start + Duration::from_millis(1500);
+    assert!(limiter.take("a".to_owned(), later).is_ok());
+    assert!(limiter.take("a".to_owned(), later).is_err());
+  }
+
+  #[test]
+  fn test_key() {
+    let limiter = RateLimiter::new(1, 1, Some(HeaderName::from_static("x-api-key")));
+    let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+    let req = Request::builder().header("x-api-key", "abc").body(()).unwrap();
+    assert_eq!(limiter.key(&req, addr), "header:abc");
+    let req = Request::builder().body(()).unwrap();
+    assert_eq!(limiter.key(&req, addr), "127.0.0.1");
+  }
+}
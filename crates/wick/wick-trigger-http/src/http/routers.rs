@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
+use parking_lot::Mutex;
 use url::Url;
 use wick_config::config::{BoundIdentifier, Codec};
 use wick_packet::RuntimeConfig;
@@ -33,6 +34,8 @@ pub(crate) struct RawRouterHandler {
   pub(super) path: String,
   pub(super) component: Arc<dyn RawRouter + Send + Sync>,
   pub(super) middleware: super::middleware::RouterMiddleware,
+  pub(super) policies: super::policies::RouterPolicies,
+  pub(super) auth: Option<Arc<super::auth::RouterAuth>>,
 }
 impl std::fmt::Debug for RawRouterHandler {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -40,6 +43,20 @@ impl std::fmt::Debug for RawRouterHandler {
   }
 }
 
+/// The route that handled a request, set by routers that serve more than one route.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MatchedRoute(Arc<Mutex<Option<String>>>);
+
+impl MatchedRoute {
+  pub(crate) fn set(&self, route: impl Into<String>) {
+    self.0.lock().replace(route.into());
+  }
+
+  pub(crate) fn get(&self) -> Option<String> {
+    self.0.lock().clone()
+  }
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct RouterOperation {
   operation: String,
@@ -13,6 +13,7 @@ use wick_trigger::Error;
 
 use super::super::{HttpError, HttpRouter, RawRouter};
 use crate::http::middleware::resolve_middleware_components;
+use crate::http::policies::resolve_router_policies;
 use crate::http::routers::get_url;
 use crate::http::{BoxFuture, RawRouterHandler};
 
@@ -77,6 +78,7 @@ pub(crate) fn register_proxy_router(
 ) -> Result<HttpRouter, Error> {
   trace!(index, "registering proxy router");
   let middleware = resolve_middleware_components(router_config)?;
+  let policies = resolve_router_policies(router_config)?;
   let url = get_url(resources, router_config.url())?;
   let strip_path = router_config.strip_path().then(|| router_config.path().to_owned());
   let router = ProxyRouter::new(url, strip_path);
@@ -84,5 +86,7 @@ pub(crate) fn register_proxy_router(
     path: router_config.path().to_owned(),
     component: Arc::new(router),
     middleware,
+    policies,
+    auth: None,
   }))
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
@@ -6,13 +7,26 @@ use hyper::{Body, Request, Response};
 use serde_json::Value;
 use tracing::{Instrument, Span};
 use uuid::Uuid;
-use wick_config::config::{Codec, RawRouterConfig, WickRouter};
-use wick_packet::{packets, Base64Bytes, Entity, InherentData, Invocation, Observer, Packet, PacketStream};
+use wick_config::config::{BoundIdentifier, Codec, RawRouterConfig, WickRouter};
+use wick_packet::{
+  packets,
+  Base64Bytes,
+  Entity,
+  InherentData,
+  Invocation,
+  InvocationContext,
+  Observer,
+  Packet,
+  PacketStream,
+};
 use wick_runtime::Runtime;
+use wick_trigger::resources::Resource;
 
+use crate::http::auth::resolve_router_auth;
 use crate::http::component_utils::respond;
 use crate::http::conversions::request_and_body_to_wick;
 use crate::http::middleware::resolve_middleware_components;
+use crate::http::policies::resolve_router_policies;
 use crate::http::{BoxFuture, HttpError, HttpRouter, RawRouter, RawRouterHandler, RouterOperation};
 
 #[derive()]
@@ -88,13 +102,14 @@ impl RawHandler {
     } = self;
 
     let (tx, rx) = PacketStream::new_channels();
+    let context = req.extensions().get::<InvocationContext>().cloned().unwrap_or_default();
 
     let invocation = Invocation::new_with_id(
       tx_id,
       Entity::server("http_client"),
       target,
       rx,
-      InherentData::unsafe_default(),
+      InherentData::unsafe_default().with_context(context),
       &Span::current(),
     );
 
@@ -150,9 +165,15 @@ impl RawHandler {
   }
 }
 
-pub(crate) fn register_raw_router(index: usize, router_config: &RawRouterConfig) -> Result<HttpRouter, HttpError> {
+pub(crate) fn register_raw_router(
+  index: usize,
+  resources: Arc<HashMap<BoundIdentifier, Resource>>,
+  router_config: &RawRouterConfig,
+) -> Result<HttpRouter, HttpError> {
   trace!(index, "registering raw router");
   let middleware = resolve_middleware_components(router_config)?;
+  let policies = resolve_router_policies(router_config)?;
+  let auth = resolve_router_auth(router_config, &resources)?;
 
   let component_id = router_config.operation().component_id()?;
 
@@ -170,5 +191,7 @@ pub(crate) fn register_raw_router(index: usize, router_config: &RawRouterConfig)
     path: router_config.path().to_owned(),
     component: Arc::new(router),
     middleware,
+    policies,
+    auth,
   }))
 }
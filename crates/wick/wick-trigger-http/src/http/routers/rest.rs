@@ -16,7 +16,7 @@ use wick_config::config::{
   RestRouterConfig,
   WickRouter,
 };
-use wick_packet::{Entity, InherentData, Invocation, Packet, PacketExt};
+use wick_packet::{Entity, InherentData, Invocation, InvocationContext, Packet, PacketExt};
 mod error;
 mod openapi;
 mod route;
@@ -25,8 +25,11 @@ use wick_runtime::Runtime;
 use wick_trigger::resources::Resource;
 
 use self::error::RestError;
+use crate::http::auth::{resolve_router_auth, Principal};
 use crate::http::component_utils::stream_to_json;
 use crate::http::middleware::resolve_middleware_components;
+use crate::http::routers::MatchedRoute;
+use crate::http::policies::{body_error, resolve_router_policies};
 use crate::http::{BoxFuture, HttpError, HttpRouter, RawRouter, RawRouterHandler};
 
 pub(crate) const OPENAPI_PATH: &str = "/openapi.json";
@@ -167,6 +170,22 @@ impl RestHandler {
       let Some((path_params, query_params)) = route.route.compare(path, request.uri().query())? else {
         continue;
       };
+      if let Some(matched) = request.extensions().get::<MatchedRoute>() {
+        matched.set(format!("{}{}", context.root, route.config.sub_path()));
+      }
+      if !route.config.scopes().is_empty()
+        && !request
+          .extensions()
+          .get::<Principal>()
+          .map_or(false, |principal| principal.has_scopes(route.config.scopes()))
+      {
+        return Ok(
+          Response::builder()
+            .status(StatusCode::FORBIDDEN)
+            .body(Body::empty())
+            .unwrap(),
+        );
+      }
       let uri = request.uri().clone();
       span.in_scope(
         || trace!(route = %uri, path_params=?path_params, query_params=?query_params, "incoming http request"),
@@ -177,9 +196,14 @@ impl RestHandler {
         .map(|f| Packet::encode(f.name(), f.value()))
         .collect();
 
-      let (_, body) = request.into_parts();
+      let (parts, body) = request.into_parts();
+      let invocation_context = parts.extensions.get::<InvocationContext>().cloned().unwrap_or_default();
 
-      let body_bytes = to_bytes(body).await.unwrap_or_default();
+      // Body limits are enforced while reading, so a body that breaks them is rejected before invoking anything.
+      let body_bytes = match to_bytes(body).await {
+        Ok(bytes) => bytes,
+        Err(e) => return Ok(body_error(e)),
+      };
       let body = String::from_utf8_lossy(&body_bytes);
 
       span.in_scope(|| trace!(route = %uri, len=body_bytes.len(), "body"));
@@ -205,7 +229,7 @@ impl RestHandler {
         Entity::server("http"),
         Entity::operation(&route.component, route.operation.name()),
         packets,
-        InherentData::unsafe_default(),
+        InherentData::unsafe_default().with_context(invocation_context),
         &span,
       );
       let runtime_config = route.operation.config().and_then(|c| c.value().cloned());
@@ -326,16 +350,24 @@ mod test {
 
 pub(crate) fn register_rest_router(
   index: usize,
-  _resources: Arc<HashMap<BoundIdentifier, Resource>>,
+  resources: Arc<HashMap<BoundIdentifier, Resource>>,
   app_config: &AppConfiguration,
   router_config: &RestRouterConfig,
 ) -> Result<HttpRouter, HttpError> {
   trace!(index, "registering rest router");
   let middleware = resolve_middleware_components(router_config)?;
+  let policies = resolve_router_policies(router_config)?;
+  let auth = resolve_router_auth(router_config, &resources)?;
   let mut routes = Vec::new();
 
   for route in router_config.routes().iter() {
     info!(sub_path = route.sub_path(), "registering rest route");
+    if !route.scopes().is_empty() && auth.is_none() {
+      return Err(HttpError::InvalidAuth(format!(
+        "route {} requires scopes but its router has no auth configured",
+        route.sub_path()
+      )));
+    }
 
     let component_id = route.operation().component_id()?;
     let route = RestRoute::new(route.clone(), component_id.to_owned()).map_err(|e| {
@@ -354,5 +386,7 @@ pub(crate) fn register_rest_router(
     path: router_config.path().to_owned(),
     component: Arc::new(router),
     middleware,
+    policies,
+    auth,
   }))
 }
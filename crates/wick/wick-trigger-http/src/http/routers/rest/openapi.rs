@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use openapiv3::{
+  APIKeyLocation,
   ArrayType,
   Components,
   Contact,
@@ -20,10 +21,12 @@ use openapiv3::{
   Responses,
   Schema,
   SchemaData,
+  SecurityRequirement,
+  SecurityScheme,
   StringType,
   VariantOrUnknownOrEmpty,
 };
-use wick_config::config::{AppConfiguration, HttpMethod, WickRouter};
+use wick_config::config::{AppConfiguration, HttpAuth, HttpMethod, WickRouter};
 use wick_interface_types::{EnumDefinition, Field, StructDefinition, Type, TypeDefinition, UnionDefinition};
 
 use super::error::RestError;
@@ -59,7 +62,7 @@ pub(crate) fn generate_openapi(
   let mut named_types = HashSet::new();
 
   let paths = routes.iter().map(|route| {
-    let path_item = route_to_path_item(route, &mut named_types);
+    let path_item = route_to_path_item(route, config.auth(), &mut named_types);
     let path = route
       .route
       .path_parts
@@ -89,11 +92,50 @@ pub(crate) fn generate_openapi(
   };
   let mut components = Components::default();
   components.schemas.extend(schemas.into_iter());
+  if let Some(auth) = config.auth() {
+    if auth.jwt().is_some() {
+      components.security_schemes.insert(
+        BEARER_AUTH.to_owned(),
+        ReferenceOr::Item(SecurityScheme::HTTP {
+          scheme: "bearer".to_owned(),
+          bearer_format: Some("JWT".to_owned()),
+          description: None,
+        }),
+      );
+    }
+    if let Some(api_key) = auth.api_key() {
+      components.security_schemes.insert(
+        API_KEY_AUTH.to_owned(),
+        ReferenceOr::Item(SecurityScheme::APIKey {
+          location: APIKeyLocation::Header,
+          name: api_key.header().to_owned(),
+          description: None,
+        }),
+      );
+    }
+    openapi.security = Some(security_requirements(auth, &[]));
+  }
 
   openapi.components = Some(components);
   Ok(openapi)
 }
 
+const BEARER_AUTH: &str = "bearerAuth";
+const API_KEY_AUTH: &str = "apiKeyAuth";
+
+/// The alternative ways a request can satisfy a router's authentication, with the scopes it must be granted.
+fn security_requirements(auth: &HttpAuth, scopes: &[String]) -> Vec<SecurityRequirement> {
+  let scopes: Vec<String> = auth.scopes().iter().chain(scopes).cloned().collect();
+  let mut requirements = Vec::new();
+  if auth.jwt().is_some() {
+    requirements.push([(BEARER_AUTH.to_owned(), scopes.clone())].into_iter().collect());
+  }
+  if auth.api_key().is_some() {
+    requirements.push([(API_KEY_AUTH.to_owned(), scopes)].into_iter().collect());
+  }
+  requirements
+}
+
 type Seen = HashSet<String>;
 type SchemaMap = HashMap<String, ReferenceOr<Schema>>;
 
@@ -119,7 +161,7 @@ fn resolve_named_types(
   Ok((schemas, named, new_named))
 }
 
-fn route_to_path_item(route: &RestRoute, named: &mut HashSet<String>) -> PathItem {
+fn route_to_path_item(route: &RestRoute, auth: Option<&HttpAuth>, named: &mut HashSet<String>) -> PathItem {
   let mut path_item = PathItem {
     summary: route.config.summary().cloned(),
     description: route.config.description().cloned(),
@@ -156,7 +198,9 @@ fn route_to_path_item(route: &RestRoute, named: &mut HashSet<String>) -> PathIte
     request_body: Default::default(),
     responses: Responses::default(),
     deprecated: Default::default(),
-    security: Default::default(),
+    security: auth
+      .filter(|_| !route.config.scopes().is_empty())
+      .map(|auth| security_requirements(auth, route.config.scopes())),
     servers: Default::default(),
     extensions: Default::default(),
   };
@@ -226,9 +270,9 @@ fn typedef_to_schema(ty: &TypeDefinition, named: &mut HashSet<String>) -> Schema
   }
 }
 
-fn struct_to_schema(ty: &StructDefinition, named: &mut HashSet<String>) -> Schema {
+fn fields_to_object(fields: &[Field], named: &mut HashSet<String>) -> ObjectType {
   let mut obj = ObjectType::default();
-  for field in &ty.fields {
+  for field in fields {
     obj.properties.insert(
       field.name().to_owned(),
       ReferenceOr::Item(Box::new(field_to_schema(field, named))),
@@ -237,13 +281,17 @@ fn struct_to_schema(ty: &StructDefinition, named: &mut HashSet<String>) -> Schem
       obj.required.push(field.name().to_owned());
     }
   }
+  obj
+}
+
+fn struct_to_schema(ty: &StructDefinition, named: &mut HashSet<String>) -> Schema {
   Schema {
     schema_data: SchemaData {
       description: ty.description.clone(),
       nullable: false,
       ..Default::default()
     },
-    schema_kind: openapiv3::SchemaKind::Type(openapiv3::Type::Object(obj)),
+    schema_kind: openapiv3::SchemaKind::Type(openapiv3::Type::Object(fields_to_object(&ty.fields, named))),
   }
 }
 
@@ -372,8 +420,34 @@ fn wick_type_to_schemakind(ty: &Type, named: &mut HashSet<String>) -> openapiv3:
       min_properties: Default::default(),
       max_properties: Default::default(),
     })),
-    Type::AnonymousStruct(_) => unimplemented!("Anonymous structs are not supported in OpenAPI config"),
+    // Anonymous structs have no name to reference, so they are emitted inline.
+    Type::AnonymousStruct(fields) => {
+      openapiv3::SchemaKind::Type(openapiv3::Type::Object(fields_to_object(fields, named)))
+    }
     #[allow(deprecated)]
     Type::Link { .. } => unreachable!(),
   }
 }
+
+#[cfg(test)]
+mod test {
+  use anyhow::Result;
+
+  use super::*;
+
+  #[test]
+  fn test_anonymous_struct_schema() -> Result<()> {
+    let ty = wick_interface_types::parse("{ id: u32, user: { name: string? } }[]")?;
+    let mut named = HashSet::new();
+    let schema = serde_json::to_value(type_to_schema(&ty, &mut named))?;
+
+    assert!(named.is_empty());
+    let item = &schema["items"];
+    assert_eq!(item["type"], "object");
+    assert_eq!(item["required"], serde_json::json!(["id", "user"]));
+    assert_eq!(item["properties"]["user"]["properties"]["name"]["nullable"], true);
+    assert!(item["properties"]["user"]["required"].is_null());
+
+    Ok(())
+  }
+}
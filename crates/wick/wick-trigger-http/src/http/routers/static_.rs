@@ -16,7 +16,9 @@ use wick_trigger::error::{Error, ErrorKind};
 use wick_trigger::resources::{Resource, ResourceKind};
 
 use self::index_list::StaticError;
+use crate::http::auth::resolve_router_auth;
 use crate::http::middleware::resolve_middleware_components;
+use crate::http::policies::resolve_router_policies;
 use crate::http::{BoxFuture, HttpError, HttpRouter, RawRouter, RawRouterHandler};
 
 #[derive()]
@@ -158,6 +160,8 @@ pub(crate) fn register_static_router(
 ) -> Result<HttpRouter, Error> {
   trace!(index, "registering static router");
   let middleware = resolve_middleware_components(router_config)?;
+  let policies = resolve_router_policies(router_config)?;
+  let auth = resolve_router_auth(router_config, &resources)?;
   let volume = resources
     .get(router_config.volume())
     .ok_or_else(|| Error::new_context("http", ErrorKind::ResourceNotFound(router_config.volume().id().into())))?;
@@ -180,5 +184,7 @@ pub(crate) fn register_static_router(
     path: router_config.path().to_owned(),
     component: Arc::new(router),
     middleware,
+    policies,
+    auth,
   }))
 }
@@ -11,12 +11,13 @@ use hyper::{Body, Request, Response, StatusCode};
 use tracing::{Id, Span};
 use uuid::Uuid;
 use wick_interface_http::types::RequestMiddlewareResponse;
-use wick_packet::Invocation;
+use wick_packet::{Invocation, InvocationContext};
 use wick_runtime::Runtime;
 
 use super::component_utils::{handle_request_middleware, handle_response_middleware};
 use super::conversions::{convert_response, convert_to_wick_response, merge_requests, request_to_wick};
 use super::error::InternalError;
+use super::routers::MatchedRoute;
 use super::{HttpError, HttpRouter, RawRouterHandler};
 
 pub(super) struct ServiceFactory {
@@ -83,7 +84,7 @@ impl Service<Request<Body>> for ResponseService {
     Poll::Ready(Ok(()))
   }
 
-  fn call(&mut self, req: Request<Body>) -> Self::Future {
+  fn call(&mut self, mut req: Request<Body>) -> Self::Future {
     let remote_addr = self.remote_addr;
     let time = chrono::Local::now().format("%d/%b/%Y:%H:%M:%S %z");
     let path = req.uri().path().to_owned();
@@ -91,6 +92,14 @@ impl Service<Request<Body>> for ResponseService {
     let span = info_span!("http:request",%tx_id);
     span.follows_from(self.span.clone());
 
+    let context = request_context(&req);
+    if let Some(traceparent) = context.traceparent() {
+      wick_logger::propagation::set_remote_parent(&span, traceparent, context.tracestate());
+    }
+    req.extensions_mut().insert(context);
+    let matched_route = MatchedRoute::default();
+    req.extensions_mut().insert(matched_route.clone());
+
     span.in_scope(|| {
       info!(
         time = %time,
@@ -108,9 +117,9 @@ impl Service<Request<Body>> for ResponseService {
 
     Box::pin(async move {
       let start = chrono::Local::now().format("%d/%b/%Y:%H:%M:%S %z");
-      let response = match router {
+      let response = match &router {
         Some(h) => match h {
-          HttpRouter::Raw(r) => match handle(tx_id, req, r, rt.clone(), remote_addr, &span).await {
+          HttpRouter::Raw(r) => match handle(tx_id, req, r.clone(), rt.clone(), remote_addr, &span).await {
             Ok(v) => v,
             Err(e) => {
               span.in_scope(|| {
@@ -131,6 +140,9 @@ impl Service<Request<Body>> for ResponseService {
           .unwrap(),
       };
       let status: u16 = response.status().into();
+      let router_path = router.as_ref().map_or("", |r| r.path());
+      let route = matched_route.get();
+      wick_metrics::record_http_response(router_path, route.as_deref().unwrap_or(router_path), status);
 
       if status >= 400 {
         span.in_scope(|| {
@@ -155,10 +167,49 @@ async fn handle(
   runtime: Runtime,
   remote_addr: SocketAddr,
   span: &Span,
+) -> Result<Response<Body>, HttpError> {
+  // Policies and authentication are enforced before any middleware or operation is invoked.
+  if let Some(response) = r.policies.check(&req, remote_addr) {
+    return r.policies.finalize(req.headers(), response).await;
+  }
+  let mut req = r.policies.prepare(req);
+  let req_headers = req.headers().clone();
+  if let Some(auth) = &r.auth {
+    if let Err(failure) = auth.authorize(&mut req) {
+      return r.policies.finalize(&req_headers, auth.reject(failure)).await;
+    }
+  }
+
+  let response = match r.policies.request_timeout() {
+    Some(timeout) => {
+      match tokio::time::timeout(timeout, handle_request(tx_id, req, &r, runtime, remote_addr, span)).await {
+        Ok(response) => response?,
+        Err(_) => {
+          span.in_scope(|| warn!(timeout_ms = timeout.as_millis(), "request timed out"));
+          Builder::new()
+            .status(StatusCode::GATEWAY_TIMEOUT)
+            .body(Body::empty())
+            .unwrap()
+        }
+      }
+    }
+    None => handle_request(tx_id, req, &r, runtime, remote_addr, span).await?,
+  };
+
+  r.policies.finalize(&req_headers, response).await
+}
+
+async fn handle_request(
+  tx_id: Uuid,
+  req: Request<Body>,
+  r: &RawRouterHandler,
+  runtime: Runtime,
+  remote_addr: SocketAddr,
+  span: &Span,
 ) -> Result<Response<Body>, HttpError> {
   let pre_span = info_span!(parent: span, "pre-request", service.name="pre-request");
   let (wick_request_object, early_response) =
-    run_request_middleware(tx_id, &req, runtime.clone(), &r, remote_addr, &pre_span).await?;
+    run_request_middleware(tx_id, &req, runtime.clone(), r, remote_addr, &pre_span).await?;
   // if we have an early response, skip the main handler.
   let response = if let Some(response) = early_response {
     response
@@ -170,7 +221,7 @@ async fn handle(
       .await?
   };
   let post_span = info_span!(parent: span, "post-request");
-  run_response_middleware(tx_id, wick_request_object, response, runtime.clone(), &r, &post_span).await
+  run_response_middleware(tx_id, wick_request_object, response, runtime.clone(), r, &post_span).await
 }
 
 async fn run_request_middleware<B>(
@@ -235,6 +286,17 @@ async fn run_response_middleware(
   Ok(response)
 }
 
+/// Build the initial [InvocationContext] for a request from its W3C trace context headers.
+fn request_context<B>(req: &Request<B>) -> InvocationContext {
+  let mut context = InvocationContext::new();
+  for key in [InvocationContext::TRACEPARENT, InvocationContext::TRACESTATE] {
+    if let Some(value) = req.headers().get(key).and_then(|v| v.to_str().ok()) {
+      context.insert(key, value);
+    }
+  }
+  context
+}
+
 fn make_ise(e: Option<String>) -> Response<Body> {
   Builder::new()
     .status(StatusCode::INTERNAL_SERVER_ERROR)
@@ -21,12 +21,13 @@ use wick_trigger::{Error, Trigger};
 
 use self::error::TimeError;
 
+/// Invoke the scheduled operation, returning whether it completed without errors.
 async fn invoke_operation(
   runtime: Runtime,
   target: Entity,
   payload: Arc<Vec<config::OperationInputConfig>>,
   span: &Span,
-) -> Result<(), Error> {
+) -> Result<bool, Error> {
   let packets: Vec<_> = payload
     .iter()
     .map(|packet| Packet::encode(packet.name(), packet.value()))
@@ -41,10 +42,12 @@ async fn invoke_operation(
   );
 
   let mut response = runtime.invoke(invocation, Default::default()).await?;
+  let mut success = true;
   while let Some(packet) = response.next().await {
     trace!(?packet, "trigger:time:response");
+    success &= packet.map_or(false, |p| !p.is_error());
   }
-  Ok(())
+  Ok(success)
 }
 
 async fn create_schedule(
@@ -95,8 +98,10 @@ async fn create_schedule(
       let fail_tx = failure_tx.clone();
 
       tokio::spawn(async move {
-        let fut = invoke_operation(rt, target, payload, &job_span);
-        if let Err(e) = fut.await {
+        let job = format!("{}::{}", target.component_id(), target.operation_id());
+        let result = invoke_operation(rt, target, payload, &job_span).await;
+        wick_metrics::record_time_trigger_job(&job, matches!(result, Ok(true)));
+        if let Err(e) = result {
           job_span.in_scope(|| error!("error invoking operation: {}", e));
           let _ = fail_tx.send(()).await;
         }
@@ -159,7 +164,7 @@ impl Trigger for Time {
     ))
   }
 
-  async fn shutdown_gracefully(self) -> Result<(), Error> {
+  async fn shutdown_gracefully(&self) -> Result<(), Error> {
     Ok(())
   }
 
@@ -77,7 +77,7 @@ impl Trigger for WasmTrigger {
     Ok(StructuredOutput::new("", serde_json::json!({ "success": exit })))
   }
 
-  async fn shutdown_gracefully(self) -> Result<(), wick_trigger::Error> {
+  async fn shutdown_gracefully(&self) -> Result<(), wick_trigger::Error> {
     Ok(())
   }
 
@@ -41,7 +41,7 @@ pub trait Trigger {
   ) -> Result<StructuredOutput, Error>;
 
   /// Shutdown a running trigger.
-  async fn shutdown_gracefully(self) -> Result<(), Error>;
+  async fn shutdown_gracefully(&self) -> Result<(), Error>;
 
   /// Wait for the trigger to finish.
   #[must_use = "this returns the output of the trigger"]
@@ -329,6 +329,11 @@ pub mod types {
       ///The remote address of the connected client
       #[serde(rename = "remote_addr")]
       pub remote_addr: String,
+      ///The invocation context (e.g. traceparent, principal, tenant) forwarded to every operation handling the request
+      #[serde(rename = "context")]
+      #[serde(default)]
+      #[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]
+      pub context: std::collections::HashMap<String, String>,
     }
     #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
     ///HTTP response
@@ -11,6 +11,7 @@ extern "C" fn __wasmrs_init(guest_buffer_size: u32, host_buffer_size: u32, max_h
   wick_component::wasmrs_guest::register_request_response("wick", "__setup", Box::new(__setup));
   wick_component::wasmrs_guest::register_request_channel("wick", "echo", Box::new(Component::echo_wrapper));
   wick_component::wasmrs_guest::register_request_channel("wick", "testop", Box::new(Component::testop_wrapper));
+  wick_component::wasmrs_guest::register_request_channel("wick", "lookup", Box::new(Component::lookup_wrapper));
 }
 #[cfg(target_family = "wasm")]
 pub(crate) mod provided {
@@ -748,6 +749,71 @@ pub mod types {
     ///A datetime value.
     Datetime(wick_component::datetime::DateTime),
   }
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
+  pub struct AnonymousMaxWindow {
+    #[serde(rename = "max")]
+    pub max: u32,
+    #[serde(rename = "window")]
+    pub window: types::AnonymousSeconds,
+  }
+  impl Default for AnonymousMaxWindow {
+    fn default() -> Self {
+      Self {
+        max: Default::default(),
+        window: Default::default(),
+      }
+    }
+  }
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
+  pub struct AnonymousIdTags {
+    #[serde(rename = "id")]
+    pub id: u32,
+    #[serde(rename = "tags")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+  }
+  impl Default for AnonymousIdTags {
+    fn default() -> Self {
+      Self {
+        id: Default::default(),
+        tags: Default::default(),
+      }
+    }
+  }
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
+  pub struct AnonymousIdName {
+    #[serde(rename = "id")]
+    pub id: u32,
+    #[serde(rename = "name")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+  }
+  impl Default for AnonymousIdName {
+    fn default() -> Self {
+      Self {
+        id: Default::default(),
+        name: Default::default(),
+      }
+    }
+  }
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
+  pub struct AnonymousSeconds {
+    #[serde(rename = "seconds")]
+    pub seconds: u64,
+  }
+  impl Default for AnonymousSeconds {
+    fn default() -> Self {
+      Self {
+        seconds: Default::default(),
+      }
+    }
+  }
   pub mod aaa {
     #[allow(unused)]
     use super::aaa;
@@ -902,6 +968,11 @@ pub mod types {
       ///The remote address of the connected client
       #[serde(rename = "remote_addr")]
       pub remote_addr: String,
+      ///The invocation context (e.g. traceparent, principal, tenant) forwarded to every operation handling the request
+      #[serde(rename = "context")]
+      #[serde(default)]
+      #[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]
+      pub context: std::collections::HashMap<String, String>,
     }
     #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
     ///HTTP response
@@ -1558,6 +1629,11 @@ pub mod types {
       ///The remote address of the connected client
       #[serde(rename = "remote_addr")]
       pub remote_addr: String,
+      ///The invocation context (e.g. traceparent, principal, tenant) forwarded to every operation handling the request
+      #[serde(rename = "context")]
+      #[serde(default)]
+      #[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]
+      pub context: std::collections::HashMap<String, String>,
     }
     #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
     ///HTTP response
@@ -2214,6 +2290,11 @@ pub mod types {
       ///The remote address of the connected client
       #[serde(rename = "remote_addr")]
       pub remote_addr: String,
+      ///The invocation context (e.g. traceparent, principal, tenant) forwarded to every operation handling the request
+      #[serde(rename = "context")]
+      #[serde(default)]
+      #[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]
+      pub context: std::collections::HashMap<String, String>,
     }
     #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
     ///HTTP response
@@ -2979,6 +3060,133 @@ pub mod testop {
     ) -> std::result::Result<(), Self::Error>;
   }
 }
+///Types associated with the `lookup` operation
+pub mod lookup {
+  #[allow(unused)]
+  use super::*;
+  #[derive(Debug, Clone, ::serde::Serialize, ::serde::Deserialize, PartialEq)]
+  #[allow(clippy::exhaustive_structs)]
+  pub struct Config {
+    #[serde(rename = "limits")]
+    pub limits: types::AnonymousMaxWindow,
+  }
+  impl Default for Config {
+    fn default() -> Self {
+      Self {
+        limits: Default::default(),
+      }
+    }
+  }
+  impl From<Config> for wick_packet::RuntimeConfig {
+    fn from(v: Config) -> Self {
+      wick_component::to_value(v).unwrap().try_into().unwrap()
+    }
+  }
+  pub struct Outputs {
+    pub(crate) channel: wasmrs_rx::FluxChannel<wasmrs::RawPayload, wasmrs::PayloadError>,
+    #[allow(unused)]
+    pub(crate) results: wick_packet::OutgoingPort<Vec<types::AnonymousIdTags>>,
+  }
+  impl wick_component::Broadcast for Outputs {
+    fn outputs_mut(&mut self) -> wick_packet::OutputIterator<'_> {
+      wick_packet::OutputIterator::new(vec![&mut self.results])
+    }
+  }
+  impl wick_packet::WasmRsChannel for Outputs {
+    fn channel(&self) -> wasmrs_rx::FluxChannel<wasmrs::RawPayload, wasmrs::PayloadError> {
+      self.channel.clone()
+    }
+  }
+  impl wick_component::SingleOutput for Outputs {
+    fn single_output(&mut self) -> &mut dyn wick_packet::Port {
+      &mut self.results
+    }
+  }
+  impl Outputs {
+    #[allow(unused)]
+    pub fn new() -> Self {
+      let channel = wasmrs_rx::FluxChannel::new();
+      Self {
+        results: wick_packet::OutgoingPort::new("results", channel.clone()),
+        channel,
+      }
+    }
+    #[allow(unused)]
+    pub fn new_parts() -> (Self, wasmrs_rx::FluxReceiver<wasmrs::RawPayload, wasmrs::PayloadError>) {
+      let (channel, rx) = wasmrs_rx::FluxChannel::new_parts();
+      (
+        Self {
+          results: wick_packet::OutgoingPort::new("results", channel.clone()),
+          channel,
+        },
+        rx,
+      )
+    }
+    #[allow(unused)]
+    pub fn with_channel(channel: wasmrs_rx::FluxChannel<wasmrs::RawPayload, wasmrs::PayloadError>) -> Self {
+      Self {
+        results: wick_packet::OutgoingPort::new("results", channel.clone()),
+        channel,
+      }
+    }
+  }
+  pub struct Inputs {
+    pub(crate) user: BoxStream<VPacket<types::AnonymousIdName>>,
+  }
+  impl wick_packet::UnaryInputs<types::AnonymousIdName> for Inputs {
+    fn input(&mut self) -> &mut BoxStream<VPacket<types::AnonymousIdName>> {
+      &mut self.user
+    }
+    fn take_input(self) -> BoxStream<VPacket<types::AnonymousIdName>> {
+      self.user
+    }
+  }
+  pub fn process_incoming(
+    mut stream: wasmrs_rx::BoxFlux<wasmrs::Payload, wasmrs::PayloadError>,
+  ) -> (wasmrs_rx::BoxMono<Context<Config>, String>, Inputs) {
+    #[allow(unused_parens)]
+    let (config, (user)) = wick_component::payload_fan_out!(
+      stream,
+      wick_component::AnyError,
+      Config,
+      [("user", types::AnonymousIdName)]
+    );
+    (config, Inputs::new(user))
+  }
+  impl Inputs {
+    pub fn new(user: BoxStream<VPacket<types::AnonymousIdName>>) -> Self {
+      Self { user }
+    }
+  }
+  #[async_trait::async_trait(?Send)]
+  #[cfg(target_family = "wasm")]
+  pub trait Operation {
+    type Error;
+    type Inputs;
+    type Outputs;
+    type Config: std::fmt::Debug;
+    #[allow(unused)]
+    async fn lookup(
+      inputs: Self::Inputs,
+      outputs: Self::Outputs,
+      ctx: wick_component::flow_component::Context<Self::Config>,
+    ) -> std::result::Result<(), Self::Error>;
+  }
+  #[async_trait::async_trait]
+  #[cfg(not(target_family = "wasm"))]
+  pub trait Operation {
+    type Error: Send;
+    type Inputs: Send;
+    type Outputs: Send;
+    type Config: std::fmt::Debug + Send;
+    #[allow(unused)]
+    async fn lookup(
+      inputs: Self::Inputs,
+      outputs: Self::Outputs,
+      ctx: wick_component::flow_component::Context<Self::Config>,
+    ) -> std::result::Result<(), Self::Error>;
+  }
+}
 #[derive(Default, Clone)]
 ///The struct that the component implementation hinges around
 pub struct Component;
@@ -3027,4 +3235,26 @@ impl Component {
     });
     Ok(Box::pin(rx))
   }
+  fn lookup_wrapper(
+    input: wasmrs_rx::BoxFlux<wasmrs::Payload, wasmrs::PayloadError>,
+  ) -> std::result::Result<wasmrs_rx::BoxFlux<wasmrs::RawPayload, wasmrs::PayloadError>, wick_component::BoxError> {
+    let (channel, rx) = wasmrs_rx::FluxChannel::<wasmrs::RawPayload, wasmrs::PayloadError>::new_parts();
+    let outputs = lookup::Outputs::with_channel(channel.clone());
+    runtime::spawn("lookup_wrapper", async move {
+      let (config, inputs) = lookup::process_incoming(input);
+      let config = match config.await {
+        Ok(config) => config,
+        Err(e) => {
+          let _ = channel
+            .send_result(wick_packet::Packet::component_error(format!("Component sent invalid context: {}", e)).into());
+          return;
+        }
+      };
+      use self::lookup::Operation;
+      if let Err(e) = Component::lookup(inputs, outputs, config).await {
+        let _ = channel.send_result(wick_packet::Packet::component_error(e.to_string()).into());
+      }
+    });
+    Ok(Box::pin(rx))
+  }
 }
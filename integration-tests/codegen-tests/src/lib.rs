@@ -34,6 +34,20 @@ mod test1 {
     }
   }
 
+  # [cfg_attr (target_family = "wasm" , async_trait :: async_trait (? Send))]
+  #[cfg_attr(not(target_family = "wasm"), async_trait::async_trait)]
+  impl lookup::Operation for Component {
+    type Error = anyhow::Error;
+    type Outputs = lookup::Outputs;
+    type Inputs = lookup::Inputs;
+    type Config = lookup::Config;
+
+    #[allow(unused)]
+    async fn lookup(inputs: Self::Inputs, outputs: Self::Outputs, ctx: Context<Self::Config>) -> Result<(), Self::Error> {
+      Ok(())
+    }
+  }
+
   #[cfg(test)]
   mod test {
 
@@ -131,5 +145,31 @@ mod test1 {
 
       Ok(())
     }
+
+    #[test]
+    fn test_anonymous_structs() -> Result<()> {
+      let config = lookup::Config {
+        limits: types::AnonymousMaxWindow {
+          max: 10,
+          window: types::AnonymousSeconds { seconds: 60 },
+        },
+      };
+      let json = serde_json::json!({"limits": {"max": 10, "window": {"seconds": 60}}});
+      assert_eq!(serde_json::to_value(&config)?, json);
+      assert_eq!(serde_json::from_value::<lookup::Config>(json)?, config);
+
+      let user: types::AnonymousIdName = serde_json::from_value(serde_json::json!({"id": 1}))?;
+      assert_eq!(user, types::AnonymousIdName { id: 1, name: None });
+
+      let results = vec![types::AnonymousIdTags {
+        id: 1,
+        tags: vec!["admin".to_owned()],
+      }];
+      let bytes = wasmrs_codec::messagepack::serialize(&results).unwrap();
+      let actual: Vec<types::AnonymousIdTags> = wasmrs_codec::messagepack::deserialize(&bytes).unwrap();
+      assert_eq!(actual, results);
+
+      Ok(())
+    }
   }
 }
@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+
+/// Marks the end of a file that has a bundle appended to it.
+static MAGIC: &[u8; 8] = b"WICKBNDL";
+
+/// The magic bytes preceded by the length of the bundle archive as a little-endian u64.
+const TRAILER_LEN: u64 = 16;
+
+static MANIFEST_FILE: &str = "bundle.json";
+static APP_DIR: &str = "app";
+static VENDOR_DIR: &str = "vendor";
+
+/// Describes the contents of a bundle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct BundleManifest {
+  /// The name of the bundled application.
+  pub(crate) name: String,
+  /// The version of the bundled application.
+  pub(crate) version: String,
+  /// The path of the application manifest within the bundle's app files.
+  pub(crate) root: PathBuf,
+  /// The remote packages the bundle carries, by reference, and their registry digests.
+  pub(crate) packages: BTreeMap<String, String>,
+}
+
+/// A bundle unpacked to wick's cache.
+#[derive(Debug)]
+pub(crate) struct ExtractedBundle {
+  dir: PathBuf,
+  manifest: BundleManifest,
+}
+
+impl ExtractedBundle {
+  /// The bundle's manifest.
+  pub(crate) const fn manifest(&self) -> &BundleManifest {
+    &self.manifest
+  }
+
+  /// The path of the application manifest.
+  pub(crate) fn root(&self) -> PathBuf {
+    self.dir.join(APP_DIR).join(&self.manifest.root)
+  }
+
+  /// The OCI image layout holding the bundle's remote packages, if it has any.
+  pub(crate) fn vendor(&self) -> Option<PathBuf> {
+    let dir = self.dir.join(VENDOR_DIR);
+    dir.exists().then_some(dir)
+  }
+}
+
+/// Create a bundle archive from the application's files and an optional OCI image layout of its remote packages.
+///
+/// Every file is stored with a fixed timestamp and mode so the same inputs produce the same archive.
+pub(crate) fn archive(
+  manifest: &BundleManifest,
+  mut app_files: Vec<(PathBuf, Vec<u8>)>,
+  vendor: Option<&Path>,
+) -> Result<Vec<u8>> {
+  let mut tar = tar::Builder::new(GzEncoder::new(Vec::new(), Compression::default()));
+  append(
+    &mut tar,
+    Path::new(MANIFEST_FILE),
+    &serde_json::to_vec_pretty(manifest)?,
+  )?;
+
+  app_files.sort_by(|a, b| a.0.cmp(&b.0));
+  for (path, contents) in &app_files {
+    append(&mut tar, &Path::new(APP_DIR).join(path), contents)?;
+  }
+
+  if let Some(vendor) = vendor {
+    let mut files = Vec::new();
+    collect_files(vendor, &mut files)?;
+    files.sort();
+    for file in files {
+      let relative = file.strip_prefix(vendor)?;
+      append(&mut tar, &Path::new(VENDOR_DIR).join(relative), &std::fs::read(&file)?)?;
+    }
+  }
+
+  Ok(tar.into_inner()?.finish()?)
+}
+
+/// Append the trailer that lets a bundle archive be found at the end of the file it's written to.
+pub(crate) fn with_trailer(mut bytes: Vec<u8>, archive_len: usize) -> Vec<u8> {
+  bytes.extend_from_slice(&(archive_len as u64).to_le_bytes());
+  bytes.extend_from_slice(MAGIC);
+  bytes
+}
+
+/// Get the contents of the running executable without any bundle appended to it.
+pub(crate) fn current_executable() -> Result<Vec<u8>> {
+  let exe = std::env::current_exe()?;
+  let mut bytes = std::fs::read(&exe)?;
+  if let Some((offset, _)) = locate(&exe)? {
+    bytes.truncate(offset as usize);
+  }
+  Ok(bytes)
+}
+
+/// Get the path of the running executable if it has a bundle appended to it.
+pub(crate) fn embedded() -> Option<PathBuf> {
+  let exe = std::env::current_exe().ok()?;
+  matches!(locate(&exe), Ok(Some(_))).then_some(exe)
+}
+
+/// Unpack the bundle at the end of the passed file to wick's cache, if it has one.
+///
+/// Bundles are unpacked to a directory named after the digest of their archive, so running the same bundle again
+/// reuses the files unpacked the first time.
+pub(crate) fn open(path: &Path) -> Result<Option<ExtractedBundle>> {
+  if !path.is_file() {
+    return Ok(None);
+  }
+  let Some((offset, len)) = locate(path)? else {
+    return Ok(None);
+  };
+
+  let mut file = File::open(path)?;
+  file.seek(SeekFrom::Start(offset))?;
+  let mut archive = Vec::with_capacity(len as usize);
+  file.take(len).read_to_end(&mut archive)?;
+
+  let xdg = wick_xdg::Settings::new();
+  let dir = xdg
+    .global()
+    .cache()
+    .join("bundles")
+    .join(sha256::digest(archive.as_slice()));
+
+  if dir.exists() {
+    debug!(bundle = %path.display(), dir = %dir.display(), "using unpacked bundle");
+  } else {
+    debug!(bundle = %path.display(), dir = %dir.display(), "unpacking bundle");
+    let staging = dir.with_extension(format!("{}.tmp", std::process::id()));
+    tar::Archive::new(GzDecoder::new(Cursor::new(archive)))
+      .unpack(&staging)
+      .map_err(|e| anyhow!("could not unpack bundle {}: {}", path.display(), e))?;
+    if let Err(e) = std::fs::rename(&staging, &dir) {
+      // Another process may have unpacked the same bundle in the meantime.
+      let _ = std::fs::remove_dir_all(&staging);
+      if !dir.exists() {
+        return Err(e.into());
+      }
+    }
+  }
+
+  let manifest = std::fs::read(dir.join(MANIFEST_FILE))?;
+  let manifest: BundleManifest =
+    serde_json::from_slice(&manifest).map_err(|e| anyhow!("invalid bundle {}: {}", path.display(), e))?;
+  Ok(Some(ExtractedBundle { dir, manifest }))
+}
+
+/// Find the offset and length of a bundle appended to the passed file.
+fn locate(path: &Path) -> std::io::Result<Option<(u64, u64)>> {
+  let mut file = File::open(path)?;
+  let size = file.metadata()?.len();
+  if size < TRAILER_LEN {
+    return Ok(None);
+  }
+  file.seek(SeekFrom::Start(size - TRAILER_LEN))?;
+  let mut trailer = [0; TRAILER_LEN as usize];
+  file.read_exact(&mut trailer)?;
+  let (len, magic) = trailer.split_at(8);
+  if magic != MAGIC {
+    return Ok(None);
+  }
+  let mut len_bytes = [0; 8];
+  len_bytes.copy_from_slice(len);
+  let len = u64::from_le_bytes(len_bytes);
+  Ok((size - TRAILER_LEN).checked_sub(len).map(|offset| (offset, len)))
+}
+
+fn append<W: std::io::Write>(tar: &mut tar::Builder<W>, path: &Path, contents: &[u8]) -> Result<()> {
+  let mut header = tar::Header::new_gnu();
+  header.set_size(contents.len() as u64);
+  header.set_mode(0o644);
+  header.set_mtime(0);
+  header.set_entry_type(tar::EntryType::Regular);
+  tar.append_data(&mut header, path, contents)?;
+  Ok(())
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+  for entry in std::fs::read_dir(dir)? {
+    let path = entry?.path();
+    if path.is_dir() {
+      collect_files(&path, files)?;
+    } else {
+      files.push(path);
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn manifest() -> BundleManifest {
+    BundleManifest {
+      name: "app".to_owned(),
+      version: "0.1.0".to_owned(),
+      root: PathBuf::from("app.wick"),
+      packages: BTreeMap::new(),
+    }
+  }
+
+  #[test]
+  fn test_archive_is_reproducible() -> Result<()> {
+    let files = vec![
+      (PathBuf::from("b.wasm"), b"\0asm".to_vec()),
+      (PathBuf::from("app.wick"), b"kind: wick/app@v1".to_vec()),
+    ];
+    let mut reversed = files.clone();
+    reversed.reverse();
+    assert_eq!(
+      archive(&manifest(), files, None)?,
+      archive(&manifest(), reversed, None)?
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn test_open_appended_bundle() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("wick-bundle-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("standalone");
+
+    let archive = archive(
+      &manifest(),
+      vec![(PathBuf::from("app.wick"), b"kind: wick/app@v1".to_vec())],
+      None,
+    )?;
+    let len = archive.len();
+    let mut bytes = b"#!/not/really/an/executable".to_vec();
+    bytes.extend(archive);
+    std::fs::write(&path, with_trailer(bytes, len))?;
+
+    let bundle = open(&path)?.unwrap();
+    assert_eq!(bundle.manifest().name, "app");
+    assert_eq!(std::fs::read(bundle.root())?, b"kind: wick/app@v1");
+    assert!(bundle.vendor().is_none());
+
+    std::fs::write(&path, b"kind: wick/app@v1")?;
+    assert!(open(&path)?.is_none());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+  }
+}
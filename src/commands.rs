@@ -1,16 +1,23 @@
+pub(crate) mod bundle;
 pub(crate) mod config;
 pub(crate) mod install;
 pub(crate) mod invoke;
 pub(crate) mod key;
 pub(crate) mod list;
+pub(crate) mod lock;
+pub(crate) mod lsp;
 pub(crate) mod new;
 pub(crate) mod query;
 pub(crate) mod registry;
 pub(crate) mod rpc;
 pub(crate) mod run;
+pub(crate) mod secret;
 pub(crate) mod serve;
 pub(crate) mod show;
 pub(crate) mod test;
+pub(crate) mod uninstall;
+pub(crate) mod upgrade;
+pub(crate) mod vendor;
 pub(crate) mod wasm;
 
 use clap::{Parser, Subcommand};
@@ -59,10 +66,34 @@ pub(crate) enum CliCommand {
   #[clap(subcommand, name = "new")]
   New(new::SubCommands),
 
+  /// Pin the remote packages a manifest imports in a wick.lock file.
+  #[clap(name = "lock")]
+  Lock(lock::Options),
+
+  /// Copy the remote packages a manifest imports into an OCI image layout directory.
+  #[clap(name = "vendor")]
+  Vendor(vendor::Options),
+
+  /// Bundle an application and every package it uses into a single file that runs without fetching anything.
+  #[clap(name = "bundle")]
+  Bundle(bundle::Options),
+
+  /// Start a language server for .wick manifests that communicates over stdio.
+  #[clap(name = "lsp")]
+  Lsp(lsp::Options),
+
   /// Install a wick app to the local system.
   #[clap(name = "install")]
   Install(install::Options),
 
+  /// Upgrade installed wick apps to the latest package their reference points to.
+  #[clap(name = "upgrade")]
+  Upgrade(upgrade::Options),
+
+  /// Remove an installed wick app from the local system.
+  #[clap(name = "uninstall")]
+  Uninstall(uninstall::Options),
+
   /// Show information about wick's configuration or manifest details.
   #[clap(subcommand, name = "show")]
   Show(show::SubCommands),
@@ -83,6 +114,10 @@ pub(crate) enum CliCommand {
   #[clap(subcommand, name = "key")]
   Key(key::SubCommands),
 
+  /// Commands to manage encrypted secrets.
+  #[clap(subcommand, name = "secret")]
+  Secret(secret::SubCommands),
+
   /// Commands to interact with running Wick instances.
   #[clap(subcommand, name = "rpc")]
   Rpc(rpc::SubCommands),
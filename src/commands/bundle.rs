@@ -0,0 +1,166 @@
+use std::io::{Cursor, Read};
+use std::path::{Component, PathBuf};
+
+use anyhow::Result;
+use clap::Args;
+use flate2::read::GzDecoder;
+use serde_json::json;
+use structured_output::StructuredOutput;
+use wick_config::WickConfiguration;
+use wick_oci_utils::package::media_types;
+use wick_oci_utils::{LockFile, OciLayout};
+use wick_package::WickPackage;
+
+use crate::bundle::{archive, current_executable, with_trailer, BundleManifest};
+use crate::commands::vendor::vendor_packages;
+use crate::utils::reconcile_fetch_options;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  #[clap(flatten)]
+  pub(crate) oci: crate::options::oci::OciOptions,
+
+  /// Path or OCI url to the application manifest to bundle.
+  #[clap(action)]
+  pub(crate) path: String,
+
+  /// The file to write the bundle to. Defaults to the application's name.
+  #[clap(short = 'o', long = "output", action)]
+  pub(crate) output: Option<PathBuf>,
+
+  /// Append the bundle to a copy of the wick executable to create a standalone executable.
+  #[clap(long = "executable", action)]
+  pub(crate) executable: bool,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let locked = opts.oci.locked;
+  let options = reconcile_fetch_options(&opts.path, &settings, opts.oci, None);
+
+  let as_path = PathBuf::from(&opts.path);
+  let package = if as_path.exists() {
+    WickPackage::from_path(None, &as_path).await?
+  } else {
+    crate::oci::pull(opts.path.clone(), options.clone()).await?
+  };
+  let config = WickConfiguration::fetch(package.path(), Default::default())
+    .await?
+    .into_inner();
+  let WickConfiguration::App(config) = config else {
+    anyhow::bail!(
+      "{} is not a wick application, it's a {} configuration",
+      opts.path,
+      config.kind()
+    );
+  };
+
+  let xdg = wick_xdg::Settings::new();
+  let staging = xdg.global().staging().join(format!("bundle-{}", std::process::id()));
+  let layout = OciLayout::new(&staging);
+  let result = vendor_packages(&opts.path, options, locked, &layout, &span).await;
+  let bytes = result.and_then(|(resolved, vendored)| {
+    let manifest = BundleManifest {
+      name: config.name().to_owned(),
+      version: config.version().unwrap_or_default().to_owned(),
+      root: package.path().file_name().map(PathBuf::from).unwrap_or_default(),
+      packages: vendored.into_iter().collect(),
+    };
+    span.in_scope(|| info!(app = manifest.name, packages = manifest.packages.len(), "bundling"));
+    let mut files = app_files(&package)?;
+    files.push((PathBuf::from(LockFile::FILENAME), lockfile(&resolved)?));
+    let vendor = (!manifest.packages.is_empty()).then_some(staging.as_path());
+    let archive = archive(&manifest, files, vendor)?;
+    Ok((manifest, archive))
+  });
+  if staging.exists() {
+    std::fs::remove_dir_all(&staging)?;
+  }
+  let (manifest, archive) = bytes?;
+
+  let output = opts.output.unwrap_or_else(|| {
+    let name = if opts.executable {
+      format!("{}{}", manifest.name, std::env::consts::EXE_SUFFIX)
+    } else {
+      format!("{}.bundle", manifest.name)
+    };
+    PathBuf::from(name)
+  });
+
+  let archive_len = archive.len();
+  let contents = if opts.executable {
+    let mut exe = current_executable()?;
+    exe.extend(archive);
+    exe
+  } else {
+    archive
+  };
+  std::fs::write(&output, with_trailer(contents, archive_len))?;
+
+  #[cfg(not(target_os = "windows"))]
+  if opts.executable {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut perms = std::fs::metadata(&output)?.permissions();
+    perms.set_mode(0o755);
+    std::fs::set_permissions(&output, perms)?;
+  }
+
+  let text = format!(
+    "bundled {} {} with {} package(s) into {}",
+    manifest.name,
+    manifest.version,
+    manifest.packages.len(),
+    output.display()
+  );
+  let json = json!({
+    "name": manifest.name,
+    "version": manifest.version,
+    "path": output,
+    "packages": manifest.packages,
+  });
+
+  Ok(StructuredOutput::new(text, json))
+}
+
+/// Get the application's own files, unpacking the archive its extra package files are stored in.
+fn app_files(package: &WickPackage) -> Result<Vec<(PathBuf, Vec<u8>)>> {
+  let mut files = Vec::new();
+  for file in package.list_files() {
+    if file.media_type() == media_types::TARGZ {
+      let mut archive = tar::Archive::new(GzDecoder::new(Cursor::new(file.contents())));
+      for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+          continue;
+        }
+        let path = entry.path()?.into_owned();
+        let mut contents = Vec::new();
+        entry.read_to_end(&mut contents)?;
+        files.push((path, contents));
+      }
+    } else {
+      files.push((file.package_path().clone(), file.contents().to_vec()));
+    }
+  }
+  if let Some((path, _)) = files.iter().find(|(path, _)| {
+    !path
+      .components()
+      .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+  }) {
+    anyhow::bail!(
+      "can not bundle {} because it is outside of the application's directory",
+      path.display()
+    );
+  }
+  Ok(files)
+}
+
+fn lockfile(resolved: &LockFile) -> Result<Vec<u8>> {
+  Ok(serde_yaml::to_string(resolved)?.into_bytes())
+}
@@ -1,8 +1,10 @@
+use std::fmt::Write;
+
 use anyhow::Result;
 use clap::Args;
 use serde_json::json;
 use structured_output::StructuredOutput;
-use wick_config::audit::Audit;
+use wick_config::audit::{check_provided_interfaces, Audit, InterfaceMismatch};
 use wick_config::WickConfiguration;
 
 use crate::utils::{fetch_wick_tree, parse_config_string, reconcile_fetch_options};
@@ -31,6 +33,7 @@ pub(crate) async fn handle(
   let runtime_config = parse_config_string(opts.component.with.as_deref())?;
   let options = reconcile_fetch_options(&opts.component.path, &settings, opts.oci, None);
   let config = fetch_wick_tree(&opts.component.path, options.clone(), runtime_config, span.clone()).await?;
+  let mismatches = check_provided_interfaces(&config);
   let flattened = config.flatten();
   let report = Audit::new_flattened(&flattened);
 
@@ -43,8 +46,9 @@ pub(crate) async fn handle(
   } else {
     let mut buffer = String::new();
     gen_report(&report, &mut buffer);
+    gen_interface_report(&mismatches, &mut buffer);
     let filtered = report.iter().filter(|a| !a.resources.is_empty()).collect::<Vec<_>>();
-    let json = json!({"audit":filtered});
+    let json = json!({"audit":filtered, "interfaces": mismatches});
 
     Ok(StructuredOutput::new(buffer, json))
   }
@@ -71,3 +75,13 @@ name: {}
     buffer.push_str(&message);
   }
 }
+
+fn gen_interface_report(mismatches: &[InterfaceMismatch], buffer: &mut String) {
+  if mismatches.is_empty() {
+    return;
+  }
+  buffer.push_str("\n\ninterfaces:");
+  for line in mismatches.iter().flat_map(|m| m.to_string().lines().map(ToOwned::to_owned).collect::<Vec<_>>()) {
+    let _ = write!(buffer, "\n  {}", line);
+  }
+}
@@ -7,7 +7,7 @@ use structured_output::StructuredOutput;
 use wick_config::WickConfiguration;
 use wick_host::ComponentHostBuilder;
 
-use crate::utils::{get_auth_for_scope, merge_config, parse_config_string};
+use crate::utils::{get_auth_for_scope, merge_config, parse_config_string, prefetch_packages, signature_policies};
 
 #[derive(Debug, Clone, Args)]
 #[clap(rename_all = "kebab-case")]
@@ -59,6 +59,11 @@ pub(crate) async fn handle(
     fetch_opts.set_cache_dir(path_dir.join(env.local().cache()));
   };
 
+  fetch_opts
+    .set_mirror(settings.mirror.clone())
+    .set_signatures(signature_policies(&settings));
+  prefetch_packages(&opts.component.path, &mut fetch_opts, &span).await?;
+
   let root_config = parse_config_string(opts.component.with.as_deref())?;
 
   let mut config = WickConfiguration::fetch(&opts.component.path, fetch_opts).await?;
@@ -1,11 +1,14 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use chrono::Utc;
 use clap::Args;
+use serde_json::json;
 use structured_output::StructuredOutput;
 use wick_config::WickConfiguration;
 use wick_package::WickPackage;
 
+use crate::installs::{pin, InstallRegistry, InstalledApp};
 use crate::utils::reconcile_fetch_options;
 
 #[derive(Debug, Clone, Args)]
@@ -19,31 +22,84 @@ pub(crate) struct Options {
   #[clap(long = "path", action)]
   pub(crate) path: Option<PathBuf>,
 
+  /// List installed apps instead of installing one.
+  #[clap(long = "list", action, conflicts_with_all = ["path", "application"])]
+  pub(crate) list: bool,
+
   /// Path or OCI url to application manifest file.
-  #[clap(action)]
-  application: String,
+  #[clap(action, required_unless_present = "list")]
+  application: Option<String>,
 }
 
-#[allow(clippy::unused_async)]
 pub(crate) async fn handle(
   opts: Options,
   settings: wick_settings::Settings,
   span: tracing::Span,
 ) -> Result<StructuredOutput> {
+  let mut registry = InstallRegistry::load_global().await?;
+  if opts.list {
+    return Ok(list(&registry));
+  }
+  let application = opts.application.ok_or_else(|| anyhow!("no application to install"))?;
+
   let xdg = wick_xdg::Settings::new();
   let bin_dir = opts.path.unwrap_or_else(|| xdg.global().root().join("bin"));
   std::fs::create_dir_all(&bin_dir)?;
 
-  span.in_scope(|| info!(app = opts.application, path = %bin_dir.display(), "installing wick app"));
+  span.in_scope(|| info!(app = application, path = %bin_dir.display(), "installing wick app"));
 
-  let oci_opts = reconcile_fetch_options(&opts.application, &settings, opts.oci, None);
-  let app_as_path = PathBuf::from(&opts.application);
-  let package = if app_as_path.exists() {
-    WickPackage::from_path(None, &app_as_path).await?
+  let oci_opts = reconcile_fetch_options(&application, &settings, opts.oci, None);
+  let app_as_path = PathBuf::from(&application);
+  let (package, digest) = if app_as_path.exists() {
+    (WickPackage::from_path(None, &app_as_path).await?, None)
+  } else {
+    let (package, digest) = pull_pinned(&application, oci_opts).await?;
+    (package, Some(digest))
+  };
+  let reference = if digest.is_some() {
+    application
   } else {
-    crate::oci::pull(opts.application, oci_opts).await?
+    package.path().to_string_lossy().to_string()
   };
 
+  let app = install(reference, digest, &package, &bin_dir).await?;
+  if let Some(previous) = registry.insert(app.clone()) {
+    span.in_scope(|| debug!(app = previous.name, reference = previous.reference, "replacing installed app"));
+  }
+  registry.save().await?;
+
+  let mut text = format!("installed {} {} to {}", app.name, app.version, app.bin.display());
+  if let Some(digest) = &app.digest {
+    text.push_str(&format!(" (pinned to {})", digest));
+  }
+  let output = StructuredOutput::new(text, json!(app));
+
+  Ok(output)
+}
+
+/// Resolve the digest an OCI reference currently points to and pull the package with that digest.
+pub(crate) async fn pull_pinned(
+  reference: &str,
+  oci_opts: wick_oci_utils::OciOptions,
+) -> Result<(WickPackage, String)> {
+  let (_, digest) = wick_oci_utils::fetch_image_manifest(reference, &oci_opts).await?;
+  debug!(reference, %digest, "resolved reference");
+  let package = crate::oci::pull(pin(reference, &digest)?, oci_opts).await?;
+  if let Some(pulled) = package.digest() {
+    if pulled != digest {
+      anyhow::bail!("pulled {} with digest {}, expected {}", reference, pulled, digest);
+    }
+  }
+  Ok((package, digest))
+}
+
+/// Write the shim for an application package to the passed directory.
+pub(crate) async fn install(
+  reference: String,
+  digest: Option<String>,
+  package: &WickPackage,
+  bin_dir: &Path,
+) -> Result<InstalledApp> {
   let path = package.path();
   let config = WickConfiguration::fetch(path, Default::default()).await?.into_inner();
 
@@ -56,40 +112,74 @@ pub(crate) async fn handle(
     ),
   };
 
-  let bin_path = bin_dir.join(config.name());
+  let app = InstalledApp {
+    name: config.name().to_owned(),
+    reference,
+    digest,
+    version: config.version().unwrap_or_default().to_owned(),
+    bin: bin_dir.join(config.name()),
+    installed_at: Utc::now(),
+  };
+  write_shims(&app.bin, &app.target()?)?;
+
+  Ok(app)
+}
+
+fn list(registry: &InstallRegistry) -> StructuredOutput {
+  let text = if registry.apps().is_empty() {
+    "no apps installed".to_owned()
+  } else {
+    registry
+      .apps()
+      .iter()
+      .map(|app| {
+        let mut lines = vec![
+          format!("{} {}", app.name, app.version),
+          format!("  reference: {}", app.reference),
+        ];
+        if let Some(digest) = &app.digest {
+          lines.push(format!("  digest: {}", digest));
+        }
+        lines.push(format!("  installed: {}", app.installed_at.to_rfc3339()));
+        lines.push(format!("  bin: {}", app.bin.display()));
+        lines.join("\n")
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  };
+  StructuredOutput::new(text, json!({ "apps": registry.apps() }))
+}
 
+/// The files a shim is written to.
+pub(crate) fn shim_paths(bin_path: &Path) -> Vec<PathBuf> {
+  #[cfg(not(target_os = "windows"))]
+  return vec![bin_path.to_path_buf()];
+  #[cfg(target_os = "windows")]
+  return vec![bin_path.with_extension("ps1"), bin_path.with_extension("cmd")];
+}
+
+fn write_shims(bin_path: &Path, target: &str) -> Result<()> {
   #[cfg(not(target_os = "windows"))]
   {
-    std::fs::write(&bin_path, make_sh(path.to_str().unwrap()))?;
+    std::fs::write(bin_path, make_sh(target))?;
 
     use std::os::unix::fs::PermissionsExt;
 
-    let mut perms = std::fs::metadata(path)?.permissions();
+    let mut perms = std::fs::metadata(bin_path)?.permissions();
     perms.set_mode(0o755);
 
-    info!(target=%path.to_string_lossy(),bin=%bin_path.to_string_lossy(), "installing");
-    std::fs::set_permissions(&bin_path, perms)?;
+    info!(%target, bin = %bin_path.to_string_lossy(), "installing");
+    std::fs::set_permissions(bin_path, perms)?;
   }
   #[cfg(target_os = "windows")]
   {
-    let mut ps1_path = bin_path.clone();
-    ps1_path.set_extension("ps1");
-    let mut cmd_path = bin_path.clone();
-    cmd_path.set_extension("cmd");
-    info!(target=%path.to_string_lossy(),cmd=%cmd_path.to_string_lossy(),ps1=%ps1_path.to_string_lossy(), "installing");
-    std::fs::write(&cmd_path, make_bat(&path.to_str().unwrap()))?;
-    std::fs::write(&ps1_path, make_ps1(&path.to_str().unwrap()))?;
+    let ps1_path = bin_path.with_extension("ps1");
+    let cmd_path = bin_path.with_extension("cmd");
+    info!(%target, cmd = %cmd_path.to_string_lossy(), ps1 = %ps1_path.to_string_lossy(), "installing");
+    std::fs::write(&cmd_path, make_bat(target))?;
+    std::fs::write(&ps1_path, make_ps1(target))?;
   }
-
-  let text = format!("installed {} to {}", config.name(), bin_path.display());
-  let json = serde_json::json!({
-    "name": config.name(),
-    "path": bin_path,
-  });
-
-  let output = StructuredOutput::new(text, json);
-
-  Ok(output)
+  Ok(())
 }
 
 #[cfg(not(target_os = "windows"))]
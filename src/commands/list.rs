@@ -7,6 +7,8 @@ use structured_output::StructuredOutput;
 use wick_config::WickConfiguration;
 use wick_interface_types::{Field, OperationSignature};
 
+use crate::utils::{prefetch_packages, signature_policies};
+
 #[derive(Debug, Clone, Args)]
 #[group(skip)]
 pub(crate) struct Options {
@@ -19,10 +21,14 @@ pub(crate) struct Options {
 
 pub(crate) async fn handle(
   opts: Options,
-  _settings: wick_settings::Settings,
-  _span: tracing::Span,
+  settings: wick_settings::Settings,
+  span: tracing::Span,
 ) -> Result<StructuredOutput> {
-  let fetch_options: wick_oci_utils::OciOptions = opts.oci.clone().into();
+  let mut fetch_options: wick_oci_utils::OciOptions = opts.oci.clone().into();
+  fetch_options
+    .set_mirror(settings.mirror.clone())
+    .set_signatures(signature_policies(&settings));
+  prefetch_packages(&opts.component.path, &mut fetch_options, &span).await?;
 
   let manifest = WickConfiguration::fetch(&opts.component.path, fetch_options).await?;
 
@@ -0,0 +1,78 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use clap::Args;
+use serde_json::json;
+use structured_output::StructuredOutput;
+use tracing::Instrument;
+use wick_config::WickConfiguration;
+use wick_oci_utils::{Lock, LockFile, LockMode};
+
+use crate::utils::{lockfile_path, reconcile_fetch_options};
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  #[clap(flatten)]
+  pub(crate) oci: crate::options::oci::OciOptions,
+
+  /// Resolve every package again instead of keeping the digests already in wick.lock.
+  #[clap(long = "update", action)]
+  pub(crate) update: bool,
+
+  /// Path to the manifest to lock.
+  #[clap(action)]
+  pub(crate) path: String,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let Some(lockfile) = lockfile_path(&opts.path) else {
+    anyhow::bail!("{} is not a local manifest file", opts.path);
+  };
+
+  let mode = if opts.update {
+    LockMode::Update
+  } else if opts.oci.locked {
+    LockMode::Locked
+  } else {
+    LockMode::Verify
+  };
+  let pinned = if mode != LockMode::Update && lockfile.exists() {
+    LockFile::load(&lockfile).await?
+  } else {
+    LockFile::default()
+  };
+
+  let lock = Lock::new(pinned, mode);
+  let mut options = reconcile_fetch_options(&opts.path, &settings, opts.oci, None);
+  options.set_lock(Some(lock.clone()));
+
+  span.in_scope(|| info!(path = opts.path, ?mode, "locking packages"));
+  WickConfiguration::fetch_uninitialized_tree(&opts.path, options)
+    .instrument(span.clone())
+    .await?;
+
+  let resolved = lock.resolved();
+  resolved.save(&lockfile).await?;
+
+  let mut text = format!(
+    "Locked {} package(s) in {}\n",
+    resolved.packages.len(),
+    lockfile.display()
+  );
+  for (reference, package) in &resolved.packages {
+    writeln!(text, "  {} => {}", reference, package.digest)?;
+  }
+
+  let json = json!({
+    "lockfile": lockfile.to_string_lossy(),
+    "packages": resolved.packages,
+  });
+
+  Ok(StructuredOutput::new(text, json))
+}
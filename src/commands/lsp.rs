@@ -0,0 +1,24 @@
+use anyhow::Result;
+use clap::Args;
+use structured_output::StructuredOutput;
+use tracing::Instrument;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  /// Communicate over stdin and stdout. This is the only supported transport and is accepted for editor compatibility.
+  #[clap(long = "stdio", action)]
+  pub(crate) stdio: bool,
+}
+
+pub(crate) async fn handle(
+  _opts: Options,
+  _settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  span.in_scope(|| debug!("starting language server on stdio"));
+  wick_language_server::serve_stdio().instrument(span).await?;
+
+  Ok(StructuredOutput::new("", serde_json::json!({})))
+}
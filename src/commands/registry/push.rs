@@ -5,6 +5,7 @@ use clap::Args;
 use serde_json::json;
 use structured_output::StructuredOutput;
 use tracing::Instrument;
+use wick_oci_utils::OciLayout;
 
 use crate::utils::get_auth_for_scope;
 
@@ -25,6 +26,14 @@ pub(crate) struct Options {
 
   #[clap(long = "tag")]
   pub(crate) tags: Vec<String>,
+
+  /// Write the package to this OCI image layout directory instead of its registry.
+  #[clap(long = "layout", action)]
+  pub(crate) layout: Option<PathBuf>,
+
+  /// Sign the package with this key, either a seed or the path of a file holding one.
+  #[clap(long = "sign", action)]
+  pub(crate) sign: Option<String>,
 }
 
 #[allow(clippy::unused_async)]
@@ -39,6 +48,12 @@ pub(crate) async fn handle(
     .instrument(span.clone())
     .await?;
 
+  if let Some(key) = &opts.sign {
+    let key = crate::keys::read_key(key).await?;
+    package.sign(&key)?;
+    span.in_scope(|| info!(signer = key.public_key(), "signed package"));
+  }
+
   let Some(registry) = package.registry_mut() else {
     span.in_scope(|| error!("no registry provided in package"));
     return Err(anyhow!("No registry provided in package"));
@@ -64,6 +79,9 @@ pub(crate) async fn handle(
     .set_password(password);
 
   let reference = package.registry_reference().unwrap(); // unwrap OK because we know we have a reg from above.
+  let layout = opts.layout.map(OciLayout::new);
+  let target = |reference: String| layout.as_ref().map_or_else(|| reference.clone(), |l| l.location(&reference));
+  let reference = target(reference);
 
   span.in_scope(|| {
     info!(reference, "pushing artifact");
@@ -75,7 +93,7 @@ pub(crate) async fn handle(
   let url = if !opts.tags.is_empty() {
     for tag in &opts.tags {
       let mut pack = package.clone();
-      let tagged_reference = pack.tagged_reference(tag).unwrap();
+      let tagged_reference = target(pack.tagged_reference(tag).unwrap());
       span.in_scope(|| info!(reference = &tagged_reference, "pushing tag"));
       pack.push(&tagged_reference, &oci_opts).await?;
       lines.push(format!("Pushed tag: {}", reference));
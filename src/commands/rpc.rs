@@ -4,6 +4,7 @@ use clap::{Args, Subcommand};
 
 pub(crate) mod invoke;
 pub(crate) mod list;
+pub(crate) mod log_filter;
 pub(crate) mod stats;
 
 #[derive(Subcommand, Debug, Clone)]
@@ -19,6 +20,10 @@ pub(crate) enum SubCommands {
   /// Query a collection for its runtime statistics.
   #[clap(name = "stats")]
   Stats(stats::Options),
+
+  /// Query or change the log filter of a running host.
+  #[clap(name = "log-filter")]
+  LogFilter(log_filter::Options),
 }
 
 #[derive(Debug, Clone, Args)]
@@ -46,4 +51,26 @@ pub(crate) struct ConnectOptions {
   /// The domain to verify against the certificate.
   #[clap(long, action)]
   pub(crate) domain: Option<String>,
+
+  /// Bearer token to authenticate with.
+  #[clap(long, env = wick_component_cli::options::env::WICK_RPC_TOKEN, hide_env_values = true, action)]
+  pub(crate) token: Option<String>,
+}
+
+impl ConnectOptions {
+  /// Connect to the RPC server, authenticating with the token if one was passed.
+  pub(crate) async fn connect(self) -> anyhow::Result<wick_rpc::RpcClient> {
+    let client = wick_rpc::make_rpc_client(
+      format!("http://{}:{}", self.address, self.port),
+      self.pem,
+      self.key,
+      self.ca,
+      self.domain,
+    )
+    .await?;
+    Ok(match self.token {
+      Some(token) => client.with_token(&token)?,
+      None => client,
+    })
+  }
 }
@@ -54,14 +54,7 @@ pub(crate) async fn handle(
   _settings: wick_settings::Settings,
   span: tracing::Span,
 ) -> Result<StructuredOutput> {
-  let mut client = wick_rpc::make_rpc_client(
-    format!("http://{}:{}", opts.connection.address, opts.connection.port),
-    opts.connection.pem,
-    opts.connection.key,
-    opts.connection.ca,
-    opts.connection.domain,
-  )
-  .await?;
+  let mut client = opts.connection.connect().await?;
 
   let signature = client.list().await?;
 
@@ -0,0 +1,49 @@
+use anyhow::Result;
+use clap::Args;
+use serde_json::json;
+use structured_output::StructuredOutput;
+use wick_rpc::rpc::log_filter_request::Destination;
+use wick_rpc::rpc::LogFilterRequest;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  #[clap(flatten)]
+  pub(crate) connection: super::ConnectOptions,
+
+  /// The log destination to query or change.
+  #[clap(long, default_value = "stderr", value_parser = ["stderr", "telemetry", "logfile"], action)]
+  pub(crate) destination: String,
+
+  /// The new filter, e.g. "info,wick_runtime<=trace". Prints the current filter when omitted.
+  #[clap(action)]
+  pub(crate) filter: Option<String>,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  _settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let _span = span.enter();
+  let mut client = opts.connection.connect().await?;
+
+  let destination = match opts.destination.as_str() {
+    "telemetry" => Destination::Telemetry,
+    "logfile" => Destination::Logfile,
+    _ => Destination::Stderr,
+  };
+
+  let response = client
+    .log_filter(LogFilterRequest {
+      destination: destination.into(),
+      filter: opts.filter,
+    })
+    .await?;
+
+  Ok(StructuredOutput::new(
+    &response.filter,
+    json!({ "destination": opts.destination, "filter": response.filter }),
+  ))
+}
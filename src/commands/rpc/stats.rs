@@ -19,14 +19,7 @@ pub(crate) async fn handle(
   span: tracing::Span,
 ) -> Result<StructuredOutput> {
   let _span = span.enter();
-  let mut client = wick_rpc::make_rpc_client(
-    format!("http://{}:{}", opts.connection.address, opts.connection.port),
-    opts.connection.pem,
-    opts.connection.key,
-    opts.connection.ca,
-    opts.connection.domain,
-  )
-  .await?;
+  let mut client = opts.connection.connect().await?;
 
   let list = client.stats(StatsRequest {}).await?;
 
@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::Path;
 
 use anyhow::Result;
 use clap::Args;
@@ -40,7 +41,21 @@ pub(crate) async fn handle(
 ) -> Result<StructuredOutput> {
   span.in_scope(|| trace!(args = ?opts.args, "rest args"));
   let runtime_config = parse_config_string(opts.component.with.as_deref())?;
-  let options = reconcile_fetch_options(&opts.component.path, &settings, opts.oci, None);
+  let mut options = reconcile_fetch_options(&opts.component.path, &settings, opts.oci, None);
+
+  // Bundles carry every package they use, so they run from their own files and pinned packages only.
+  let path = match crate::bundle::open(Path::new(&opts.component.path))? {
+    Some(bundle) => {
+      let manifest = bundle.manifest();
+      span.in_scope(|| debug!(app = manifest.name, packages = ?manifest.packages.keys(), "running bundle"));
+      options.set_locked(true);
+      if let Some(vendor) = bundle.vendor() {
+        options.set_mirror(Some(vendor));
+      }
+      bundle.root().to_string_lossy().to_string()
+    }
+    None => opts.component.path.clone(),
+  };
 
   let config = if let Some(lockdown) = opts.lockdown {
     let env: HashMap<String, String> = std::env::vars().collect();
@@ -51,13 +66,13 @@ pub(crate) async fn handle(
     lockdown_config.set_env(env.clone());
     let lockdown_config = lockdown_config.finish()?.try_lockdown_config()?;
 
-    let tree = fetch_wick_tree(&opts.component.path, options.clone(), runtime_config, span.clone()).await?;
+    let tree = fetch_wick_tree(&path, options.clone(), runtime_config, span.clone()).await?;
     let mut flattened = tree.flatten();
     wick_config::lockdown::assert_restrictions(&flattened, &lockdown_config)?;
 
     flattened.remove(0).as_config().unwrap()
   } else {
-    fetch_wick_config(&opts.component.path, options.clone(), runtime_config, span.clone()).await?
+    fetch_wick_config(&path, options.clone(), runtime_config, span.clone()).await?
   };
 
   let mut app_config = config.try_app_config()?;
@@ -75,6 +90,7 @@ pub(crate) async fn handle(
     span.in_scope(|| debug!("waiting on triggers to finish..."));
 
     let output = host.wait_for_done().instrument(span.clone()).await?;
+    host.stop().await;
     let mut lines = String::new();
     let mut json = Vec::new();
     for output in output {
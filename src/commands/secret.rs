@@ -0,0 +1,15 @@
+use clap::Subcommand;
+
+pub(crate) mod encrypt;
+pub(crate) mod gen;
+
+#[derive(Subcommand, Debug, Clone)]
+pub(crate) enum SubCommands {
+  /// Generate a new key for encrypted secrets files.
+  #[clap(name = "gen")]
+  Gen(gen::Options),
+
+  /// Encrypt a value for use in an encrypted secrets file.
+  #[clap(name = "encrypt")]
+  Encrypt(encrypt::Options),
+}
@@ -0,0 +1,49 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Result};
+use clap::Args;
+use serde_json::json;
+use structured_output::StructuredOutput;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  /// The value to encrypt.
+  #[clap(action)]
+  value: String,
+
+  /// A file containing the encryption key.
+  #[clap(long = "key-file", action)]
+  key_file: Option<PathBuf>,
+
+  /// The encryption key, generated with `wick secret gen`.
+  #[clap(long = "key", env = wick_secrets::DEFAULT_KEY_ENV, hide_env_values = true, action)]
+  key: Option<String>,
+}
+
+#[allow(clippy::unused_async)]
+pub(crate) async fn handle(
+  opts: Options,
+  _settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let _enter = span.enter();
+
+  let key = match (opts.key_file, opts.key) {
+    (Some(file), _) => std::fs::read_to_string(file)?,
+    (None, Some(key)) => key,
+    (None, None) => {
+      return Err(anyhow!(
+        "no key provided, pass --key-file or set {}",
+        wick_secrets::DEFAULT_KEY_ENV
+      ))
+    }
+  };
+  wick_secrets::track("The value to encrypt", &opts.value)?;
+
+  let encrypted = wick_secrets::encrypt(&key, &opts.value)?;
+
+  let json = json!({ "value": encrypted });
+  Ok(StructuredOutput::new(encrypted, json))
+}
@@ -0,0 +1,24 @@
+use anyhow::Result;
+use clap::Args;
+use serde_json::json;
+use structured_output::StructuredOutput;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {}
+
+#[allow(clippy::unused_async)]
+pub(crate) async fn handle(
+  _opts: Options,
+  _settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let _enter = span.enter();
+  debug!("generating secrets key");
+
+  let key = wick_secrets::generate_key()?;
+
+  let json = json!({ "key": key });
+  Ok(StructuredOutput::new(key, json))
+}
@@ -6,7 +6,7 @@ use wick_component_cli::options::DefaultCliOptions;
 use wick_config::WickConfiguration;
 use wick_host::ComponentHostBuilder;
 
-use crate::utils::{merge_config, parse_config_string};
+use crate::utils::{merge_config, parse_config_string, prefetch_packages, signature_policies};
 
 #[derive(Debug, Clone, Args)]
 #[group(skip)]
@@ -23,10 +23,14 @@ pub(crate) struct Options {
 
 pub(crate) async fn handle(
   opts: Options,
-  _settings: wick_settings::Settings,
+  settings: wick_settings::Settings,
   span: tracing::Span,
 ) -> Result<StructuredOutput> {
-  let fetch_options: wick_oci_utils::OciOptions = opts.oci.clone().into();
+  let mut fetch_options: wick_oci_utils::OciOptions = opts.oci.clone().into();
+  fetch_options
+    .set_mirror(settings.mirror.clone())
+    .set_signatures(signature_policies(&settings));
+  prefetch_packages(&opts.component.path, &mut fetch_options, &span).await?;
 
   let with_config = parse_config_string(opts.component.with.as_deref())?;
 
@@ -14,7 +14,7 @@ use wick_host::ComponentHostBuilder;
 use wick_oci_utils::OciOptions;
 use wick_test::{ComponentFactory, SharedComponent, TestSuite};
 
-use crate::utils::merge_config;
+use crate::utils::{merge_config, prefetch_packages, signature_policies};
 
 #[derive(Debug, Clone, Args)]
 #[clap(rename_all = "kebab-case")]
@@ -46,10 +46,14 @@ pub(crate) struct Options {
 
 pub(crate) async fn handle(
   opts: Options,
-  _settings: wick_settings::Settings,
+  settings: wick_settings::Settings,
   span: tracing::Span,
 ) -> Result<StructuredOutput> {
-  let oci_opts: OciOptions = opts.oci.clone().into();
+  let mut oci_opts: OciOptions = opts.oci.clone().into();
+  oci_opts
+    .set_mirror(settings.mirror.clone())
+    .set_signatures(signature_policies(&settings));
+  prefetch_packages(&opts.location, &mut oci_opts, &span).await?;
   let root_manifest = WickConfiguration::fetch(&opts.location, oci_opts.clone())
     .await?
     .into_inner()
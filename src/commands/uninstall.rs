@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use anyhow::Result;
+use clap::Args;
+use serde_json::json;
+use structured_output::StructuredOutput;
+
+use crate::commands::install::shim_paths;
+use crate::installs::InstallRegistry;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  /// The installed app to remove.
+  #[clap(action)]
+  app: String,
+}
+
+#[allow(clippy::unused_async)]
+pub(crate) async fn handle(
+  opts: Options,
+  _settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let mut registry = InstallRegistry::load_global().await?;
+  let app = registry
+    .remove(&opts.app)
+    .ok_or_else(|| anyhow!("'{}' is not installed, see `wick install --list`", opts.app))?;
+
+  span.in_scope(|| info!(app = app.name, bin = %app.bin.display(), "uninstalling wick app"));
+  remove_shims(&app.bin)?;
+  registry.save().await?;
+
+  let text = format!("uninstalled {} from {}", app.name, app.bin.display());
+  Ok(StructuredOutput::new(text, json!(app)))
+}
+
+/// Delete the shim written for an app, ignoring files that are already gone.
+pub(crate) fn remove_shims(bin_path: &Path) -> Result<()> {
+  for path in shim_paths(bin_path) {
+    match std::fs::remove_file(&path) {
+      Ok(()) => debug!(path = %path.display(), "removed shim"),
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+      Err(e) => return Err(anyhow!("could not remove {}: {}", path.display(), e)),
+    }
+  }
+  Ok(())
+}
@@ -0,0 +1,93 @@
+use anyhow::Result;
+use clap::Args;
+use serde_json::json;
+use structured_output::StructuredOutput;
+
+use crate::commands::install::{install, pull_pinned};
+use crate::commands::uninstall::remove_shims;
+use crate::installs::InstallRegistry;
+use crate::utils::reconcile_fetch_options;
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  #[clap(flatten)]
+  pub(crate) oci: crate::options::oci::OciOptions,
+
+  /// The installed app to upgrade. Upgrades all installed apps by default.
+  #[clap(action)]
+  app: Option<String>,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let mut registry = InstallRegistry::load_global().await?;
+
+  let apps = match &opts.app {
+    Some(name) => vec![registry
+      .get(name)
+      .cloned()
+      .ok_or_else(|| anyhow!("'{}' is not installed, see `wick install --list`", name))?],
+    None => registry.apps().to_vec(),
+  };
+
+  let mut lines = Vec::new();
+  let mut results = Vec::new();
+  for app in apps {
+    let Some(previous) = app.digest.clone() else {
+      lines.push(format!("{}: installed from a local path, skipping", app.name));
+      results.push(json!({"name": app.name, "upgraded": false}));
+      continue;
+    };
+
+    span.in_scope(|| info!(app = app.name, reference = app.reference, "checking for upgrade"));
+    let oci_opts = reconcile_fetch_options(&app.reference, &settings, opts.oci.clone(), None);
+    let (package, digest) = pull_pinned(&app.reference, oci_opts).await?;
+
+    if digest == previous {
+      lines.push(format!("{}: {} is up to date ({})", app.name, app.version, digest));
+      results.push(json!({"name": app.name, "upgraded": false, "digest": digest}));
+      continue;
+    }
+
+    let bin_dir = app
+      .bin
+      .parent()
+      .ok_or_else(|| anyhow!("invalid shim path {}", app.bin.display()))?;
+    let upgraded = install(app.reference.clone(), Some(digest), &package, bin_dir).await?;
+    if upgraded.name != app.name {
+      span.in_scope(|| warn!(from = app.name, to = upgraded.name, "upgraded app was renamed"));
+      registry.remove(&app.name);
+      remove_shims(&app.bin)?;
+    }
+
+    lines.push(format!(
+      "{}: {} -> {} ({} -> {})",
+      app.name,
+      app.version,
+      upgraded.version,
+      previous,
+      upgraded.digest.as_deref().unwrap_or_default()
+    ));
+    results.push(json!({
+      "name": app.name,
+      "upgraded": true,
+      "from": {"version": app.version, "digest": previous},
+      "to": {"version": upgraded.version, "digest": upgraded.digest},
+    }));
+    registry.insert(upgraded);
+  }
+  registry.save().await?;
+
+  let text = if lines.is_empty() {
+    "no apps installed".to_owned()
+  } else {
+    lines.join("\n")
+  };
+
+  Ok(StructuredOutput::new(text, json!({ "apps": results })))
+}
@@ -0,0 +1,99 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Args;
+use serde_json::json;
+use structured_output::StructuredOutput;
+use tracing::Instrument;
+use wick_config::WickConfiguration;
+use wick_oci_utils::{Lock, LockFile, LockMode, OciLayout, OciOptions};
+
+use crate::utils::{lockfile_path, reconcile_fetch_options};
+
+#[derive(Debug, Clone, Args)]
+#[clap(rename_all = "kebab-case")]
+#[group(skip)]
+pub(crate) struct Options {
+  #[clap(flatten)]
+  pub(crate) oci: crate::options::oci::OciOptions,
+
+  /// Path or OCI url to the manifest whose packages to vendor.
+  #[clap(action)]
+  pub(crate) path: String,
+
+  /// The OCI image layout directory to write packages to.
+  #[clap(action)]
+  pub(crate) dir: PathBuf,
+}
+
+pub(crate) async fn handle(
+  opts: Options,
+  settings: wick_settings::Settings,
+  span: tracing::Span,
+) -> Result<StructuredOutput> {
+  let locked = opts.oci.locked;
+  let options = reconcile_fetch_options(&opts.path, &settings, opts.oci, None);
+
+  let layout = OciLayout::new(&opts.dir);
+  let (resolved, vendored) = vendor_packages(&opts.path, options, locked, &layout, &span).await?;
+
+  let mut text = format!(
+    "Vendored {} package(s) into {}\n",
+    resolved.packages.len(),
+    opts.dir.display()
+  );
+  let mut packages = serde_json::Map::new();
+  for (reference, digest) in vendored {
+    writeln!(text, "  {} => {}", reference, digest)?;
+    packages.insert(reference, json!(digest));
+  }
+
+  let json = json!({
+    "dir": opts.dir.to_string_lossy(),
+    "packages": packages,
+  });
+
+  Ok(StructuredOutput::new(text, json))
+}
+
+/// Copy every remote package the manifest at `path` imports, directly or through other packages, into `layout`.
+///
+/// Returns the [LockFile] the packages resolved to and the registry digest of each vendored reference.
+pub(crate) async fn vendor_packages(
+  path: &str,
+  mut options: OciOptions,
+  locked: bool,
+  layout: &OciLayout,
+  span: &tracing::Span,
+) -> Result<(LockFile, Vec<(String, String)>)> {
+  let lockfile = lockfile_path(path).filter(|p| p.exists());
+  let pinned = match &lockfile {
+    Some(lockfile) => LockFile::load(lockfile).await?,
+    None if locked => anyhow::bail!("--locked was passed but {} has no {}", path, LockFile::FILENAME),
+    None => LockFile::default(),
+  };
+  let mode = if locked { LockMode::Locked } else { LockMode::Verify };
+  let lock = Lock::new(pinned, mode);
+  options.set_lock(Some(lock.clone()));
+
+  span.in_scope(|| info!(path, dir = %layout.root().display(), "resolving packages to vendor"));
+  WickConfiguration::fetch_uninitialized_tree(path, options.clone())
+    .instrument(span.clone())
+    .await?;
+
+  // Copy exactly what the tree resolved to, even if a tag has moved since.
+  let resolved = lock.resolved();
+  options.set_lock(Some(Lock::new(resolved.clone(), LockMode::Locked)));
+
+  let mut vendored = Vec::new();
+  for reference in resolved.packages.keys() {
+    span.in_scope(|| debug!(reference, "vendoring package"));
+    let digest = wick_oci_utils::package::vendor(reference, layout, &options)
+      .instrument(span.clone())
+      .await?;
+    vendored.push((reference.clone(), digest));
+  }
+
+  Ok((resolved, vendored))
+}
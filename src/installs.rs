@@ -0,0 +1,164 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::io::{mkdirp, read_to_string, write_bytes};
+
+static REGISTRY_FILE: &str = "installs.json";
+
+/// An app installed with `wick install`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct InstalledApp {
+  /// The name of the app and its shim.
+  pub(crate) name: String,
+  /// The OCI reference or local path the app was installed from.
+  pub(crate) reference: String,
+  /// The digest of the package the app was installed from. Only set for apps installed from a registry.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) digest: Option<String>,
+  /// The version of the installed app.
+  pub(crate) version: String,
+  /// The path of the app's shim, without any platform-specific extension.
+  pub(crate) bin: PathBuf,
+  /// When the app was installed or last upgraded.
+  pub(crate) installed_at: DateTime<Utc>,
+}
+
+impl InstalledApp {
+  /// The location the app's shim runs, pinned to the installed digest when there is one.
+  pub(crate) fn target(&self) -> Result<String> {
+    self
+      .digest
+      .as_ref()
+      .map_or_else(|| Ok(self.reference.clone()), |digest| pin(&self.reference, digest))
+  }
+}
+
+/// The record of apps installed with `wick install`, stored in wick's global data directory.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct InstallRegistry {
+  #[serde(skip)]
+  path: PathBuf,
+  apps: Vec<InstalledApp>,
+}
+
+impl InstallRegistry {
+  /// Load the registry from wick's global data directory.
+  pub(crate) async fn load_global() -> Result<Self> {
+    Self::load(wick_xdg::Settings::new().data().join(REGISTRY_FILE)).await
+  }
+
+  /// Load the registry from the passed file. A missing file is an empty registry.
+  pub(crate) async fn load(path: PathBuf) -> Result<Self> {
+    if !path.exists() {
+      return Ok(Self { path, apps: Vec::new() });
+    }
+    let json = read_to_string(&path).await?;
+    let registry: Self =
+      serde_json::from_str(&json).map_err(|e| anyhow!("could not parse {}: {}", path.display(), e))?;
+    Ok(Self { path, ..registry })
+  }
+
+  /// Write the registry back to the file it was loaded from.
+  pub(crate) async fn save(&self) -> Result<()> {
+    if let Some(dir) = self.path.parent() {
+      mkdirp(dir).await?;
+    }
+    write_bytes(&self.path, serde_json::to_string_pretty(self)?).await?;
+    Ok(())
+  }
+
+  /// The installed apps, ordered by name.
+  pub(crate) fn apps(&self) -> &[InstalledApp] {
+    &self.apps
+  }
+
+  /// Get an installed app by name.
+  pub(crate) fn get(&self, name: &str) -> Option<&InstalledApp> {
+    self.apps.iter().find(|app| app.name == name)
+  }
+
+  /// Record an installed app, replacing any app with the same name.
+  pub(crate) fn insert(&mut self, app: InstalledApp) -> Option<InstalledApp> {
+    let previous = self.remove(&app.name);
+    let index = self.apps.partition_point(|a| a.name < app.name);
+    self.apps.insert(index, app);
+    previous
+  }
+
+  /// Remove an installed app by name.
+  pub(crate) fn remove(&mut self, name: &str) -> Option<InstalledApp> {
+    let index = self.apps.iter().position(|app| app.name == name)?;
+    Some(self.apps.remove(index))
+  }
+}
+
+/// Replace the tag of an OCI reference with the passed digest.
+pub(crate) fn pin(reference: &str, digest: &str) -> Result<String> {
+  let reference = wick_oci_utils::parse_reference(reference)?;
+  Ok(format!(
+    "{}/{}@{}",
+    reference.registry(),
+    reference.repository(),
+    digest
+  ))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn app(name: &str, digest: Option<&str>) -> InstalledApp {
+    InstalledApp {
+      name: name.to_owned(),
+      reference: format!("registry.example.com/acme/{}:latest", name),
+      digest: digest.map(ToOwned::to_owned),
+      version: "0.1.0".to_owned(),
+      bin: PathBuf::from("/usr/local/bin").join(name),
+      installed_at: Utc::now(),
+    }
+  }
+
+  #[test]
+  fn test_pin() -> Result<()> {
+    assert_eq!(
+      pin("registry.example.com/acme/app:1.0.0", "sha256:abc123")?,
+      "registry.example.com/acme/app@sha256:abc123"
+    );
+    assert_eq!(
+      pin("localhost:5000/acme/app:latest", "sha256:abc123")?,
+      "localhost:5000/acme/app@sha256:abc123"
+    );
+    assert_eq!(app("app", None).target()?, "registry.example.com/acme/app:latest");
+    assert_eq!(
+      app("app", Some("sha256:abc123")).target()?,
+      "registry.example.com/acme/app@sha256:abc123"
+    );
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn test_registry_roundtrip() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("wick-installs-{}.json", std::process::id()));
+    let mut registry = InstallRegistry::load(path.clone()).await?;
+    assert!(registry.apps().is_empty());
+
+    registry.insert(app("zeta", Some("sha256:1")));
+    registry.insert(app("alpha", None));
+    let previous = registry.insert(app("zeta", Some("sha256:2")));
+    assert_eq!(previous.and_then(|p| p.digest), Some("sha256:1".to_owned()));
+    registry.save().await?;
+
+    let mut loaded = InstallRegistry::load(path.clone()).await?;
+    let names: Vec<_> = loaded.apps().iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, ["alpha", "zeta"]);
+    assert_eq!(loaded.get("zeta"), registry.get("zeta"));
+
+    assert!(loaded.remove("alpha").is_some());
+    assert!(loaded.remove("alpha").is_none());
+    std::fs::remove_file(path)?;
+    Ok(())
+  }
+}
@@ -59,6 +59,16 @@ pub(crate) async fn get_key(directory: Option<PathBuf>, path: PathBuf) -> Result
   Ok(kp)
 }
 
+/// Get a key from a seed or from the path of a file holding one.
+pub(crate) async fn read_key(key: &str) -> Result<KeyPair> {
+  let seed = if Path::new(key).is_file() {
+    read_to_string(key).await?
+  } else {
+    key.to_owned()
+  };
+  KeyPair::from_seed(seed.trim()).map_err(|e| anyhow!("invalid signing key: {}", e))
+}
+
 fn get_key_home() -> PathBuf {
   let env = wick_xdg::Settings::new();
   env.config_dir().join("keys")
@@ -94,7 +94,9 @@ use anyhow::Result;
 extern crate tracing;
 #[macro_use]
 extern crate anyhow;
+mod bundle;
 mod commands;
+mod installs;
 mod utils;
 mod wasm;
 use clap::Parser;
@@ -148,10 +150,13 @@ fn main() {
         (serde_json::json!({"success":true,"output":&output.json}), 0)
       };
 
+      // Output can include rendered configuration or settings so redact anything marked as secret.
+      let json = wick_secrets::redact_json(json);
+
       if options.json {
         let _ = writeln!(out, "{}", serde_json::to_string(&json).unwrap());
       } else {
-        let output = output.to_string();
+        let output = wick_secrets::redact(&output.to_string()).into_owned();
         if !output.is_empty() {
           let _ = writeln!(out, "{}", output);
         }
@@ -159,7 +164,8 @@ fn main() {
       code
     }
     Err((options, e)) => {
-      let json = serde_json::json!({"error": e.to_string(),"success":false});
+      let e = wick_secrets::redact(&e.to_string()).into_owned();
+      let json = serde_json::json!({"error": e,"success":false});
 
       if options.json {
         let _ = writeln!(out, "{}", serde_json::to_string(&json).unwrap());
@@ -191,6 +197,13 @@ async fn async_start() -> Result<(GlobalOptions, StructuredOutput), (GlobalOptio
 
   let mut args = std::env::args().collect::<Vec<_>>();
 
+  // An executable created with `wick bundle --executable` runs its bundle and passes every argument to the app.
+  if let Some(exe) = bundle::embedded() {
+    let app_args = args.split_off(1);
+    args.extend(["run".to_owned(), exe.to_string_lossy().to_string(), "--".to_owned()]);
+    args.extend(app_args);
+  }
+
   let matches = <Cli as clap::CommandFactory>::command().try_get_matches_from(&args);
   if let Err(e) = matches {
     // If we have an invalid subcommand...
@@ -208,6 +221,11 @@ async fn async_start() -> Result<(GlobalOptions, StructuredOutput), (GlobalOptio
   let settings = wick_settings::Settings::new();
   apply_log_settings(&settings, &mut cli.logging);
 
+  // Secrets are loaded before the logger starts so nothing is logged before they can be redacted.
+  if let Err(e) = settings.load_secrets() {
+    return Err((options, anyhow!("could not load secrets: {}", e)));
+  }
+
   let logger_opts: wick_logger::LoggingOptions = cli.logging.name(BIN_NAME).into();
 
   // Initialize the global logger
@@ -257,13 +275,24 @@ async fn async_main(span: Span, cli: Cli, settings: wick_settings::Settings) ->
       commands::registry::SubCommands::Login(cmd) => commands::registry::login::handle(cmd, settings, span).await,
       commands::registry::SubCommands::Manifest(cmd) => commands::registry::manifest::handle(cmd, settings, span).await,
     },
+    CliCommand::Secret(cmd) => match cmd {
+      commands::secret::SubCommands::Gen(cmd) => commands::secret::gen::handle(cmd, settings, span).await,
+      commands::secret::SubCommands::Encrypt(cmd) => commands::secret::encrypt::handle(cmd, settings, span).await,
+    },
     CliCommand::Rpc(cmd) => match cmd {
       commands::rpc::SubCommands::Invoke(cmd) => commands::rpc::invoke::handle(cmd, settings, span).await,
       commands::rpc::SubCommands::List(cmd) => commands::rpc::list::handle(cmd, settings, span).await,
       commands::rpc::SubCommands::Stats(cmd) => commands::rpc::stats::handle(cmd, settings, span).await,
+      commands::rpc::SubCommands::LogFilter(cmd) => commands::rpc::log_filter::handle(cmd, settings, span).await,
     },
     CliCommand::Query(cmd) => commands::query::handle(cmd, settings, span).await,
     CliCommand::Install(cmd) => commands::install::handle(cmd, settings, span).await,
+    CliCommand::Upgrade(cmd) => commands::upgrade::handle(cmd, settings, span).await,
+    CliCommand::Uninstall(cmd) => commands::uninstall::handle(cmd, settings, span).await,
+    CliCommand::Lock(cmd) => commands::lock::handle(cmd, settings, span).await,
+    CliCommand::Vendor(cmd) => commands::vendor::handle(cmd, settings, span).await,
+    CliCommand::Bundle(cmd) => commands::bundle::handle(cmd, settings, span).await,
+    CliCommand::Lsp(cmd) => commands::lsp::handle(cmd, settings, span).await,
     CliCommand::New(cmd) => match cmd {
       new::SubCommands::Component(cmd) => match cmd {
         new::component::SubCommands::Http(cmd) => new::component::http::handle(cmd, settings, span).await,
@@ -1,6 +1,4 @@
-use once_cell::sync::Lazy;
-use regex::Regex;
-use wick_logger::{FilterOptions, LogLevel, LogModifier, LoggingOptionsBuilder, TargetLevel};
+use wick_logger::{FilterOptions, LogLevel, LoggingOptionsBuilder, TargetLevel, DEFAULT_FILTER};
 
 #[derive(clap::Args, Debug, Default, Clone)]
 /// Logging options that can be used directly or via [Args].
@@ -33,6 +31,14 @@ pub(crate) struct LoggingOptions {
   #[clap(long = "otel-filter", env = "OTEL_FILTER", global = true, action)]
   pub(crate) tel_filter: Option<String>,
 
+  /// The format of events posted to STDERR.
+  #[clap(long = "log-format", env = "LOG_FORMAT", global = true, value_parser = ["text", "json"], action)]
+  pub(crate) log_format: Option<String>,
+
+  /// Where and how to write log files, configured in the settings file.
+  #[clap(skip)]
+  pub(crate) logfile: Option<wick_settings::LogFileSettings>,
+
   /// The application doing the logging.
   #[clap(skip)]
   pub(crate) app_name: String,
@@ -46,52 +52,43 @@ impl LoggingOptions {
   }
 }
 
-static DEFAULT_FILTER: Lazy<Vec<TargetLevel>> = Lazy::new(|| {
-  vec![
-    TargetLevel::lte("flow", wick_logger::LogLevel::Warn),
-    TargetLevel::lte("wick_wascap", wick_logger::LogLevel::Warn),
-    TargetLevel::lte("wasmrs", wick_logger::LogLevel::Error),
-    TargetLevel::lte("wasmrs_runtime", wick_logger::LogLevel::Error),
-    TargetLevel::lte("wasmrs_wasmtime", wick_logger::LogLevel::Error),
-  ]
-});
-
-static RULE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new("(\\w+)\\s*(<|<=|>|>=|!=|==|=)\\s*(\\w+)").unwrap());
-
 fn parse_logstr(default_level: LogLevel, default_filter: &[TargetLevel], logstr: &str) -> FilterOptions {
-  let parts: Vec<(LogModifier, Option<&str>, LogLevel)> = logstr
-    .split(',')
-    .filter_map(|s| {
-      let s = s.trim();
-      if s.is_empty() {
-        return None;
-      }
+  FilterOptions::parse(logstr, default_level, default_filter).unwrap_or_else(|e| {
+    // The logger isn't initialized yet so this can't be logged.
+    eprintln!("{}, using the default filter", e);
+    FilterOptions::new(default_level, default_filter.to_vec())
+  })
+}
 
-      if !s.contains(['<', '>', '=', '!']) {
-        return Some((LogModifier::LessThanOrEqualTo, None, s.parse().ok()?));
+const DEFAULT_MAX_LOG_SIZE: u64 = 10 * 1024 * 1024;
+
+fn logfile_options(settings: &wick_settings::LogFileSettings) -> wick_logger::LogFileOptions {
+  let mut builder = wick_logger::LogFileOptionsBuilder::default();
+  builder
+    .directory(settings.dir.clone())
+    .max_files(settings.max_files)
+    .rotation(match settings.rotation {
+      wick_settings::LogRotation::Never => wick_logger::LogRotation::Never,
+      wick_settings::LogRotation::Hourly => wick_logger::LogRotation::Hourly,
+      wick_settings::LogRotation::Daily => wick_logger::LogRotation::Daily,
+      wick_settings::LogRotation::Size => {
+        wick_logger::LogRotation::Size(settings.max_size.unwrap_or(DEFAULT_MAX_LOG_SIZE))
       }
-      let parts = RULE_REGEX.captures(s)?;
-
-      let target = parts.get(1)?.as_str();
-      let modifier: LogModifier = parts.get(2)?.as_str().parse().ok()?;
-      let level: LogLevel = parts.get(3)?.as_str().parse().ok()?;
-
-      Some((modifier, Some(target), level))
-    })
-    .collect();
-
-  let global_level = parts
-    .iter()
-    .find(|(_, target, _)| target.is_none())
-    .map_or_else(|| default_level, |(_, _, level)| *level);
-
-  let filter = parts
-    .iter()
-    .filter_map(|(modifier, target, level)| target.map(|target| TargetLevel::new(target, *level, *modifier)))
-    .collect::<Vec<_>>();
+    });
+  if let Some(prefix) = &settings.prefix {
+    builder.prefix(prefix.clone());
+  }
+  if let Some(format) = settings.format {
+    builder.format(log_format(format));
+  }
+  builder.build().unwrap()
+}
 
-  // If the filter had inclusion rules, use those. Otherwise, use the default.
-  FilterOptions::new(global_level, [filter, default_filter.to_vec()].concat())
+const fn log_format(format: wick_settings::LogFormat) -> wick_logger::LogFormat {
+  match format {
+    wick_settings::LogFormat::Text => wick_logger::LogFormat::Text,
+    wick_settings::LogFormat::Json => wick_logger::LogFormat::Json,
+  }
 }
 
 impl From<&LoggingOptions> for wick_logger::LoggingOptions {
@@ -117,14 +114,34 @@ impl From<&LoggingOptions> for wick_logger::LoggingOptions {
       &DEFAULT_FILTER,
       value.tel_filter.as_deref().unwrap_or_default(),
     );
+
+    let logfile_opts = parse_logstr(
+      global_level,
+      &DEFAULT_FILTER,
+      value
+        .logfile
+        .as_ref()
+        .and_then(|l| l.filter.as_deref())
+        .unwrap_or_default(),
+    );
+
+    let format: wick_logger::LogFormat = value
+      .log_format
+      .as_deref()
+      .and_then(|f| f.parse().ok())
+      .unwrap_or_default();
+
     LoggingOptionsBuilder::default()
       .verbose(value.verbose == 1)
       .otlp_endpoint(value.otlp_endpoint.clone())
       .app_name(value.app_name.clone())
+      .format(format)
+      .logfile(value.logfile.as_ref().map(logfile_options))
       .levels(
         wick_logger::LogFiltersBuilder::default()
           .telemetry(otel_opts)
           .stderr(stderr_opts)
+          .logfile(logfile_opts)
           .build()
           .unwrap(),
       )
@@ -161,6 +178,12 @@ pub(crate) fn apply_log_settings(settings: &wick_settings::Settings, options: &m
   if options.otlp_endpoint.is_none() {
     options.otlp_endpoint = settings.trace.otlp.clone();
   }
+  if options.log_format.is_none() && settings.trace.format == wick_settings::LogFormat::Json {
+    options.log_format = Some("json".to_owned());
+  }
+  if options.logfile.is_none() {
+    options.logfile = settings.trace.logfile.clone();
+  }
 }
 
 #[cfg(test)]
@@ -7,7 +7,8 @@ impl From<OciOptions> for wick_config::FetchOptions {
       .set_allow_latest(value.allow_latest)
       .set_allow_insecure(value.insecure_registries.clone())
       .set_username(value.username)
-      .set_password(value.password);
+      .set_password(value.password)
+      .set_locked(value.locked);
 
     fetch_options
   }
@@ -32,6 +33,10 @@ pub(crate) struct OciOptions {
   #[clap(long = "allow-latest", action)]
   pub(crate) allow_latest: bool,
 
+  /// Refuse to fetch packages that the manifest's wick.lock does not pin.
+  #[clap(long = "locked", action)]
+  pub(crate) locked: bool,
+
   /// Force overwriting of files.
   #[clap(long = "force", short = 'f', action)]
   pub(crate) force: bool,
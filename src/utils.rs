@@ -1,7 +1,7 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use futures::StreamExt;
 use liquid_json::LiquidJsonValue;
 use serde_json::Value;
@@ -9,16 +9,17 @@ use tracing::{Instrument, Span};
 use wick_component_cli::options::DefaultCliOptions;
 use wick_config::config::{ComponentConfiguration, ConfigurationTreeNode, HttpConfigBuilder, LiquidJsonConfig};
 use wick_config::{AssetReference, WickConfiguration};
-use wick_oci_utils::{OciOptions, OnExisting};
+use wick_oci_utils::{Lock, LockFile, LockMode, OciOptions, OnExisting, SignatureMode, SignaturePolicy};
 use wick_packet::{InherentData, Packet, PacketExt, PacketStream, RuntimeConfig};
 use wick_settings::Credential;
 
 pub(crate) async fn fetch_wick_config(
   path: &str,
-  fetch_opts: OciOptions,
+  mut fetch_opts: OciOptions,
   runtime_config: Option<RuntimeConfig>,
   span: Span,
 ) -> Result<WickConfiguration> {
+  prefetch_packages(path, &mut fetch_opts, &span).await?;
   let mut builder = WickConfiguration::fetch(path, fetch_opts.clone())
     .instrument(span.clone())
     .await?;
@@ -31,10 +32,11 @@ pub(crate) async fn fetch_wick_config(
 
 pub(crate) async fn fetch_wick_tree(
   path: &str,
-  fetch_opts: OciOptions,
+  mut fetch_opts: OciOptions,
   runtime_config: Option<RuntimeConfig>,
   span: Span,
 ) -> Result<ConfigurationTreeNode<WickConfiguration>> {
+  prefetch_packages(path, &mut fetch_opts, &span).await?;
   let env: HashMap<String, String> = std::env::vars().collect();
   let config = WickConfiguration::fetch_tree(path, runtime_config, Some(env), fetch_opts.clone())
     .instrument(span.clone())
@@ -43,6 +45,59 @@ pub(crate) async fn fetch_wick_tree(
   Ok(config)
 }
 
+/// Get the path of the lockfile that belongs to the manifest at `path`, if `path` is a local file.
+pub(crate) fn lockfile_path(path: &str) -> Option<PathBuf> {
+  let path = Path::new(path);
+  path.is_file().then(|| path.with_file_name(LockFile::FILENAME))
+}
+
+/// Fetch the remote packages a manifest imports before anything runs.
+///
+/// Packages are verified against the `wick.lock` next to a local manifest and read from the configured mirror, if
+/// any. Later fetches of the same references, including the runtime's, are served from the cache this fills.
+pub(crate) async fn prefetch_packages(path: &str, options: &mut OciOptions, span: &Span) -> Result<()> {
+  if options.lock().is_some() {
+    return Ok(());
+  }
+  let lockfile = lockfile_path(path).filter(|p| p.exists());
+  let lock = match &lockfile {
+    Some(lockfile) => {
+      let mode = if *options.locked() {
+        LockMode::Locked
+      } else {
+        LockMode::Verify
+      };
+      let lock = Lock::new(LockFile::load(lockfile).await?, mode);
+      options.set_lock(Some(lock.clone()));
+      span.in_scope(|| debug!(lockfile = %lockfile.display(), ?mode, "verifying packages against lockfile"));
+      Some(lock)
+    }
+    None if *options.locked() => bail!("--locked was passed but {} has no {}", path, LockFile::FILENAME),
+    None => None,
+  };
+  if lock.is_none() && options.mirror().is_none() {
+    return Ok(());
+  }
+
+  WickConfiguration::fetch_uninitialized_tree(path, options.clone())
+    .instrument(span.clone())
+    .await?;
+
+  if let (Some(lock), Some(lockfile)) = (lock, lockfile) {
+    let unpinned = lock.unpinned();
+    if !unpinned.is_empty() {
+      span.in_scope(|| {
+        warn!(
+          packages = ?unpinned,
+          "packages are not pinned in {}, run `wick lock` to pin them",
+          lockfile.display()
+        );
+      });
+    }
+  }
+  Ok(())
+}
+
 pub(crate) fn merge_config(
   def: ComponentConfiguration,
   local_cli_opts: &crate::options::oci::OciOptions,
@@ -123,9 +178,12 @@ pub(crate) fn get_auth_for_scope(
     username = override_username.map(|v| v.to_owned());
   }
 
-  if override_password.is_some() {
+  if let Some(override_password) = override_password {
     debug!("overriding password from arguments.");
-    password = override_password.map(|v| v.to_owned());
+    if let Err(e) = wick_secrets::track("The --password argument", override_password) {
+      warn!("{}", e);
+    }
+    password = Some(override_password.to_owned());
   }
 
   (username, password)
@@ -148,6 +206,9 @@ pub(crate) fn reconcile_fetch_options(
     .set_allow_latest(true)
     .set_username(username)
     .set_password(password)
+    .set_locked(opts.locked)
+    .set_mirror(settings.mirror.clone())
+    .set_signatures(signature_policies(settings))
     .set_on_existing(if opts.force {
       OnExisting::Overwrite
     } else {
@@ -163,6 +224,21 @@ pub(crate) fn reconcile_fetch_options(
   oci_opts
 }
 
+/// Convert the signature policies from the settings file to the policies pulls are checked against.
+pub(crate) fn signature_policies(settings: &wick_settings::Settings) -> Vec<SignaturePolicy> {
+  settings
+    .signatures
+    .iter()
+    .map(|policy| {
+      let mode = match policy.mode {
+        wick_settings::SignatureMode::Warn => SignatureMode::Warn,
+        wick_settings::SignatureMode::Enforce => SignatureMode::Enforce,
+      };
+      SignaturePolicy::new(policy.scope.clone(), policy.issuers.clone(), mode)
+    })
+    .collect()
+}
+
 fn log_override<T: std::fmt::Debug>(field: &str, from: &mut T, to: T) {
   debug!(%field, ?from, ?to, "overriding manifest value");
   *from = to;
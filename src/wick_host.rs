@@ -9,7 +9,7 @@ use wick_host::{AppHost, AppHostBuilder, ComponentHostBuilder, WickHost};
 use wick_packet::RuntimeConfig;
 
 use crate::options::oci::OciOptions as WickOciOptions;
-use crate::utils::{get_auth_for_scope, merge_config};
+use crate::utils::{get_auth_for_scope, merge_config, prefetch_packages, signature_policies};
 
 pub(crate) async fn build_host(
   path: &str,
@@ -28,7 +28,11 @@ pub(crate) async fn build_host(
   let mut fetch_opts: wick_oci_utils::OciOptions = oci.clone().into();
   fetch_opts.set_username(username).set_password(password);
 
-  fetch_opts.set_cache_dir(env.global().cache().clone());
+  fetch_opts
+    .set_cache_dir(env.global().cache().clone())
+    .set_mirror(settings.mirror.clone())
+    .set_signatures(signature_policies(&settings));
+  prefetch_packages(path, &mut fetch_opts, &span).await?;
 
   let mut manifest = WickConfiguration::fetch(path, fetch_opts).await?;
   manifest.set_root_config(root_config);